@@ -68,6 +68,8 @@ pub enum ArrayError {
     NotImplemented,
     /// Matrix is singular
     SingularMatrix,
+    /// Matrix is not positive definite
+    NotPositiveDefinite,
 }
 
 impl std::error::Error for ArrayError {}
@@ -90,6 +92,7 @@ impl std::fmt::Display for ArrayError {
             Self::MustBeOneOf { value1, value2 } => write!(f, "`{value1}` must be one of `{value2}`"),
             Self::NotImplemented => write!(f, "not implemented"),
             Self::SingularMatrix => write!(f, "matrix is singular"),
+            Self::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
         }
     }
 }
@@ -0,0 +1,180 @@
+use std::f64::consts::PI;
+
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+type C64 = Complex<f64>;
+
+/// Computes discrete fourier transform of a sequence of any length.
+/// Composite lengths use mixed-radix Cooley-Tukey, large prime lengths use Bluestein's algorithm
+pub(crate) fn fft_vec(input: &[C64], inverse: bool) -> Vec<C64> {
+    let n = input.len();
+    if n <= 1 { return input.to_vec() }
+    let sign = if inverse { 1. } else { -1. };
+    let twiddles = (0..n)
+        .map(|k| unit_root(k, n, sign))
+        .collect::<Vec<C64>>();
+    mixed_radix(input, &twiddles, inverse)
+}
+
+fn unit_root(k: usize, n: usize, sign: f64) -> C64 {
+    if (4 * k).is_multiple_of(n) {
+        match 4 * k / n {
+            0 => C64::new(1., 0.),
+            1 => C64::new(0., sign),
+            2 => C64::new(-1., 0.),
+            _ => C64::new(0., -sign),
+        }
+    } else {
+        C64::from_polar(1., sign * 2. * PI * k.to_f64() / n.to_f64())
+    }
+}
+
+/// Computes full linear convolution of two real sequences via fft
+pub(crate) fn fft_convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    let pad = |v: &[f64]| v.iter()
+        .map(|&x| C64::new(x, 0.))
+        .chain(std::iter::repeat(C64::new(0., 0.)))
+        .take(size)
+        .collect::<Vec<C64>>();
+    let product = fft_vec(&pad(a), false).into_iter()
+        .zip(fft_vec(&pad(b), false))
+        .map(|(x, y)| x * y)
+        .collect::<Vec<C64>>();
+    fft_vec(&product, true).into_iter()
+        .take(len)
+        .map(|c| c.re / size.to_f64())
+        .collect()
+}
+
+fn mixed_radix(input: &[C64], twiddles: &[C64], inverse: bool) -> Vec<C64> {
+    let n = input.len();
+    if n <= 1 { return input.to_vec() }
+    let stride = twiddles.len() / n;
+    let radix = (2..=n).take_while(|p| p * p <= n).find(|p| n.is_multiple_of(*p)).unwrap_or(n);
+
+    if radix == n {
+        return if n <= 32 { dft(input, twiddles, stride) }
+        else { bluestein(input, inverse) }
+    }
+
+    let m = n / radix;
+    let subs = (0..radix)
+        .map(|r| input.iter().skip(r).step_by(radix).copied().collect::<Vec<C64>>())
+        .map(|sub| mixed_radix(&sub, twiddles, inverse))
+        .collect::<Vec<Vec<C64>>>();
+    (0..n)
+        .map(|k| subs.iter()
+            .enumerate()
+            .fold(C64::new(0., 0.), |acc, (r, sub)| acc + sub[k % m] * twiddles[(r * k % n) * stride]))
+        .collect()
+}
+
+fn dft(input: &[C64], twiddles: &[C64], stride: usize) -> Vec<C64> {
+    let n = input.len();
+    (0..n)
+        .map(|k| input.iter()
+            .enumerate()
+            .fold(C64::new(0., 0.), |acc, (j, &x)| acc + x * twiddles[(j * k % n) * stride]))
+        .collect()
+}
+
+fn bluestein(input: &[C64], inverse: bool) -> Vec<C64> {
+    let n = input.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1. } else { -1. };
+    let chirp = (0..n)
+        .map(|k| C64::from_polar(1., sign * PI * ((k * k) % (2 * n)).to_f64() / n.to_f64()))
+        .collect::<Vec<C64>>();
+
+    let mut a = vec![C64::new(0., 0.); m];
+    input.iter().zip(&chirp).enumerate()
+        .for_each(|(k, (&x, &c))| a[k] = x * c);
+    let mut b = vec![C64::new(0., 0.); m];
+    b[0] = chirp[0].conj();
+    (1..n).for_each(|k| {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    });
+
+    let product = fft_vec(&a, false).into_iter()
+        .zip(fft_vec(&b, false))
+        .map(|(x, y)| x * y)
+        .collect::<Vec<C64>>();
+    fft_vec(&product, true).into_iter()
+        .zip(&chirp)
+        .map(|(x, &c)| (x * c).scale(1. / m.to_f64()))
+        .collect()
+}
+
+/// Applies function to every 1-D lane of elements along given axis.
+/// The function receives a lane of `shape[axis]` elements and must return a lane of `len` elements
+pub(crate) fn map_lanes<T: Clone, S: Clone, F>(elements: &[T], shape: &[usize], axis: usize, len: usize, f: F) -> (Vec<S>, Vec<usize>)
+    where F: Fn(Vec<T>) -> Vec<S> {
+    let inner = shape[axis + 1..].iter().product::<usize>();
+    let outer = shape[..axis].iter().product::<usize>();
+    let axis_len = shape[axis];
+
+    let mut new_shape = shape.to_vec();
+    new_shape[axis] = len;
+    let lanes = (0..outer)
+        .flat_map(|o| (0..inner).map(move |i| (o, i)))
+        .map(|(o, i)| f((0..axis_len)
+            .map(|j| elements[o * axis_len * inner + j * inner + i].clone())
+            .collect()))
+        .collect::<Vec<Vec<S>>>();
+    let result = (0..outer)
+        .flat_map(|o| (0..len).flat_map(move |j| (0..inner).map(move |i| (o * inner + i, j))))
+        .map(|(lane, j)| lanes[lane][j].clone())
+        .collect();
+
+    (result, new_shape)
+}
+
+/// Resolves and validates the axis and length of 1-D transform
+pub(crate) fn resolve_axis<T: ArrayElement>(array: &Array<T>, n: Option<usize>, axis: Option<isize>) -> Result<(usize, usize), ArrayError> {
+    array.is_dim_unsupported(&[0])?;
+    let axis = array.normalize_axis(axis.unwrap_or(-1));
+    array.axis_in_bounds(axis)?;
+    let n = n.unwrap_or(array.get_shape()?[axis]);
+    if n == 0 {
+        return Err(ArrayError::ParameterError { param: "`n`", message: "invalid number of data points" })
+    }
+    Ok((axis, n))
+}
+
+/// Resolves and validates the axes and lengths of n-D transform
+pub(crate) fn resolve_axes<T: ArrayElement>(array: &Array<T>, s: Option<Vec<usize>>, axes: Option<Vec<isize>>) -> Result<Vec<(usize, usize)>, ArrayError> {
+    array.is_dim_unsupported(&[0])?;
+    let ndim = array.ndim()?;
+    let axes = match (&s, axes) {
+        (_, Some(axes)) => axes,
+        (Some(s), None) => (ndim.to_isize() - s.len().to_isize()..ndim.to_isize()).collect(),
+        (None, None) => (0..ndim.to_isize()).collect(),
+    };
+    let axes = axes.into_iter()
+        .map(|axis| array.normalize_axis(axis))
+        .collect::<Vec<usize>>();
+    axes.iter().try_for_each(|&axis| array.axis_in_bounds(axis))?;
+    let shape = array.get_shape()?;
+    let s = s.unwrap_or_else(|| axes.iter().map(|&axis| shape[axis]).collect());
+    s.len().is_equal(&axes.len())?;
+    if s.contains(&0) {
+        return Err(ArrayError::ParameterError { param: "`s`", message: "invalid number of data points" })
+    }
+    Ok(axes.into_iter().zip(s).collect())
+}
+
+/// Crops or pads lane with zeros to given length
+pub(crate) fn fit_lane(lane: Vec<C64>, n: usize) -> Vec<C64> {
+    lane.into_iter()
+        .chain(std::iter::repeat(C64::new(0., 0.)))
+        .take(n)
+        .collect()
+}
@@ -0,0 +1,153 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    fft::operations::common::{map_lanes, resolve_axes},
+    numeric::prelude::*,
+};
+
+/// `ArrayTrait` - Array Fft Helper functions
+pub trait ArrayFftHelper<T: ArrayElement> where Self: Sized + Clone {
+
+    /// Shift the zero-frequency component to the center of the spectrum
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - axes over which to shift. optional, defaults to all axes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![0., 1., 2., 3., 4., -5., -4., -3., -2., -1.]);
+    /// assert_eq!(Array::flat(vec![-5., -4., -3., -2., -1., 0., 1., 2., 3., 4.]), arr.fftshift(None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn fftshift(&self, axes: Option<Vec<isize>>) -> Result<Array<T>, ArrayError>;
+
+    /// The inverse of `fftshift`
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - axes over which to shift. optional, defaults to all axes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![-5., -4., -3., -2., -1., 0., 1., 2., 3., 4.]);
+    /// assert_eq!(Array::flat(vec![0., 1., 2., 3., 4., -5., -4., -3., -2., -1.]), arr.ifftshift(None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn ifftshift(&self, axes: Option<Vec<isize>>) -> Result<Array<T>, ArrayError>;
+}
+
+impl <T: ArrayElement> ArrayFftHelper<T> for Array<T> {
+
+    fn fftshift(&self, axes: Option<Vec<isize>>) -> Result<Self, ArrayError> {
+        resolve_axes(self, None, axes)?.into_iter()
+            .try_fold(self.clone(), |array, (axis, n)| shift_axis(&array, axis, n / 2))
+    }
+
+    fn ifftshift(&self, axes: Option<Vec<isize>>) -> Result<Self, ArrayError> {
+        resolve_axes(self, None, axes)?.into_iter()
+            .try_fold(self.clone(), |array, (axis, n)| shift_axis(&array, axis, n - n / 2))
+    }
+}
+
+impl <T: ArrayElement> ArrayFftHelper<T> for Result<Array<T>, ArrayError> {
+
+    fn fftshift(&self, axes: Option<Vec<isize>>) -> Self {
+        self.clone()?.fftshift(axes)
+    }
+
+    fn ifftshift(&self, axes: Option<Vec<isize>>) -> Self {
+        self.clone()?.ifftshift(axes)
+    }
+}
+
+/// `ArrayTrait` - Array Fft Frequencies functions
+pub trait ArrayFftFreq where Self: Sized + Clone {
+
+    /// Return the Discrete Fourier Transform sample frequencies
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - window length
+    /// * `d` - sample spacing. optional, defaults to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// assert_eq!(Array::flat(vec![0., 0.25, -0.5, -0.25]), Array::fftfreq(4, None));
+    /// assert_eq!(Array::flat(vec![0., 1., 2., -2., -1.]), Array::fftfreq(5, Some(0.2)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn fftfreq(n: usize, d: Option<f64>) -> Result<Self, ArrayError>;
+
+    /// Return the Discrete Fourier Transform sample frequencies for usage with `rfft`, `irfft`
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - window length
+    /// * `d` - sample spacing. optional, defaults to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// assert_eq!(Array::flat(vec![0., 0.25, 0.5]), Array::rfftfreq(4, None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn rfftfreq(n: usize, d: Option<f64>) -> Result<Self, ArrayError>;
+}
+
+impl ArrayFftFreq for Array<f64> {
+
+    fn fftfreq(n: usize, d: Option<f64>) -> Result<Self, ArrayError> {
+        let scale = freq_scale(n, d)?;
+        Self::flat((0..n)
+            .map(|i| if i < n - n / 2 { i.to_f64() } else { i.to_f64() - n.to_f64() })
+            .map(|i| i * scale)
+            .collect())
+    }
+
+    fn rfftfreq(n: usize, d: Option<f64>) -> Result<Self, ArrayError> {
+        let scale = freq_scale(n, d)?;
+        Self::flat((0..=n / 2)
+            .map(|i| i.to_f64() * scale)
+            .collect())
+    }
+}
+
+fn freq_scale(n: usize, d: Option<f64>) -> Result<f64, ArrayError> {
+    if n == 0 {
+        return Err(ArrayError::ParameterError { param: "`n`", message: "must be greater than zero" })
+    }
+    Ok(1. / (n.to_f64() * d.unwrap_or(1.)))
+}
+
+fn shift_axis<T: ArrayElement>(array: &Array<T>, axis: usize, shift: usize) -> Result<Array<T>, ArrayError> {
+    let len = array.get_shape()?[axis];
+    let (elements, shape) = map_lanes(&array.get_elements()?, &array.get_shape()?, axis, len, |mut lane| {
+        lane.rotate_right(shift % len.max(1));
+        lane
+    });
+    Array::new(elements, shape)
+}
@@ -0,0 +1,8 @@
+/// array fft common functions
+pub(crate) mod common;
+
+/// array fft helper functions
+pub mod helper;
+
+/// array fft transforms functions
+pub mod transforms;
@@ -0,0 +1,332 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    fft::{
+        operations::common::{fft_vec, fit_lane, map_lanes, resolve_axes, resolve_axis},
+        prelude::*,
+    },
+    numeric::prelude::*,
+};
+
+/// `ArrayTrait` - Array Fft functions
+pub trait ArrayFft<N: FftElement> where Self: Sized + Clone {
+
+    /// Compute the one-dimensional discrete Fourier Transform
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - length of the transformed axis of the output. input is cropped or zero-padded. optional, defaults to axis length
+    /// * `axis` - axis over which to compute the transform. optional, defaults to -1
+    /// * `norm` - normalization mode {`backward`, `ortho`, `forward`}. optional, defaults to `backward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![1., 1., 1., 1.]);
+    /// let expected = Array::flat(vec![Complex::new(4., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 0.)]);
+    /// assert_eq!(expected, arr.fft(None, None, None::<FftNorm>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn fft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Compute the one-dimensional inverse discrete Fourier Transform
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - length of the transformed axis of the output. input is cropped or zero-padded. optional, defaults to axis length
+    /// * `axis` - axis over which to compute the transform. optional, defaults to -1
+    /// * `norm` - normalization mode {`backward`, `ortho`, `forward`}. optional, defaults to `backward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![Complex::new(4., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 0.)]);
+    /// let expected = Array::flat(vec![Complex::new(1., 0.); 4]);
+    /// assert_eq!(expected, arr.ifft(None, None, None::<FftNorm>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn ifft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Compute the one-dimensional discrete Fourier Transform for real input.
+    /// Only the non-negative frequency terms are returned, imaginary part of input is discarded
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - number of points along transformation axis in the input to use. optional, defaults to axis length
+    /// * `axis` - axis over which to compute the transform. optional, defaults to -1
+    /// * `norm` - normalization mode {`backward`, `ortho`, `forward`}. optional, defaults to `backward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![1., 1., 1., 1.]);
+    /// let expected = Array::flat(vec![Complex::new(4., 0.), Complex::new(0., 0.), Complex::new(0., 0.)]);
+    /// assert_eq!(expected, arr.rfft(None, None, None::<FftNorm>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn rfft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Compute the inverse of `rfft`, returning real output
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - length of the transformed axis of the output. optional, defaults to `2 * (m - 1)` where `m` is the input axis length
+    /// * `axis` - axis over which to compute the transform. optional, defaults to -1
+    /// * `norm` - normalization mode {`backward`, `ortho`, `forward`}. optional, defaults to `backward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![Complex::new(4., 0.), Complex::new(0., 0.), Complex::new(0., 0.)]);
+    /// assert_eq!(Array::flat(vec![1., 1., 1., 1.]), arr.irfft(None, None, None::<FftNorm>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn irfft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<f64>, ArrayError>;
+
+    /// Compute the two-dimensional discrete Fourier Transform
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - shape (length of each transformed axis) of the output. optional, defaults to input shape along `axes`
+    /// * `axes` - axes over which to compute the transform. optional, defaults to [-2, -1]
+    /// * `norm` - normalization mode {`backward`, `ortho`, `forward`}. optional, defaults to `backward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1., 1., 1., 1.], vec![2, 2]);
+    /// let expected = Array::new(vec![Complex::new(4., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 0.)], vec![2, 2]);
+    /// assert_eq!(expected, arr.fft2(None, None, None::<FftNorm>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn fft2(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Compute the two-dimensional inverse discrete Fourier Transform
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - shape (length of each transformed axis) of the output. optional, defaults to input shape along `axes`
+    /// * `axes` - axes over which to compute the transform. optional, defaults to [-2, -1]
+    /// * `norm` - normalization mode {`backward`, `ortho`, `forward`}. optional, defaults to `backward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![Complex::new(4., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 0.)], vec![2, 2]);
+    /// let expected = Array::new(vec![Complex::new(1., 0.); 4], vec![2, 2]);
+    /// assert_eq!(expected, arr.ifft2(None, None, None::<FftNorm>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn ifft2(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Compute the N-dimensional discrete Fourier Transform
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - shape (length of each transformed axis) of the output. optional, defaults to input shape along `axes`
+    /// * `axes` - axes over which to compute the transform. optional, defaults to last `len(s)` axes, or all axes
+    /// * `norm` - normalization mode {`backward`, `ortho`, `forward`}. optional, defaults to `backward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1., 1., 1., 1.], vec![2, 2]);
+    /// let expected = Array::new(vec![Complex::new(4., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 0.)], vec![2, 2]);
+    /// assert_eq!(expected, arr.fftn(None, None, None::<FftNorm>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn fftn(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Compute the N-dimensional inverse discrete Fourier Transform
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - shape (length of each transformed axis) of the output. optional, defaults to input shape along `axes`
+    /// * `axes` - axes over which to compute the transform. optional, defaults to last `len(s)` axes, or all axes
+    /// * `norm` - normalization mode {`backward`, `ortho`, `forward`}. optional, defaults to `backward`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![Complex::new(4., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 0.)], vec![2, 2]);
+    /// let expected = Array::new(vec![Complex::new(1., 0.); 4], vec![2, 2]);
+    /// assert_eq!(expected, arr.ifftn(None, None, None::<FftNorm>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn ifftn(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError>;
+}
+
+impl <N: FftElement> ArrayFft<N> for Array<N> {
+
+    fn fft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        let (axis, n) = resolve_axis(self, n, axis)?;
+        Self::transform_axis(&self.to_complex()?, axis, n, norm, false)
+    }
+
+    fn ifft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        let (axis, n) = resolve_axis(self, n, axis)?;
+        Self::transform_axis(&self.to_complex()?, axis, n, norm, true)
+    }
+
+    fn rfft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        let (axis, n) = resolve_axis(self, n, axis)?;
+        let factor = Self::norm_factor(norm, n, false)?;
+        let real = self.get_elements()?.iter()
+            .map(|item| Complex::new(item.to_complex().re, 0.))
+            .collect::<Vec<Complex<f64>>>();
+        let (elements, shape) = map_lanes(&real, &self.get_shape()?, axis, n / 2 + 1, |lane| {
+            fft_vec(&fit_lane(lane, n), false).into_iter()
+                .take(n / 2 + 1)
+                .map(|c| c.scale(factor))
+                .collect()
+        });
+        Array::new(elements, shape)
+    }
+
+    fn irfft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<f64>, ArrayError> {
+        let (axis, _) = resolve_axis(self, Some(1), axis)?;
+        let n = n.unwrap_or(2 * self.get_shape()?[axis].saturating_sub(1));
+        if n == 0 {
+            return Err(ArrayError::ParameterError { param: "`n`", message: "invalid number of data points" })
+        }
+        let factor = Self::norm_factor(norm, n, true)?;
+        let (elements, shape) = map_lanes(&self.to_complex()?.get_elements()?, &self.get_shape()?, axis, n, |lane| {
+            let half = fit_lane(lane, n / 2 + 1);
+            let full = (0..n)
+                .map(|k| if k < half.len() { half[k] } else { half[n - k].conj() })
+                .collect::<Vec<Complex<f64>>>();
+            fft_vec(&full, true).into_iter()
+                .map(|c| c.re * factor)
+                .collect()
+        });
+        Array::new(elements, shape)
+    }
+
+    fn fft2(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.fftn(s, Some(axes.unwrap_or_else(|| vec![-2, -1])), norm)
+    }
+
+    fn ifft2(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.ifftn(s, Some(axes.unwrap_or_else(|| vec![-2, -1])), norm)
+    }
+
+    fn fftn(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        let norm = Self::parse_norm(norm)?;
+        resolve_axes(self, s, axes)?.into_iter()
+            .try_fold(self.to_complex()?, |array, (axis, n)| Self::transform_axis(&array, axis, n, Some(norm), false))
+    }
+
+    fn ifftn(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        let norm = Self::parse_norm(norm)?;
+        resolve_axes(self, s, axes)?.into_iter()
+            .try_fold(self.to_complex()?, |array, (axis, n)| Self::transform_axis(&array, axis, n, Some(norm), true))
+    }
+}
+
+impl <N: FftElement> ArrayFft<N> for Result<Array<N>, ArrayError> {
+
+    fn fft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.fft(n, axis, norm)
+    }
+
+    fn ifft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.ifft(n, axis, norm)
+    }
+
+    fn rfft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.rfft(n, axis, norm)
+    }
+
+    fn irfft(&self, n: Option<usize>, axis: Option<isize>, norm: Option<impl FftNormType>) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.irfft(n, axis, norm)
+    }
+
+    fn fft2(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.fft2(s, axes, norm)
+    }
+
+    fn ifft2(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.ifft2(s, axes, norm)
+    }
+
+    fn fftn(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.fftn(s, axes, norm)
+    }
+
+    fn ifftn(&self, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, norm: Option<impl FftNormType>) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.ifftn(s, axes, norm)
+    }
+}
+
+trait FftHelper<N: FftElement> {
+
+    fn to_complex(&self) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    fn parse_norm(norm: Option<impl FftNormType>) -> Result<FftNorm, ArrayError> {
+        norm.map_or_else(|| Ok(FftNorm::default()), FftNormType::to_norm)
+    }
+
+    fn norm_factor(norm: Option<impl FftNormType>, n: usize, inverse: bool) -> Result<f64, ArrayError> {
+        Ok(Self::parse_norm(norm)?.factor(n, inverse))
+    }
+
+    fn transform_axis(array: &Array<Complex<f64>>, axis: usize, n: usize, norm: Option<impl FftNormType>, inverse: bool) -> Result<Array<Complex<f64>>, ArrayError> {
+        let factor = Self::norm_factor(norm, n, inverse)?;
+        let (elements, shape) = map_lanes(&array.get_elements()?, &array.get_shape()?, axis, n, |lane| {
+            fft_vec(&fit_lane(lane, n), inverse).into_iter()
+                .map(|c| c.scale(factor))
+                .collect()
+        });
+        Array::new(elements, shape)
+    }
+}
+
+impl <N: FftElement> FftHelper<N> for Array<N> {
+
+    fn to_complex(&self) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.get_elements()?.iter()
+            .map(Numeric::to_complex)
+            .collect::<Array<Complex<f64>>>()
+            .reshape(&self.get_shape()?)
+    }
+}
@@ -0,0 +1,11 @@
+#[cfg(feature = "fft")]
+pub use crate::fft::{
+    operations::{
+        helper::{ArrayFftFreq, ArrayFftHelper},
+        transforms::ArrayFft,
+    },
+    types::{
+        fft_element::FftElement,
+        fft_norm::{FftNorm, FftNormType},
+    },
+};
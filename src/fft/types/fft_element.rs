@@ -0,0 +1,6 @@
+use crate::numeric::prelude::*;
+
+/// Element type accepted by fft operations
+pub trait FftElement: Numeric {}
+
+impl <N: Numeric> FftElement for N {}
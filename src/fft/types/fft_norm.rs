@@ -0,0 +1,65 @@
+use crate::{
+    errors::prelude::*,
+    numeric::prelude::*,
+};
+
+/// the normalization mode of fft operation
+#[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq)]
+pub enum FftNorm {
+    /// no normalization on forward transform, `1/n` on inverse
+    #[default]
+    Backward,
+    /// `1/sqrt(n)` on both forward and inverse transforms
+    Ortho,
+    /// `1/n` on forward transform, no normalization on inverse
+    Forward,
+}
+
+/// `FftNorm` trait
+pub trait FftNormType {
+
+    /// Parse input to `FftNorm` type
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_norm(self) -> Result<FftNorm, ArrayError>;
+}
+
+impl FftNormType for FftNorm {
+
+    fn to_norm(self) -> Result<FftNorm, ArrayError> {
+        Ok(self)
+    }
+}
+
+impl FftNormType for &str {
+
+    fn to_norm(self) -> Result<FftNorm, ArrayError> {
+        match self {
+            "backward" => Ok(FftNorm::Backward),
+            "ortho" => Ok(FftNorm::Ortho),
+            "forward" => Ok(FftNorm::Forward),
+            _ => Err(ArrayError::ParameterError { param: "`norm`", message: "must be one of {`backward`, `ortho`, `forward`}" })
+        }
+    }
+}
+
+impl FftNormType for String {
+
+    fn to_norm(self) -> Result<FftNorm, ArrayError> {
+        self.as_str().to_norm()
+    }
+}
+
+impl FftNorm {
+
+    pub(crate) fn factor(self, n: usize, inverse: bool) -> f64 {
+        let n = n.to_f64();
+        match (self, inverse) {
+            (Self::Backward, false) | (Self::Forward, true) => 1.,
+            (Self::Ortho, _) => 1. / n.sqrt(),
+            (Self::Backward, true) | (Self::Forward, false) => 1. / n,
+        }
+    }
+}
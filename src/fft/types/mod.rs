@@ -0,0 +1,5 @@
+/// Fft element type definition
+pub mod fft_element;
+
+/// Fft norm type definition
+pub mod fft_norm;
@@ -0,0 +1,250 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    interpolate::prelude::*,
+    linalg::prelude::*,
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// validates sample points and converts them to `f64`
+pub(crate) fn validate_samples<N: NumericOps>(x: &Array<N>, y: &Array<N>) -> Result<(Vec<f64>, Vec<f64>), ArrayError> {
+    x.is_dim_supported(&[1])?;
+    y.is_dim_supported(&[1])?;
+    let (x, y) = (to_f64_vec(x)?, to_f64_vec(y)?);
+    y.len().is_equal(&x.len())?;
+    x.len().is_at_least(&2)?;
+    if x.iter().chain(&y).any(|v| !v.is_finite()) {
+        return Err(ArrayError::ParameterError { param: "`x`, `y`", message: "sample points must be finite" })
+    }
+    if x.windows(2).any(|w| w[1] <= w[0]) {
+        return Err(ArrayError::ParameterError { param: "`x`", message: "must be strictly increasing" })
+    }
+    Ok((x, y))
+}
+
+pub(crate) fn to_f64_vec<N: NumericOps>(array: &Array<N>) -> Result<Vec<f64>, ArrayError> {
+    Ok(array.get_elements()?.iter().map(Numeric::to_f64).collect())
+}
+
+/// applies `f` to every element of `x`, keeping its shape
+pub(crate) fn map_points<N: NumericOps, F: Fn(f64) -> f64>(x: &Array<N>, f: F) -> Result<Array<N>, ArrayError> {
+    x.map(|v| N::from_f64(f(v.to_f64()))).reshape(&x.get_shape()?)
+}
+
+/// piecewise cubic polynomial, with coefficients of each piece in increasing order of degree in `x - x[i]`
+#[derive(Clone, Debug)]
+pub(crate) struct PiecewiseCubic {
+    breaks: Vec<f64>,
+    coef: Vec<[f64; 4]>,
+    cumulative: Vec<f64>,
+}
+
+impl PiecewiseCubic {
+
+    /// cubic hermite interpolation of `values` and first derivatives `slopes` at `points`
+    pub(crate) fn from_slopes(points: &[f64], values: &[f64], slopes: &[f64]) -> Self {
+        let coef = (0..points.len() - 1)
+            .map(|i| {
+                let width = points[i + 1] - points[i];
+                let secant = (values[i + 1] - values[i]) / width;
+                let (left, right) = (slopes[i], slopes[i + 1]);
+                [
+                    values[i],
+                    left,
+                    3_f64.mul_add(secant, -2_f64.mul_add(left, right)) / width,
+                    2_f64.mul_add(-secant, left + right) / (width * width),
+                ]
+            })
+            .collect::<Vec<[f64; 4]>>();
+        let cumulative = std::iter::once(0.)
+            .chain(coef.iter().zip(points.windows(2)).scan(0., |acc, (c, w)| {
+                *acc += Self::antiderivative(c, w[1] - w[0]);
+                Some(*acc)
+            }))
+            .collect();
+        Self { breaks: points.to_vec(), coef, cumulative }
+    }
+
+    fn antiderivative(coef: &[f64; 4], t: f64) -> f64 {
+        coef.iter().enumerate().rev()
+            .fold(0., |acc: f64, (j, &c)| acc.mul_add(t, c / (j + 1).to_f64())) * t
+    }
+
+    /// index of the piece containing `x`. points outside the breaks use the outermost pieces
+    fn piece(&self, x: f64) -> usize {
+        self.breaks.partition_point(|&b| b <= x).clamp(1, self.coef.len()) - 1
+    }
+
+    /// value of `nu`-th derivative at `x`
+    pub(crate) fn eval(&self, x: f64, nu: usize) -> f64 {
+        if x.is_nan() { return f64::NAN }
+        let i = self.piece(x);
+        let t = x - self.breaks[i];
+        (nu..4).rev().fold(0., |acc, j| acc.mul_add(t, self.coef[i][j] * falling_factorial(j, nu)))
+    }
+
+    /// definite integral from `a` to `b`
+    pub(crate) fn integrate(&self, a: f64, b: f64) -> f64 {
+        let primitive = |x: f64| {
+            let i = self.piece(x);
+            self.cumulative[i] + Self::antiderivative(&self.coef[i], x - self.breaks[i])
+        };
+        primitive(b) - primitive(a)
+    }
+}
+
+fn falling_factorial(j: usize, nu: usize) -> f64 {
+    (j + 1 - nu..=j).map(|v| v.to_f64()).product()
+}
+
+/// widths and slopes of segments between consecutive points
+fn secants(points: &[f64], values: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let widths = points.windows(2).map(|w| w[1] - w[0]).collect::<Vec<f64>>();
+    let slopes = values.windows(2).zip(&widths).map(|(w, width)| (w[1] - w[0]) / width).collect();
+    (widths, slopes)
+}
+
+/// first derivatives of cubic spline at sample points
+pub(crate) fn spline_slopes(points: &[f64], values: &[f64], boundary: SplineBoundary) -> Result<Vec<f64>, ArrayError> {
+    let n = points.len();
+    let (widths, secants) = secants(points, values);
+    match (n, boundary) {
+        (2, SplineBoundary::Clamped(left, right)) => return Ok(vec![left, right]),
+        (2, _) => return Ok(vec![secants[0]; 2]),
+        (3, SplineBoundary::NotAKnot) => {
+            // not-a-knot spline through three points is a single parabola
+            let curvature = (secants[1] - secants[0]) / (widths[0] + widths[1]);
+            return Ok([-widths[0], widths[0], 2_f64.mul_add(widths[1], widths[0])].iter()
+                .map(|offset| curvature.mul_add(*offset, secants[0]))
+                .collect())
+        },
+        _ => (),
+    }
+
+    // row `i` holds `lower[i - 1]`, `diag[i]` and `upper[i]`
+    let (mut lower, mut diag, mut upper, mut rhs) = (vec![0.; n - 1], vec![0.; n], vec![0.; n - 1], vec![0.; n]);
+    (1..n - 1).for_each(|i| {
+        lower[i - 1] = widths[i];
+        diag[i] = 2. * (widths[i - 1] + widths[i]);
+        upper[i] = widths[i - 1];
+        rhs[i] = 3. * widths[i].mul_add(secants[i - 1], widths[i - 1] * secants[i]);
+    });
+    match boundary {
+        SplineBoundary::NotAKnot => {
+            let span = points[2] - points[0];
+            diag[0] = widths[1];
+            upper[0] = span;
+            rhs[0] = 2_f64.mul_add(span, widths[0]).mul_add(widths[1] * secants[0], widths[0] * widths[0] * secants[1]) / span;
+            let span = points[n - 1] - points[n - 3];
+            lower[n - 2] = span;
+            diag[n - 1] = widths[n - 3];
+            rhs[n - 1] = (widths[n - 2] * widths[n - 2]).mul_add(secants[n - 3], 2_f64.mul_add(span, widths[n - 2]) * widths[n - 3] * secants[n - 2]) / span;
+        },
+        SplineBoundary::Natural => {
+            (diag[0], upper[0], rhs[0]) = (2., 1., 3. * secants[0]);
+            (lower[n - 2], diag[n - 1], rhs[n - 1]) = (1., 2., 3. * secants[n - 2]);
+        },
+        SplineBoundary::Clamped(left, right) => {
+            (diag[0], rhs[0]) = (1., left);
+            (diag[n - 1], rhs[n - 1]) = (1., right);
+        },
+    }
+    Array::solve_tridiagonal(&Array::flat(lower)?, &Array::flat(diag)?, &Array::flat(upper)?, &Array::flat(rhs)?)?
+        .get_elements()
+}
+
+/// first derivatives of monotone piecewise cubic hermite interpolation (fritsch-carlson)
+pub(crate) fn pchip_slopes(points: &[f64], values: &[f64]) -> Vec<f64> {
+    let n = points.len();
+    let (widths, secants) = secants(points, values);
+    if n == 2 { return vec![secants[0]; 2] }
+
+    let sign = |v: f64| if v > 0. { 1 } else if v < 0. { -1 } else { 0 };
+    let edge = |width: (f64, f64), secant: (f64, f64)| {
+        let slope = 2_f64.mul_add(width.0, width.1).mul_add(secant.0, -width.0 * secant.1) / (width.0 + width.1);
+        if sign(slope) != sign(secant.0) { 0. }
+        else if sign(secant.0) != sign(secant.1) && slope.abs() > 3. * secant.0.abs() { 3. * secant.0 }
+        else { slope }
+    };
+
+    let mut slopes = vec![0.; n];
+    slopes[0] = edge((widths[0], widths[1]), (secants[0], secants[1]));
+    slopes[n - 1] = edge((widths[n - 2], widths[n - 3]), (secants[n - 2], secants[n - 3]));
+    (1..n - 1).for_each(|k| {
+        if sign(secants[k - 1]) * sign(secants[k]) > 0 {
+            let (w_1, w_2) = (2_f64.mul_add(widths[k], widths[k - 1]), 2_f64.mul_add(widths[k - 1], widths[k]));
+            slopes[k] = (w_1 + w_2) / (w_1 / secants[k - 1] + w_2 / secants[k]);
+        }
+    });
+    slopes
+}
+
+/// first derivatives of akima interpolation
+pub(crate) fn akima_slopes(points: &[f64], values: &[f64]) -> Vec<f64> {
+    let n = points.len();
+    let (_, m) = secants(points, values);
+    if n == 2 { return vec![m[0]; 2] }
+
+    // secants are extended with two extrapolated values at both ends
+    let before = 2_f64.mul_add(m[0], -m[1]);
+    let after = 2_f64.mul_add(m[n - 2], -m[n - 3]);
+    let ext = [2_f64.mul_add(before, -m[0]), before].into_iter()
+        .chain(m.iter().copied())
+        .chain([after, 2_f64.mul_add(after, -m[n - 2])])
+        .collect::<Vec<f64>>();
+    let weights = (0..n)
+        .map(|i| ((ext[i + 3] - ext[i + 2]).abs(), (ext[i + 1] - ext[i]).abs()))
+        .collect::<Vec<(f64, f64)>>();
+    let max_weight = weights.iter().map(|(w_1, w_2)| w_1 + w_2).fold(0., f64::max);
+
+    weights.iter().enumerate()
+        .map(|(i, &(w_1, w_2))| if w_1 + w_2 > 1e-9 * max_weight {
+            w_1.mul_add(ext[i + 1], w_2 * ext[i + 2]) / (w_1 + w_2)
+        } else {
+            0.5 * (ext[i + 1] + ext[i + 2])
+        })
+        .collect()
+}
+
+/// value of b-spline at `x`, evaluated with de boor's algorithm
+pub(crate) fn de_boor(knots: &[f64], coef: &[f64], degree: usize, x: f64) -> f64 {
+    if x.is_nan() { return f64::NAN }
+    let count = knots.len() - degree - 1;
+    let span = knots.partition_point(|&v| v <= x).saturating_sub(1).clamp(degree, count - 1);
+    let mut values = coef[span - degree..=span].to_vec();
+    (1..=degree).for_each(|r| (r..=degree).rev().for_each(|j| {
+        let (left, right) = (knots[j + span - degree], knots[j + 1 + span - r]);
+        let alpha = if right > left { (x - left) / (right - left) } else { 0. };
+        values[j] = (1. - alpha).mul_add(values[j - 1], alpha * values[j]);
+    }));
+    values[degree]
+}
+
+/// knots, coefficients and degree of b-spline derivative
+pub(crate) fn b_spline_derivative(knots: &[f64], coef: &[f64], degree: usize) -> (Vec<f64>, Vec<f64>, usize) {
+    let count = knots.len() - degree - 1;
+    let derivative = (0..count - 1)
+        .map(|i| {
+            let width = knots[i + degree + 1] - knots[i + 1];
+            if width > 0. { degree.to_f64() * (coef[i + 1] - coef[i]) / width } else { 0. }
+        })
+        .collect();
+    (knots[1..knots.len() - 1].to_vec(), derivative, degree - 1)
+}
+
+/// knots, coefficients and degree of b-spline antiderivative
+pub(crate) fn b_spline_antiderivative(knots: &[f64], coef: &[f64], degree: usize) -> (Vec<f64>, Vec<f64>, usize) {
+    let count = knots.len() - degree - 1;
+    let antiderivative = std::iter::once(0.)
+        .chain((0..count).scan(0., |acc, i| {
+            *acc += coef[i] * (knots[i + degree + 1] - knots[i]) / (degree + 1).to_f64();
+            Some(*acc)
+        }))
+        .collect();
+    let extended = std::iter::once(knots[0])
+        .chain(knots.iter().copied())
+        .chain(std::iter::once(knots[knots.len() - 1]))
+        .collect();
+    (extended, antiderivative, degree + 1)
+}
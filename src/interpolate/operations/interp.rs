@@ -0,0 +1,103 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    interpolate::operations::common::{map_points, to_f64_vec},
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// `ArrayTrait` - Array Interpolate functions
+pub trait ArrayInterp<N: NumericOps> where Self: Sized + Clone {
+
+    /// One-dimensional linear interpolation for monotonically increasing sample points.
+    /// self is treated as x-coordinates at which to evaluate the interpolated values
+    ///
+    /// # Arguments
+    ///
+    /// * `xp` - x-coordinates of the data points, increasing if `period` is not specified
+    /// * `fp` - y-coordinates of the data points, same length as `xp`
+    /// * `left` - value to return for `x < xp[0]`. optional, defaults to `fp[0]`
+    /// * `right` - value to return for `x > xp[-1]`. optional, defaults to `fp[-1]`
+    /// * `period` - period of the x-coordinates. `left` and `right` are ignored if specified. optional
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let xp = array_flat!(f64, 1., 2., 3.).unwrap();
+    /// let fp = array_flat!(f64, 3., 2., 0.).unwrap();
+    /// assert_eq!(array_flat!(f64, 3., 3., 2.5, 0.5, 0.), array_flat!(f64, 0., 1., 1.5, 2.75, 3.).interp(&xp, &fp, None, None, None));
+    /// assert_eq!(array_flat!(f64, -99., 0.), array_flat!(f64, 0., 3.5).interp(&xp, &fp, Some(-99.), None, None));
+    ///
+    /// let xp = array_flat!(f64, 190., -190., 350., -350.).unwrap();
+    /// let fp = array_flat!(f64, 5., 10., 3., 4.).unwrap();
+    /// assert_eq!(array_flat!(f64, 7.5, 5., 8.75), array_flat!(f64, -180., -170., -185.).interp(&xp, &fp, None, None, Some(360.)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn interp(&self, xp: &Array<N>, fp: &Array<N>, left: Option<N>, right: Option<N>, period: Option<N>) -> Result<Array<N>, ArrayError>;
+}
+
+impl <N: NumericOps> ArrayInterp<N> for Array<N> {
+
+    fn interp(&self, xp: &Self, fp: &Self, left: Option<N>, right: Option<N>, period: Option<N>) -> Result<Self, ArrayError> {
+        xp.is_dim_supported(&[1])?;
+        fp.is_dim_supported(&[1])?;
+        let (mut xp, mut fp) = (to_f64_vec(xp)?, to_f64_vec(fp)?);
+        fp.len().is_equal(&xp.len())?;
+        xp.len().is_at_least(&1)?;
+
+        if let Some(period) = period {
+            let period = period.to_f64().abs();
+            if period == 0. {
+                return Err(ArrayError::ParameterError { param: "`period`", message: "must be non-zero" })
+            }
+            let mut points = xp.iter().map(|x| x.rem_euclid(period)).zip(fp).collect::<Vec<(f64, f64)>>();
+            points.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let (first, last) = (points[0], points[points.len() - 1]);
+            (xp, fp) = std::iter::once((last.0 - period, last.1))
+                .chain(points)
+                .chain(std::iter::once((first.0 + period, first.1)))
+                .unzip();
+            return map_points(self, |x| Self::interp_linear(&xp, &fp, x.rem_euclid(period), fp[0], fp[fp.len() - 1]))
+        }
+
+        if xp.windows(2).any(|w| w[1] < w[0]) {
+            return Err(ArrayError::ParameterError { param: "`xp`", message: "must be increasing" })
+        }
+        let left = left.map_or(fp[0], |v| v.to_f64());
+        let right = right.map_or(fp[fp.len() - 1], |v| v.to_f64());
+        map_points(self, |x| Self::interp_linear(&xp, &fp, x, left, right))
+    }
+}
+
+impl <N: NumericOps> ArrayInterp<N> for Result<Array<N>, ArrayError> {
+
+    fn interp(&self, xp: &Array<N>, fp: &Array<N>, left: Option<N>, right: Option<N>, period: Option<N>) -> Self {
+        self.clone()?.interp(xp, fp, left, right, period)
+    }
+}
+
+trait InterpHelper {
+
+    fn interp_linear(xp: &[f64], fp: &[f64], x: f64, left: f64, right: f64) -> f64;
+}
+
+impl <N: NumericOps> InterpHelper for Array<N> {
+
+    fn interp_linear(xp: &[f64], fp: &[f64], x: f64, left: f64, right: f64) -> f64 {
+        let last = xp.len() - 1;
+        if x.is_nan() { f64::NAN }
+        else if x < xp[0] { left }
+        else if x > xp[last] { right }
+        else if x >= xp[last] { fp[last] }
+        else {
+            let j = xp.partition_point(|&v| v <= x) - 1;
+            let slope = (fp[j + 1] - fp[j]) / (xp[j + 1] - xp[j]);
+            slope.mul_add(x - xp[j], fp[j])
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    interpolate::{
+        operations::common::{b_spline_antiderivative, b_spline_derivative, de_boor, map_points},
+        prelude::*,
+    },
+    numeric::prelude::*,
+};
+
+/// `InterpolatorTrait` - Interpolator functions
+pub trait Interpolator<N: NumericOps> where Self: Sized + Clone {
+
+    /// Evaluate the interpolator at specific values
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - values at which to evaluate the interpolator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let x = array_flat!(f64, 0., 1., 2.).unwrap();
+    /// let spline = CubicSpline::new(&x, &array_flat!(f64, 0., 1., 4.).unwrap(), None::<SplineBoundary>);
+    /// assert_eq!(array!(f64, [[0.25, 2.25], [9., 16.]]), spline.eval(&array!(f64, [[0.5, 1.5], [3., 4.]]).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn eval(&self, x: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Evaluate the derivative of the interpolator at specific values
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - values at which to evaluate the derivative
+    /// * `nu` - order of the derivative. optional, defaults to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let x = array_flat!(f64, 0., 1., 2.).unwrap();
+    /// let spline = CubicSpline::new(&x, &array_flat!(f64, 0., 1., 4.).unwrap(), None::<SplineBoundary>);
+    /// assert_eq!(array_flat!(f64, 1., 3.), spline.derivative(&array_flat!(f64, 0.5, 1.5).unwrap(), None));
+    /// assert_eq!(array_flat!(f64, 2., 2.), spline.derivative(&array_flat!(f64, 0.5, 1.5).unwrap(), Some(2)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn derivative(&self, x: &Array<N>, nu: Option<usize>) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the definite integral of the interpolator
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - lower limit of integration
+    /// * `b` - upper limit of integration
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let x = array_flat!(f64, 0., 1., 2.).unwrap();
+    /// let pchip = Pchip::new(&x, &array_flat!(f64, 1., 1., 1.).unwrap());
+    /// assert_eq!(Ok(2.), pchip.integrate(0., 2.));
+    /// assert_eq!(Ok(-3.), pchip.integrate(2., -1.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn integrate(&self, a: N, b: N) -> Result<N, ArrayError>;
+}
+
+macro_rules! impl_piecewise_interpolator {
+    ($t:ident) => {
+        impl <N: NumericOps> Interpolator<N> for $t<N> {
+
+            fn eval(&self, x: &Array<N>) -> Result<Array<N>, ArrayError> {
+                map_points(x, |v| self.piecewise.eval(v, 0))
+            }
+
+            fn derivative(&self, x: &Array<N>, nu: Option<usize>) -> Result<Array<N>, ArrayError> {
+                let nu = nu.unwrap_or(1);
+                map_points(x, |v| self.piecewise.eval(v, nu))
+            }
+
+            fn integrate(&self, a: N, b: N) -> Result<N, ArrayError> {
+                Ok(N::from_f64(self.piecewise.integrate(a.to_f64(), b.to_f64())))
+            }
+        }
+    };
+}
+
+impl_piecewise_interpolator!(CubicSpline);
+impl_piecewise_interpolator!(Pchip);
+impl_piecewise_interpolator!(Akima);
+
+impl <N: NumericOps> Interpolator<N> for BSpline<N> {
+
+    fn eval(&self, x: &Array<N>) -> Result<Array<N>, ArrayError> {
+        let (knots, coef, degree) = (self.knots_f64(), self.coef_f64(), self.get_degree());
+        map_points(x, |v| de_boor(&knots, &coef, degree, v))
+    }
+
+    fn derivative(&self, x: &Array<N>, nu: Option<usize>) -> Result<Array<N>, ArrayError> {
+        let nu = nu.unwrap_or(1);
+        if nu > self.get_degree() { return map_points(x, |v| if v.is_nan() { v } else { 0. }) }
+        let (knots, coef, degree) = (0..nu)
+            .fold((self.knots_f64(), self.coef_f64(), self.get_degree()), |(knots, coef, degree), _| b_spline_derivative(&knots, &coef, degree));
+        map_points(x, |v| de_boor(&knots, &coef, degree, v))
+    }
+
+    fn integrate(&self, a: N, b: N) -> Result<N, ArrayError> {
+        let (knots, coef, degree) = b_spline_antiderivative(&self.knots_f64(), &self.coef_f64(), self.get_degree());
+        Ok(N::from_f64(de_boor(&knots, &coef, degree, b.to_f64()) - de_boor(&knots, &coef, degree, a.to_f64())))
+    }
+}
+
+macro_rules! impl_interpolator_result {
+    ($t:ident) => {
+        impl <N: NumericOps> Interpolator<N> for Result<$t<N>, ArrayError> {
+
+            fn eval(&self, x: &Array<N>) -> Result<Array<N>, ArrayError> {
+                self.clone()?.eval(x)
+            }
+
+            fn derivative(&self, x: &Array<N>, nu: Option<usize>) -> Result<Array<N>, ArrayError> {
+                self.clone()?.derivative(x, nu)
+            }
+
+            fn integrate(&self, a: N, b: N) -> Result<N, ArrayError> {
+                self.clone()?.integrate(a, b)
+            }
+        }
+    };
+}
+
+impl_interpolator_result!(CubicSpline);
+impl_interpolator_result!(Pchip);
+impl_interpolator_result!(Akima);
+impl_interpolator_result!(BSpline);
@@ -0,0 +1,8 @@
+/// array interpolate common functions
+pub(crate) mod common;
+
+/// array interpolate functions
+pub mod interp;
+
+/// interpolator functions
+pub mod interpolator;
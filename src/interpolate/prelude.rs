@@ -0,0 +1,14 @@
+#[cfg(feature = "interpolate")]
+pub use crate::interpolate::{
+    operations::{
+        interp::ArrayInterp,
+        interpolator::Interpolator,
+    },
+    types::{
+        akima::Akima,
+        b_spline::BSpline,
+        cubic_spline::CubicSpline,
+        pchip::Pchip,
+        spline_boundary::{SplineBoundary, SplineBoundaryType},
+    },
+};
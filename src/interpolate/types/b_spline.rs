@@ -0,0 +1,84 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    interpolate::operations::common::to_f64_vec,
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// B-spline of given degree, defined by its knots and coefficients.
+/// points outside the base interval `[t[k], t[n]]` are extrapolated with the outermost pieces
+#[derive(Clone, Debug)]
+pub struct BSpline<N: NumericOps> {
+    knots: Array<N>,
+    coef: Array<N>,
+    degree: usize,
+}
+
+impl <N: NumericOps> BSpline<N> {
+
+    /// Create new B-spline
+    ///
+    /// # Arguments
+    ///
+    /// * `knots` - knot vector, non-decreasing
+    /// * `coef` - spline coefficients. at least `knots.len() - degree - 1` values, extra ones are ignored
+    /// * `degree` - degree of the spline
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let knots = array_flat!(f64, 0., 0., 0., 1., 1., 1.).unwrap();
+    /// let spline = BSpline::new(&knots, &array_flat!(f64, 0., 1., 0.).unwrap(), 2).unwrap();
+    /// assert_eq!(array_flat!(f64, 0., 0.5, 0.), spline.eval(&array_flat!(f64, 0., 0.5, 1.).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn new(knots: &Array<N>, coef: &Array<N>, degree: usize) -> Result<Self, ArrayError> {
+        knots.is_dim_supported(&[1])?;
+        coef.is_dim_supported(&[1])?;
+        knots.len()?.is_at_least(&(2 * degree + 2))?;
+        coef.len()?.is_at_least(&(knots.len()? - degree - 1))?;
+        let t = to_f64_vec(knots)?;
+        if t.iter().any(|v| !v.is_finite()) {
+            return Err(ArrayError::ParameterError { param: "`knots`", message: "must be finite" })
+        }
+        if t.windows(2).any(|w| w[1] < w[0]) {
+            return Err(ArrayError::ParameterError { param: "`knots`", message: "must be non-decreasing" })
+        }
+        if t[degree] >= t[t.len() - degree - 1] {
+            return Err(ArrayError::ParameterError { param: "`knots`", message: "base interval must not be empty" })
+        }
+        Ok(Self { knots: knots.clone(), coef: coef.clone(), degree })
+    }
+
+    /// Knot vector of the spline
+    #[must_use]
+    pub fn get_knots(&self) -> Array<N> {
+        self.knots.clone()
+    }
+
+    /// Coefficients of the spline
+    #[must_use]
+    pub fn get_coef(&self) -> Array<N> {
+        self.coef.clone()
+    }
+
+    /// Degree of the spline
+    #[must_use]
+    pub const fn get_degree(&self) -> usize {
+        self.degree
+    }
+
+    pub(crate) fn knots_f64(&self) -> Vec<f64> {
+        to_f64_vec(&self.knots).unwrap_or_default()
+    }
+
+    pub(crate) fn coef_f64(&self) -> Vec<f64> {
+        to_f64_vec(&self.coef).unwrap_or_default()
+    }
+}
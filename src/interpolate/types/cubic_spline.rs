@@ -0,0 +1,76 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    interpolate::{
+        operations::common::{spline_slopes, validate_samples, PiecewiseCubic},
+        prelude::*,
+    },
+    numeric::prelude::*,
+};
+
+/// Cubic spline interpolator, twice continuously differentiable.
+/// points outside the sample range are extrapolated with the outermost pieces
+#[derive(Clone, Debug)]
+pub struct CubicSpline<N: NumericOps> {
+    x: Array<N>,
+    y: Array<N>,
+    boundary: SplineBoundary,
+    pub(crate) piecewise: PiecewiseCubic,
+}
+
+impl <N: NumericOps> CubicSpline<N> {
+
+    /// Create new cubic spline interpolator
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-coordinates of the sample points, strictly increasing
+    /// * `y` - y-coordinates of the sample points
+    /// * `boundary` - boundary condition of the spline. optional, defaults to `NotAKnot`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let x = array_flat!(f64, 0., 1., 2., 3.).unwrap();
+    /// let spline = CubicSpline::new(&x, &x.map(|v| v * v * v).unwrap(), None::<SplineBoundary>).unwrap();
+    /// let value = spline.eval(&array_flat!(f64, 1.5).unwrap()).unwrap();
+    /// assert!((value[0] - 3.375).abs() < 1e-12);
+    ///
+    /// let spline = CubicSpline::new(&x, &array_flat!(f64, 0., 1., 0., 1.).unwrap(), Some("natural")).unwrap();
+    /// let second = spline.derivative(&array_flat!(f64, 0., 3.).unwrap(), Some(2)).unwrap();
+    /// assert!(second.get_elements().unwrap().iter().all(|v| v.abs() < 1e-12));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn new(x: &Array<N>, y: &Array<N>, boundary: Option<impl SplineBoundaryType>) -> Result<Self, ArrayError> {
+        let boundary = match boundary {
+            Some(boundary) => boundary.to_boundary()?,
+            None => SplineBoundary::default(),
+        };
+        let (xs, ys) = validate_samples(x, y)?;
+        let piecewise = PiecewiseCubic::from_slopes(&xs, &ys, &spline_slopes(&xs, &ys, boundary)?);
+        Ok(Self { x: x.clone(), y: y.clone(), boundary, piecewise })
+    }
+
+    /// x-coordinates of the sample points
+    #[must_use]
+    pub fn get_x(&self) -> Array<N> {
+        self.x.clone()
+    }
+
+    /// y-coordinates of the sample points
+    #[must_use]
+    pub fn get_y(&self) -> Array<N> {
+        self.y.clone()
+    }
+
+    /// Boundary condition of the spline
+    #[must_use]
+    pub const fn get_boundary(&self) -> SplineBoundary {
+        self.boundary
+    }
+}
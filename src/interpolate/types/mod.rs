@@ -0,0 +1,14 @@
+/// Akima interpolator type definition
+pub mod akima;
+
+/// B-spline type definition
+pub mod b_spline;
+
+/// Cubic spline interpolator type definition
+pub mod cubic_spline;
+
+/// PCHIP interpolator type definition
+pub mod pchip;
+
+/// Spline boundary condition type definition
+pub mod spline_boundary;
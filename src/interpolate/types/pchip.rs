@@ -0,0 +1,56 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    interpolate::operations::common::{pchip_slopes, validate_samples, PiecewiseCubic},
+    numeric::prelude::*,
+};
+
+/// Piecewise cubic hermite interpolator preserving monotonicity of the data (PCHIP).
+/// points outside the sample range are extrapolated with the outermost pieces
+#[derive(Clone, Debug)]
+pub struct Pchip<N: NumericOps> {
+    x: Array<N>,
+    y: Array<N>,
+    pub(crate) piecewise: PiecewiseCubic,
+}
+
+impl <N: NumericOps> Pchip<N> {
+
+    /// Create new PCHIP interpolator
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-coordinates of the sample points, strictly increasing
+    /// * `y` - y-coordinates of the sample points
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let x = array_flat!(f64, 0., 1., 2., 3.).unwrap();
+    /// let pchip = Pchip::new(&x, &array_flat!(f64, 0., 0., 1., 1.).unwrap()).unwrap();
+    /// assert_eq!(array_flat!(f64, 0., 0.5, 1.), pchip.eval(&array_flat!(f64, 0.5, 1.5, 2.5).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn new(x: &Array<N>, y: &Array<N>) -> Result<Self, ArrayError> {
+        let (xs, ys) = validate_samples(x, y)?;
+        let piecewise = PiecewiseCubic::from_slopes(&xs, &ys, &pchip_slopes(&xs, &ys));
+        Ok(Self { x: x.clone(), y: y.clone(), piecewise })
+    }
+
+    /// x-coordinates of the sample points
+    #[must_use]
+    pub fn get_x(&self) -> Array<N> {
+        self.x.clone()
+    }
+
+    /// y-coordinates of the sample points
+    #[must_use]
+    pub fn get_y(&self) -> Array<N> {
+        self.y.clone()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::errors::prelude::*;
+
+/// the boundary condition of cubic spline
+#[derive(Copy, Clone, Debug, Default, PartialOrd, PartialEq)]
+pub enum SplineBoundary {
+    /// the first and second segments at each end are the same polynomial
+    #[default]
+    NotAKnot,
+    /// the second derivative at both ends is zero
+    Natural,
+    /// the first derivative at both ends is set to given values
+    Clamped(f64, f64),
+}
+
+/// `SplineBoundary` trait
+pub trait SplineBoundaryType {
+
+    /// Parse input to `SplineBoundary` type
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_boundary(self) -> Result<SplineBoundary, ArrayError>;
+}
+
+impl SplineBoundaryType for SplineBoundary {
+
+    fn to_boundary(self) -> Result<SplineBoundary, ArrayError> {
+        Ok(self)
+    }
+}
+
+impl SplineBoundaryType for &str {
+
+    fn to_boundary(self) -> Result<SplineBoundary, ArrayError> {
+        match self {
+            "not-a-knot" => Ok(SplineBoundary::NotAKnot),
+            "natural" => Ok(SplineBoundary::Natural),
+            "clamped" => Ok(SplineBoundary::Clamped(0., 0.)),
+            _ => Err(ArrayError::ParameterError { param: "`boundary`", message: "must be one of {`not-a-knot`, `natural`, `clamped`}" })
+        }
+    }
+}
+
+impl SplineBoundaryType for String {
+
+    fn to_boundary(self) -> Result<SplineBoundary, ArrayError> {
+        self.as_str().to_boundary()
+    }
+}
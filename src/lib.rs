@@ -59,6 +59,14 @@ pub mod alphanumeric;
 #[cfg(feature = "boolean")]
 pub mod boolean;
 
+/// fast fourier transform module implementation
+#[cfg(feature = "fft")]
+pub mod fft;
+
+/// interpolate module implementation
+#[cfg(feature = "interpolate")]
+pub mod interpolate;
+
 /// linear algebra module implementation
 #[cfg(feature = "linalg")]
 pub mod linalg;
@@ -71,6 +79,18 @@ pub mod math;
 #[cfg(feature = "numeric")]
 pub mod numeric;
 
+/// polynomial module implementation
+#[cfg(feature = "polynomial")]
+pub mod polynomial;
+
+/// random module implementation
+#[cfg(feature = "random")]
+pub mod random;
+
+/// sparse matrices module implementation
+#[cfg(feature = "sparse")]
+pub mod sparse;
+
 /// array error definitions
 pub mod errors;
 
@@ -0,0 +1,201 @@
+use std::{
+    any::Any,
+    fmt::Debug,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use crate::{
+    core::prelude::*,
+    linalg::{
+        operations::{
+            common::{householder_qr, lu_decompose, svd_golub_kahan},
+            eigen::{EigenHelper, HermitianEigenHelper, RealSchurHelper},
+            gemm::gemm,
+        },
+        types::{EighKernelResult, RealSchurKernelResult, SchurKernelResult, SvdKernelResult},
+    },
+    numeric::prelude::*,
+};
+
+/// Linear algebra backend - dense kernels used by `ArrayLinalg*` functions
+///
+/// matrices are given in row-major order, with elements converted to `f64` (or `Complex<f64>`).
+/// every kernel defaults to the pure rust implementation, so that alternative backend may override only some of them
+pub trait LinalgBackend: Debug + Send + Sync {
+
+    /// General matrix multiplication `c += a b`, for `rows x depth` matrix `a`, `depth x cols` matrix `b` and `rows x cols` matrix `c`.
+    /// used by products of `f64` arrays
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - number of rows of `a` and `c`
+    /// * `depth` - number of columns of `a` and rows of `b`
+    /// * `cols` - number of columns of `b` and `c`
+    /// * `a` - left matrix, as flat row-major slice
+    /// * `b` - right matrix, as flat row-major slice
+    /// * `c` - accumulated result, as flat row-major slice
+    fn gemm(&self, rows: usize, depth: usize, cols: usize, a: &[f64], b: &[f64], c: &mut [f64]) {
+        gemm(rows, depth, cols, a, b, c);
+    }
+
+    /// Lu decomposition of `m x n` matrix with partial pivoting.
+    /// Returns combined `l` and `u` factors (unit diagonal of `l` is not stored) and pivot indices,
+    /// where row `i` was interchanged with row `piv[i]`
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - matrix to decompose
+    fn lu(&self, matrix: Vec<Vec<f64>>) -> (Vec<Vec<f64>>, Vec<usize>) {
+        lu_decompose(matrix)
+    }
+
+    /// Qr decomposition of `m x n` matrix with householder reflections.
+    /// Returns `r` in the upper triangle and reflectors below the diagonal, their scaling factors `tau` and column permutation
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - matrix to decompose
+    /// * `pivoting` - if true, columns are pivoted by their norms
+    fn qr(&self, matrix: Vec<Vec<f64>>, pivoting: bool) -> (Vec<Vec<f64>>, Vec<f64>, Vec<usize>) {
+        householder_qr(matrix, pivoting)
+    }
+
+    /// Singular value decomposition of `m x n` matrix.
+    /// Returns `u` (`m x k`), singular values sorted in descending order and `v` (`n x k`), where `k = min(m, n)`
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - matrix to decompose
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn svd(&self, matrix: &[Vec<f64>]) -> SvdKernelResult {
+        svd_golub_kahan(matrix)
+    }
+
+    /// Complex schur decomposition of square matrix, such that `a = z t z^H`.
+    /// Returns upper triangular `t`, with eigenvalues on the diagonal, and unitary `z`
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - matrix to decompose
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn schur(&self, matrix: Vec<Vec<Complex<f64>>>) -> SchurKernelResult {
+        Array::<f64>::complex_schur(matrix)
+    }
+
+    /// Real schur decomposition of square matrix, such that `a = z t z^T`.
+    /// Returns quasi upper triangular `t`, with `1 x 1` blocks for real eigenvalues
+    /// and `2 x 2` blocks for pairs of complex conjugate eigenvalues on the diagonal, and orthogonal `z`
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - matrix to decompose
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn real_schur(&self, matrix: Vec<Vec<f64>>) -> RealSchurKernelResult {
+        Array::<f64>::real_schur(matrix)
+    }
+
+    /// Eigen decomposition of hermitian matrix.
+    /// Returns eigenvalues sorted in ascending order and orthonormal eigenvectors as columns
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - matrix to decompose
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn eigh(&self, matrix: &[Vec<Complex<f64>>]) -> EighKernelResult {
+        Array::<f64>::hermitian_eigen(matrix)
+    }
+}
+
+/// Default linear algebra backend, with pure rust kernels
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DefaultBackend;
+
+impl LinalgBackend for DefaultBackend {}
+
+static BACKEND: RwLock<Option<Arc<dyn LinalgBackend>>> = RwLock::new(None);
+
+/// Register backend used by all linalg functions. `DefaultBackend` may be registered to restore the default kernels
+///
+/// # Arguments
+///
+/// * `backend` - backend to register
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+/// use arr_rs::prelude::*;
+///
+/// #[derive(Debug)]
+/// struct CountingBackend(Arc<AtomicUsize>);
+///
+/// impl LinalgBackend for CountingBackend {
+///     fn svd(&self, matrix: &[Vec<f64>]) -> SvdKernelResult {
+///         self.0.fetch_add(1, Ordering::SeqCst);
+///         DefaultBackend.svd(matrix)
+///     }
+/// }
+///
+/// let calls = Arc::new(AtomicUsize::new(0));
+/// set_linalg_backend(CountingBackend(calls.clone()));
+/// let array = Array::new(vec![3., 0., 0., -2.], vec![2, 2]).unwrap();
+/// assert_eq!(Array::flat(vec![3., 2.]), array.svdvals());
+/// assert_eq!(1, calls.load(Ordering::SeqCst));
+///
+/// set_linalg_backend(DefaultBackend);
+/// ```
+pub fn set_linalg_backend(backend: impl LinalgBackend + 'static) {
+    *BACKEND.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(backend));
+}
+
+/// Currently registered linalg backend
+///
+/// # Examples
+///
+/// ```
+/// use arr_rs::prelude::*;
+///
+/// assert_eq!("DefaultBackend", format!("{:?}", linalg_backend()));
+/// ```
+#[must_use]
+pub fn linalg_backend() -> Arc<dyn LinalgBackend> {
+    BACKEND.read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+        .unwrap_or_else(|| Arc::new(DefaultBackend))
+}
+
+/// batched general matrix multiplication `c = a b`, see `LinalgBackend::gemm`.
+/// each pair of `offsets` selects `rows x depth` matrix of `a` and `depth x cols` matrix of `b`,
+/// multiplied into consecutive `rows x cols` blocks of the result.
+/// `f64` elements are passed to the registered backend without copying, other types are multiplied in their own precision
+pub(crate) fn backend_gemm<N: NumericOps>(dims: (usize, usize, usize), a: &Array<N>, b: &Array<N>, offsets: &[(usize, usize)]) -> Vec<N> {
+    let (rows, depth, cols) = dims;
+    let mut c = vec![N::zero(); offsets.len() * rows * cols];
+    let (a_f64, b_f64) = ((&a.elements as &dyn Any).downcast_ref::<Vec<f64>>(), (&b.elements as &dyn Any).downcast_ref::<Vec<f64>>());
+    if let (Some(a), Some(b), Some(c)) = (a_f64, b_f64, (&mut c as &mut dyn Any).downcast_mut::<Vec<f64>>()) {
+        let backend = linalg_backend();
+        gemm_blocks(|a, b, c| backend.gemm(rows, depth, cols, a, b, c), dims, a, b, offsets, c);
+    } else {
+        gemm_blocks(|a, b, c| gemm(rows, depth, cols, a, b, c), dims, &a.elements, &b.elements, offsets, &mut c);
+    }
+    c
+}
+
+fn gemm_blocks<N: NumericOps, F: Fn(&[N], &[N], &mut [N])>(kernel: F, (rows, depth, cols): (usize, usize, usize), a: &[N], b: &[N], offsets: &[(usize, usize)], c: &mut [N]) {
+    c.chunks_exact_mut(rows * cols)
+        .zip(offsets)
+        .for_each(|(target, &(offset_a, offset_b))| kernel(&a[offset_a..offset_a + rows * depth], &b[offset_b..offset_b + depth * cols], target));
+}
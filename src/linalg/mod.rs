@@ -1,3 +1,6 @@
+/// linear algebra backend definition
+pub mod backend;
+
 /// array operations
 pub mod operations;
 
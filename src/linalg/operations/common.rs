@@ -1,52 +1,40 @@
+use std::ops::{Div, DivAssign, Mul, Neg};
+
 use crate::{
     core::prelude::*,
     errors::prelude::*,
     extensions::prelude::*,
-    linalg::prelude::*,
-    math::prelude::*,
+    linalg::{backend::linalg_backend, types::SvdKernelResult},
     numeric::prelude::*,
     validators::prelude::*,
 };
 
 pub(crate) trait LinalgHelper<N: NumericOps> {
 
-    fn get_columns(&self) -> Result<Vec<Self>, ArrayError> where Self: Sized;
-
-    fn get_rows(&self) -> Result<Vec<Self>, ArrayError> where Self: Sized;
-
     fn to_matrix(&self) -> Result<Vec<Vec<N>>, ArrayError>;
 
     fn from_matrix(matrix: &[Vec<N>]) -> Result<Self, ArrayError> where Self: Sized;
 
-    fn is_convergent(&self) -> Result<bool, ArrayError>;
+    fn to_matrix_f64(&self) -> Result<Vec<Vec<f64>>, ArrayError>;
 
-    fn hessenberg_reduction(&self) -> Result<Self, ArrayError> where Self: Sized;
-}
+    fn from_matrix_f64(matrix: &[Vec<f64>]) -> Result<Self, ArrayError> where Self: Sized;
 
-impl <N: NumericOps> LinalgHelper<N> for Array<N> {
+    fn to_matrix_complex(&self) -> Result<Vec<Vec<Complex<f64>>>, ArrayError>;
 
-    fn get_columns(&self) -> Result<Vec<Self>, ArrayError> {
-        let col_len = self.get_shape()?[0];
-        let cols = self.get_shape()?[1];
-        let elements = self
-            .transpose(None)
-            .get_elements()?;
-        let result = parse_elements(&elements, cols, col_len);
-        Ok(result)
-    }
+    fn from_matrix_scalar<T: LinalgScalar>(matrix: &[Vec<T>]) -> Result<Self, ArrayError> where Self: Sized;
 
-    fn get_rows(&self) -> Result<Vec<Self>, ArrayError> where Self: Sized {
-        if self.ndim()? == 1 {
-            return self.split(self.len()?, None);
-        }
+    fn is_complex(&self) -> Result<bool, ArrayError>;
 
-        let row_len = self.get_shape()?[1];
-        let rows = self.get_shape()?[0];
-        let elements = self
-            .get_elements()?;
-        let result = parse_elements(&elements, rows, row_len);
-        Ok(result)
-    }
+    fn is_complex_unsupported(&self) -> Result<(), ArrayError>;
+
+    fn get_matrices(&self) -> Result<Vec<Self>, ArrayError> where Self: Sized;
+
+    fn leading_shape(&self) -> Result<Vec<usize>, ArrayError>;
+
+    fn map_matrices<M: ArrayElement, F: Fn(&Self) -> Result<Array<M>, ArrayError>>(&self, f: F) -> Result<Array<M>, ArrayError> where Self: Sized;
+}
+
+impl <N: NumericOps> LinalgHelper<N> for Array<N> {
 
     fn to_matrix(&self) -> Result<Vec<Vec<N>>, ArrayError> {
         self.is_dim_supported(&[2])?;
@@ -67,76 +55,632 @@ impl <N: NumericOps> LinalgHelper<N> for Array<N> {
         Self::new(array, vec![r, c])
     }
 
-    fn is_convergent(&self) -> Result<bool, ArrayError> {
-        self.is_dim_supported(&[2])?;
-        let matrix = self.to_array_f64()?.to_matrix()?;
+    fn to_matrix_f64(&self) -> Result<Vec<Vec<f64>>, ArrayError> {
+        // real kernels would silently drop imaginary parts
+        self.is_complex_unsupported()?;
+        self.to_array_f64()?.to_matrix()
+    }
+
+    fn from_matrix_f64(matrix: &[Vec<f64>]) -> Result<Self, ArrayError> {
+        Array::from_matrix(matrix)?.to_array_num()
+    }
+
+    fn to_matrix_complex(&self) -> Result<Vec<Vec<Complex<f64>>>, ArrayError> {
+        Ok(self.to_matrix()?.iter()
+            .map(|row| row.iter().map(Numeric::to_complex).collect())
+            .collect())
+    }
+
+    fn from_matrix_scalar<T: LinalgScalar>(matrix: &[Vec<T>]) -> Result<Self, ArrayError> {
+        let matrix = matrix.iter()
+            .map(|row| row.iter().map(|&value| value.to_numeric()).collect())
+            .collect::<Vec<Vec<N>>>();
+        Self::from_matrix(&matrix)
+    }
+
+    fn is_complex(&self) -> Result<bool, ArrayError> {
+        Ok(self.get_elements()?.iter().any(|value| value.to_complex().im != 0.))
+    }
+
+    fn is_complex_unsupported(&self) -> Result<(), ArrayError> {
+        if self.is_complex()? { Err(ArrayError::ParameterError { param: "`array`", message: "complex matrices are not supported" }) }
+        else { Ok(()) }
+    }
+
+    fn get_matrices(&self) -> Result<Vec<Self>, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        let shape = self.get_shape()?;
+        let (leading, sub_shape) = shape.split_at(shape.len() - 2);
+        self.ravel()?
+            .split(leading.iter().product::<usize>(), None)?
+            .iter()
+            .map(|arr| arr.reshape(sub_shape))
+            .collect()
+    }
+
+    fn leading_shape(&self) -> Result<Vec<usize>, ArrayError> {
+        let shape = self.get_shape()?;
+        Ok(shape[..shape.len().saturating_sub(2)].to_vec())
+    }
+
+    fn map_matrices<M: ArrayElement, F: Fn(&Self) -> Result<Array<M>, ArrayError>>(&self, f: F) -> Result<Array<M>, ArrayError> {
+        let leading = self.leading_shape()?;
+        let results = self
+            .get_matrices()?
+            .iter()
+            .map(f)
+            .collect::<Result<Vec<Array<M>>, ArrayError>>()?;
+        // scalar results of stacked matrices are shaped as the leading dimensions
+        if !leading.is_empty() && results[0].get_shape()? == [1] {
+            return stack_matrices(&leading, results).reshape(&leading)
+        }
+        stack_matrices(&leading, results)
+    }
+}
+
+/// stacks results computed for every matrix into array of shape `[leading..., result_shape...]`
+pub(crate) fn stack_matrices<M: ArrayElement>(leading: &[usize], results: Vec<Array<M>>) -> Result<Array<M>, ArrayError> {
+    let result_shape = results[0].get_shape()?;
+    results.into_iter()
+        .flatten()
+        .collect::<Array<M>>()
+        .reshape(&[leading, &result_shape].concat())
+}
+
+/// stacks optional results computed for every matrix, see `stack_matrices`
+pub(crate) fn stack_optional<M: ArrayElement>(leading: &[usize], results: Vec<Option<Array<M>>>) -> Result<Option<Array<M>>, ArrayError> {
+    results.into_iter()
+        .collect::<Option<Vec<Array<M>>>>()
+        .map(|results| stack_matrices(leading, results))
+        .transpose()
+}
+
+/// broadcasts leading (stack) dimensions of two arrays of matrices
+pub(crate) fn broadcast_leading_shape(leading_1: &[usize], leading_2: &[usize]) -> Result<Vec<usize>, ArrayError> {
+    let len = leading_1.len().max(leading_2.len());
+    let pad = |shape: &[usize]| [vec![1; len - shape.len()], shape.to_vec()].concat();
+    pad(leading_1).into_iter()
+        .zip(pad(leading_2))
+        .map(|(dim_1, dim_2)| match (dim_1, dim_2) {
+            (dim_1, dim_2) if dim_1 == dim_2 || dim_2 == 1 => Ok(dim_1),
+            (1, dim_2) => Ok(dim_2),
+            _ => Err(ArrayError::BroadcastShapeMismatch),
+        })
+        .collect()
+}
+
+/// offsets of matrices of length `matrix_len` with given leading dimensions, for every matrix of broadcast stack
+pub(crate) fn batch_offsets(leading: &[usize], broadcast: &[usize], matrix_len: usize) -> Vec<usize> {
+    // broadcast dimensions of size one do not advance over the operand
+    let padded = [vec![1; broadcast.len() - leading.len()], leading.to_vec()].concat();
+    let mut strides = vec![0; padded.len()];
+    let mut stride = matrix_len;
+    for (axis, &dim) in padded.iter().enumerate().rev() {
+        strides[axis] = if dim == 1 { 0 } else { stride };
+        stride *= dim;
+    }
+    (0..broadcast.iter().product::<usize>())
+        .map(|batch| broadcast.iter().zip(&strides).rev()
+            .fold((batch, 0), |(rest, offset), (&dim, &stride)| (rest / dim, offset + rest % dim * stride))
+            .1)
+        .collect()
+}
+
+/// scalar of matrices factorised by routines working on both real and complex matrices
+pub(crate) trait LinalgScalar: ArrayElement + Copy + Neg<Output = Self> + Mul<Output = Self> + Div<Output = Self> + DivAssign {
+
+    fn from_numeric<N: Numeric>(value: &N) -> Self;
+
+    fn to_numeric<N: Numeric>(self) -> N;
+
+    fn modulus(&self) -> f64;
+
+    /// computes `self * a + b`
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+impl LinalgScalar for f64 {
+
+    fn from_numeric<N: Numeric>(value: &N) -> Self {
+        value.to_f64()
+    }
+
+    fn to_numeric<N: Numeric>(self) -> N {
+        N::from(self)
+    }
+
+    fn modulus(&self) -> f64 {
+        self.abs()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+}
+
+impl LinalgScalar for Complex<f64> {
+
+    fn from_numeric<N: Numeric>(value: &N) -> Self {
+        value.to_complex()
+    }
+
+    fn to_numeric<N: Numeric>(self) -> N {
+        N::from_complex(self)
+    }
+
+    fn modulus(&self) -> f64 {
+        self.norm()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(&self, a, b)
+    }
+}
+
+/// solves stacked systems `a x = b` of square matrices with shape `a_shape`, broadcasting their leading dimensions.
+/// vector `b` of shape `[N]` is shared by all matrices, otherwise `b` is a stack of `[N, K]` matrices.
+/// `solve` is called with index of matrix of `a` and right-hand side matrix
+pub(crate) fn solve_stacked<N: NumericOps, T: LinalgScalar, F>(a_shape: &[usize], b: &Array<N>, solve: F) -> Result<Array<N>, ArrayError>
+    where F: Fn(usize, Vec<Vec<T>>) -> Result<Vec<Vec<T>>, ArrayError> {
+    b.is_dim_unsupported(&[0])?;
+    let (a_leading, size) = (&a_shape[..a_shape.len() - 2], a_shape[a_shape.len() - 1]);
+    let b_shape = b.get_shape()?;
+    let is_vector = b_shape.len() == 1;
+    let (b_leading, rhs_shape) = b_shape.split_at(if is_vector { 0 } else { b_shape.len() - 2 });
+    rhs_shape[0].is_equal(&size)?;
+    let cols = if is_vector { 1 } else { rhs_shape[1] };
 
-        for (i, item) in matrix.iter().enumerate() {
-            let diagonal = item[i].to_f64().abs();
-            let row_sum = item.iter().map(|e| e.abs()).sum::<f64>() - diagonal;
+    let leading = broadcast_leading_shape(a_leading, b_leading)?;
+    let elements = b.get_elements()?.iter().map(T::from_numeric).collect::<Vec<T>>();
+    let solutions = batch_offsets(a_leading, &leading, 1).into_iter()
+        .zip(batch_offsets(b_leading, &leading, size * cols))
+        .map(|(matrix, offset)| solve(matrix, elements[offset..offset + size * cols].chunks(cols.max(1)).map(<[T]>::to_vec).collect()))
+        .collect::<Result<Vec<Vec<Vec<T>>>, ArrayError>>()?;
+    let elements = solutions.into_iter().flatten().flatten().map(T::to_numeric).collect();
+    Array::new(elements, [&leading, rhs_shape].concat())
+}
+
+/// singular value decomposition of `m x n` matrix, with householder bidiagonalisation (golub-kahan)
+/// followed by implicit shifted qr iterations on the bidiagonal matrix (golub-reinsch).
+/// returns `u` (`m x k`), singular values sorted in descending order and `v` (`n x k`), where `k = min(m, n)`.
+/// signs of singular vectors are chosen so that the largest component of every column of `v` is positive
+pub(crate) fn svd_golub_kahan(matrix: &[Vec<f64>]) -> SvdKernelResult {
+    let (rows, cols) = (matrix.len(), matrix[0].len());
+    let (mut left, diagonal, mut right) = if rows < cols {
+        let (left, diagonal, _, right) = bidiagonal_qr(bidiagonalise(&transpose(matrix)))?;
+        (right, diagonal, left)
+    } else {
+        let (left, diagonal, _, right) = bidiagonal_qr(bidiagonalise(matrix))?;
+        (left, diagonal, right)
+    };
+    left.iter_mut().zip(&mut right).for_each(|(u, v)| {
+        let largest = v.iter().fold(0., |acc: f64, &item| if item.abs() > acc.abs() { item } else { acc });
+        if largest < 0. { u.iter_mut().chain(v.iter_mut()).for_each(|item| *item = -*item) }
+    });
+    Ok((transpose(&left), diagonal, transpose(&right)))
+}
 
+/// bidiagonal form `u b v^t` of `m x n` matrix, where `m >= n`. holds columns of `u` and `v` as rows,
+/// so that reflections and rotations run over contiguous memory, and diagonal and superdiagonal of `b`
+type Bidiagonal = (Vec<Vec<f64>>, Vec<f64>, Vec<f64>, Vec<Vec<f64>>);
+
+// inner loops avoid `mul_add`, which without hardware fma target feature is a library call and prevents vectorisation
+fn dot(first: &[f64], second: &[f64]) -> f64 {
+    first.iter().zip(second).map(|(a, b)| a * b).sum()
+}
 
-            if diagonal <= row_sum {
-                return Ok(false)
+fn axpy(factor: f64, source: &[f64], target: &mut [f64]) {
+    target.iter_mut().zip(source).for_each(|(value, item)| *value += factor * item);
+}
+
+/// applies reflector `i - v v^t / v[0]`, stored in row `k` from `offset`, to the following rows
+fn apply_reflector(matrix: &mut [Vec<f64>], k: usize, offset: usize) {
+    let (head, tail) = matrix.split_at_mut(k + 1);
+    let vector = &head[k][offset..];
+    for row in tail { axpy(-dot(vector, &row[offset..]) / vector[0], vector, &mut row[offset..]) }
+}
+
+/// scales vector to the reflector mapping it onto multiple of first unit vector, stored as in `apply_reflector`.
+/// returns the opposite of the resulting first element
+fn reflector(vector: &mut [f64]) -> f64 {
+    let mut norm = vector.iter().fold(0., |acc: f64, item| acc.hypot(*item));
+    if norm != 0. {
+        if vector[0] < 0. { norm = -norm }
+        for item in vector.iter_mut() { *item /= norm }
+        vector[0] += 1.;
+    }
+    -norm
+}
+
+fn bidiagonalise(matrix: &[Vec<f64>]) -> Bidiagonal {
+    let (rows, cols) = (matrix.len(), matrix[0].len());
+    let mut columns = transpose(matrix);
+    let (mut left, mut right) = (vec![vec![0.; rows]; cols], vec![vec![0.; cols]; cols]);
+    let (mut diagonal, mut superdiagonal, mut work) = (vec![0.; cols], vec![0.; cols], vec![0.; rows]);
+    let (col_steps, row_steps) = ((rows - 1).min(cols), cols.saturating_sub(2));
+
+    for k in 0..col_steps.max(row_steps) {
+        if k < col_steps {
+            // reflection zeroing column `k` below the diagonal
+            diagonal[k] = reflector(&mut columns[k][k..]);
+            if diagonal[k] != 0. { apply_reflector(&mut columns, k, k) }
+            left[k][k..].copy_from_slice(&columns[k][k..]);
+        }
+        (k + 1..cols).for_each(|j| superdiagonal[j] = columns[j][k]);
+        if k < row_steps {
+            // reflection zeroing row `k` right of the superdiagonal
+            superdiagonal[k] = reflector(&mut superdiagonal[k + 1..]);
+            if superdiagonal[k] != 0. {
+                work[k + 1..].iter_mut().for_each(|item| *item = 0.);
+                (k + 1..cols).for_each(|j| axpy(superdiagonal[j], &columns[j][k + 1..], &mut work[k + 1..]));
+                (k + 1..cols).for_each(|j| axpy(-superdiagonal[j] / superdiagonal[k + 1], &work[k + 1..], &mut columns[j][k + 1..]));
             }
+            right[k][k + 1..].copy_from_slice(&superdiagonal[k + 1..]);
+        }
+    }
+    if col_steps < cols { diagonal[col_steps] = columns[col_steps][col_steps] }
+    if row_steps + 1 < cols { superdiagonal[row_steps] = columns[cols - 1][row_steps] }
+    superdiagonal[cols - 1] = 0.;
+
+    // reflectors are accumulated backwards, every one acting only on the rows following it
+    for k in (0..cols).rev() {
+        if k < col_steps && diagonal[k] != 0. {
+            apply_reflector(&mut left, k, k);
+            left[k].iter_mut().enumerate().for_each(|(i, item)| *item = if i < k { 0. } else { -*item });
+        } else {
+            left[k].iter_mut().for_each(|item| *item = 0.);
         }
+        left[k][k] += 1.;
 
-        Ok(true)
-    }
-
-    fn hessenberg_reduction(&self) -> Result<Self, ArrayError> {
-        let n = self.get_shape()?[0];
-        let mut h = self.clone();
-
-        for k in 0..n - 2 {
-            let column = h.get_columns()?[k].clone();
-            let x = column.into_iter().skip(k + 1).take(n - (k + 1)).collect();
-            let mut v = Self::zeros_like(&x)?;
-            let x_norm = x.norm(None::<NormOrd>, None, None)?[0];
-            v[0] = N::from(x.sign()?[0]) * x_norm;
-            v = x.clone() - v;
-            let v_norm = v.norm(None::<NormOrd>, None, None)?[0];
-            if v_norm != N::zero() { v /= v_norm; }
-
-            let mut hh = Self::concatenate(h.get_rows()?[k + 1..].to_vec(), None)
-                .reshape(&[n - k - 1, n])?;
-            hh = Self::concatenate(hh.get_columns()?[k..].to_vec(), None)?
-                .reshape(&[n - k, n - k - 1])
-                .transpose(None)?;
-            let outer = v.outer(&v.dot(&hh)?)?;
-            hh -= (outer * N::from(2))?;
-            let mut h_m = h.to_matrix()?;
-            let hh_m = hh.to_matrix()?;
-            for i in 0..hh_m.len() { for j in 0..hh_m[0].len() {
-                h_m[i + k + 1][j + k] = hh_m[i][j];
-            } }
-            h = Self::from_matrix(&h_m)?;
-
-            let mut hh = Self::concatenate(h.get_columns()?[k + 1..].to_vec(), None)
-                .reshape(&[n - k - 1, n])
-                .transpose(None)?;
-            let outer = hh.dot(&v).outer(&v)?;
-            hh -= (outer * N::from(2))?;
-            let mut h_m = h.to_matrix()?;
-            let hh_m = hh.to_matrix()?;
-            for i in 0..hh_m.len() { for j in 0..hh_m[0].len() {
-                h_m[i][j + k + 1] = hh_m[i][j];
+        if k < row_steps && superdiagonal[k] != 0. { apply_reflector(&mut right, k, k + 1) }
+        right[k].iter_mut().for_each(|item| *item = 0.);
+        right[k][k] = 1.;
+    }
+    (left, diagonal, superdiagonal, right)
+}
+
+/// applies givens rotation to rows `first` and `second` of matrix, as `(first, second) <- (c first + s second, c second - s first)`
+fn rotate_rows(matrix: &mut [Vec<f64>], first: usize, second: usize, (cos, sin): (f64, f64)) {
+    let (head, tail) = matrix.split_at_mut(first.max(second));
+    let (a, b) = if first < second { (&mut head[first], &mut tail[0]) } else { (&mut tail[0], &mut head[second]) };
+    a.iter_mut().zip(b.iter_mut()).for_each(|(x, y)| {
+        let (first, second) = (*x, *y);
+        *x *= cos;
+        *x += sin * second;
+        *y *= cos;
+        *y -= sin * first;
+    });
+}
+
+/// givens rotation mapping `(f, g)` onto `(r, 0)`. returns cosine, sine and `r`
+fn givens(f: f64, g: f64) -> ((f64, f64), f64) {
+    let radius = f.hypot(g);
+    ((f / radius, g / radius), radius)
+}
+
+/// diagonalises bidiagonal matrix with implicit shifted qr iterations, accumulating rotations into `u` and `v`.
+/// converged singular values are made non-negative and sorted in descending order
+fn bidiagonal_qr(mut bidiagonal: Bidiagonal) -> Result<Bidiagonal, ArrayError> {
+    let (left, diagonal, superdiagonal, right) = &mut bidiagonal;
+    let size = diagonal.len();
+    let negligible = |value: f64, scale: f64| value.abs() <= f64::EPSILON.mul_add(scale, f64::MIN_POSITIVE / f64::EPSILON);
+    let (mut end, mut iter) = (size, 0);
+    while end > 0 {
+        if iter > SVD_MAX_ITER_PER_VALUE {
+            return Err(ArrayError::ParameterError { param: "`array`", message: "singular value decomposition did not converge" })
+        }
+
+        // unreduced block `start..end` is bounded above by negligible superdiagonal element
+        let split = (0..end - 1).rev()
+            .find(|&k| negligible(superdiagonal[k], diagonal[k].abs() + diagonal[k + 1].abs()));
+        if let Some(k) = split { superdiagonal[k] = 0. }
+        let start = split.map_or(0, |k| k + 1);
+
+        if start == end - 1 {
+            if diagonal[start] <= 0. {
+                diagonal[start] = diagonal[start].abs();
+                right[start].iter_mut().for_each(|item| *item = -*item);
+            }
+            let mut idx = start;
+            while idx + 1 < size && diagonal[idx] < diagonal[idx + 1] {
+                diagonal.swap(idx, idx + 1);
+                left.swap(idx, idx + 1);
+                right.swap(idx, idx + 1);
+                idx += 1;
+            }
+            (end, iter) = (end - 1, 0);
+            continue
+        }
+
+        let zero = (start..end).rev().find(|&k| {
+            let below = if k == end - 1 { 0. } else { superdiagonal[k].abs() };
+            let above = if k == start { 0. } else { superdiagonal[k - 1].abs() };
+            negligible(diagonal[k], below + above)
+        });
+        match zero {
+            Some(k) if k == end - 1 => {
+                // last superdiagonal element is chased up with rotations from the right
+                diagonal[k] = 0.;
+                let mut bulge = std::mem::take(&mut superdiagonal[end - 2]);
+                for j in (start..end - 1).rev() {
+                    let (rotation, radius) = givens(diagonal[j], bulge);
+                    diagonal[j] = radius;
+                    if j != start {
+                        bulge = -rotation.1 * superdiagonal[j - 1];
+                        superdiagonal[j - 1] *= rotation.0;
+                    }
+                    rotate_rows(right, j, end - 1, rotation);
+                }
+            },
+            Some(k) => {
+                // matrix is split at zero diagonal element, by chasing its superdiagonal element to the right
+                diagonal[k] = 0.;
+                let mut bulge = std::mem::take(&mut superdiagonal[k]);
+                for j in k + 1..end {
+                    let (rotation, radius) = givens(diagonal[j], bulge);
+                    diagonal[j] = radius;
+                    bulge = -rotation.1 * superdiagonal[j];
+                    superdiagonal[j] *= rotation.0;
+                    rotate_rows(left, j, k, rotation);
+                }
+            },
+            None => {
+                bidiagonal_qr_step(left, diagonal, superdiagonal, right, start, end);
+                iter += 1;
+            },
+        }
+    }
+    Ok(bidiagonal)
+}
+
+/// implicit qr step on unreduced block `start..end`, shifted by eigenvalue of trailing `2 x 2` block of `b^t b`
+/// closer to its last element
+fn bidiagonal_qr_step(left: &mut [Vec<f64>], diagonal: &mut [f64], superdiagonal: &mut [f64], right: &mut [Vec<f64>], start: usize, end: usize) {
+    let scale = [diagonal[end - 1], diagonal[end - 2], superdiagonal[end - 2], diagonal[start], superdiagonal[start]]
+        .iter().fold(0., |acc: f64, item| acc.max(item.abs()));
+    let (last, prev, prev_super) = (diagonal[end - 1] / scale, diagonal[end - 2] / scale, superdiagonal[end - 2] / scale);
+    let (first, first_super) = (diagonal[start] / scale, superdiagonal[start] / scale);
+    let half_gap = (prev + last).mul_add(prev - last, prev_super * prev_super) / 2.;
+    let product = (last * prev_super) * (last * prev_super);
+    let shift = if half_gap == 0. && product == 0. { 0. } else {
+        let root = half_gap.mul_add(half_gap, product).sqrt();
+        product / (half_gap + if half_gap < 0. { -root } else { root })
+    };
+
+    let (mut f, mut g) = ((first + last).mul_add(first - last, shift), first * first_super);
+    for j in start..end - 1 {
+        let (rotation, radius) = givens(f, g);
+        if j != start { superdiagonal[j - 1] = radius }
+        f = rotation.0.mul_add(diagonal[j], rotation.1 * superdiagonal[j]);
+        superdiagonal[j] = rotation.0.mul_add(superdiagonal[j], -rotation.1 * diagonal[j]);
+        g = rotation.1 * diagonal[j + 1];
+        diagonal[j + 1] *= rotation.0;
+        rotate_rows(right, j, j + 1, rotation);
+
+        let (rotation, radius) = givens(f, g);
+        diagonal[j] = radius;
+        f = rotation.0.mul_add(superdiagonal[j], rotation.1 * diagonal[j + 1]);
+        diagonal[j + 1] = rotation.0.mul_add(diagonal[j + 1], -rotation.1 * superdiagonal[j]);
+        g = rotation.1 * superdiagonal[j + 1];
+        superdiagonal[j + 1] *= rotation.0;
+        rotate_rows(left, j, j + 1, rotation);
+    }
+    superdiagonal[end - 2] = f;
+}
+
+/// extends columns of `m x k` matrix, which are either orthonormal or zero, to `m x cols` matrix with orthonormal columns.
+/// zero columns are replaced in place, missing columns are appended
+pub(crate) fn complete_orthonormal(matrix: &[Vec<f64>], cols: usize) -> Vec<Vec<f64>> {
+    let rows = matrix.len();
+    let mut columns = transpose(matrix)
+        .into_iter()
+        .map(|column| if column.iter().map(|item| item * item).sum::<f64>() > 0.25 { Some(column) } else { None })
+        .collect::<Vec<Option<Vec<f64>>>>();
+    columns.resize(cols, None);
+
+    for idx in 0..cols {
+        if columns[idx].is_some() { continue }
+        let basis = columns.iter().flatten().cloned().collect::<Vec<Vec<f64>>>();
+        let residual = |candidate: usize| {
+            let mut vector = (0..rows).map(|i| if i == candidate { 1. } else { 0. }).collect::<Vec<f64>>();
+            for _ in 0..2 { for column in &basis {
+                let dot = column.iter().zip(&vector).fold(0., |acc: f64, (a, b)| a.mul_add(*b, acc));
+                vector.iter_mut().zip(column).for_each(|(value, item)| *value = (-dot).mul_add(*item, *value));
             } }
-            h = Self::from_matrix(&h_m)?;
+            vector
+        };
+        let best = (0..rows)
+            .map(residual)
+            .max_by(|a, b| a.iter().map(|item| item * item).sum::<f64>().total_cmp(&b.iter().map(|item| item * item).sum::<f64>()))
+            .unwrap_or_default();
+        let norm = best.iter().map(|item| item * item).sum::<f64>().sqrt();
+        columns[idx] = Some(best.into_iter().map(|item| item / norm).collect());
+    }
+    transpose(&columns.into_iter().flatten().collect::<Vec<Vec<f64>>>())
+}
+
+/// householder reflector `h = i - tau v v^t`, mapping vector `x` onto `beta e1`.
+/// returns `v` with unit first element, `tau` and `beta`. no reflection (`tau = 0`) is made for vector already in that form
+pub(crate) fn householder_vector(x: &[f64]) -> (Vec<f64>, f64, f64) {
+    let tail_norm = x[1..].iter().map(|item| item * item).sum::<f64>().sqrt();
+    let mut vector = vec![0.; x.len()];
+    vector[0] = 1.;
+    if tail_norm == 0. { return (vector, 0., x[0]) }
+
+    let beta = -x[0].hypot(tail_norm).copysign(x[0]);
+    let head = x[0] - beta;
+    vector[1..].iter_mut().zip(&x[1..]).for_each(|(value, item)| *value = item / head);
+    (vector, (beta - x[0]) / beta, beta)
+}
+
+/// applies householder reflector `i - tau v v^t` from the left to rows `offset..` of given columns of matrix
+pub(crate) fn apply_householder(matrix: &mut [Vec<f64>], vector: &[f64], tau: f64, offset: usize, columns: std::ops::Range<usize>) {
+    if tau == 0. { return }
+    for col in columns {
+        let dot = vector.iter().zip(&matrix[offset..]).fold(0., |acc: f64, (v, row)| v.mul_add(row[col], acc));
+        vector.iter().zip(&mut matrix[offset..]).for_each(|(v, row)| row[col] = (-tau * dot).mul_add(*v, row[col]));
+    }
+}
+
+/// householder qr decomposition of `m x n` matrix, optionally with column pivoting.
+/// returns factors in compact form, holding `r` in the upper triangle and householder vectors without their unit
+/// first element below the diagonal, scaling factors `tau` of the reflectors, and column permutation
+pub(crate) fn householder_qr(mut matrix: Vec<Vec<f64>>, pivoting: bool) -> (Vec<Vec<f64>>, Vec<f64>, Vec<usize>) {
+    let (rows, cols) = (matrix.len(), matrix[0].len());
+    let mut permutation = (0..cols).collect::<Vec<usize>>();
+    let mut taus = Vec::with_capacity(rows.min(cols));
+    for k in 0..rows.min(cols) {
+        if pivoting {
+            // column with largest remaining norm goes first, so that diagonal of `r` is non-increasing in magnitude
+            let norms = (k..cols)
+                .map(|col| matrix[k..].iter().map(|row| row[col] * row[col]).sum::<f64>())
+                .collect::<Vec<f64>>();
+            let pivot = k + (0..norms.len()).fold(0, |best, idx| if norms[idx] > norms[best] { idx } else { best });
+            if pivot != k {
+                for row in &mut matrix { row.swap(k, pivot) }
+                permutation.swap(k, pivot);
+            }
         }
+        let column = matrix[k..].iter().map(|row| row[k]).collect::<Vec<f64>>();
+        let (vector, tau, beta) = householder_vector(&column);
+        apply_householder(&mut matrix, &vector, tau, k, k + 1..cols);
+        matrix[k][k] = beta;
+        matrix[k + 1..].iter_mut().zip(&vector[1..]).for_each(|(row, v)| row[k] = *v);
+        taus.push(tau);
+    }
+    (matrix, taus, permutation)
+}
 
-        Ok(h)
+/// first `cols` columns of orthogonal `q` (`m x m`) of householder qr decomposition, given in compact form
+pub(crate) fn householder_q(factors: &[Vec<f64>], taus: &[f64], cols: usize) -> Vec<Vec<f64>> {
+    let rows = factors.len();
+    let mut q = (0..rows).map(|i| (0..cols).map(|j| if i == j { 1. } else { 0. }).collect()).collect::<Vec<Vec<f64>>>();
+    for (k, &tau) in taus.iter().enumerate().rev() {
+        let vector = std::iter::once(1.).chain(factors[k + 1..].iter().map(|row| row[k])).collect::<Vec<f64>>();
+        apply_householder(&mut q, &vector, tau, k, 0..cols);
     }
+    q
 }
 
-fn parse_elements<N: NumericOps>(elements: &[N], count_1: usize, count_2: usize) -> Vec<Array<N>> {
-    (0..count_1)
-        .map(|i| elements
-            .to_vec()
-            .iter()
-            .copied()
-            .skip(i * count_2)
-            .take(count_2)
-            .collect())
+/// first `rows` rows of upper triangular `r` of householder qr decomposition, given in compact form
+pub(crate) fn householder_r(factors: &[Vec<f64>], rows: usize) -> Vec<Vec<f64>> {
+    (0..rows)
+        .map(|i| factors.get(i).map_or_else(
+            || vec![0.; factors[0].len()],
+            |row| row.iter().enumerate().map(|(j, &item)| if j >= i { item } else { 0. }).collect()))
         .collect()
 }
+
+/// singular value decomposition of `m x n` matrix, as with `svd_golub_kahan`.
+/// rectangular input is first reduced with pivoted householder qr, so that decomposition is computed for the smaller triangular factor
+pub(crate) fn svd_qr(matrix: &[Vec<f64>]) -> SvdKernelResult {
+    let (rows, cols) = (matrix.len(), matrix[0].len());
+    if rows < cols {
+        let (u, s, v) = svd_qr(&transpose(matrix))?;
+        return Ok((v, s, u))
+    }
+    let backend = linalg_backend();
+    if rows == cols { return backend.svd(matrix) }
+
+    let (factors, taus, permutation) = backend.qr(matrix.to_vec(), true);
+    let (u, values, v) = backend.svd(&householder_r(&factors, cols))?;
+    let q = householder_q(&factors, &taus, cols);
+    let u = q.iter()
+        .map(|q_row| (0..cols).map(|j| q_row.iter().zip(&u).fold(0., |acc: f64, (q, u_row)| q.mul_add(u_row[j], acc))).collect())
+        .collect();
+    let mut permuted = vec![vec![]; cols];
+    permutation.into_iter().zip(v).for_each(|(idx, row)| permuted[idx] = row);
+    Ok((u, values, permuted))
+}
+
+/// qr decomposition of `m x n` matrix with modified gram-schmidt process, where every column is orthogonalised twice.
+/// returns `q` (`m x k`) with orthonormal columns and upper triangular `r` (`k x n`), where `k = min(m, n)`.
+/// columns linearly dependent on the previous ones get zero diagonal in `r`, and are replaced with orthonormal completion in `q`
+pub(crate) fn gram_schmidt(matrix: &[Vec<f64>]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+    let (rows, cols) = (matrix.len(), matrix[0].len());
+    let size = rows.min(cols);
+    let mut basis = Vec::<Vec<f64>>::with_capacity(size);
+    let mut r = vec![vec![0.; cols]; size];
+    for (j, mut vector) in transpose(matrix).into_iter().enumerate() {
+        let column_norm = vector.iter().map(|item| item * item).sum::<f64>().sqrt();
+        // single pass loses orthogonality for ill-conditioned input, second one restores it to working precision
+        for _ in 0..2 { for (i, column) in basis.iter().enumerate() {
+            let dot = column.iter().zip(&vector).fold(0., |acc: f64, (a, b)| a.mul_add(*b, acc));
+            r[i][j] += dot;
+            vector.iter_mut().zip(column).for_each(|(value, item)| *value = (-dot).mul_add(*item, *value));
+        } }
+        if basis.len() == size { continue }
+
+        let norm = vector.iter().map(|item| item * item).sum::<f64>().sqrt();
+        if norm <= rows.to_f64() * f64::EPSILON * column_norm || norm == 0. {
+            basis.push(vec![0.; rows]);
+        } else {
+            r[basis.len()][j] = norm;
+            basis.push(vector.into_iter().map(|item| item / norm).collect());
+        }
+    }
+    (complete_orthonormal(&transpose(&basis), size), r)
+}
+
+/// lu decomposition of `m x n` matrix with partial pivoting.
+/// returns combined `l` and `u` factors, with unit diagonal of `l` omitted, and row interchanges made at each step
+pub(crate) fn lu_decompose<T: LinalgScalar>(mut matrix: Vec<Vec<T>>) -> (Vec<Vec<T>>, Vec<usize>) {
+    let (rows, cols) = (matrix.len(), matrix[0].len());
+    let mut pivots = Vec::with_capacity(rows.min(cols));
+    for k in 0..rows.min(cols) {
+        let pivot = (k..rows).fold(k, |best, row| if matrix[row][k].modulus() > matrix[best][k].modulus() { row } else { best });
+        pivots.push(pivot);
+        matrix.swap(k, pivot);
+        let head = matrix[k][k];
+        if head.modulus() == 0. { continue }
+        let (upper, lower) = matrix.split_at_mut(k + 1);
+        for row in lower {
+            let factor = row[k] / head;
+            row[k] = factor;
+            row[k + 1..].iter_mut().zip(&upper[k][k + 1..])
+                .for_each(|(value, &pivot_value)| *value = (-factor).mul_add(pivot_value, *value));
+        }
+    }
+    (matrix, pivots)
+}
+
+/// row permutation described by pivots of lu decomposition
+pub(crate) fn lu_permutation(pivots: &[usize], rows: usize) -> Vec<usize> {
+    pivots.iter().enumerate().fold((0..rows).collect(), |mut permutation: Vec<usize>, (k, &pivot)| {
+        permutation.swap(k, pivot);
+        permutation
+    })
+}
+
+/// solution of `a x = b` for square `a` given by its lu decomposition, with `b` of shape `n x k`
+pub(crate) fn lu_substitute<T: LinalgScalar>(lu: &[Vec<T>], pivots: &[usize], mut rhs: Vec<Vec<T>>) -> Result<Vec<Vec<T>>, ArrayError> {
+    let size = lu.len();
+    let scale = lu.iter().flatten().fold(0., |acc: f64, value| acc.max(value.modulus()));
+    if (0..size).any(|i| lu[i][i].modulus() <= size.to_f64() * f64::EPSILON * scale) {
+        return Err(ArrayError::SingularMatrix)
+    }
+
+    pivots.iter().enumerate().for_each(|(k, &pivot)| rhs.swap(k, pivot));
+    for (i, lu_row) in lu.iter().enumerate() {
+        let (solved, rest) = rhs.split_at_mut(i);
+        for (factor, row) in lu_row.iter().zip(solved.iter()) {
+            rest[0].iter_mut().zip(row).for_each(|(value, &known)| *value = (-*factor).mul_add(known, *value));
+        }
+    }
+    for i in (0..size).rev() {
+        let (head, solved) = rhs.split_at_mut(i + 1);
+        for (j, row) in solved.iter().enumerate() {
+            head[i].iter_mut().zip(row).for_each(|(value, &known)| *value = (-lu[i][i + 1 + j]).mul_add(known, *value));
+        }
+        head[i].iter_mut().for_each(|value| *value /= lu[i][i]);
+    }
+    Ok(rhs)
+}
+
+pub(crate) fn transpose(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    (0..matrix[0].len())
+        .map(|j| matrix.iter().map(|row| row[j]).collect())
+        .collect()
+}
+
+const SVD_MAX_ITER_PER_VALUE: usize = 75;
@@ -1,7 +1,11 @@
 use crate::{
     core::prelude::*,
     errors::prelude::*,
-    linalg::prelude::*,
+    linalg::{
+        backend::linalg_backend,
+        operations::common::{complete_orthonormal, gram_schmidt, householder_q, householder_r, lu_decompose, lu_permutation, lu_substitute, solve_stacked, stack_matrices, stack_optional, transpose, LinalgScalar},
+        prelude::*,
+    },
     numeric::prelude::*,
     validators::prelude::*,
 };
@@ -9,98 +13,595 @@ use crate::{
 /// `ArrayTrait` - Array Linalg Decompositions functions
 pub trait ArrayLinalgDecompositions<N: NumericOps> where Self: Sized + Clone {
 
-    /// Compute the qr factorization of a matrix
+    /// Compute the qr factorization of a matrix, using householder reflections.
+    /// Returns `(q, r, p)` for reduced and complete modes, `(None, r, p)` for `r` mode and `(h, tau, p)` for raw mode,
+    /// where `p` is the column permutation, such that `a[:, p] = q r`, given only with pivoting.
+    /// stacks of matrices are decomposed over the leading dimensions, with results stacked the same way
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - {`reduced`, `complete`, `r`, `raw`}. optional, defaults to `reduced`
+    /// * `pivoting` - if true, columns are pivoted by their norms, revealing rank in decreasing diagonal of `r`. optional, defaults to false
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![1., 2., 3., 4.], vec![2, 2]);
+    /// let (q, r, _) = array.qr(None::<QrMode>, None).unwrap();
+    ///
+    /// assert_eq!(Array::new(vec![-0.316227766, -0.948683298, -0.948683298, 0.316227766], vec![2, 2]), q.unwrap().map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// assert_eq!(Array::new(vec![-3.16227766, -4.427188724, 0., -0.632455532], vec![2, 2]), r.map(|v: &f64| (v * 1e9).round() / 1e9));
+    ///
+    /// let (q, r, p) = array.qr(Some("r"), Some(true)).unwrap();
+    /// assert_eq!(None, q);
+    /// assert_eq!(vec![2, 2], r.get_shape().unwrap());
+    /// assert_eq!(Some(Array::flat(vec![1, 0]).unwrap()), p);
+    ///
+    /// let stack = Array::new(vec![1., 2., 3., 4., 2., 0., 0., 2.], vec![2, 2, 2]);
+    /// let (q, r, _) = stack.qr(None::<QrMode>, None).unwrap();
+    /// assert_eq!(vec![2, 2, 2], q.unwrap().get_shape().unwrap());
+    /// assert_eq!(vec![2, 2, 2], r.get_shape().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn qr(&self, mode: Option<impl QrModeType>, pivoting: Option<bool>) -> QrResult<N>;
+
+    /// Compute the lu decomposition of a matrix with partial pivoting, such that `a = p l u`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![2., 1., 4., 3.], vec![2, 2]);
+    /// let (p, l, u) = array.lu().unwrap();
+    ///
+    /// assert_eq!(p, Array::new(vec![0., 1., 1., 0.], vec![2, 2]).unwrap());
+    /// assert_eq!(l, Array::new(vec![1., 0., 0.5, 1.], vec![2, 2]).unwrap());
+    /// assert_eq!(u, Array::new(vec![4., 3., 0., -0.5], vec![2, 2]).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn lu(&self) -> LuResult<N>;
+
+    /// Compute the lu factorization of a square matrix, to be used with `lu_solve`
+    /// Returns combined `l` and `u` factors (unit diagonal of `l` is not stored) and pivot indices,
+    /// where row `i` was interchanged with row `piv[i]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![2., 1., 4., 3.], vec![2, 2]);
+    /// let (lu, piv) = array.lu_factor().unwrap();
+    ///
+    /// assert_eq!(lu, Array::new(vec![4., 3., 0.5, -0.5], vec![2, 2]).unwrap());
+    /// assert_eq!(piv, Array::flat(vec![1, 1]).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn lu_factor(&self) -> LuFactorResult<N>;
+
+    /// Solve a linear equation system `a x = b`, given the lu factorization of `a`
+    ///
+    /// # Arguments
+    ///
+    /// * `piv` - pivot indices returned by `lu_factor`, of shape `[..., N]`
+    /// * `b` - right-hand side of the system, vector of shape `[N]` or stack of matrices of shape `[..., N, K]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![2., 1., 4., 3.], vec![2, 2]);
+    /// let (lu, piv) = array.lu_factor().unwrap();
+    ///
+    /// assert_eq!(Array::flat(vec![1., 1.]), lu.lu_solve(&piv, &Array::flat(vec![3., 7.]).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn lu_solve(&self, piv: &Array<usize>, b: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the cholesky decomposition of a symmetric positive-definite matrix.
+    /// Only the lower triangle of the input matrix is referenced
+    ///
+    /// # Arguments
+    ///
+    /// * `lower` - if true, returns lower triangular `l`, such that `a = l l^T`, otherwise upper `u`, such that `a = u^T u`. optional, defaults to true
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![4., 2., 2., 5.], vec![2, 2]);
+    ///
+    /// assert_eq!(Array::new(vec![2., 0., 1., 2.], vec![2, 2]), array.cholesky(None));
+    /// assert_eq!(Array::new(vec![2., 1., 0., 2.], vec![2, 2]), array.cholesky(Some(false)));
+    /// assert_eq!(Err(ArrayError::NotPositiveDefinite), Array::new(vec![1., 2., 2., 1.], vec![2, 2]).cholesky(None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn cholesky(&self, lower: Option<bool>) -> Result<Array<N>, ArrayError>;
+
+    /// Solve a linear equation system `a x = b`, given the cholesky factor of `a`
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - right-hand side of the system, vector of shape `[N]` or stack of matrices of shape `[..., N, K]`
+    /// * `lower` - whether the factor is lower or upper triangular. optional, defaults to true
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![4., 2., 2., 5.], vec![2, 2]);
+    /// let factor = array.cholesky(None).unwrap();
+    ///
+    /// assert_eq!(Array::flat(vec![1., 1.]), factor.cho_solve(&Array::flat(vec![6., 7.]).unwrap(), None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn cho_solve(&self, b: &Array<N>, lower: Option<bool>) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the ldl decomposition of a symmetric, possibly indefinite, matrix with bunch-kaufman pivoting.
+    /// Returns unit lower triangular `l`, block diagonal `d` (with 1x1 and 2x2 blocks) and permutation `perm`,
+    /// such that `a[perm][:, perm] = l d l^T`. Only the lower triangle of the input matrix is referenced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![4., 2., 2., -3.], vec![2, 2]);
+    /// let (l, d, perm) = array.ldl().unwrap();
+    ///
+    /// assert_eq!(l, Array::new(vec![1., 0., 0.5, 1.], vec![2, 2]).unwrap());
+    /// assert_eq!(d, Array::new(vec![4., 0., 0., -4.], vec![2, 2]).unwrap());
+    /// assert_eq!(perm, Array::flat(vec![0, 1]).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn ldl(&self) -> LdlResult<N>;
+
+    /// Compute the singular value decomposition of a matrix, such that `a = u diag(s) vh`
+    ///
+    /// # Arguments
+    ///
+    /// * `full_matrices` - if true, `u` and `vh` are of shape `[M, M]` and `[N, N]`, otherwise `[M, K]` and `[K, N]`, where `K = min(M, N)`. optional, defaults to true
+    /// * `compute_uv` - whether to compute `u` and `vh` in addition to `s`. optional, defaults to true
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![3., 0., 0., -2.], vec![2, 2]);
+    /// let (u, s, vh) = array.svd(None, None).unwrap();
+    ///
+    /// assert_eq!(u, Some(Array::new(vec![1., 0., 0., -1.], vec![2, 2]).unwrap()));
+    /// assert_eq!(s, Array::flat(vec![3., 2.]).unwrap());
+    /// assert_eq!(vh, Some(Array::new(vec![1., 0., 0., 1.], vec![2, 2]).unwrap()));
+    ///
+    /// let result = array.svd(None, Some(false)).unwrap();
+    /// assert_eq!((None, Array::flat(vec![3., 2.]).unwrap(), None), result);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn svd(&self, full_matrices: Option<bool>, compute_uv: Option<bool>) -> SvdResult<N>;
+
+    /// Compute the singular values of a matrix, sorted in descending order
     ///
     /// # Examples
     ///
     /// ```
     /// use arr_rs::prelude::*;
     ///
-    /// let array = Array::new(vec![1., 2., 3., 4., 5., 6., 7., 8., 9.], vec![3, 3]);
-    /// let result = array.qr().unwrap();
-    /// let (q, r) = &result.clone()[0];
+    /// let array = Array::new(vec![3., 0., 0., -2.], vec![2, 2]);
+    /// assert_eq!(Array::flat(vec![3., 2.]), array.svdvals());
     ///
-    /// assert_eq!(q, &Array::new(vec![0.12309149097933272, 0.9045340337332908, 0.1111111111111111, 0.4923659639173309, 0.30151134457776335, 0.4444444444444444, 0.8616404368553291, -0.30151134457776435, 0.8888888888888888], vec![3, 3]).unwrap());
-    /// assert_eq!(r, &Array::new(vec![8.12403840463596, 9.601136296387953, 11.078234188139945, -6.494804694057166e-15, 0.9045340337332837, 1.809068067466573, 8.11111111111111, 9.555555555555555, 11.], vec![3, 3]).unwrap());
+    /// let array = Array::new(vec![3., 0., 0., -2., 1., 0., 0., 4.], vec![2, 2, 2]);
+    /// assert_eq!(Array::new(vec![3., 2., 4., 1.], vec![2, 2]), array.svdvals());
     /// ```
     ///
     /// # Errors
     ///
     /// may returns `ArrayError`
-    fn qr(&self) -> LinalgResult<N>;
+    fn svdvals(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the qr factorization of a matrix with modified gram-schmidt process, orthogonalising every column twice.
+    /// for `m x n` matrix returns `q` of shape `m x k` with orthonormal columns and upper triangular `r` of shape `k x n`,
+    /// where `k = min(m, n)`. columns dependent on the previous ones get zero on the diagonal of `r`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![3., 1., 4., 2.], vec![2, 2]);
+    /// let (q, r) = array.gram_schmidt().unwrap();
+    ///
+    /// assert_eq!(Array::new(vec![0.6, -0.8, 0.8, 0.6], vec![2, 2]), q.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// assert_eq!(Array::new(vec![5., 2.2, 0., 0.4], vec![2, 2]), r.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn gram_schmidt(&self) -> LinalgResult<N>;
 }
 
 impl <N: NumericOps> ArrayLinalgDecompositions<N> for Array<N> {
 
-    fn qr(&self) -> LinalgResult<N> {
+    fn qr(&self, mode: Option<impl QrModeType>, pivoting: Option<bool>) -> QrResult<N> {
+        let mode = mode.map_or(Ok(QrMode::Reduced), QrModeType::to_qr_mode)?;
+        let pivoting = pivoting.unwrap_or(false);
+        let leading = self.leading_shape()?;
+        let (q, r, p): (Vec<_>, Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let rows = matrix.get_shape()?[0];
+                let (factors, taus, permutation) = linalg_backend().qr(matrix.to_matrix_f64()?, pivoting);
+                let size = taus.len();
+                let permutation = if pivoting { Some(Array::flat(permutation)?) } else { None };
+                let (q, r) = match mode {
+                    QrMode::Reduced => (Some(householder_q(&factors, &taus, size)), householder_r(&factors, size)),
+                    QrMode::Complete => (Some(householder_q(&factors, &taus, rows)), householder_r(&factors, rows)),
+                    QrMode::R => (None, householder_r(&factors, size)),
+                    QrMode::Raw => return Ok((Some(Self::from_matrix_f64(&factors)?), Self::flat(taus.into_iter().map(N::from).collect())?, permutation)),
+                };
+                Ok((q.map(|q| Self::from_matrix_f64(&q)).transpose()?, Self::from_matrix_f64(&r)?, permutation))
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_optional(&leading, q)?, stack_matrices(&leading, r)?, stack_optional(&leading, p)?))
+    }
+
+    fn lu(&self) -> LuResult<N> {
+        let leading = self.leading_shape()?;
+        let is_complex = self.is_complex()?;
+        let (p, l, u): (Vec<_>, Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                if is_complex { Self::lu_factors(lu_decompose(matrix.to_matrix_complex()?)) }
+                else { Self::lu_factors(linalg_backend().lu(matrix.to_matrix_f64()?)) }
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_matrices(&leading, p)?, stack_matrices(&leading, l)?, stack_matrices(&leading, u)?))
+    }
+
+    fn lu_factor(&self) -> LuFactorResult<N> {
         self.is_dim_unsupported(&[0, 1])?;
-        self.is_square()?;
-        if self.ndim()? == 2 {
-            Ok(vec![Self::gram_schmidt(self)?])
-        } else {
-            let shape = self.get_shape()?;
-            let sub_shape = shape[self.ndim()? - 2 ..].to_vec();
-            let qrs = self
-                .ravel()?
-                .split(self.len()? / sub_shape.iter().product::<usize>(), None)?
-                .iter()
-                .map(|arr| arr.reshape(&sub_shape).qr())
-                .collect::<Vec<Result<Vec<(Self, Self)>, _>>>()
-                .has_error()?.into_iter()
-                .flat_map(Result::unwrap)
-                .collect::<Vec<(Self, Self)>>();
-            Ok(qrs)
+        self.get_shape()?.is_square()?;
+        let leading = self.leading_shape()?;
+        let is_complex = self.is_complex()?;
+        let (lu, piv): (Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let (lu, pivots) = if is_complex {
+                    let (lu, pivots) = lu_decompose(matrix.to_matrix_complex()?);
+                    (Self::from_matrix_scalar(&lu)?, pivots)
+                } else {
+                    let (lu, pivots) = linalg_backend().lu(matrix.to_matrix_f64()?);
+                    (Self::from_matrix_scalar(&lu)?, pivots)
+                };
+                Ok((lu, Array::flat(pivots)?))
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_matrices(&leading, lu)?, stack_matrices(&leading, piv)?))
+    }
+
+    fn lu_solve(&self, piv: &Array<usize>, b: &Self) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        let shape = self.get_shape()?;
+        let size = shape[shape.len() - 1];
+        piv.get_shape()?.is_equal(&[&shape[..shape.len() - 1]].concat())?;
+        if piv.get_elements()?.iter().any(|&pivot| pivot >= size) {
+            return Err(ArrayError::OutOfBounds { value: "`piv`" })
+        }
+
+        let pivots = piv.get_elements()?;
+        if self.is_complex()? || b.is_complex()? {
+            let factors = self.get_matrices()?.iter()
+                .map(LinalgHelper::to_matrix_complex)
+                .collect::<Result<Vec<Vec<Vec<Complex<f64>>>>, ArrayError>>()?;
+            return solve_stacked(&shape, b, |idx, rhs| lu_substitute(&factors[idx], &pivots[idx * size..(idx + 1) * size], rhs))
         }
+        let factors = self.get_matrices()?.iter()
+            .map(LinalgHelper::to_matrix_f64)
+            .collect::<Result<Vec<Vec<Vec<f64>>>, ArrayError>>()?;
+        solve_stacked(&shape, b, |idx, rhs| lu_substitute(&factors[idx], &pivots[idx * size..(idx + 1) * size], rhs))
+    }
+
+    fn cholesky(&self, lower: Option<bool>) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        self.map_matrices(|matrix| {
+            let factor = Self::cholesky_decompose(&matrix.to_matrix_f64()?)?;
+            if lower.unwrap_or(true) { Self::from_matrix_f64(&factor) }
+            else { Self::from_matrix_f64(&transpose(&factor)) }
+        })
+    }
+
+    fn cho_solve(&self, b: &Self, lower: Option<bool>) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        b.is_complex_unsupported()?;
+        let factors = self.get_matrices()?.iter()
+            .map(|matrix| {
+                let factor = matrix.to_matrix_f64()?;
+                Ok(if lower.unwrap_or(true) { factor } else { transpose(&factor) })
+            })
+            .collect::<Result<Vec<Vec<Vec<f64>>>, ArrayError>>()?;
+        solve_stacked(&self.get_shape()?, b, |idx, rhs| Self::cholesky_substitute(&factors[idx], rhs))
+    }
+
+    fn ldl(&self) -> LdlResult<N> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        let leading = self.leading_shape()?;
+        let (l, d, perm): (Vec<_>, Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let (l, d, perm) = Self::bunch_kaufman(&matrix.to_matrix_f64()?);
+                Ok((Self::from_matrix_f64(&l)?, Self::from_matrix_f64(&d)?, Array::flat(perm)?))
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_matrices(&leading, l)?, stack_matrices(&leading, d)?, stack_matrices(&leading, perm)?))
+    }
+
+    fn svd(&self, full_matrices: Option<bool>, compute_uv: Option<bool>) -> SvdResult<N> {
+        let leading = self.leading_shape()?;
+        let (u, s, vh): (Vec<_>, Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let (rows, cols) = (matrix.get_shape()?[0], matrix.get_shape()?[1]);
+                let (u, values, v) = linalg_backend().svd(&matrix.to_matrix_f64()?)?;
+                let values = Self::flat(values.into_iter().map(N::from).collect())?;
+                if !compute_uv.unwrap_or(true) { return Ok((None, values, None)) }
+
+                let size = rows.min(cols);
+                let (u_cols, v_cols) = if full_matrices.unwrap_or(true) { (rows, cols) } else { (size, size) };
+                let u = complete_orthonormal(&u, u_cols);
+                let vh = transpose(&complete_orthonormal(&v, v_cols));
+                Ok((Some(Self::from_matrix_f64(&u)?), values, Some(Self::from_matrix_f64(&vh)?)))
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_optional(&leading, u)?, stack_matrices(&leading, s)?, stack_optional(&leading, vh)?))
+    }
+
+    fn svdvals(&self) -> Result<Self, ArrayError> {
+        self.map_matrices(|matrix| Self::flat(linalg_backend().svd(&matrix.to_matrix_f64()?)?.1.into_iter().map(N::from).collect()))
+    }
+
+    fn gram_schmidt(&self) -> LinalgResult<N> {
+        let leading = self.leading_shape()?;
+        let (q, r): (Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let (q, r) = gram_schmidt(&matrix.to_matrix_f64()?);
+                Ok((Self::from_matrix_f64(&q)?, Self::from_matrix_f64(&r)?))
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_matrices(&leading, q)?, stack_matrices(&leading, r)?))
     }
 }
 
 impl <N: NumericOps> ArrayLinalgDecompositions<N> for Result<Array<N>, ArrayError> {
 
-    fn qr(&self) -> LinalgResult<N> {
-        self.clone()?.qr()
+    fn qr(&self, mode: Option<impl QrModeType>, pivoting: Option<bool>) -> QrResult<N> {
+        self.clone()?.qr(mode, pivoting)
+    }
+
+    fn lu(&self) -> LuResult<N> {
+        self.clone()?.lu()
+    }
+
+    fn lu_factor(&self) -> LuFactorResult<N> {
+        self.clone()?.lu_factor()
+    }
+
+    fn lu_solve(&self, piv: &Array<usize>, b: &Array<N>) -> Self {
+        self.clone()?.lu_solve(piv, b)
+    }
+
+    fn cholesky(&self, lower: Option<bool>) -> Self {
+        self.clone()?.cholesky(lower)
+    }
+
+    fn cho_solve(&self, b: &Array<N>, lower: Option<bool>) -> Self {
+        self.clone()?.cho_solve(b, lower)
+    }
+
+    fn ldl(&self) -> LdlResult<N> {
+        self.clone()?.ldl()
+    }
+
+    fn svd(&self, full_matrices: Option<bool>, compute_uv: Option<bool>) -> SvdResult<N> {
+        self.clone()?.svd(full_matrices, compute_uv)
+    }
+
+    fn svdvals(&self) -> Self {
+        self.clone()?.svdvals()
+    }
+
+    fn gram_schmidt(&self) -> LinalgResult<N> {
+        self.clone()?.gram_schmidt()
+    }
+}
+
+trait LuHelper<N: NumericOps> {
+
+    fn lu_factors<T: LinalgScalar>((lu, pivots): (Vec<Vec<T>>, Vec<usize>)) -> LuResult<N> {
+        let (rows, cols) = (lu.len(), lu[0].len());
+        let size = rows.min(cols);
+        let permutation = lu_permutation(&pivots, rows);
+        let p = (0..rows)
+            .map(|i| (0..rows).map(|j| if permutation[j] == i { T::one() } else { T::zero() }).collect())
+            .collect::<Vec<Vec<T>>>();
+        let l = (0..rows)
+            .map(|i| (0..size).map(|j| match i.cmp(&j) {
+                std::cmp::Ordering::Greater => lu[i][j],
+                std::cmp::Ordering::Equal => T::one(),
+                std::cmp::Ordering::Less => T::zero(),
+            }).collect())
+            .collect::<Vec<Vec<T>>>();
+        let u = (0..size)
+            .map(|i| (0..cols).map(|j| if j >= i { lu[i][j] } else { T::zero() }).collect())
+            .collect::<Vec<Vec<T>>>();
+        Ok((Array::from_matrix_scalar(&p)?, Array::from_matrix_scalar(&l)?, Array::from_matrix_scalar(&u)?))
     }
 }
 
-trait QRHelper<N: NumericOps> {
+impl <N: NumericOps> LuHelper<N> for Array<N> {}
 
-    fn gram_schmidt(arr: &Array<N>) -> Result<(Array<N>, Array<N>), ArrayError> {
+trait CholeskyHelper {
 
-        fn project<N: NumericOps>(u: &Array<N>, a: &Array<N>) -> Result<Array<N>, ArrayError> {
-            let cols = u.len()?;
-            let result = u.inner(a).broadcast_to(vec![cols])?
-                / u.inner(u).broadcast_to(vec![cols])?
-                * u.clone();
-            Ok(result)
+    fn cholesky_decompose(matrix: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, ArrayError> {
+        let size = matrix.len();
+        let mut factor = vec![vec![0_f64; size]; size];
+        for j in 0..size {
+            let (solved, rest) = factor.split_at_mut(j);
+            let row = &mut rest[0];
+            for (k, solved_row) in solved.iter().enumerate() {
+                let dot = row.iter().zip(solved_row).take(k).fold(0., |acc: f64, (a, b)| a.mul_add(*b, acc));
+                row[k] = (matrix[j][k] - dot) / solved_row[k];
+            }
+            let diagonal = row.iter().take(j).fold(matrix[j][j], |acc, value| (-value).mul_add(*value, acc));
+            if diagonal.is_nan() || diagonal <= 0. {
+                return Err(ArrayError::NotPositiveDefinite)
+            }
+            row[j] = diagonal.sqrt();
         }
+        Ok(factor)
+    }
 
-        fn normalize<N: NumericOps>(arr: &Array<N>) -> Result<Array<N>, ArrayError> {
-            let norm = arr
-                .get_elements()?
-                .into_iter()
-                .map(|u| u.to_f64().powi(2))
-                .sum::<f64>().sqrt();
-            Array::single(N::from(norm))
-                .broadcast_to(vec![arr.len()?])
+    fn cholesky_substitute(factor: &[Vec<f64>], mut rhs: Vec<Vec<f64>>) -> Result<Vec<Vec<f64>>, ArrayError> {
+        let size = factor.len();
+        if factor.iter().enumerate().any(|(i, row)| row[i] == 0.) {
+            return Err(ArrayError::SingularMatrix)
         }
 
-        let (mut u_vecs, mut e_vecs) = (vec![], vec![]);
-        for col in arr.get_columns()? {
-            let mut a = col;
-            for u in &u_vecs { a -= project(u, &a)? }
-            u_vecs.push(a.clone());
-            let a_norm = normalize(&a)?;
-            e_vecs.push(a / a_norm);
+        for (i, factor_row) in factor.iter().enumerate() {
+            let (solved, rest) = rhs.split_at_mut(i);
+            for (coefficient, row) in factor_row.iter().zip(solved.iter()) {
+                rest[0].iter_mut().zip(row).for_each(|(value, &known)| *value = (-coefficient).mul_add(known, *value));
+            }
+            rest[0].iter_mut().for_each(|value| *value /= factor_row[i]);
+        }
+        for i in (0..size).rev() {
+            let (head, solved) = rhs.split_at_mut(i + 1);
+            for (j, row) in solved.iter().enumerate() {
+                let coefficient = factor[i + 1 + j][i];
+                head[i].iter_mut().zip(row).for_each(|(value, &known)| *value = (-coefficient).mul_add(known, *value));
+            }
+            head[i].iter_mut().for_each(|value| *value /= factor[i][i]);
         }
+        Ok(rhs)
+    }
+}
+
+impl <N: NumericOps> CholeskyHelper for Array<N> {}
+
+trait LdlHelper {
 
-        let q = Array::concatenate(e_vecs, None)
-            .reshape(&arr.get_shape()?)
-            .transpose(None)?;
-        let r = q
-            .transpose(None)
-            .dot(arr)?;
+    fn bunch_kaufman(matrix: &[Vec<f64>]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>, Vec<usize>) {
 
-        Ok((q, r))
+        fn swap_symmetric(work: &mut [Vec<f64>], l: &mut [Vec<f64>], perm: &mut [usize], p: usize, q: usize) {
+            if p == q { return }
+            work.swap(p, q);
+            for row in work.iter_mut() { row.swap(p, q) }
+            l.swap(p, q);
+            perm.swap(p, q);
+        }
+
+        let alpha = (1. + 17_f64.sqrt()) / 8.;
+        let size = matrix.len();
+        let mut work = (0..size)
+            .map(|i| (0..size).map(|j| if j <= i { matrix[i][j] } else { matrix[j][i] }).collect())
+            .collect::<Vec<Vec<f64>>>();
+        let mut l = vec![vec![0.; size]; size];
+        let mut d = vec![vec![0.; size]; size];
+        let mut perm = (0..size).collect::<Vec<usize>>();
+
+        let mut k = 0;
+        while k < size {
+            let (max_row, col_max) = (k + 1..size)
+                .fold((k, 0.), |(row, max): (usize, f64), i| if work[i][k].abs() > max { (i, work[i][k].abs()) } else { (row, max) });
+            let diagonal = work[k][k].abs();
+            let block = if col_max == 0. || diagonal >= alpha * col_max { 1 } else {
+                let row_max = (k..size)
+                    .filter(|&j| j != max_row)
+                    .fold(0., |max: f64, j| max.max(work[max_row][j].abs()));
+                if diagonal * row_max >= alpha * col_max * col_max { 1 }
+                else if work[max_row][max_row].abs() >= alpha * row_max {
+                    swap_symmetric(&mut work, &mut l, &mut perm, k, max_row);
+                    1
+                } else {
+                    swap_symmetric(&mut work, &mut l, &mut perm, k + 1, max_row);
+                    2
+                }
+            };
+
+            if block == 1 {
+                let pivot = work[k][k];
+                d[k][k] = pivot;
+                l[k][k] = 1.;
+                if pivot == 0. { k += 1; continue }
+                let column = (k + 1..size).map(|i| work[i][k]).collect::<Vec<f64>>();
+                for (i, &a) in column.iter().enumerate() {
+                    l[k + 1 + i][k] = a / pivot;
+                    for (j, &b) in column.iter().enumerate() {
+                        work[k + 1 + i][k + 1 + j] -= a * b / pivot;
+                    }
+                }
+            } else {
+                let (e11, e21, e22) = (work[k][k], work[k + 1][k], work[k + 1][k + 1]);
+                let det = e11.mul_add(e22, -e21 * e21);
+                let inverse = [[e22 / det, -e21 / det], [-e21 / det, e11 / det]];
+                d[k][k] = e11;
+                d[k + 1][k] = e21;
+                d[k][k + 1] = e21;
+                d[k + 1][k + 1] = e22;
+                l[k][k] = 1.;
+                l[k + 1][k + 1] = 1.;
+                let columns = (k + 2..size).map(|i| (work[i][k], work[i][k + 1])).collect::<Vec<(f64, f64)>>();
+                let factors = columns.iter()
+                    .map(|&(a, b)| (a.mul_add(inverse[0][0], b * inverse[1][0]), a.mul_add(inverse[0][1], b * inverse[1][1])))
+                    .collect::<Vec<(f64, f64)>>();
+                for (i, &(f1, f2)) in factors.iter().enumerate() {
+                    l[k + 2 + i][k] = f1;
+                    l[k + 2 + i][k + 1] = f2;
+                    for (j, &(a, b)) in columns.iter().enumerate() {
+                        work[k + 2 + i][k + 2 + j] -= f1.mul_add(a, f2 * b);
+                    }
+                }
+            }
+            k += block;
+        }
+        (l, d, perm)
     }
 }
 
-impl <N: NumericOps> QRHelper<N> for Array<N> {}
+impl <N: NumericOps> LdlHelper for Array<N> {}
@@ -1,7 +1,11 @@
 use crate::{
     core::prelude::*,
     errors::prelude::*,
-    linalg::prelude::*,
+    linalg::{
+        backend::linalg_backend,
+        operations::common::{apply_householder, householder_vector, stack_matrices},
+        prelude::*,
+    },
     numeric::prelude::*,
     validators::prelude::*,
 };
@@ -9,112 +13,611 @@ use crate::{
 /// `ArrayTrait` - Array Linalg Eigen functions
 pub trait ArrayLinalgEigen<N: NumericOps> where Self: Sized + Clone {
 
-    /// Compute the eigenvalues of a square array
+    /// Compute the eigenvalues of a square array.
+    /// eigenvalues are returned as complex numbers, as real arrays may have complex eigenvalues.
+    /// for stack of arrays of shape `[..., N, N]` eigenvalues are of shape `[..., N]`
     ///
     /// # Examples
     ///
     /// ```
     /// use arr_rs::prelude::*;
     ///
-    /// let array = Array::new(vec![12., -51., 4., 6., 167., -68., -4., 24., -41.], vec![3, 3]).unwrap();
-    /// let vals = array.eigvals().unwrap()[0].clone();
-    /// assert_eq!(Array::flat(vec![156.20350762022625, -35.473244608879945, 17.26973698865371]).unwrap(), vals);
+    /// let array = Array::new(vec![2., 0., 0., 3.], vec![2, 2]).unwrap();
+    /// let vals = array.eigvals().unwrap();
+    /// assert_eq!(Array::flat(vec![Complex::new(2., 0.), Complex::new(3., 0.)]).unwrap(), vals);
+    ///
+    /// let array = Array::new(vec![0., -1., 1., 0.], vec![2, 2]).unwrap();
+    /// let vals = array.eigvals().unwrap().map(|v| Complex::new(v.re.round(), v.im.round())).unwrap();
+    /// assert_eq!(Array::flat(vec![Complex::new(0., -1.), Complex::new(0., 1.)]).unwrap(), vals);
+    ///
+    /// let array = Array::new(vec![2., 0., 0., 3., 1., 0., 0., 4.], vec![2, 2, 2]).unwrap();
+    /// assert_eq!(vec![2, 2], array.eigvals().unwrap().get_shape().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn eigvals(&self) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Compute the eigenvalues and right eigenvectors of a square array.
+    /// eigenvectors are normalized to unit length, with the largest component real and positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![2., 0., 0., 3.], vec![2, 2]).unwrap();
+    /// let (vals, vecs) = array.eig().unwrap();
+    /// assert_eq!(Array::flat(vec![Complex::new(2., 0.), Complex::new(3., 0.)]).unwrap(), vals);
+    /// assert_eq!(Array::new(vec![Complex::new(1., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(1., 0.)], vec![2, 2]).unwrap(), vecs);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn eig(&self) -> LinalgResult<Complex<f64>>;
+
+    /// Compute the eigenvalues of a symmetric or hermitian array.
+    /// eigenvalues are real and sorted in ascending order. Only one triangle of the input array is referenced
+    ///
+    /// # Arguments
+    ///
+    /// * `lower` - whether to use the lower or upper triangle of the array. optional, defaults to true
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![2., 1., 1., 2.], vec![2, 2]).unwrap();
+    /// assert_eq!(Array::flat(vec![1., 3.]), array.eigvalsh(None));
+    ///
+    /// let array = Array::new(vec![2., 1., 1., 2., 5., 0., 0., 4.], vec![2, 2, 2]).unwrap();
+    /// assert_eq!(Array::new(vec![1., 3., 4., 5.], vec![2, 2]), array.eigvalsh(None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn eigvalsh(&self, lower: Option<bool>) -> Result<Array<f64>, ArrayError>;
+
+    /// Compute the eigenvalues and eigenvectors of a symmetric or hermitian array.
+    /// eigenvalues are real and sorted in ascending order, eigenvectors are orthonormal columns,
+    /// with the largest component real and positive. Only one triangle of the input array is referenced
+    ///
+    /// # Arguments
+    ///
+    /// * `lower` - whether to use the lower or upper triangle of the array. optional, defaults to true
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![3., 0., 0., 2.], vec![2, 2]).unwrap();
+    /// let (vals, vecs) = array.eigh(None).unwrap();
+    /// assert_eq!(Array::flat(vec![2., 3.]).unwrap(), vals);
+    /// assert_eq!(Array::new(vec![0., 1., 1., 0.], vec![2, 2]).unwrap(), vecs);
     /// ```
     ///
     /// # Errors
     ///
     /// may returns `ArrayError`
-    fn eigvals(&self) -> Result<Vec<Array<N>>, ArrayError>;
+    fn eigh(&self, lower: Option<bool>) -> EighResult<N>;
 
-    /// Compute the eigenvalues and right eigenvectors of a square array
+    /// Compute the schur decomposition of a square array, such that `a = z t z^H`.
+    /// for real arrays, `t` is quasi upper triangular, with 2x2 blocks for complex conjugate eigenvalues
+    /// and `z` is orthogonal. for complex arrays, `t` is upper triangular and `z` is unitary
     ///
     /// # Examples
     ///
     /// ```
     /// use arr_rs::prelude::*;
     ///
-    /// let array = Array::new(vec![12., -51., 4., 6., 167., -68., -4., 24., -41.], vec![3, 3]).unwrap();
-    /// let (vals, vecs) = array.eig().unwrap()[0].clone();
-    /// assert_eq!(Array::flat(vec![156.20350762022625, -35.473244608879945, 17.26973698865371]).unwrap(), vals);
-    /// assert_eq!(Array::new(vec![0.3274744043621118, 0.2741043942532785, -0.9929229419852038, -0.9370666300603048, 0.3040145084128465, 0.08536232642252124, -0.12110592601150529, 0.9123825731158716, 0.08256696983166067], vec![3, 3]).unwrap(), vecs);
+    /// let array = Array::new(vec![1., 2., 0., 3.], vec![2, 2]).unwrap();
+    /// let (t, z) = array.schur().unwrap();
+    /// assert_eq!(Array::new(vec![1., 2., 0., 3.], vec![2, 2]).unwrap(), t);
+    /// assert_eq!(Array::new(vec![1., 0., 0., 1.], vec![2, 2]).unwrap(), z);
     /// ```
     ///
     /// # Errors
     ///
     /// may returns `ArrayError`
-    fn eig(&self) -> LinalgResult<N>;
+    fn schur(&self) -> LinalgResult<N>;
 }
 
 impl <N: NumericOps> ArrayLinalgEigen<N> for Array<N> {
 
-    fn eigvals(&self) -> Result<Vec<Self>, ArrayError> {
-        self.is_square()?;
-        if self.ndim()? == 2 {
-            let mut h = self.hessenberg_reduction()?;
-
-            let mut max_iter = 10000;
-            // fix condition - check convergence
-            while max_iter > 0 {
-                let (q, r) = h.qr()?[0].clone();
-                h = r.dot(&q)?.hessenberg_reduction()?;
-                if h.is_convergent()? { break }
-                max_iter -= 1;
+    fn eigvals(&self) -> Result<Array<Complex<f64>>, ArrayError> {
+        Ok(self.eig()?.0)
+    }
+
+    fn eig(&self) -> LinalgResult<Complex<f64>> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        let leading = self.leading_shape()?;
+        let (values, vectors): (Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let n = matrix.get_shape()?[0];
+                let complex = matrix.to_matrix()?.iter()
+                    .map(|row| row.iter().map(Numeric::to_complex).collect())
+                    .collect();
+                let (schur, unitary) = linalg_backend().schur(complex)?;
+                let values = (0..n).map(|i| schur[i][i]).collect::<Vec<Complex<f64>>>();
+                let vectors = Self::schur_eigenvectors(&schur, &unitary);
+                Ok((Array::flat(values)?, Array::from_matrix(&vectors)?))
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_matrices(&leading, values)?, stack_matrices(&leading, vectors)?))
+    }
+
+    fn eigvalsh(&self, lower: Option<bool>) -> Result<Array<f64>, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        self.map_matrices(|matrix| {
+            let (values, _) = linalg_backend().eigh(&Self::hermitian_matrix(matrix, lower)?)?;
+            Array::flat(values)
+        })
+    }
+
+    fn eigh(&self, lower: Option<bool>) -> EighResult<N> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        let leading = self.leading_shape()?;
+        let (values, vectors): (Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let (values, vectors) = linalg_backend().eigh(&Self::hermitian_matrix(matrix, lower)?)?;
+                let vectors = vectors.into_iter().flatten().map(N::from_complex).collect::<Self>();
+                Ok((Array::flat(values)?, vectors.reshape(&matrix.get_shape()?)?))
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_matrices(&leading, values)?, stack_matrices(&leading, vectors)?))
+    }
+
+    fn schur(&self) -> LinalgResult<N> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        let is_real = self.get_elements()?.iter().all(|item| item.to_complex().im == 0.);
+        let leading = self.leading_shape()?;
+        let (schur, unitary): (Vec<_>, Vec<_>) = self.get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let (schur, unitary) = if is_real {
+                    let (schur, unitary) = linalg_backend().real_schur(matrix.to_matrix_f64()?)?;
+                    (Self::from_matrix_f64(&schur)?, Self::from_matrix_f64(&unitary)?)
+                } else {
+                    let complex = matrix.to_matrix()?.iter()
+                        .map(|row| row.iter().map(Numeric::to_complex).collect())
+                        .collect();
+                    let (schur, unitary) = linalg_backend().schur(complex)?;
+                    let convert = |matrix: ComplexMatrix| matrix.into_iter().flatten().map(N::from_complex).collect::<Self>();
+                    (convert(schur).reshape(&matrix.get_shape()?)?, convert(unitary).reshape(&matrix.get_shape()?)?)
+                };
+                Ok((schur, unitary))
+            })
+            .collect::<Result<_, ArrayError>>()?;
+        Ok((stack_matrices(&leading, schur)?, stack_matrices(&leading, unitary)?))
+    }
+}
+
+impl <N: NumericOps> ArrayLinalgEigen<N> for Result<Array<N>, ArrayError> {
+
+    fn eigvals(&self) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.eigvals()
+    }
+
+    fn eig(&self) -> LinalgResult<Complex<f64>> {
+        self.clone()?.eig()
+    }
+
+    fn eigvalsh(&self, lower: Option<bool>) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.eigvalsh(lower)
+    }
+
+    fn eigh(&self, lower: Option<bool>) -> EighResult<N> {
+        self.clone()?.eigh(lower)
+    }
+
+    fn schur(&self) -> LinalgResult<N> {
+        self.clone()?.schur()
+    }
+}
+
+pub(crate) type ComplexMatrix = Vec<Vec<Complex<f64>>>;
+
+type RealMatrix = Vec<Vec<f64>>;
+
+pub(crate) trait EigenHelper {
+
+    const MAX_ITER_PER_VALUE: usize = 100;
+
+    fn hessenberg(mut matrix: ComplexMatrix) -> (ComplexMatrix, ComplexMatrix) {
+        let n = matrix.len();
+        let mut unitary = identity(n);
+        for k in 0..n.saturating_sub(2) {
+            let column = (k + 1..n).map(|i| matrix[i][k]).collect::<Vec<Complex<f64>>>();
+            let norm = column.iter().map(Complex::norm_sqr).sum::<f64>().sqrt();
+            if norm == 0. { continue }
+            let phase = if column[0].norm() == 0. { Complex::one() } else { column[0].scale(1. / column[0].norm()) };
+            let mut reflector = column;
+            reflector[0] += phase.scale(norm);
+            let reflector_norm = reflector.iter().map(Complex::norm_sqr).sum::<f64>().sqrt();
+            for v in &mut reflector { *v = v.scale(1. / reflector_norm) }
+
+            let dots = (0..n)
+                .map(|j| reflector.iter().enumerate()
+                    .fold(Complex::zero(), |acc, (i, v)| acc + v.conj() * matrix[k + 1 + i][j]))
+                .collect::<Vec<Complex<f64>>>();
+            for (v, row) in reflector.iter().zip(matrix.iter_mut().skip(k + 1)) {
+                for (entry, dot) in row.iter_mut().zip(&dots) { *entry -= (*v * *dot).scale(2.) }
+            }
+            for row in matrix.iter_mut().chain(unitary.iter_mut()) {
+                let dot = reflector.iter().enumerate()
+                    .fold(Complex::zero(), |acc, (j, v)| acc + row[k + 1 + j] * *v);
+                reflector.iter().enumerate()
+                    .for_each(|(j, v)| row[k + 1 + j] -= (dot * v.conj()).scale(2.));
+            }
+            (k + 2..n).for_each(|i| matrix[i][k] = Complex::zero());
+        }
+        (matrix, unitary)
+    }
+
+    fn wilkinson_shift(matrix: &ComplexMatrix, hi: usize) -> Complex<f64> {
+        let (a, b) = (matrix[hi - 1][hi - 1], matrix[hi - 1][hi]);
+        let (c, d) = (matrix[hi][hi - 1], matrix[hi][hi]);
+        let half_diff = (a - d).scale(0.5);
+        let square = half_diff * half_diff;
+        let root = (square + b * c).sqrt();
+        let (first, second) = (d + half_diff - root, d + half_diff + root);
+        if (first - d).norm() < (second - d).norm() { first } else { second }
+    }
+
+    fn complex_schur(matrix: ComplexMatrix) -> Result<(ComplexMatrix, ComplexMatrix), ArrayError> {
+        let n = matrix.len();
+        let (mut schur, mut unitary) = Self::hessenberg(matrix);
+        let (mut hi, mut iter, mut total_iter) = (n.saturating_sub(1), 0, 0);
+
+        while hi > 0 {
+            let lo = (1..=hi).rev()
+                .find(|&l| {
+                    let scale = schur[l - 1][l - 1].norm() + schur[l][l].norm();
+                    schur[l][l - 1].norm() <= f64::EPSILON * if scale == 0. { 1. } else { scale }
+                })
+                .unwrap_or(0);
+            if lo > 0 { schur[lo][lo - 1] = Complex::zero() }
+            if lo == hi {
+                (hi, iter) = (hi - 1, 0);
+                continue
+            }
+            if total_iter >= Self::MAX_ITER_PER_VALUE * n {
+                return Err(ArrayError::ParameterError { param: "`array`", message: "eigenvalues did not converge" })
             }
 
-            let mut eigenvalues = vec![];
-            for i in 0..self.get_shape()?[0] {
-                eigenvalues.push(h.at(&[i, i])?);
+            let shift = if iter > 0 && iter % 10 == 0 {
+                schur[hi][hi] + Complex::new(schur[hi][hi - 1].norm(), 0.)
+            } else {
+                Self::wilkinson_shift(&schur, hi)
+            };
+            (lo..=hi).for_each(|k| schur[k][k] -= shift);
+            let rotations = (lo..hi)
+                .map(|k| {
+                    let (x, y) = (schur[k][k], schur[k + 1][k]);
+                    let radius = x.norm().hypot(y.norm());
+                    let (cos, sin) = if radius == 0. { (Complex::one(), Complex::zero()) } else { (x.scale(1. / radius), y.scale(1. / radius)) };
+                    let (upper_rows, lower_rows) = schur.split_at_mut(k + 1);
+                    for (upper, lower) in upper_rows[k][k..].iter_mut().zip(&mut lower_rows[0][k..]) {
+                        (*upper, *lower) = (cos.conj() * *upper + sin.conj() * *lower, cos * *lower - sin * *upper);
+                    }
+                    (cos, sin)
+                })
+                .collect::<Vec<(Complex<f64>, Complex<f64>)>>();
+            for (k, (cos, sin)) in (lo..hi).zip(rotations) {
+                let rotate = |row: &mut Vec<Complex<f64>>| {
+                    let (left, right) = (row[k], row[k + 1]);
+                    row[k] = left * cos + right * sin;
+                    row[k + 1] = right * cos.conj() - left * sin.conj();
+                };
+                schur.iter_mut().take(k + 2).for_each(rotate);
+                unitary.iter_mut().for_each(rotate);
             }
+            (lo..=hi).for_each(|k| schur[k][k] += shift);
+            iter += 1;
+            total_iter += 1;
+        }
+        Ok((schur, unitary))
+    }
+
+    fn schur_eigenvectors(schur: &ComplexMatrix, unitary: &ComplexMatrix) -> ComplexMatrix {
+        let n = schur.len();
+        let norm = schur.iter().flatten().map(Complex::norm).fold(0., f64::max);
+        let small = f64::EPSILON * if norm == 0. { 1. } else { norm };
+        let vectors = (0..n)
+            .map(|k| {
+                let mut triangular = vec![Complex::zero(); n];
+                triangular[k] = Complex::one();
+                for i in (0..k).rev() {
+                    let sum = (i + 1..=k).fold(Complex::zero(), |acc, j| acc + schur[i][j] * triangular[j]);
+                    let mut denominator = schur[i][i] - schur[k][k];
+                    if denominator.norm() < small { denominator = Complex::new(small, 0.) }
+                    triangular[i] = -sum / denominator;
+                }
+                let vector = (0..n)
+                    .map(|i| (0..=k).fold(Complex::zero(), |acc, j| acc + unitary[i][j] * triangular[j]))
+                    .collect::<Vec<Complex<f64>>>();
+                normalize_vector(vector)
+            })
+            .collect::<Vec<Vec<Complex<f64>>>>();
+        (0..n).map(|i| (0..n).map(|k| vectors[k][i]).collect()).collect()
+    }
+}
 
-            Ok(vec![Self::flat(eigenvalues)?])
-        } else {
-            let shape = self.get_shape()?;
-            let sub_shape = shape[self.ndim()? - 2 ..].to_vec();
-            let vals = self
-                .ravel()?
-                .split(self.len()? / sub_shape.iter().product::<usize>(), None)?
-                .iter()
-                .map(|arr| arr.reshape(&sub_shape).eigvals())
-                .collect::<Vec<Result<Vec<Self>, _>>>()
-                .has_error()?.into_iter()
-                .flat_map(Result::unwrap)
-                .collect::<Vec<Self>>();
-            Ok(vals)
+pub(crate) trait HermitianEigenHelper<N: NumericOps>: EigenHelper {
+
+    fn hermitian_matrix(matrix: &Array<N>, lower: Option<bool>) -> Result<ComplexMatrix, ArrayError> {
+        let elements = matrix.to_matrix()?;
+        let lower = lower.unwrap_or(true);
+        let result = (0..elements.len())
+            .map(|i| (0..elements.len()).map(|j| match i.cmp(&j) {
+                std::cmp::Ordering::Equal => Complex::new(elements[i][i].to_complex().re, 0.),
+                std::cmp::Ordering::Greater => if lower { elements[i][j].to_complex() } else { elements[j][i].to_complex().conj() },
+                std::cmp::Ordering::Less => if lower { elements[j][i].to_complex().conj() } else { elements[i][j].to_complex() },
+            }).collect())
+            .collect();
+        Ok(result)
+    }
+
+    fn hermitian_eigen(matrix: &[Vec<Complex<f64>>]) -> Result<(Vec<f64>, ComplexMatrix), ArrayError> {
+        let n = matrix.len();
+        let (tridiagonal, unitary) = Self::hessenberg(matrix.to_vec());
+        let mut diagonal = (0..n).map(|i| tridiagonal[i][i].re).collect::<Vec<f64>>();
+        let mut off_diagonal = vec![0.; n];
+        // diagonal similarity making the hermitian tridiagonal matrix real symmetric
+        let mut phases = vec![Complex::one(); n];
+        for k in 0..n.saturating_sub(1) {
+            let element = tridiagonal[k + 1][k];
+            off_diagonal[k] = element.norm();
+            phases[k + 1] = if off_diagonal[k] == 0. { phases[k] } else { phases[k] * element.scale(1. / off_diagonal[k]) };
         }
+        let mut rotation = (0..n).map(|i| (0..n).map(|j| if i == j { 1. } else { 0. }).collect()).collect::<RealMatrix>();
+        Self::tridiagonal_ql(&mut diagonal, &mut off_diagonal, &mut rotation)?;
+
+        let mut order = (0..n).collect::<Vec<usize>>();
+        order.sort_by(|&a, &b| diagonal[a].total_cmp(&diagonal[b]));
+        let vectors = order.iter()
+            .map(|&k| normalize_vector((0..n)
+                .map(|i| (0..n).fold(Complex::zero(), |acc, j| acc + unitary[i][j] * phases[j].scale(rotation[j][k])))
+                .collect()))
+            .collect::<ComplexMatrix>();
+        let values = order.iter().map(|&k| diagonal[k]).collect();
+        Ok((values, (0..n).map(|i| (0..n).map(|k| vectors[k][i]).collect()).collect()))
     }
 
-    fn eig(&self) -> LinalgResult<N> {
-        let mut results = vec![];
-        for eigenvalues in self.eigvals()? {
-            let mut vectors = vec![];
-            for value in eigenvalues.get_elements()? {
-                let mut vector = (self.clone() - (Self::eye(self.get_shape()?[0], None, None)? * value)?)
-                    .solve(&Self::ones(vec![self.get_shape()?[0]])?)?;
-                vector /= vector
-                    .norm(None::<NormOrd>, None, None)
-                    .broadcast_to(vector.get_shape()?)?;
-                vectors.push(vector);
+    fn tridiagonal_ql(diagonal: &mut [f64], off_diagonal: &mut [f64], rotation: &mut [Vec<f64>]) -> Result<(), ArrayError> {
+        let n = diagonal.len();
+        let (mut shift, mut norm) = (0., 0_f64);
+        for l in 0..n {
+            norm = norm.max(diagonal[l].abs() + off_diagonal[l].abs());
+            let m = (l..n).find(|&m| off_diagonal[m].abs() <= f64::EPSILON * norm).unwrap_or(n - 1);
+            let mut iter = 0;
+            while m > l && off_diagonal[l].abs() > f64::EPSILON * norm {
+                if iter >= Self::MAX_ITER_PER_VALUE {
+                    return Err(ArrayError::ParameterError { param: "`array`", message: "eigenvalues did not converge" })
+                }
+                iter += 1;
+
+                let head = diagonal[l];
+                let ratio = (diagonal[l + 1] - head) / (2. * off_diagonal[l]);
+                let radius = ratio.hypot(1.).copysign(ratio);
+                diagonal[l] = off_diagonal[l] / (ratio + radius);
+                diagonal[l + 1] = off_diagonal[l] * (ratio + radius);
+                let next = diagonal[l + 1];
+                let delta = head - diagonal[l];
+                diagonal.iter_mut().skip(l + 2).for_each(|value| *value -= delta);
+                shift += delta;
+
+                let (mut p, mut cos, mut sin) = (diagonal[m], 1., 0.);
+                let (mut cos_prev, mut cos_prev_2, mut sin_prev) = (1., 1., 0.);
+                let following = off_diagonal[l + 1];
+                for i in (l..m).rev() {
+                    (cos_prev_2, cos_prev, sin_prev) = (cos_prev, cos, sin);
+                    let g = cos * off_diagonal[i];
+                    let h = cos * p;
+                    let r = p.hypot(off_diagonal[i]);
+                    off_diagonal[i + 1] = sin * r;
+                    (sin, cos) = (off_diagonal[i] / r, p / r);
+                    p = cos.mul_add(diagonal[i], -sin * g);
+                    diagonal[i + 1] = sin.mul_add(cos.mul_add(g, sin * diagonal[i]), h);
+                    for row in rotation.iter_mut() {
+                        let (left, right) = (row[i], row[i + 1]);
+                        row[i + 1] = sin.mul_add(left, cos * right);
+                        row[i] = cos.mul_add(left, -sin * right);
+                    }
+                }
+                p = -sin * sin_prev * cos_prev_2 * following * off_diagonal[l] / next;
+                off_diagonal[l] = sin * p;
+                diagonal[l] = cos * p;
             }
-            let eigenvectors = vectors.iter()
-                .flatten()
-                .copied()
-                .collect::<Self>()
-                .reshape(&[eigenvalues.len()?; 2])
-                .transpose(None)?;
-            results.push((eigenvalues, eigenvectors));
+            diagonal[l] += shift;
+            off_diagonal[l] = 0.;
         }
-
-        Ok(results)
+        Ok(())
     }
 }
 
-impl <N: NumericOps> ArrayLinalgEigen<N> for Result<Array<N>, ArrayError> {
+impl <N: NumericOps> EigenHelper for Array<N> {}
 
-    fn eigvals(&self) -> Result<Vec<Array<N>>, ArrayError> {
-        self.clone()?.eigvals()
+impl <N: NumericOps> HermitianEigenHelper<N> for Array<N> {}
+
+pub(crate) trait RealSchurHelper: EigenHelper {
+
+    fn real_hessenberg(mut matrix: RealMatrix) -> (RealMatrix, RealMatrix) {
+        let n = matrix.len();
+        let mut orthogonal = (0..n).map(|i| (0..n).map(|j| if i == j { 1. } else { 0. }).collect()).collect::<RealMatrix>();
+        for k in 0..n.saturating_sub(2) {
+            let column = (k + 1..n).map(|i| matrix[i][k]).collect::<Vec<f64>>();
+            let (reflector, tau, beta) = householder_vector(&column);
+            if tau == 0. { continue }
+
+            apply_householder(&mut matrix, &reflector, tau, k + 1, k + 1..n);
+            for row in matrix.iter_mut().chain(orthogonal.iter_mut()) {
+                let dot = reflector.iter().enumerate().fold(0., |acc: f64, (j, v)| v.mul_add(row[k + 1 + j], acc));
+                reflector.iter().enumerate().for_each(|(j, v)| row[k + 1 + j] = (-tau * dot).mul_add(*v, row[k + 1 + j]));
+            }
+            matrix[k + 1][k] = beta;
+            (k + 2..n).for_each(|i| matrix[i][k] = 0.);
+        }
+        (matrix, orthogonal)
     }
 
-    fn eig(&self) -> LinalgResult<N> {
-        self.clone()?.eig()
+    fn real_schur(matrix: RealMatrix) -> Result<(RealMatrix, RealMatrix), ArrayError> {
+
+        fn rotate(schur: &mut [Vec<f64>], orthogonal: &mut [Vec<f64>], k: usize, cos: f64, sin: f64) {
+            let (upper_rows, lower_rows) = schur.split_at_mut(k + 1);
+            for (upper, lower) in upper_rows[k][k..].iter_mut().zip(&mut lower_rows[0][k..]) {
+                (*upper, *lower) = (cos.mul_add(*upper, sin * *lower), cos.mul_add(*lower, -sin * *upper));
+            }
+            for row in schur.iter_mut().take(k + 2).chain(orthogonal.iter_mut()) {
+                let (left, right) = (row[k], row[k + 1]);
+                row[k] = cos.mul_add(left, sin * right);
+                row[k + 1] = cos.mul_add(right, -sin * left);
+            }
+        }
+
+        let n = matrix.len();
+        let (mut schur, mut orthogonal) = Self::real_hessenberg(matrix);
+        let norm = schur.iter().flatten().map(|v| v.abs()).sum::<f64>();
+        let (mut hi, mut iter, mut exceptional_shift) = (n.saturating_sub(1), 0, 0.);
+
+        loop {
+            let lo = (1..=hi).rev()
+                .find(|&l| {
+                    let scale = schur[l - 1][l - 1].abs() + schur[l][l].abs();
+                    schur[l][l - 1].abs() <= f64::EPSILON * if scale == 0. { norm } else { scale }
+                })
+                .unwrap_or(0);
+            if lo > 0 { schur[lo][lo - 1] = 0. }
+
+            if lo == hi {
+                schur[hi][hi] += exceptional_shift;
+                if hi == 0 { break }
+                (hi, iter) = (hi - 1, 0);
+            } else if lo + 1 == hi {
+                schur[hi][hi] += exceptional_shift;
+                schur[hi - 1][hi - 1] += exceptional_shift;
+                // standardize 2x2 block, splitting it when eigenvalues are real
+                let (a, b, c, d) = (schur[hi - 1][hi - 1], schur[hi - 1][hi], schur[hi][hi - 1], schur[hi][hi]);
+                let half_diff = (a - d) / 2.;
+                let discriminant = half_diff.mul_add(half_diff, b * c);
+                if discriminant >= 0. {
+                    let root = half_diff + discriminant.sqrt().copysign(half_diff);
+                    let (x, y) = (root, c);
+                    let radius = x.hypot(y);
+                    if radius > 0. { rotate(&mut schur, &mut orthogonal, hi - 1, x / radius, y / radius) }
+                    schur[hi][hi - 1] = 0.;
+                }
+                if hi < 2 { break }
+                (hi, iter) = (hi - 2, 0);
+            } else {
+                if iter >= Self::MAX_ITER_PER_VALUE {
+                    return Err(ArrayError::ParameterError { param: "`array`", message: "schur decomposition did not converge" })
+                }
+                let (mut x, mut y, mut w) = (schur[hi][hi], schur[hi - 1][hi - 1], schur[hi][hi - 1] * schur[hi - 1][hi]);
+                if iter > 0 && iter % 10 == 0 {
+                    exceptional_shift += x;
+                    (0..=hi).for_each(|i| schur[i][i] -= x);
+                    let scale = schur[hi][hi - 1].abs() + schur[hi - 1][hi - 2].abs();
+                    (x, y, w) = (0.75 * scale, 0.75 * scale, -0.4375 * scale * scale);
+                }
+                iter += 1;
+                Self::francis_step(&mut schur, &mut orthogonal, lo, hi, (x, y, w));
+            }
+        }
+        for (i, row) in schur.iter_mut().enumerate() {
+            row.iter_mut().take(i.saturating_sub(1)).for_each(|item| *item = 0.);
+        }
+        Ok((schur, orthogonal))
+    }
+
+    fn francis_step(schur: &mut [Vec<f64>], orthogonal: &mut [Vec<f64>], lo: usize, hi: usize, shift: (f64, f64, f64)) {
+        let (x, y, w) = shift;
+        let first_column = |schur: &[Vec<f64>], m: usize| {
+            let z = schur[m][m];
+            let (r, s) = (x - z, y - z);
+            let p = r.mul_add(s, -w) / schur[m + 1][m] + schur[m][m + 1];
+            let q = schur[m + 1][m + 1] - z - r - s;
+            let r = schur[m + 2][m + 1];
+            let scale = p.abs() + q.abs() + r.abs();
+            (p / scale, q / scale, r / scale)
+        };
+        let mut m = hi - 2;
+        let (mut p, mut q, mut r) = first_column(schur, m);
+        while m > lo {
+            let left = schur[m][m - 1].abs() * (q.abs() + r.abs());
+            let right = f64::EPSILON * p.abs() * (schur[m - 1][m - 1].abs() + schur[m][m].abs() + schur[m + 1][m + 1].abs());
+            if left < right { break }
+            m -= 1;
+            (p, q, r) = first_column(schur, m);
+        }
+        for i in m + 2..=hi {
+            schur[i][i - 2] = 0.;
+            if i > m + 2 { schur[i][i - 3] = 0. }
+        }
+
+        for k in m..hi {
+            let not_last = k + 1 != hi;
+            let mut scale = 1.;
+            if k != m {
+                (p, q, r) = (schur[k][k - 1], schur[k + 1][k - 1], if not_last { schur[k + 2][k - 1] } else { 0. });
+                scale = p.abs() + q.abs() + r.abs();
+                if scale == 0. { continue }
+                (p, q, r) = (p / scale, q / scale, r / scale);
+            }
+            let norm = p.hypot(q).hypot(r).copysign(p);
+            if norm == 0. { continue }
+            if k != m { schur[k][k - 1] = -norm * scale }
+            else if lo != m { schur[k][k - 1] = -schur[k][k - 1] }
+            p += norm;
+            let (vx, vy, vz) = (p / norm, q / norm, r / norm);
+            (q, r) = (q / p, r / p);
+
+            let (upper_rows, lower_rows) = schur.split_at_mut(k + 1);
+            let (middle_rows, lower_rows) = lower_rows.split_at_mut(1);
+            let mut third = lower_rows.first_mut().filter(|_| not_last).map(|row| row[k..].iter_mut());
+            for (first, second) in upper_rows[k][k..].iter_mut().zip(&mut middle_rows[0][k..]) {
+                let mut dot = q.mul_add(*second, *first);
+                if let Some(third) = third.as_mut().and_then(Iterator::next) {
+                    dot = r.mul_add(*third, dot);
+                    *third = (-dot).mul_add(vz, *third);
+                }
+                *first = (-dot).mul_add(vx, *first);
+                *second = (-dot).mul_add(vy, *second);
+            }
+            for row in schur.iter_mut().take(hi.min(k + 3) + 1).chain(orthogonal.iter_mut()) {
+                let mut dot = vx.mul_add(row[k], vy * row[k + 1]);
+                if not_last {
+                    dot = vz.mul_add(row[k + 2], dot);
+                    row[k + 2] = (-dot).mul_add(r, row[k + 2]);
+                }
+                row[k] -= dot;
+                row[k + 1] = (-dot).mul_add(q, row[k + 1]);
+            }
+        }
     }
 }
+
+impl <N: NumericOps> RealSchurHelper for Array<N> {}
+
+fn normalize_vector(vector: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+    let largest = vector.iter().copied()
+        .fold(Complex::zero(), |acc: Complex<f64>, v| if v.norm() > acc.norm() { v } else { acc });
+    let length = vector.iter().map(Complex::norm_sqr).sum::<f64>().sqrt();
+    let factor = largest.conj().scale(1. / (largest.norm() * length));
+    vector.into_iter().map(|v| v * factor).collect()
+}
+
+fn identity(n: usize) -> ComplexMatrix {
+    (0..n).map(|i| (0..n).map(|j| if i == j { Complex::one() } else { Complex::zero() }).collect()).collect()
+}
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+};
+
+/// `ArrayTrait` - Array Linalg Einstein summation functions
+pub trait ArrayLinalgEinsum<N: NumericOps> where Self: Sized + Clone {
+
+    /// Evaluates the Einstein summation convention on the operands
+    ///
+    /// # Arguments
+    ///
+    /// * `subscripts` - comma separated labels for each operand, with optional explicit output after `->`. repeated labels take the diagonal, `...` broadcasts remaining dimensions
+    /// * `operands` - arrays for the operation
+    /// * `optimize` - if true, operands are contracted pairwise in greedy order, minimizing intermediate sizes. optional, defaults to false
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let a = Array::new(vec![1, 2, 3, 4], vec![2, 2]).unwrap();
+    /// let b = Array::new(vec![5, 6, 7, 8], vec![2, 2]).unwrap();
+    ///
+    /// assert_eq!(Array::new(vec![19, 22, 43, 50], vec![2, 2]), Array::einsum("ij,jk->ik", &[&a, &b], None));
+    /// assert_eq!(Array::single(5), Array::einsum("ii", &[&a], None));
+    /// assert_eq!(Array::new(vec![1, 3, 2, 4], vec![2, 2]), Array::einsum("ji", &[&a], None));
+    /// assert_eq!(Array::flat(vec![1, 4]), Array::einsum("ii->i", &[&a], None));
+    /// assert_eq!(Array::new(vec![1, 3, 2, 4], vec![2, 2]), Array::einsum("...ij->...ji", &[&a], None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn einsum(subscripts: &str, operands: &[&Array<N>], optimize: Option<bool>) -> Result<Array<N>, ArrayError>;
+}
+
+impl <N: NumericOps> ArrayLinalgEinsum<N> for Array<N> {
+
+    fn einsum(subscripts: &str, operands: &[&Self], optimize: Option<bool>) -> Result<Self, ArrayError> {
+        let (inputs, output) = Self::parse_subscripts(subscripts, operands)?;
+        let mut sizes = HashMap::new();
+        let mut terms = inputs.into_iter()
+            .zip(operands)
+            .map(|(labels, operand)| Self::prepare_term(&labels, operand, &mut sizes))
+            .collect::<Result<Vec<Term<N>>, ArrayError>>()?;
+
+        if optimize.unwrap_or(false) {
+            while terms.len() > 2 {
+                let (first, second, labels) = Self::greedy_pair(&terms, &output, &sizes);
+                let second = terms.remove(second);
+                let first = terms.remove(first);
+                terms.push(Self::contract(&[first, second], &labels, &sizes));
+            }
+        }
+        let result = Self::contract(&terms, &output, &sizes);
+        if output.is_empty() { Self::flat(result.elements) }
+        else { Self::new(result.elements, output.iter().map(|label| sizes[label]).collect()) }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Label {
+    Ellipsis(usize),
+    Char(char),
+}
+
+#[derive(Clone, Debug)]
+struct Term<N: NumericOps> {
+    labels: Vec<Label>,
+    strides: Vec<usize>,
+    elements: Vec<N>,
+}
+
+trait EinsumHelper<N: NumericOps> {
+
+    fn parse_labels(term: &str) -> Result<(Vec<char>, Option<usize>), ArrayError> {
+        let ellipsis = term.find("...");
+        if ellipsis.is_some() && term.matches("...").count() > 1 {
+            return Err(ArrayError::ParameterError { param: "`subscripts`", message: "ellipsis can appear only once per operand" })
+        }
+        let letters = term.replacen("...", "", 1);
+        if letters.chars().any(|c| !c.is_ascii_alphabetic()) {
+            return Err(ArrayError::ParameterError { param: "`subscripts`", message: "invalid subscript character" })
+        }
+        let position = ellipsis.map(|position| term[..position].chars().count());
+        Ok((letters.chars().collect(), position))
+    }
+
+    fn expand_labels(letters: &[char], ellipsis: Option<usize>, ellipsis_dims: usize, total_dims: usize) -> Vec<Label> {
+        let named = letters.iter().map(|&c| Label::Char(c)).collect::<Vec<Label>>();
+        ellipsis.map_or_else(|| named.clone(), |position| {
+            let block = (total_dims - ellipsis_dims..total_dims).map(Label::Ellipsis).collect::<Vec<Label>>();
+            [&named[..position], &block, &named[position..]].concat()
+        })
+    }
+
+    fn parse_subscripts(subscripts: &str, operands: &[&Array<N>]) -> Result<(Vec<Vec<Label>>, Vec<Label>), ArrayError> {
+        let subscripts = subscripts.replace(' ', "");
+        let (inputs, output) = subscripts.split_once("->")
+            .map_or_else(|| (subscripts.clone(), None), |(inputs, output)| (inputs.to_string(), Some(output.to_string())));
+        let inputs = inputs.split(',')
+            .map(Self::parse_labels)
+            .collect::<Result<Vec<(Vec<char>, Option<usize>)>, ArrayError>>()?;
+        if inputs.len() != operands.len() {
+            return Err(ArrayError::ParameterError { param: "`subscripts`", message: "number of operands does not match subscripts" })
+        }
+
+        let ellipsis_dims = inputs.iter().zip(operands)
+            .map(|((letters, ellipsis), operand)| {
+                // single element operands without labels are treated as scalars
+                let ndim = if letters.is_empty() && ellipsis.is_none() && operand.len()? == 1 { 0 } else { operand.ndim()? };
+                if ellipsis.is_none() && letters.len() != ndim || letters.len() > ndim {
+                    return Err(ArrayError::ParameterError { param: "`subscripts`", message: "number of labels does not match operand dimensions" })
+                }
+                Ok(ndim - letters.len())
+            })
+            .collect::<Result<Vec<usize>, ArrayError>>()?;
+        let total_dims = ellipsis_dims.iter().copied().max().unwrap_or(0);
+        let labels = inputs.iter().zip(&ellipsis_dims)
+            .map(|((letters, ellipsis), &dims)| Self::expand_labels(letters, *ellipsis, dims, total_dims))
+            .collect::<Vec<Vec<Label>>>();
+
+        let output = if let Some(output) = output {
+            let (letters, ellipsis) = Self::parse_labels(&output)?;
+            let output = Self::expand_labels(&letters, ellipsis, total_dims, total_dims);
+            if output.iter().enumerate().any(|(idx, label)| output[..idx].contains(label)) {
+                return Err(ArrayError::ParameterError { param: "`subscripts`", message: "output subscript contains repeated labels" })
+            }
+            if output.iter().any(|label| !labels.iter().flatten().any(|item| item == label)) {
+                return Err(ArrayError::ParameterError { param: "`subscripts`", message: "output subscript does not appear in the input" })
+            }
+            output
+        } else {
+            // implicit output keeps broadcast dimensions first, then labels appearing once in alphabetical order
+            let mut output = labels.iter().flatten()
+                .filter(|label| matches!(label, Label::Ellipsis(_)) || labels.iter().flatten().filter(|item| item == label).count() == 1)
+                .copied()
+                .collect::<Vec<Label>>();
+            output.sort_unstable();
+            output.dedup();
+            output
+        };
+        Ok((labels, output))
+    }
+
+    fn prepare_term(labels: &[Label], operand: &Array<N>, sizes: &mut HashMap<Label, usize>) -> Result<Term<N>, ArrayError> {
+        let shape = operand.get_shape()?;
+        let shape = if labels.is_empty() { vec![] } else { shape };
+        let mut strides = shape.iter().rev()
+            .scan(1, |acc, &dim| { let stride = *acc; *acc *= dim; Some(stride) })
+            .collect::<Vec<usize>>();
+        strides.reverse();
+
+        let mut unique = Vec::<Label>::new();
+        let mut unique_strides = Vec::<usize>::new();
+        for ((&label, &dim), &stride) in labels.iter().zip(&shape).zip(&strides) {
+            match sizes.get(&label) {
+                Some(&size) if size != dim && dim != 1 && size != 1 => {
+                    return Err(match label {
+                        Label::Ellipsis(_) => ArrayError::BroadcastShapeMismatch,
+                        Label::Char(_) => ArrayError::ParameterError { param: "`operands`", message: "dimensions for the same subscript do not match" },
+                    })
+                },
+                Some(&size) if size >= dim => (),
+                _ => { sizes.insert(label, dim); },
+            }
+            // broadcast dimensions of size one do not advance over the operand
+            let stride = if dim == 1 { 0 } else { stride };
+            if let Some(idx) = unique.iter().position(|&item| item == label) { unique_strides[idx] += stride }
+            else { unique.push(label); unique_strides.push(stride) }
+        }
+        Ok(Term { labels: unique, strides: unique_strides, elements: operand.get_elements()? })
+    }
+
+    fn greedy_pair(terms: &[Term<N>], output: &[Label], sizes: &HashMap<Label, usize>) -> (usize, usize, Vec<Label>) {
+        let mut best: Option<(usize, usize, usize, Vec<Label>)> = None;
+        for first in 0..terms.len() { for second in first + 1..terms.len() {
+            let mut labels = terms[first].labels.clone();
+            terms[second].labels.iter().for_each(|label| if !labels.contains(label) { labels.push(*label) });
+            labels.retain(|label| output.contains(label) || terms.iter().enumerate()
+                .any(|(idx, term)| idx != first && idx != second && term.labels.contains(label)));
+            let cost = labels.iter().map(|label| sizes[label]).product::<usize>();
+            if best.as_ref().is_none_or(|(_, _, best_cost, _)| cost < *best_cost) {
+                best = Some((first, second, cost, labels));
+            }
+        } }
+        let (first, second, _, labels) = best.unwrap_or_default();
+        (first, second, labels)
+    }
+
+    fn contract(terms: &[Term<N>], output: &[Label], sizes: &HashMap<Label, usize>) -> Term<N> {
+        let mut labels = output.to_vec();
+        terms.iter().flat_map(|term| &term.labels).for_each(|label| if !labels.contains(label) { labels.push(*label) });
+        let dims = labels.iter().map(|label| sizes[label]).collect::<Vec<usize>>();
+        let term_strides = terms.iter()
+            .map(|term| labels.iter()
+                .map(|label| term.labels.iter().position(|item| item == label).map_or(0, |idx| term.strides[idx]))
+                .collect())
+            .collect::<Vec<Vec<usize>>>();
+        let output_dims = &dims[..output.len()];
+        let mut output_strides = output_dims.iter().rev()
+            .scan(1, |acc, &dim| { let stride = *acc; *acc *= dim; Some(stride) })
+            .collect::<Vec<usize>>();
+        output_strides.reverse();
+
+        let mut elements = vec![N::zero(); output_dims.iter().product()];
+        let total = dims.iter().product::<usize>();
+        let mut index = vec![0; dims.len()];
+        let mut offsets = vec![0; terms.len()];
+        let mut output_offset = 0;
+        for _ in 0..total {
+            let product = terms.iter().zip(&offsets)
+                .fold(N::one(), |acc, (term, &offset)| acc * term.elements[offset]);
+            elements[output_offset] += product;
+            for axis in (0..dims.len()).rev() {
+                index[axis] += 1;
+                offsets.iter_mut().zip(&term_strides).for_each(|(offset, strides)| *offset += strides[axis]);
+                if axis < output.len() { output_offset += output_strides[axis] }
+                if index[axis] < dims[axis] { break }
+                offsets.iter_mut().zip(&term_strides).for_each(|(offset, strides)| *offset -= strides[axis] * dims[axis]);
+                if axis < output.len() { output_offset -= output_strides[axis] * dims[axis] }
+                index[axis] = 0;
+            }
+        }
+        Term { labels: output.to_vec(), strides: output_strides, elements }
+    }
+}
+
+impl <N: NumericOps> EinsumHelper<N> for Array<N> {}
@@ -0,0 +1,79 @@
+use crate::numeric::prelude::*;
+
+/// rows of the micro-kernel tile
+const MR: usize = 4;
+/// columns of the micro-kernel tile
+const NR: usize = 4;
+/// rows of the packed block of `a`
+const MC: usize = 64;
+/// depth of the packed blocks of `a` and `b`
+const KC: usize = 256;
+/// columns of the packed panel of `b`
+const NC: usize = 512;
+
+/// general matrix multiplication `c += a b` for row-major `rows x depth` matrix `a`, `depth x cols` matrix `b` and `rows x cols` matrix `c`.
+/// blocks of `a` and panels of `b` are packed into contiguous buffers sized to stay in cache,
+/// and multiplied by a register-tiled micro-kernel
+pub(crate) fn gemm<N: NumericOps>(rows: usize, depth: usize, cols: usize, a: &[N], b: &[N], c: &mut [N]) {
+    if rows == 0 || depth == 0 || cols == 0 { return }
+    let mut packed_a = vec![N::zero(); MC.min(rows).div_ceil(MR) * MR * KC.min(depth)];
+    let mut packed_b = vec![N::zero(); NC.min(cols).div_ceil(NR) * NR * KC.min(depth)];
+
+    for jc in (0..cols).step_by(NC) {
+        let nc = NC.min(cols - jc);
+        for pc in (0..depth).step_by(KC) {
+            let kc = KC.min(depth - pc);
+            pack_b(b, cols, pc, kc, jc, nc, &mut packed_b);
+            for ic in (0..rows).step_by(MC) {
+                let mc = MC.min(rows - ic);
+                pack_a(a, depth, ic, mc, pc, kc, &mut packed_a);
+                for jr in (0..nc).step_by(NR) {
+                    let sliver_b = &packed_b[jr * kc..(jr + NR) * kc];
+                    for ir in (0..mc).step_by(MR) {
+                        let sliver_a = &packed_a[ir * kc..(ir + MR) * kc];
+                        let tile = micro_kernel(kc, sliver_a, sliver_b);
+                        for (i, row) in tile.iter().enumerate().take(MR.min(mc - ir)) {
+                            let offset = (ic + ir + i) * cols + jc + jr;
+                            c[offset..offset + NR.min(nc - jr)].iter_mut()
+                                .zip(row)
+                                .for_each(|(target, value)| *target += *value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// packs `mc x kc` block of `a` into slivers of `MR` rows, stored column by column and padded with zeros
+fn pack_a<N: NumericOps>(a: &[N], lda: usize, ic: usize, mc: usize, pc: usize, kc: usize, packed: &mut [N]) {
+    for (sliver, ir) in (0..mc).step_by(MR).enumerate() {
+        let target = &mut packed[sliver * MR * kc..(sliver + 1) * MR * kc];
+        for p in 0..kc { for i in 0..MR {
+            target[p * MR + i] = if ir + i < mc { a[(ic + ir + i) * lda + pc + p] } else { N::zero() };
+        } }
+    }
+}
+
+/// packs `kc x nc` panel of `b` into slivers of `NR` columns, stored row by row and padded with zeros
+fn pack_b<N: NumericOps>(b: &[N], ldb: usize, pc: usize, kc: usize, jc: usize, nc: usize, packed: &mut [N]) {
+    for (sliver, jr) in (0..nc).step_by(NR).enumerate() {
+        let target = &mut packed[sliver * NR * kc..(sliver + 1) * NR * kc];
+        for p in 0..kc {
+            let row = &b[(pc + p) * ldb + jc..];
+            for j in 0..NR {
+                target[p * NR + j] = if jr + j < nc { row[jr + j] } else { N::zero() };
+            }
+        }
+    }
+}
+
+fn micro_kernel<N: NumericOps>(kc: usize, sliver_a: &[N], sliver_b: &[N]) -> [[N; NR]; MR] {
+    let mut tile = [[N::zero(); NR]; MR];
+    for (column, row) in sliver_a.chunks_exact(MR).zip(sliver_b.chunks_exact(NR)).take(kc) {
+        for (tile_row, &value) in tile.iter_mut().zip(column) {
+            tile_row.iter_mut().zip(row).for_each(|(acc, &item)| *acc += value * item);
+        }
+    }
+    tile
+}
@@ -0,0 +1,390 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    linalg::prelude::*,
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// `ArrayTrait` - Array Linalg Iterative solvers functions.
+/// implemented for every `LinearOperator`, solving `Ax = b` for square operator `A`
+pub trait ArrayLinalgIterative<N: NumericOps> {
+
+    /// Solve a symmetric positive definite linear system with (preconditioned) conjugate gradient method
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - right-hand side vector
+    /// * `x0` - initial guess. optional, defaults to zeros
+    /// * `tol` - relative tolerance of the residual norm, `|b - Ax| <= tol * |b|`. optional, defaults to 1e-5
+    /// * `max_iter` - maximum number of iterations. optional, defaults to `10 * N`
+    /// * `preconditioner` - preconditioner to apply. optional, defaults to `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![4., 1., 1., 3.], vec![2, 2]).unwrap();
+    /// let (x, info) = arr.cg(&Array::flat(vec![1., 2.]).unwrap(), None, Some(1e-10), None, None::<Preconditioner>).unwrap();
+    /// assert_eq!(Array::flat(vec![0.090909091, 0.636363636]), x.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// assert!(info.converged);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn cg(&self, b: &Array<N>, x0: Option<&Array<N>>, tol: Option<f64>, max_iter: Option<usize>, preconditioner: Option<impl PreconditionerType>) -> IterativeResult<N>;
+
+    /// Solve a general linear system with (preconditioned) biconjugate gradient stabilized method
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - right-hand side vector
+    /// * `x0` - initial guess. optional, defaults to zeros
+    /// * `tol` - relative tolerance of the residual norm, `|b - Ax| <= tol * |b|`. optional, defaults to 1e-5
+    /// * `max_iter` - maximum number of iterations. optional, defaults to `10 * N`
+    /// * `preconditioner` - preconditioner to apply. optional, defaults to `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![3., 2., 1., 4.], vec![2, 2]).unwrap();
+    /// let (x, info) = arr.bicgstab(&Array::flat(vec![5., 5.]).unwrap(), None, Some(1e-10), None, Some("jacobi")).unwrap();
+    /// assert_eq!(Array::flat(vec![1., 1.]), x.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// assert!(info.converged);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn bicgstab(&self, b: &Array<N>, x0: Option<&Array<N>>, tol: Option<f64>, max_iter: Option<usize>, preconditioner: Option<impl PreconditionerType>) -> IterativeResult<N>;
+
+    /// Solve a general linear system with restarted generalized minimal residual method (right preconditioned)
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - right-hand side vector
+    /// * `x0` - initial guess. optional, defaults to zeros
+    /// * `tol` - relative tolerance of the residual norm, `|b - Ax| <= tol * |b|`. optional, defaults to 1e-5
+    /// * `restart` - number of iterations between restarts. optional, defaults to `min(20, N)`
+    /// * `max_iter` - maximum total number of iterations. optional, defaults to `10 * N`
+    /// * `preconditioner` - preconditioner to apply. optional, defaults to `None`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1., 2., 0., 0., 1., 2., 2., 0., 1.], vec![3, 3]).unwrap();
+    /// let (x, info) = arr.gmres(&Array::flat(vec![3., 3., 3.]).unwrap(), None, Some(1e-10), None, None, Some("ilu0")).unwrap();
+    /// assert_eq!(Array::flat(vec![1., 1., 1.]), x.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// assert!(info.converged);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn gmres(&self, b: &Array<N>, x0: Option<&Array<N>>, tol: Option<f64>, restart: Option<usize>, max_iter: Option<usize>, preconditioner: Option<impl PreconditionerType>) -> IterativeResult<N>;
+}
+
+impl <N: NumericOps, T: LinearOperator> ArrayLinalgIterative<N> for T {
+
+    fn cg(&self, b: &Array<N>, x0: Option<&Array<N>>, tol: Option<f64>, max_iter: Option<usize>, preconditioner: Option<impl PreconditionerType>) -> IterativeResult<N> {
+        let problem = Problem::new(self, b, x0, tol, max_iter, preconditioner)?;
+        let (x, info) = problem.cg()?;
+        Ok((x.into_iter().map(N::from_f64).collect(), info))
+    }
+
+    fn bicgstab(&self, b: &Array<N>, x0: Option<&Array<N>>, tol: Option<f64>, max_iter: Option<usize>, preconditioner: Option<impl PreconditionerType>) -> IterativeResult<N> {
+        let problem = Problem::new(self, b, x0, tol, max_iter, preconditioner)?;
+        let (x, info) = problem.bicgstab()?;
+        Ok((x.into_iter().map(N::from_f64).collect(), info))
+    }
+
+    fn gmres(&self, b: &Array<N>, x0: Option<&Array<N>>, tol: Option<f64>, restart: Option<usize>, max_iter: Option<usize>, preconditioner: Option<impl PreconditionerType>) -> IterativeResult<N> {
+        let problem = Problem::new(self, b, x0, tol, max_iter, preconditioner)?;
+        let restart = restart.unwrap_or_else(|| problem.b.len().min(20));
+        restart.is_at_least(&1)?;
+        let (x, info) = problem.gmres(restart)?;
+        Ok((x.into_iter().map(N::from_f64).collect(), info))
+    }
+}
+
+impl <N: NumericOps> LinearOperator for Array<N> {
+
+    fn operator_shape(&self) -> Result<(usize, usize), ArrayError> {
+        self.is_dim_supported(&[2])?;
+        let shape = self.get_shape()?;
+        Ok((shape[0], shape[1]))
+    }
+
+    fn matvec(&self, x: &[f64]) -> Result<Vec<f64>, ArrayError> {
+        let (_, cols) = self.operator_shape()?;
+        x.len().is_equal(&cols)?;
+        let result = self.get_elements()?
+            .chunks(cols)
+            .map(|row| row.iter().zip(x).fold(0., |acc, (a, b)| a.to_f64().mul_add(*b, acc)))
+            .collect();
+        Ok(result)
+    }
+
+    fn operator_rows(&self) -> Result<Vec<Vec<(usize, f64)>>, ArrayError> {
+        let (_, cols) = self.operator_shape()?;
+        let result = self.get_elements()?
+            .chunks(cols)
+            .map(|row| row.iter().enumerate()
+                .map(|(col, value)| (col, value.to_f64()))
+                .filter(|(_, value)| *value != 0.)
+                .collect())
+            .collect();
+        Ok(result)
+    }
+}
+
+/// preconditioner applied as `z = M^-1 r`
+enum Inverse {
+    Identity,
+    Jacobi(Vec<f64>),
+    Ilu(Vec<Vec<(usize, f64)>>, Vec<usize>),
+}
+
+impl Inverse {
+
+    fn new(operator: &impl LinearOperator, preconditioner: Preconditioner) -> Result<Self, ArrayError> {
+        match preconditioner {
+            Preconditioner::None => Ok(Self::Identity),
+            Preconditioner::Jacobi => {
+                let diagonal = operator.operator_rows()?.iter().enumerate()
+                    .map(|(row, entries)| entries.iter().find(|(col, _)| *col == row).map_or(0., |(_, value)| *value))
+                    .collect::<Vec<f64>>();
+                if diagonal.contains(&0.) {
+                    return Err(ArrayError::ParameterError { param: "`preconditioner`", message: "requires non-zero diagonal" })
+                }
+                Ok(Self::Jacobi(diagonal))
+            },
+            Preconditioner::Ilu0 => Self::ilu0(operator.operator_rows()?),
+        }
+    }
+
+    /// incomplete LU factorization restricted to the sparsity pattern of the operator.
+    /// unit lower and upper factors are stored in place of the operator entries
+    fn ilu0(mut rows: Vec<Vec<(usize, f64)>>) -> Result<Self, ArrayError> {
+        let mut diagonal = Vec::<usize>::with_capacity(rows.len());
+        for row in 0..rows.len() {
+            let Some(position) = rows[row].iter().position(|(col, _)| *col == row) else {
+                return Err(ArrayError::ParameterError { param: "`preconditioner`", message: "requires non-zero diagonal" })
+            };
+            let (upper, current) = rows.split_at_mut(row);
+            for idx in 0..position {
+                let (k, value) = current[0][idx];
+                let pivot = upper[k][diagonal[k]].1;
+                let factor = value / pivot;
+                current[0][idx].1 = factor;
+                for &(col, upper_value) in &upper[k][diagonal[k] + 1..] {
+                    if let Ok(target) = current[0].binary_search_by_key(&col, |(col, _)| *col) {
+                        if target > idx { current[0][target].1 -= factor * upper_value; }
+                    }
+                }
+            }
+            if current[0][position].1 == 0. { return Err(ArrayError::SingularMatrix) }
+            diagonal.push(position);
+        }
+        Ok(Self::Ilu(rows, diagonal))
+    }
+
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        match self {
+            Self::Identity => r.to_vec(),
+            Self::Jacobi(diagonal) => r.iter().zip(diagonal).map(|(value, d)| value / d).collect(),
+            Self::Ilu(rows, diagonal) => {
+                let mut y = r.to_vec();
+                for row in 0..rows.len() {
+                    let sum = rows[row][..diagonal[row]].iter().fold(0., |acc, (col, value)| value.mul_add(y[*col], acc));
+                    y[row] -= sum;
+                }
+                for row in (0..rows.len()).rev() {
+                    let sum = rows[row][diagonal[row] + 1..].iter().fold(0., |acc, (col, value)| value.mul_add(y[*col], acc));
+                    y[row] = (y[row] - sum) / rows[row][diagonal[row]].1;
+                }
+                y
+            },
+        }
+    }
+}
+
+struct Problem<'a, T: LinearOperator> {
+    operator: &'a T,
+    b: Vec<f64>,
+    x: Vec<f64>,
+    threshold: f64,
+    max_iter: usize,
+    inverse: Inverse,
+}
+
+impl <'a, T: LinearOperator> Problem<'a, T> {
+
+    fn new<N: NumericOps>(operator: &'a T, b: &Array<N>, x0: Option<&Array<N>>, tol: Option<f64>, max_iter: Option<usize>, preconditioner: Option<impl PreconditionerType>) -> Result<Self, ArrayError> {
+        let (rows, cols) = operator.operator_shape()?;
+        rows.is_equal(&cols)?;
+        b.is_dim_supported(&[1])?;
+        b.len()?.is_equal(&rows)?;
+        let x = match x0 {
+            Some(x0) => {
+                x0.is_dim_supported(&[1])?;
+                x0.len()?.is_equal(&rows)?;
+                x0.get_elements()?.iter().map(Numeric::to_f64).collect()
+            },
+            None => vec![0.; rows],
+        };
+        let preconditioner = preconditioner.map_or(Ok(Preconditioner::None), PreconditionerType::to_preconditioner)?;
+        let b = b.get_elements()?.iter().map(Numeric::to_f64).collect::<Vec<f64>>();
+        Ok(Self {
+            operator,
+            threshold: tol.unwrap_or(1e-5) * norm(&b),
+            b,
+            x,
+            max_iter: max_iter.unwrap_or(10 * rows),
+            inverse: Inverse::new(operator, preconditioner)?,
+        })
+    }
+
+    fn residual(&self, x: &[f64]) -> Result<Vec<f64>, ArrayError> {
+        Ok(self.b.iter().zip(self.operator.matvec(x)?).map(|(b, ax)| b - ax).collect())
+    }
+
+    fn info(&self, x: &[f64], iterations: usize) -> Result<IterativeInfo, ArrayError> {
+        let residual = norm(&self.residual(x)?);
+        Ok(IterativeInfo { converged: residual <= self.threshold, iterations, residual })
+    }
+
+    fn cg(mut self) -> Result<(Vec<f64>, IterativeInfo), ArrayError> {
+        let mut r = self.residual(&self.x)?;
+        let mut z = self.inverse.apply(&r);
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z);
+        let mut iterations = 0;
+        while iterations < self.max_iter && norm(&r) > self.threshold {
+            iterations += 1;
+            let ap = self.operator.matvec(&p)?;
+            let curvature = dot(&p, &ap);
+            if curvature == 0. { break }
+            let alpha = rz / curvature;
+            axpy(alpha, &p, &mut self.x);
+            axpy(-alpha, &ap, &mut r);
+            z = self.inverse.apply(&r);
+            let rz_next = dot(&r, &z);
+            let beta = rz_next / rz;
+            p.iter_mut().zip(&z).for_each(|(p, z)| *p = beta.mul_add(*p, *z));
+            rz = rz_next;
+        }
+        let info = self.info(&self.x, iterations)?;
+        Ok((self.x, info))
+    }
+
+    fn bicgstab(mut self) -> Result<(Vec<f64>, IterativeInfo), ArrayError> {
+        let mut r = self.residual(&self.x)?;
+        let r_hat = r.clone();
+        let (mut rho, mut alpha, mut omega) = (1., 1., 1.);
+        let (mut v, mut p) = (vec![0.; r.len()], vec![0.; r.len()]);
+        let mut iterations = 0;
+        while iterations < self.max_iter && norm(&r) > self.threshold {
+            iterations += 1;
+            let rho_next = dot(&r_hat, &r);
+            if rho_next == 0. { break }
+            let beta = (rho_next / rho) * (alpha / omega);
+            p.iter_mut().zip(r.iter().zip(&v)).for_each(|(p, (r, v))| *p = beta.mul_add(omega.mul_add(-v, *p), *r));
+            let p_hat = self.inverse.apply(&p);
+            v = self.operator.matvec(&p_hat)?;
+            let denominator = dot(&r_hat, &v);
+            if denominator == 0. { break }
+            alpha = rho_next / denominator;
+            axpy(alpha, &p_hat, &mut self.x);
+            let mut half_residual = r.clone();
+            axpy(-alpha, &v, &mut half_residual);
+            if norm(&half_residual) <= self.threshold { break }
+            let s_precond = self.inverse.apply(&half_residual);
+            let as_precond = self.operator.matvec(&s_precond)?;
+            let t_norm = dot(&as_precond, &as_precond);
+            omega = if t_norm == 0. { 0. } else { dot(&as_precond, &half_residual) / t_norm };
+            axpy(omega, &s_precond, &mut self.x);
+            r = half_residual;
+            axpy(-omega, &as_precond, &mut r);
+            if omega == 0. { break }
+            rho = rho_next;
+        }
+        let info = self.info(&self.x, iterations)?;
+        Ok((self.x, info))
+    }
+
+    fn gmres(mut self, restart: usize) -> Result<(Vec<f64>, IterativeInfo), ArrayError> {
+        let mut iterations = 0;
+        loop {
+            let r = self.residual(&self.x)?;
+            let beta = norm(&r);
+            if beta <= self.threshold || iterations >= self.max_iter { break }
+
+            let mut basis = vec![r.iter().map(|value| value / beta).collect::<Vec<f64>>()];
+            let mut hessenberg = Vec::<Vec<f64>>::new();
+            let mut rotations = Vec::<(f64, f64)>::new();
+            let mut rhs = vec![beta];
+            while basis.len() <= restart && iterations < self.max_iter {
+                iterations += 1;
+                let mut w = self.operator.matvec(&self.inverse.apply(&basis[basis.len() - 1]))?;
+                let mut column = basis.iter()
+                    .map(|vector| {
+                        let h = dot(&w, vector);
+                        axpy(-h, vector, &mut w);
+                        h
+                    })
+                    .collect::<Vec<f64>>();
+                let norm_w = norm(&w);
+                column.push(norm_w);
+                for (idx, &(cos, sin)) in rotations.iter().enumerate() {
+                    let (a, b) = (column[idx], column[idx + 1]);
+                    column[idx] = cos.mul_add(a, sin * b);
+                    column[idx + 1] = cos.mul_add(b, -sin * a);
+                }
+                let last = column.len() - 1;
+                let radius = column[last - 1].hypot(column[last]);
+                let (cos, sin) = if radius == 0. { (1., 0.) } else { (column[last - 1] / radius, column[last] / radius) };
+                column[last - 1] = radius;
+                column[last] = 0.;
+                rotations.push((cos, sin));
+                rhs.push(-sin * rhs[last - 1]);
+                rhs[last - 1] *= cos;
+                hessenberg.push(column);
+
+                if rhs[last].abs() <= self.threshold || norm_w == 0. { break }
+                basis.push(w.iter().map(|value| value / norm_w).collect());
+            }
+
+            let size = hessenberg.len();
+            let mut coefficients = vec![0.; size];
+            for row in (0..size).rev() {
+                let sum = (row + 1..size).fold(0., |acc, col| hessenberg[col][row].mul_add(coefficients[col], acc));
+                coefficients[row] = (rhs[row] - sum) / hessenberg[row][row];
+            }
+            let mut update = vec![0.; self.x.len()];
+            coefficients.iter().zip(&basis).for_each(|(coefficient, vector)| axpy(*coefficient, vector, &mut update));
+            axpy(1., &self.inverse.apply(&update), &mut self.x);
+            if hessenberg[size - 1][size - 1] == 0. { break }
+        }
+        let info = self.info(&self.x, iterations)?;
+        Ok((self.x, info))
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).fold(0., |acc, (a, b)| a.mul_add(*b, acc))
+}
+
+fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    y.iter_mut().zip(x).for_each(|(y, x)| *y = alpha.mul_add(*x, *y));
+}
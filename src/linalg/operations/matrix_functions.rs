@@ -0,0 +1,344 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    linalg::{
+        operations::eigen::{ComplexMatrix, EigenHelper},
+        prelude::*,
+    },
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// `ArrayTrait` - Array Linalg Matrix functions
+pub trait ArrayLinalgMatrixFunctions<N: NumericOps> where Self: Sized + Clone {
+
+    /// Compute the matrix exponential, using scaling and squaring with pade approximants
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![0., 1., 0., 0.], vec![2, 2]).unwrap();
+    /// assert_eq!(Array::new(vec![1., 1., 0., 1.], vec![2, 2]), arr.expm());
+    ///
+    /// let arr = Array::new(vec![1., 0., 0., 2.], vec![2, 2]).unwrap();
+    /// let expected = Array::new(vec![2.718281828, 0., 0., 7.389056099], vec![2, 2]);
+    /// assert_eq!(expected, arr.expm().unwrap().map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn expm(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the principal matrix logarithm, using inverse scaling and squaring on schur form.
+    /// real arrays must have a real logarithm, i.e. no eigenvalues on the closed negative real axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1., 1., 0., 1.], vec![2, 2]).unwrap();
+    /// let expected = Array::new(vec![0., 1., 0., 0.], vec![2, 2]);
+    /// assert_eq!(expected, arr.logm().unwrap().map(|v: &f64| (v * 1e9).round() / 1e9 + 0.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn logm(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the principal matrix square root, using schur method
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1., 4., 0., 9.], vec![2, 2]).unwrap();
+    /// let expected = Array::new(vec![1., 1., 0., 3.], vec![2, 2]);
+    /// assert_eq!(expected, arr.sqrtm().unwrap().map(|v: &f64| (v * 1e9).round() / 1e9 + 0.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn sqrtm(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Evaluate a general matrix function of diagonalizable array, as `v f(d) v^-1` for `a = v d v^-1`
+    ///
+    /// # Arguments
+    ///
+    /// * `func` - function applied to the eigenvalues
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![2., 1., 1., 2.], vec![2, 2]).unwrap();
+    /// let expected = Array::new(vec![5., 4., 4., 5.], vec![2, 2]);
+    /// assert_eq!(expected, arr.funm(|v| v * v).unwrap().map(|v: &f64| (v * 1e9).round() / 1e9 + 0.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn funm<F: Fn(Complex<f64>) -> Complex<f64>>(&self, func: F) -> Result<Array<N>, ArrayError>;
+}
+
+impl <N: NumericOps> ArrayLinalgMatrixFunctions<N> for Array<N> {
+
+    fn expm(&self) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        self.map_matrices(|matrix| {
+            let result = Self::pade_exponential(&Self::to_complex_matrix(matrix)?)?;
+            Self::from_complex_matrix(&result, "function result is not real")
+        })
+    }
+
+    fn logm(&self) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        self.map_matrices(|matrix| {
+            let (schur, unitary) = linalg_backend().schur(Self::to_complex_matrix(matrix)?)?;
+            let logarithm = Self::triangular_logarithm(schur)?;
+            Self::from_complex_matrix(&Self::unitary_similarity(&unitary, &logarithm), "has no real principal logarithm")
+        })
+    }
+
+    fn sqrtm(&self) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        self.map_matrices(|matrix| {
+            let (schur, unitary) = linalg_backend().schur(Self::to_complex_matrix(matrix)?)?;
+            let root = Self::triangular_sqrt(&schur)?;
+            Self::from_complex_matrix(&Self::unitary_similarity(&unitary, &root), "has no real principal square root")
+        })
+    }
+
+    fn funm<F: Fn(Complex<f64>) -> Complex<f64>>(&self, func: F) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        self.map_matrices(|matrix| {
+            let (schur, unitary) = linalg_backend().schur(Self::to_complex_matrix(matrix)?)?;
+            let vectors = Self::schur_eigenvectors(&schur, &unitary);
+            let scaled = vectors.iter()
+                .map(|row| row.iter().enumerate().map(|(j, v)| *v * func(schur[j][j])).collect())
+                .collect::<ComplexMatrix>();
+            // v f(d) v^-1 is computed as (v^-H (v f(d))^H)^H
+            let result = Self::complex_solve(conjugate_transpose(&vectors), conjugate_transpose(&scaled))?;
+            Self::from_complex_matrix(&conjugate_transpose(&result), "function result is not real")
+        })
+    }
+}
+
+impl <N: NumericOps> ArrayLinalgMatrixFunctions<N> for Result<Array<N>, ArrayError> {
+
+    fn expm(&self) -> Self {
+        self.clone()?.expm()
+    }
+
+    fn logm(&self) -> Self {
+        self.clone()?.logm()
+    }
+
+    fn sqrtm(&self) -> Self {
+        self.clone()?.sqrtm()
+    }
+
+    fn funm<F: Fn(Complex<f64>) -> Complex<f64>>(&self, func: F) -> Self {
+        self.clone()?.funm(func)
+    }
+}
+
+trait MatrixFunctionsHelper<N: NumericOps>: EigenHelper {
+
+    const PADE_THETA: [(usize, f64); 5] = [
+        (3, 1.495_585_217_958_292e-2),
+        (5, 2.539_398_330_063_23e-1),
+        (7, 9.504_178_996_162_932e-1),
+        (9, 2.097_847_961_257_068),
+        (13, 5.371_920_351_148_152),
+    ];
+
+    const MAX_SQRT_ITER: usize = 64;
+
+    fn to_complex_matrix(matrix: &Array<N>) -> Result<ComplexMatrix, ArrayError> {
+        Ok(matrix.to_matrix()?.iter()
+            .map(|row| row.iter().map(Numeric::to_complex).collect())
+            .collect())
+    }
+
+    fn from_complex_matrix(matrix: &ComplexMatrix, message: &'static str) -> Result<Array<N>, ArrayError> {
+        // real arrays can hold the result only if its imaginary part is negligible
+        let is_real_type = N::from_complex(Complex::new(0., 1.)).to_complex().im == 0.;
+        let scale = norm_1(matrix).max(1.);
+        if is_real_type && matrix.iter().flatten().any(|v| v.im.abs() > 1e-8 * scale) {
+            return Err(ArrayError::ParameterError { param: "`array`", message })
+        }
+        let elements = matrix.iter().flatten().map(|v| N::from_complex(*v)).collect::<Array<N>>();
+        elements.reshape(&[matrix.len(), matrix.len()])
+    }
+
+    fn pade_coefficients(degree: usize) -> Vec<f64> {
+        let factorial = |n: usize| (1..=n).map(f64::from_usize).product::<f64>();
+        (0..=degree)
+            .map(|k| factorial(2 * degree - k) * factorial(degree) / (factorial(2 * degree) * factorial(k) * factorial(degree - k)))
+            .collect()
+    }
+
+    fn pade_exponential(matrix: &ComplexMatrix) -> Result<ComplexMatrix, ArrayError> {
+        let norm = norm_1(matrix);
+        let (degree, theta) = Self::PADE_THETA.iter()
+            .find(|(_, theta)| norm <= *theta)
+            .copied()
+            .unwrap_or(Self::PADE_THETA[Self::PADE_THETA.len() - 1]);
+        let squarings = if norm > theta { (norm / theta).log2().ceil().to_usize() } else { 0 };
+        let factor = 0.5_f64.powi(squarings.to_i32());
+        let scaled = matrix.iter()
+            .map(|row| row.iter().map(|v| v.scale(factor)).collect())
+            .collect::<ComplexMatrix>();
+
+        let coefficients = Self::pade_coefficients(degree);
+        let square = multiply(&scaled, &scaled);
+        let mut power = identity(matrix.len());
+        let (mut odd, mut even) = (zeros(matrix.len()), zeros(matrix.len()));
+        for pair in coefficients.chunks(2) {
+            accumulate(&mut even, &power, pair[0]);
+            if pair.len() > 1 { accumulate(&mut odd, &power, pair[1]) }
+            power = multiply(&power, &square);
+        }
+        let odd = multiply(&scaled, &odd);
+        let numerator = even.iter().zip(&odd)
+            .map(|(e, o)| e.iter().zip(o).map(|(e, o)| *e + *o).collect())
+            .collect::<ComplexMatrix>();
+        let denominator = even.iter().zip(&odd)
+            .map(|(e, o)| e.iter().zip(o).map(|(e, o)| *e - *o).collect())
+            .collect::<ComplexMatrix>();
+        let mut result = Self::complex_solve(denominator, numerator)?;
+        for _ in 0..squarings { result = multiply(&result, &result) }
+        Ok(result)
+    }
+
+    fn triangular_sqrt(triangular: &ComplexMatrix) -> Result<ComplexMatrix, ArrayError> {
+        let size = triangular.len();
+        let mut root = zeros(size);
+        (0..size).for_each(|i| root[i][i] = triangular[i][i].sqrt());
+        for j in 1..size { for i in (0..j).rev() {
+            let sum = (i + 1..j).fold(Complex::zero(), |acc, k| acc + root[i][k] * root[k][j]);
+            let numerator = triangular[i][j] - sum;
+            let denominator = root[i][i] + root[j][j];
+            if denominator.norm() == 0. {
+                if numerator.norm() > 0. { return Err(ArrayError::SingularMatrix) }
+            } else {
+                root[i][j] = numerator / denominator;
+            }
+        } }
+        Ok(root)
+    }
+
+    fn triangular_logarithm(mut triangular: ComplexMatrix) -> Result<ComplexMatrix, ArrayError> {
+        let size = triangular.len();
+        if (0..size).any(|i| triangular[i][i].norm() == 0.) {
+            return Err(ArrayError::SingularMatrix)
+        }
+        // repeated square roots bring the matrix close to identity, where the series converges quickly
+        let mut squarings = 0;
+        while squarings < Self::MAX_SQRT_ITER && norm_1(&subtract_identity(&triangular)) > 0.25 {
+            triangular = Self::triangular_sqrt(&triangular)?;
+            squarings += 1;
+        }
+
+        // log(I + X) = 2 atanh(Z), with Z = (2I + X)^-1 X
+        let difference = subtract_identity(&triangular);
+        let shifted = triangular.iter().enumerate()
+            .map(|(i, row)| row.iter().enumerate().map(|(j, v)| if i == j { *v + Complex::one() } else { *v }).collect())
+            .collect::<ComplexMatrix>();
+        let ratio = Self::complex_solve(shifted, difference)?;
+        let ratio_square = multiply(&ratio, &ratio);
+        let mut term = ratio;
+        let mut result = zeros(size);
+        for k in 0..100 {
+            accumulate(&mut result, &term, 1. / f64::from_usize(2 * k + 1));
+            if norm_1(&term) <= f64::EPSILON * norm_1(&result) { break }
+            term = multiply(&term, &ratio_square);
+        }
+        let factor = 2_f64.powi(squarings.to_i32() + 1);
+        Ok(result.into_iter().map(|row| row.into_iter().map(|v| v.scale(factor)).collect()).collect())
+    }
+
+    fn unitary_similarity(unitary: &ComplexMatrix, matrix: &ComplexMatrix) -> ComplexMatrix {
+        multiply(&multiply(unitary, matrix), &conjugate_transpose(unitary))
+    }
+
+    fn complex_solve(mut matrix: ComplexMatrix, mut rhs: ComplexMatrix) -> Result<ComplexMatrix, ArrayError> {
+        let size = matrix.len();
+        let scale = matrix.iter().flatten().map(Complex::norm).fold(0., f64::max);
+        for k in 0..size {
+            let pivot = (k..size).fold(k, |best, row| if matrix[row][k].norm() > matrix[best][k].norm() { row } else { best });
+            if matrix[pivot][k].norm() <= size.to_f64() * f64::EPSILON * scale {
+                return Err(ArrayError::SingularMatrix)
+            }
+            matrix.swap(k, pivot);
+            rhs.swap(k, pivot);
+            let (upper, lower) = matrix.split_at_mut(k + 1);
+            let (upper_rhs, lower_rhs) = rhs.split_at_mut(k + 1);
+            for (row, row_rhs) in lower.iter_mut().zip(lower_rhs.iter_mut()) {
+                let factor = row[k] / upper[k][k];
+                row[k..].iter_mut().zip(&upper[k][k..]).for_each(|(v, p)| *v -= factor * *p);
+                row_rhs.iter_mut().zip(&upper_rhs[k]).for_each(|(v, p)| *v -= factor * *p);
+            }
+        }
+        for i in (0..size).rev() {
+            let (head, solved) = rhs.split_at_mut(i + 1);
+            for (j, row) in solved.iter().enumerate() {
+                head[i].iter_mut().zip(row).for_each(|(v, known)| *v -= matrix[i][i + 1 + j] * *known);
+            }
+            head[i].iter_mut().for_each(|v| *v /= matrix[i][i]);
+        }
+        Ok(rhs)
+    }
+}
+
+impl <N: NumericOps> MatrixFunctionsHelper<N> for Array<N> {}
+
+fn identity(size: usize) -> ComplexMatrix {
+    (0..size).map(|i| (0..size).map(|j| if i == j { Complex::one() } else { Complex::zero() }).collect()).collect()
+}
+
+fn zeros(size: usize) -> ComplexMatrix {
+    vec![vec![Complex::zero(); size]; size]
+}
+
+fn multiply(first: &ComplexMatrix, second: &ComplexMatrix) -> ComplexMatrix {
+    first.iter()
+        .map(|row| (0..second[0].len())
+            .map(|j| row.iter().zip(second).fold(Complex::zero(), |acc, (v, other)| acc + *v * other[j]))
+            .collect())
+        .collect()
+}
+
+fn accumulate(target: &mut ComplexMatrix, matrix: &ComplexMatrix, factor: f64) {
+    target.iter_mut().zip(matrix)
+        .for_each(|(row, other)| row.iter_mut().zip(other).for_each(|(v, o)| *v += o.scale(factor)));
+}
+
+fn subtract_identity(matrix: &ComplexMatrix) -> ComplexMatrix {
+    matrix.iter().enumerate()
+        .map(|(i, row)| row.iter().enumerate().map(|(j, v)| if i == j { *v - Complex::one() } else { *v }).collect())
+        .collect()
+}
+
+fn conjugate_transpose(matrix: &ComplexMatrix) -> ComplexMatrix {
+    (0..matrix[0].len()).map(|j| matrix.iter().map(|row| row[j].conj()).collect()).collect()
+}
+
+fn norm_1(matrix: &ComplexMatrix) -> f64 {
+    (0..matrix[0].len())
+        .map(|j| matrix.iter().map(|row| row[j].norm()).sum::<f64>())
+        .fold(0., f64::max)
+}
@@ -1,12 +1,24 @@
 /// array linalg common functions
 pub(crate) mod common;
 
+/// array linalg blocked matrix multiplication kernel
+pub(crate) mod gemm;
+
+/// array linalg einsum functions
+pub mod einsum;
+
 /// array linalg eigen functions
 pub mod eigen;
 
 /// array linalg decompositions functions
 pub mod decompositions;
 
+/// array linalg iterative solvers functions
+pub mod iterative;
+
+/// array linalg matrix functions
+pub mod matrix_functions;
+
 /// array linalg norms functions
 pub mod norms;
 
@@ -15,3 +27,6 @@ pub mod products;
 
 /// array linalg solving equations and inverting matrices functions
 pub mod solving_inverting;
+
+/// array linalg subspaces functions
+pub mod subspaces;
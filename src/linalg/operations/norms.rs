@@ -1,7 +1,11 @@
 use crate::{
     core::prelude::*,
     errors::prelude::*,
-    linalg::prelude::*,
+    linalg::{
+        backend::linalg_backend,
+        operations::common::{lu_decompose, LinalgScalar},
+        prelude::*,
+    },
     math::prelude::*,
     numeric::prelude::*,
     validators::prelude::*,
@@ -44,12 +48,57 @@ pub trait ArrayLinalgNorms<N: NumericOps> where Self: Sized + Clone {
     /// use arr_rs::prelude::*;
     ///
     /// assert_eq!(Array::single(-14), Array::new(vec![3, 8, 4, 6], vec![2, 2]).det());
+    ///
+    /// let arr = Array::new(vec![Complex::new(0., 1.), Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 1.)], vec![2, 2]);
+    /// assert_eq!(Array::single(Complex::new(-2., 0.)), arr.det());
     /// ```
     ///
     /// # Errors
     ///
     /// may returns `ArrayError`
     fn det(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the sign and natural logarithm of the absolute value of the determinant of an array.
+    /// Returns sign equal to 0 and logarithm equal to `-inf` for singular matrices.
+    /// complex matrices are not supported
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let (sign, logdet) = Array::new(vec![4., 1., 0., -1.], vec![2, 2]).slogdet().unwrap();
+    /// assert_eq!(Array::single(-1.), Ok(sign));
+    /// assert_eq!(Array::single(4_f64.ln()), Ok(logdet));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn slogdet(&self) -> Result<(Array<f64>, Array<f64>), ArrayError>;
+
+    /// Compute the condition number of a matrix, with respect to the norm of given order.
+    /// Returns `inf` for singular matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - order of the norm: {1, -1, 2, -2, inf, -inf, `fro`, `nuc`}. optional, defaults to 2. orders other than 2 and -2 are computed as `norm(a, p) * norm(inv(a), p)` and require square matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![1., 0., -1., 0., 1., 0., 1., 0., 1.], vec![3, 3]).unwrap();
+    /// assert_eq!(Array::single(1.4142135623730951), array.cond(None::<NormOrd>));
+    /// assert_eq!(Array::single(2.), array.cond(Some("inf")));
+    /// assert_eq!(Array::single(f64::INFINITY), Array::new(vec![1., 2., 2., 4.], vec![2, 2]).cond(Some(NormOrd::Int(1))));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn cond(&self, p: Option<impl NormOrdType>) -> Result<Array<f64>, ArrayError>;
 }
 
 impl <N: NumericOps> ArrayLinalgNorms<N> for Array<N> {
@@ -129,8 +178,9 @@ impl <N: NumericOps> ArrayLinalgNorms<N> for Array<N> {
                         let row_axis = if row_axis > col_axis { -row_axis } else { row_axis };
                         self.abs().sum(Some(col_axis)).min(Some(row_axis))
                     },
-                    _ => {
-                        Err(ArrayError::ParameterError { param: "`ord`", message: "invalid norm order for vectors." })
+                    NormOrd::Fro | NormOrd::Nuc | NormOrd::Int(2 | -2) => Self::trailing_axes_norm(self, ord, row_axis, col_axis),
+                    NormOrd::Int(_) => {
+                        Err(ArrayError::ParameterError { param: "`ord`", message: "invalid norm order for matrices." })
                     },
                 };
                 if keepdims.unwrap_or(false) {
@@ -145,48 +195,78 @@ impl <N: NumericOps> ArrayLinalgNorms<N> for Array<N> {
         }
     }
 
-    fn det(&self) -> Result<Self<>, ArrayError> {
+    fn det(&self) -> Result<Self, ArrayError> {
         if self.ndim()? == 0 {
             Err(ArrayError::MustBeAtLeast { value1: "`dimension`".to_string(), value2: "1".to_string() })
         } else if self.ndim()? == 1 {
             Ok(self.clone())
-        } else if self.ndim()? == 2 {
-            let shape = self.get_shape()?;
-            self.is_square()?;
-            if shape[0] == 2 {
-                Self::single(N::from(self[0].to_f64().mul_add(self[3].to_f64(), -self[1].to_f64() * self[2].to_f64())))
-            } else {
-                let elems = (0..shape[0])
-                    .map(|i| self[i * shape[0]].to_f64())
-                    .collect::<Vec<f64>>();
-                let dets = (0..shape[0])
-                    .map(|i| Self::minor(self, i, 0).det())
-                    .collect::<Vec<Result<Self, _>>>()
-                    .has_error()?.into_iter()
-                    .map(Result::unwrap)
-                    .map(|i| i[0].to_f64())
-                    .collect::<Vec<f64>>();
-                let result = elems.iter().zip(&dets)
-                    .enumerate()
-                    .map(|(i, (&e, &d))| e * f64::powi(-1., i.to_i32() + 2) * d)
-                    .sum::<f64>();
-                Self::single(N::from(result))
-            }
         } else {
-            let shape = self.get_shape()?;
-            shape.is_square()?;
-            let sub_shape = shape[self.ndim()? - 2 ..].to_vec();
-            let dets = self
-                .ravel()?
-                .split(self.len()? / sub_shape.iter().product::<usize>(), None)?
-                .iter()
-                .map(|arr| arr.reshape(&sub_shape).det())
-                .collect::<Vec<Result<Self, _>>>()
-                .has_error()?.into_iter()
-                .flat_map(Result::unwrap)
-                .collect::<Self>();
-            Ok(dets)
+            self.get_shape()?.is_square()?;
+            if self.is_complex()? {
+                return self.map_matrices(|matrix| {
+                    let (sign, diagonal) = Self::lu_diagonal(lu_decompose(matrix.to_matrix_complex()?));
+                    let det = diagonal.iter().fold(Complex::new(sign, 0.), |acc, &item| acc * item);
+                    Self::single(N::from_complex(det))
+                })
+            }
+            // determinant of integral matrix is integral, so rounding removes elimination error
+            let is_integral = self.get_elements()?.iter().all(|item| item.to_f64().fract() == 0.);
+            self.map_matrices(|matrix| {
+                let (sign, diagonal) = Self::lu_diagonal(linalg_backend().lu(matrix.to_matrix_f64()?));
+                let det = sign * diagonal.iter().product::<f64>();
+                Self::single(N::from(if is_integral { det.round() } else { det }))
+            })
+        }
+    }
+
+    fn slogdet(&self) -> Result<(Array<f64>, Array<f64>), ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        if self.is_complex()? {
+            return Err(ArrayError::ParameterError { param: "`array`", message: "complex matrices are not supported, use `det` instead" })
         }
+        let shape = self.get_shape()?;
+        let leading = if shape.len() == 2 { vec![1] } else { shape[..shape.len() - 2].to_vec() };
+        let (signs, logdets): (Vec<f64>, Vec<f64>) = self
+            .get_matrices()?
+            .iter()
+            .map(|matrix| {
+                let (sign, diagonal) = Self::lu_diagonal(linalg_backend().lu(matrix.to_matrix_f64()?));
+                let sign = diagonal.iter().fold(sign, |acc, item| acc * item.signum());
+                let logdet = diagonal.iter().map(|item| item.abs().ln()).sum::<f64>();
+                if logdet == f64::NEG_INFINITY { Ok((0., logdet)) }
+                else { Ok((sign, logdet)) }
+            })
+            .collect::<Result<Vec<(f64, f64)>, ArrayError>>()?
+            .into_iter()
+            .unzip();
+        Ok((Array::new(signs, leading.clone())?, Array::new(logdets, leading)?))
+    }
+
+    fn cond(&self, p: Option<impl NormOrdType>) -> Result<Array<f64>, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        let ord = p.map_or(Ok(NormOrd::Int(2)), NormOrdType::to_ord)?;
+        if !matches!(ord, NormOrd::Int(2 | -2)) { self.get_shape()?.is_square()? }
+        self.map_matrices(|matrix| {
+            let matrix = Array::<f64>::from_matrix_f64(&matrix.to_matrix_f64()?)?;
+            let value = match ord {
+                NormOrd::Int(2 | -2) => {
+                    let values = linalg_backend().svd(&matrix.to_matrix_f64()?)?.1;
+                    let (max, min) = (values[0], values[values.len() - 1]);
+                    if ord == NormOrd::Int(2) { max / min } else { min / max }
+                },
+                _ => match matrix.inv() {
+                    Ok(inverse) => matrix.norm(Some(ord), None, None)?[0] * inverse.norm(Some(ord), None, None)?[0],
+                    Err(ArrayError::SingularMatrix) => {
+                        // validate the order, so that invalid one is reported also for singular matrices
+                        matrix.norm(Some(ord), None, None)?;
+                        f64::INFINITY
+                    },
+                    Err(err) => return Err(err),
+                },
+            };
+            Array::single(if value.is_nan() { f64::INFINITY } else { value })
+        })
     }
 }
 
@@ -199,28 +279,37 @@ impl <N: NumericOps> ArrayLinalgNorms<N> for Result<Array<N>, ArrayError> {
     fn det(&self) -> Self {
         self.clone()?.det()
     }
-}
 
-trait NormsHelper<N: NumericOps> {
+    fn slogdet(&self) -> Result<(Array<f64>, Array<f64>), ArrayError> {
+        self.clone()?.slogdet()
+    }
 
-    fn minor(arr: &Array<N>, row: usize, col: usize) -> Result<Array<N>, ArrayError> {
-        arr.is_dim_supported(&[2])?;
-        if row >= arr.get_shape()?[0] || col >= arr.get_shape()?[1] {
-            return Err(ArrayError::OutOfBounds { value: "Row or column index out of bounds" })
-        }
+    fn cond(&self, p: Option<impl NormOrdType>) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.cond(p)
+    }
+}
 
-        let mut sub_shape = arr.get_shape()?;
-        sub_shape[arr.ndim()? - 1] -= 1;
-        sub_shape[arr.ndim()? - 2] -= 1;
+trait NormsHelper<N: NumericOps> {
 
-        let mut sub_elements = Vec::new();
-        for (i, &element) in arr.get_elements()?.iter().enumerate() {
-            if i / arr.get_shape()?[1] != row && i % arr.get_shape()?[1] != col {
-                sub_elements.push(element);
-            }
+    fn trailing_axes_norm(array: &Array<N>, ord: NormOrd, row_axis: isize, col_axis: isize) -> Result<Array<N>, ArrayError> {
+        let array = array.moveaxis(vec![row_axis, col_axis], vec![-2, -1])?;
+        match ord {
+            NormOrd::Fro => array
+                .multiply(&array)
+                .abs()
+                .sum(Some(-1))
+                .sum(Some(-1))
+                .sqrt(),
+            NormOrd::Nuc => array.svdvals().sum(Some(-1)),
+            NormOrd::Int(2) => array.svdvals().max(Some(-1)),
+            NormOrd::Int(_) | NormOrd::Inf | NormOrd::NegInf => array.svdvals().min(Some(-1)),
         }
+    }
 
-        Array::new(sub_elements, sub_shape)
+    fn lu_diagonal<T: LinalgScalar>((lu, pivots): (Vec<Vec<T>>, Vec<usize>)) -> (f64, Vec<T>) {
+        let swaps = pivots.iter().enumerate().filter(|&(i, &pivot)| i != pivot).count();
+        let diagonal = lu.iter().enumerate().map(|(i, row)| row[i]).collect();
+        (if swaps % 2 == 0 { 1. } else { -1. }, diagonal)
     }
 }
 
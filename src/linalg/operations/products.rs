@@ -2,7 +2,11 @@ use crate::{
     core::prelude::*,
     errors::prelude::*,
     extensions::prelude::*,
-    linalg::prelude::*,
+    linalg::{
+        backend::backend_gemm,
+        operations::common::{batch_offsets, broadcast_leading_shape},
+        prelude::*,
+    },
     math::prelude::*,
     numeric::prelude::*,
     validators::prelude::*,
@@ -32,7 +36,8 @@ pub trait ArrayLinalgProducts<N: NumericOps> where Self: Sized + Clone {
     /// may returns `ArrayError`
     fn dot(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
 
-    /// Dot product of two vectors. If input is an array, it will be raveled
+    /// Dot product of two vectors. If input is an array, it will be raveled.
+    /// complex conjugate of the first argument is used
     ///
     /// # Arguments
     ///
@@ -45,6 +50,9 @@ pub trait ArrayLinalgProducts<N: NumericOps> where Self: Sized + Clone {
     ///
     /// assert_eq!(Array::single(20), Array::flat(vec![1, 2, 3]).vdot(&Array::flat(vec![2, 3, 4]).unwrap()));
     /// assert_eq!(Array::single(30), Array::new(vec![1, 4, 5, 6], vec![2, 2]).vdot(&Array::new(vec![4, 1, 2, 2], vec![2, 2]).unwrap()));
+    ///
+    /// let arr = Array::flat(vec![Complex::new(1., 2.), Complex::new(0., 3.)]).unwrap();
+    /// assert_eq!(Array::single(Complex::new(14., 0.)), arr.vdot(&arr));
     /// ```
     ///
     /// # Errors
@@ -111,6 +119,144 @@ pub trait ArrayLinalgProducts<N: NumericOps> where Self: Sized + Clone {
     ///
     /// may returns `ArrayError`
     fn matmul(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Tensor dot product of two arrays, summing over the specified axes
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - other array to perform operations with
+    /// * `axes` - number of trailing axes of `self` and leading axes of `other`, or pairs of axes to sum over. optional, defaults to 2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1, 2, 3, 4], vec![2, 2]).unwrap();
+    /// assert_eq!(Array::single(30), arr.tensordot(&arr, None::<usize>));
+    /// assert_eq!(Array::new(vec![7, 10, 15, 22], vec![2, 2]), arr.tensordot(&arr, Some(1)));
+    /// assert_eq!(Array::new(vec![10, 14, 14, 20], vec![2, 2]), arr.tensordot(&arr, Some((vec![0], vec![0]))));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn tensordot(&self, other: &Array<N>, axes: Option<impl TensorAxesType>) -> Result<Array<N>, ArrayError>;
+
+    /// Kronecker product of two arrays
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - other array to perform operations with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// assert_eq!(Array::flat(vec![5, 6, 10, 12]), Array::flat(vec![1, 2]).kron(&Array::flat(vec![5, 6]).unwrap()));
+    /// assert_eq!(Array::new(vec![1, 2, 0, 0, 3, 4, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4], vec![4, 4]), Array::new(vec![1, 0, 0, 1], vec![2, 2]).kron(&Array::new(vec![1, 2, 3, 4], vec![2, 2]).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn kron(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Cross product of two arrays of 2-D or 3-D vectors
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - other array to perform operations with
+    /// * `axis` - axis containing vectors. optional, defaults to -1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// assert_eq!(Array::flat(vec![-3, 6, -3]), Array::flat(vec![1, 2, 3]).cross(&Array::flat(vec![4, 5, 6]).unwrap(), None));
+    /// assert_eq!(Array::single(-3), Array::flat(vec![1, 2]).cross(&Array::flat(vec![4, 5]).unwrap(), None));
+    /// assert_eq!(Array::flat(vec![12, -6, -3]), Array::flat(vec![1, 2, 0]).cross(&Array::flat(vec![4, 5, 6]).unwrap(), None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn cross(&self, other: &Array<N>, axis: Option<isize>) -> Result<Array<N>, ArrayError>;
+
+    /// Sum along diagonals of the array
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - offset of the diagonal from the main diagonal. optional, defaults to 0
+    /// * `axis1` - first axis of the 2-D sub-arrays. optional, defaults to 0
+    /// * `axis2` - second axis of the 2-D sub-arrays. optional, defaults to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], vec![3, 3]).unwrap();
+    /// assert_eq!(Array::single(15), arr.trace(None, None, None));
+    /// assert_eq!(Array::single(8), arr.trace(Some(1), None, None));
+    /// assert_eq!(Array::flat(vec![5, 13]), Array::new(vec![1, 2, 3, 4, 5, 6, 7, 8], vec![2, 2, 2]).trace(None, Some(1), Some(2)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn trace(&self, offset: Option<isize>, axis1: Option<isize>, axis2: Option<isize>) -> Result<Array<N>, ArrayError>;
+
+    /// Returns specified diagonals. Diagonal is appended as the last axis of the result
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - offset of the diagonal from the main diagonal. optional, defaults to 0
+    /// * `axis1` - first axis of the 2-D sub-arrays. optional, defaults to 0
+    /// * `axis2` - second axis of the 2-D sub-arrays. optional, defaults to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], vec![3, 3]).unwrap();
+    /// assert_eq!(Array::flat(vec![1, 5, 9]), arr.diagonal(None, None, None));
+    /// assert_eq!(Array::flat(vec![2, 6]), arr.diagonal(Some(1), None, None));
+    /// assert_eq!(Array::flat(vec![4, 8]), arr.diagonal(Some(-1), None, None));
+    /// assert_eq!(Array::new(vec![1, 4, 5, 8], vec![2, 2]), Array::new(vec![1, 2, 3, 4, 5, 6, 7, 8], vec![2, 2, 2]).diagonal(None, Some(1), Some(2)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn diagonal(&self, offset: Option<isize>, axis1: Option<isize>, axis2: Option<isize>) -> Result<Array<N>, ArrayError>;
+
+    /// Dot product of two or more arrays, evaluated in the cheapest order.
+    /// First and last arrays may be 1-D, treated as row and column vector respectively
+    ///
+    /// # Arguments
+    ///
+    /// * `arrays` - arrays to multiply
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let a = Array::new(vec![1, 2, 3, 4], vec![2, 2]).unwrap();
+    /// let b = Array::new(vec![5, 6, 7, 8], vec![2, 2]).unwrap();
+    /// let c = Array::new(vec![1, 1, 0, 1], vec![2, 2]).unwrap();
+    /// assert_eq!(Array::new(vec![19, 41, 43, 93], vec![2, 2]), Array::multi_dot(&[&a, &b, &c]));
+    /// assert_eq!(Array::single(62), Array::multi_dot(&[&Array::flat(vec![1, 1]).unwrap(), &a, &b, &Array::flat(vec![1, 0]).unwrap()]));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn multi_dot(arrays: &[&Array<N>]) -> Result<Array<N>, ArrayError>;
 }
 
 impl <N: NumericOps> ArrayLinalgProducts<N> for Array<N> {
@@ -120,10 +266,6 @@ impl <N: NumericOps> ArrayLinalgProducts<N> for Array<N> {
             self.multiply(other)
         } else if self.ndim()? == 1 && other.ndim()? == 1 {
             self.vdot(other)
-        } else if self.ndim()? == 2 && other.ndim()? == 2 {
-            self.matmul(other)
-        } else if self.ndim()? == 1 || other.ndim()? == 1 {
-            Self::dot_1d(self, other)
         } else {
             Self::dot_nd(self, other)
         }
@@ -132,18 +274,17 @@ impl <N: NumericOps> ArrayLinalgProducts<N> for Array<N> {
     fn vdot(&self, other: &Self) -> Result<Self, ArrayError> {
         self.len()?.is_equal(&other.len()?)?;
         let result = self.ravel()?.zip(&other.ravel()?)?
-            .map(|tuple| tuple.0.to_f64() * tuple.1.to_f64())?
-            .fold(0., |a, b| a + b)?;
-        Self::single(N::from(result))
+            .map(|tuple| tuple.0.to_complex().conj() * tuple.1.to_complex())?
+            .fold(Complex::zero(), |a, b| *a + *b)?;
+        Self::single(N::from_complex(result))
     }
 
     fn inner(&self, other: &Self) -> Result<Self, ArrayError> {
         if self.ndim()? == 1 && other.ndim()? == 1 {
             self.shapes_align(0, &other.get_shape()?, 0)?;
             self.zip(other)?
-                .map(|i| i.0.to_f64() * i.1.to_f64())
-                .sum(None)?
-                .to_array_num()
+                .map(|i| N::from_complex(i.0.to_complex() * i.1.to_complex()))
+                .sum(None)
         } else {
             self.shapes_align(self.ndim()? - 1, &other.get_shape()?, other.ndim()? - 1)?;
             Self::inner_nd(self, other)
@@ -152,7 +293,7 @@ impl <N: NumericOps> ArrayLinalgProducts<N> for Array<N> {
 
     fn outer(&self, other: &Self) -> Result<Self, ArrayError> {
         self.into_iter().flat_map(|a| other.into_iter()
-            .map(|b| N::from(a.to_f64() * b.to_f64()))
+            .map(|b| N::from_complex(a.to_complex() * b.to_complex()))
             .collect::<Self>())
             .collect::<Self>()
             .reshape(&[self.len()?, other.len()?])
@@ -161,17 +302,131 @@ impl <N: NumericOps> ArrayLinalgProducts<N> for Array<N> {
     fn matmul(&self, other: &Self) -> Result<Self, ArrayError> {
         if self.ndim()? == 1 && other.ndim()? == 1 {
             self.vdot(other)
-        } else if self.ndim()? == 1 || other.ndim()? == 1 {
-            if self.ndim()? == 1 { self.shapes_align(0, &other.get_shape()?, other.ndim()? - 1)?; }
-            else { self.shapes_align(self.ndim()? - 1, &other.get_shape()?, 0)?; }
-            Self::matmul_1d_nd(self, other)
-        } else if self.ndim()? == 2 && other.ndim()? == 2 {
-            self.shapes_align(0, &other.get_shape()?, 1)?;
-            Self::matmul_iterate(self, other)
         } else {
             Self::matmul_nd(self, other)
         }
     }
+
+    fn tensordot(&self, other: &Self, axes: Option<impl TensorAxesType>) -> Result<Self, ArrayError> {
+        let (axes_1, axes_2) = Self::tensordot_axes(self, other, axes.map_or(Ok(TensorAxes::Count(2)), TensorAxesType::to_axes)?)?;
+        let (shape_1, shape_2) = (self.get_shape()?, other.get_shape()?);
+        let free_1 = (0..shape_1.len()).filter(|axis| !axes_1.contains(axis)).collect::<Vec<usize>>();
+        let free_2 = (0..shape_2.len()).filter(|axis| !axes_2.contains(axis)).collect::<Vec<usize>>();
+        let summed = axes_1.iter().map(|&axis| shape_1[axis]).product::<usize>();
+        let new_shape = free_1.iter().map(|&axis| shape_1[axis])
+            .chain(free_2.iter().map(|&axis| shape_2[axis]))
+            .collect::<Vec<usize>>();
+
+        let arr_1 = self
+            .transpose(Some(free_1.iter().chain(&axes_1).map(|&axis| axis.to_isize()).collect()))?
+            .reshape(&[self.len()? / summed, summed])?;
+        let arr_2 = other
+            .transpose(Some(axes_2.iter().chain(&free_2).map(|&axis| axis.to_isize()).collect()))?
+            .reshape(&[summed, other.len()? / summed])?;
+        arr_1.matmul(&arr_2)
+            .reshape(&if new_shape.is_empty() { vec![1] } else { new_shape })
+    }
+
+    fn kron(&self, other: &Self) -> Result<Self, ArrayError> {
+        let ndim = self.ndim()?.max(other.ndim()?);
+        let pad = |shape: Vec<usize>| [vec![1; ndim - shape.len()], shape].concat();
+        let (shape_1, shape_2) = (pad(self.get_shape()?), pad(other.get_shape()?));
+        // interleave axes of both operands, so each block of the result is scaled `other`
+        let order = (0..ndim).flat_map(|axis| [axis, ndim + axis]).map(|axis| axis.to_isize()).collect();
+        self.outer(other)
+            .reshape(&[shape_1.clone(), shape_2.clone()].concat())
+            .transpose(Some(order))
+            .reshape(&shape_1.iter().zip(&shape_2).map(|(a, b)| a * b).collect::<Vec<usize>>())
+    }
+
+    fn cross(&self, other: &Self, axis: Option<isize>) -> Result<Self, ArrayError> {
+        let axis = axis.unwrap_or(-1);
+        let arr_1 = self.moveaxis(vec![axis], vec![-1])?;
+        let arr_2 = other.moveaxis(vec![axis], vec![-1])?;
+        let (dim_1, dim_2) = (arr_1.get_shape()?[arr_1.ndim()? - 1], arr_2.get_shape()?[arr_2.ndim()? - 1]);
+        dim_1.is_one_of(vec![&2, &3])?;
+        dim_2.is_one_of(vec![&2, &3])?;
+
+        let dim = dim_1.max(dim_2);
+        let leading = Self::cross_leading_shape(&arr_1.get_shape()?, &arr_2.get_shape()?)?;
+        let vectors_1 = Self::cross_vectors(&arr_1, &leading, dim)?;
+        let vectors_2 = Self::cross_vectors(&arr_2, &leading, dim)?;
+        let product = |a: N, b: N, c: N, d: N| N::from_complex(a.to_complex() * b.to_complex() - c.to_complex() * d.to_complex());
+        let elements = vectors_1.chunks(dim)
+            .zip(vectors_2.chunks(dim))
+            .flat_map(|(a, b)| if dim == 2 { vec![product(a[0], b[1], a[1], b[0])] } else { vec![
+                product(a[1], b[2], a[2], b[1]),
+                product(a[2], b[0], a[0], b[2]),
+                product(a[0], b[1], a[1], b[0]),
+            ] })
+            .collect::<Vec<N>>();
+
+        if dim == 2 {
+            Self::new(elements, if leading.is_empty() { vec![1] } else { leading })
+        } else {
+            Self::new(elements, [leading, vec![3]].concat())
+                .moveaxis(vec![-1], vec![axis])
+        }
+    }
+
+    fn trace(&self, offset: Option<isize>, axis1: Option<isize>, axis2: Option<isize>) -> Result<Self, ArrayError> {
+        let diagonal = self.diagonal(offset, axis1, axis2)?;
+        if diagonal.ndim()? == 1 { diagonal.sum(None) }
+        else { diagonal.sum(Some(-1)) }
+    }
+
+    fn diagonal(&self, offset: Option<isize>, axis1: Option<isize>, axis2: Option<isize>) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        let (axis1, axis2) = (axis1.unwrap_or(0), axis2.unwrap_or(1));
+        self.axis_in_bounds(self.normalize_axis(axis1))?;
+        self.axis_in_bounds(self.normalize_axis(axis2))?;
+        if self.normalize_axis(axis1) == self.normalize_axis(axis2) {
+            return Err(ArrayError::ParameterError { param: "`axis1` and `axis2`", message: "cannot be the same" })
+        }
+
+        let array = self.moveaxis(vec![axis1, axis2], vec![-2, -1])?;
+        let shape = array.get_shape()?;
+        let (rows, cols) = (shape[shape.len() - 2], shape[shape.len() - 1]);
+        let offset = offset.unwrap_or(0);
+        let (row_start, col_start) = if offset >= 0 { (0, offset.to_usize()) } else { ((-offset).to_usize(), 0) };
+        let len = rows.saturating_sub(row_start).min(cols.saturating_sub(col_start));
+        let elements = array.get_elements()?
+            .chunks((rows * cols).max(1))
+            .flat_map(|matrix| (0..len).map(move |i| matrix[(row_start + i) * cols + col_start + i]))
+            .collect::<Vec<N>>();
+        Self::new(elements, [&shape[..shape.len() - 2], &[len]].concat())
+    }
+
+    fn multi_dot(arrays: &[&Self]) -> Result<Self, ArrayError> {
+        arrays.len().is_at_least(&2)?;
+        let last = arrays.len() - 1;
+        let (vector_first, vector_last) = (arrays[0].ndim()? == 1, arrays[last].ndim()? == 1);
+        let matrices = arrays.iter().enumerate()
+            .map(|(idx, arr)| match (idx, arr.ndim()?) {
+                (0, 1) => arr.reshape(&[1, arr.len()?]),
+                (idx, 1) if idx == last => arr.reshape(&[arr.len()?, 1]),
+                _ => {
+                    arr.is_dim_supported(&[2])?;
+                    Ok((*arr).clone())
+                },
+            })
+            .collect::<Result<Vec<Self>, ArrayError>>()?;
+        matrices.windows(2)
+            .try_for_each(|pair| pair[0].get_shape()?.shapes_align(1, &pair[1].get_shape()?, 0))?;
+
+        let mut dims = vec![matrices[0].get_shape()?[0]];
+        for matrix in &matrices { dims.push(matrix.get_shape()?[1]); }
+        let order = Self::multi_dot_order(&dims);
+        let result = Self::multi_dot_chain(&matrices, &order, 0, last)?;
+
+        let shape = result.get_shape()?;
+        match (vector_first, vector_last) {
+            (true, true) => result.reshape(&[1]),
+            (true, false) => result.reshape(&[shape[1]]),
+            (false, true) => result.reshape(&[shape[0]]),
+            (false, false) => Ok(result),
+        }
+    }
 }
 
 impl <N: NumericOps> ArrayLinalgProducts<N> for Result<Array<N>, ArrayError> {
@@ -195,59 +450,132 @@ impl <N: NumericOps> ArrayLinalgProducts<N> for Result<Array<N>, ArrayError> {
     fn matmul(&self, other: &Array<N>) -> Self {
         self.clone()?.matmul(other)
     }
+
+    fn tensordot(&self, other: &Array<N>, axes: Option<impl TensorAxesType>) -> Self {
+        self.clone()?.tensordot(other, axes)
+    }
+
+    fn kron(&self, other: &Array<N>) -> Self {
+        self.clone()?.kron(other)
+    }
+
+    fn cross(&self, other: &Array<N>, axis: Option<isize>) -> Self {
+        self.clone()?.cross(other, axis)
+    }
+
+    fn trace(&self, offset: Option<isize>, axis1: Option<isize>, axis2: Option<isize>) -> Self {
+        self.clone()?.trace(offset, axis1, axis2)
+    }
+
+    fn diagonal(&self, offset: Option<isize>, axis1: Option<isize>, axis2: Option<isize>) -> Self {
+        self.clone()?.diagonal(offset, axis1, axis2)
+    }
+
+    fn multi_dot(arrays: &[&Array<N>]) -> Self {
+        Array::multi_dot(arrays)
+    }
 }
 
 trait ProductsHelper<N: NumericOps> {
 
-    fn dot_split_array(arr: &Array<N>, axis: usize) -> Result<Vec<Array<N>>, ArrayError> {
-        arr.split_axis(axis)?
-            .into_iter().flatten()
-            .collect::<Array<N>>()
-            .split(arr.get_shape()?.remove_at(axis).iter().product(), None)
+    fn tensordot_axes(arr_1: &Array<N>, arr_2: &Array<N>, axes: TensorAxes) -> Result<(Vec<usize>, Vec<usize>), ArrayError> {
+        let (axes_1, axes_2) = match axes {
+            TensorAxes::Count(count) => {
+                arr_1.ndim()?.min(arr_2.ndim()?).is_at_least(&count)?;
+                ((arr_1.ndim()? - count..arr_1.ndim()?).collect::<Vec<usize>>(), (0..count).collect::<Vec<usize>>())
+            },
+            TensorAxes::Pairs(axes_1, axes_2) => {
+                axes_1.len().is_equal(&axes_2.len())?;
+                (axes_1.iter().map(|&axis| arr_1.normalize_axis(axis)).collect(), axes_2.iter().map(|&axis| arr_2.normalize_axis(axis)).collect())
+            },
+        };
+        axes_1.is_unique()?;
+        axes_2.is_unique()?;
+        let (shape_1, shape_2) = (arr_1.get_shape()?, arr_2.get_shape()?);
+        axes_1.iter().zip(&axes_2).try_for_each(|(&axis_1, &axis_2)| {
+            arr_1.axis_in_bounds(axis_1)?;
+            arr_2.axis_in_bounds(axis_2)?;
+            shape_1.shapes_align(axis_1, &shape_2, axis_2)
+        })?;
+        Ok((axes_1, axes_2))
     }
 
-    fn dot_iterate(v_arr_1: &[Array<N>], v_arr_2: &[Array<N>]) -> Result<Array<N>, ArrayError> {
-        v_arr_1.iter().flat_map(|a| {
-            v_arr_2.iter().map(move |b| a.vdot(b))
-        })
-            .collect::<Vec<Result<Array<N>, _>>>()
-            .has_error()?.into_iter()
-            .flat_map(Result::unwrap)
-            .collect::<Array<N>>()
-            .ravel()
+    fn cross_leading_shape(shape_1: &[usize], shape_2: &[usize]) -> Result<Vec<usize>, ArrayError> {
+        let (leading_1, leading_2) = (&shape_1[..shape_1.len() - 1], &shape_2[..shape_2.len() - 1]);
+        let ndim = leading_1.len().max(leading_2.len());
+        let pad = |shape: &[usize]| [vec![1; ndim - shape.len()], shape.to_vec()].concat();
+        pad(leading_1).into_iter()
+            .zip(pad(leading_2))
+            .map(|(dim_1, dim_2)|
+                if dim_1 == dim_2 || dim_2 == 1 { Ok(dim_1) }
+                else if dim_1 == 1 { Ok(dim_2) }
+                else { Err(ArrayError::BroadcastShapeMismatch) })
+            .collect()
+    }
+
+    fn cross_vectors(arr: &Array<N>, leading: &[usize], dim: usize) -> Result<Vec<N>, ArrayError> {
+        let shape = arr.get_shape()?;
+        let (own, size) = (&shape[..shape.len() - 1], shape[shape.len() - 1]);
+        let pad = leading.len() - own.len();
+        let elements = arr.get_elements()?;
+        // 2-D vectors are extended with zero `z` component when crossed with 3-D ones
+        let result = (0..leading.iter().product::<usize>())
+            .flat_map(|flat| {
+                let (mut remaining, mut offset, mut stride) = (flat, 0, 1);
+                for (axis, &extent) in leading.iter().enumerate().rev() {
+                    let idx = remaining % extent;
+                    remaining /= extent;
+                    if axis < pad { continue }
+                    if own[axis - pad] != 1 { offset += idx * stride }
+                    stride *= own[axis - pad];
+                }
+                elements[offset * size..(offset + 1) * size].iter().copied()
+                    .chain(std::iter::repeat(N::zero()))
+                    .take(dim)
+            })
+            .collect();
+        Ok(result)
     }
 
-    fn dot_1d(arr_1: &Array<N>, arr_2: &Array<N>) -> Result<Array<N>, ArrayError> {
-        let arr_1 = if arr_1.ndim()? > 1 { arr_1.get_rows()? } else { vec![arr_1.clone()] };
-        let arr_2 = if arr_2.ndim()? > 1 { arr_2.get_columns()? } else { vec![arr_2.clone()] };
-        Self::dot_iterate(&arr_1, &arr_2)
+    fn multi_dot_order(dims: &[usize]) -> Vec<Vec<usize>> {
+        // classic matrix chain dynamic programming, `split[i][j]` holds the optimal split point of chain `i..=j`
+        let count = dims.len() - 1;
+        let mut cost = vec![vec![0; count]; count];
+        let mut split = vec![vec![0; count]; count];
+        for len in 1..count { for i in 0..count - len {
+            let j = i + len;
+            cost[i][j] = usize::MAX;
+            for k in i..j {
+                let candidate = cost[i][k] + cost[k + 1][j] + dims[i] * dims[k + 1] * dims[j + 1];
+                if candidate < cost[i][j] {
+                    cost[i][j] = candidate;
+                    split[i][j] = k;
+                }
+            }
+        } }
+        split
+    }
+
+    fn multi_dot_chain(matrices: &[Array<N>], split: &[Vec<usize>], i: usize, j: usize) -> Result<Array<N>, ArrayError> {
+        if i == j { return Ok(matrices[i].clone()) }
+        let k = split[i][j];
+        Self::multi_dot_chain(matrices, split, i, k)?
+            .matmul(&Self::multi_dot_chain(matrices, split, k + 1, j)?)
     }
 
     fn dot_nd(arr_1: &Array<N>, arr_2: &Array<N>) -> Result<Array<N>, ArrayError> {
-        arr_1.shapes_align(arr_1.ndim()? - 1, &arr_2.get_shape()?, arr_2.ndim()? - 2)?;
-        let mut new_shape = arr_1.get_shape()?.remove_at(arr_1.ndim()? - 2);
-        new_shape.extend_from_slice(&arr_2.get_shape()?.remove_at(arr_2.ndim()? - 1));
-        let v_arr_1 = Self::dot_split_array(arr_1, arr_1.ndim()? - 2)?;
-        let v_arr_2 = Self::dot_split_array(arr_2, arr_2.ndim()? - 1)?;
-
-        let rev = arr_2.len()? > arr_1.len()?;
-        let pairs = (0..new_shape.len().to_isize())
-            .collect::<Vec<isize>>()
-            .reverse_if(rev)
-            .into_iter()
-            .step_by(2)
-            .map(|item|
-                if rev { if item <= 1 { vec![item] } else { vec![item, item - 1] } }
-                else if new_shape.len().to_isize() > item + 1 { vec![item + 1, item] }
-                else { vec![item] })
-            .collect::<Vec<Vec<isize>>>()
-            .reverse_if(rev)
-            .into_iter()
-            .flatten()
-            .collect::<Vec<isize>>();
-        Self::dot_iterate(&v_arr_1, &v_arr_2)
-            .reshape(&new_shape)
-            .transpose(Some(pairs))
+        let (shape_1, shape_2) = (arr_1.get_shape()?, arr_2.get_shape()?);
+        // last axis of `arr_1` is summed with the second-to-last axis of `arr_2`, or its only axis
+        let axis_2 = shape_2.len().saturating_sub(2);
+        if shape_1.len() == 1 || shape_2.len() == 1 { shape_1[shape_1.len() - 1].is_equal(&shape_2[axis_2])?; }
+        else { shape_1.shapes_align(shape_1.len() - 1, &shape_2, axis_2)?; }
+
+        let summed = shape_2[axis_2];
+        let moved = if shape_2.len() > 2 { Some(arr_2.moveaxis(vec![-2], vec![0])?) } else { None };
+        let (rows, cols) = (arr_1.len()? / summed, arr_2.len()? / summed);
+        let result = backend_gemm((rows, summed, cols), arr_1, moved.as_ref().unwrap_or(arr_2), &[(0, 0)]);
+        let new_shape = [&shape_1[..shape_1.len() - 1], &arr_2.get_shape()?.remove_at(axis_2)[..]].concat();
+        Array::new(result, new_shape)
     }
 
     fn inner_nd(arr_1: &Array<N>, arr_2: &Array<N>) -> Result<Array<N>, ArrayError> {
@@ -274,97 +602,26 @@ trait ProductsHelper<N: NumericOps> {
             .reshape(&new_shape)
     }
 
-    fn matmul_iterate(arr_1: &Array<N>, arr_2: &Array<N>) -> Result<Array<N>, ArrayError> {
-        let (shape_1, shape_2) = (&arr_1.get_shape()?, &arr_2.get_shape()?);
-        (0..shape_1[0])
-            .flat_map(|i| (0..shape_2[1])
-                .map(move |j| (0..shape_1[1])
-                    .fold(0., |acc, k| arr_1[i * shape_1[1] + k].to_f64().mul_add(arr_2[k * shape_2[1] + j].to_f64(), acc))))
-            .map(N::from_f64)
-            .collect::<Array<N>>()
-            .reshape(&[shape_1[0], shape_2[1]])
-    }
-
-    fn matmul_1d_nd(arr_1: &Array<N>, arr_2: &Array<N>) -> Result<Array<N>, ArrayError> {
-        if arr_1.ndim()? == 1 {
-            if arr_2.ndim()? > 2 {
-                let new_shape = arr_2.get_shape()?.remove_at(0);
-                arr_2.split_axis(0)?.into_iter()
-                    .map(|arr| Self::matmul_1d_nd(arr_1, &arr.reshape(&new_shape).unwrap()))
-                    .collect::<Vec<Result<Array<N>, _>>>()
-                    .has_error()?
-                    .into_iter()
-                    .flat_map(Result::unwrap)
-                    .collect::<Array<N>>()
-                    .reshape(&new_shape)
-            } else {
-                let result = arr_1
-                    .get_elements()?
-                    .into_iter()
-                    .zip(&arr_2.split_axis(0)?)
-                    .map(|(a, b)| b.into_iter()
-                        .map(|item| a.to_f64() * item.to_f64())
-                        .sum::<f64>())
-                    .map(N::from)
-                    .collect::<Array<N>>();
-                Ok(result)
-            }
-        } else if arr_1.ndim()? > 2 {
-            let new_shape = arr_1.get_shape()?.remove_at(0);
-            arr_1.split_axis(0)?
-                .into_iter()
-                .map(|arr| Self::matmul_1d_nd(&arr.reshape(&new_shape).unwrap(), arr_2))
-                .collect::<Vec<Result<Array<N>, _>>>()
-                .has_error()?
-                .into_iter()
-                .flat_map(Result::unwrap)
-                .collect::<Array<N>>()
-                .reshape(&new_shape)
-        } else {
-            let result = arr_1
-                .split_axis(0)?
-                .iter()
-                .map(|arr| (0..arr.shape[arr.shape.len() - 1])
-                    .map(|idx| arr[idx].to_f64() * arr_2[idx].to_f64())
-                    .sum::<f64>())
-                .map(N::from)
-                .collect::<Array<N>>();
-            Ok(result)
-        }
-    }
-
     fn matmul_nd(arr_1: &Array<N>, arr_2: &Array<N>) -> Result<Array<N>, ArrayError> {
-        fn matmul_split<N: NumericOps>(arr: &Array<N>, len: usize, chunk_len: usize) -> Result<Vec<Array<N>>, ArrayError> {
-            let shape_last = arr.get_shape()?
-                .into_iter()
-                .skip(arr.ndim()? - 2)
-                .take(2)
-                .collect::<Vec<usize>>();
-            let result = arr.split(arr.len()? / chunk_len, Some(0))?
-                .into_iter().cycle().take(len)
-                .map(|arr| arr.reshape(&shape_last).unwrap())
-                .collect::<Vec<Array<N>>>();
-            Ok(result)
-        }
-
-        let mut new_shape =
-            if arr_1.ndim()? >= arr_2.ndim()? { arr_1.get_shape()? }
-            else { arr_2.get_shape()? };
-        let shape_len = new_shape.len();
-        new_shape[shape_len - 2] = arr_1.get_shape()?[arr_1.ndim()? - 2];
-        new_shape[shape_len - 1] = arr_2.get_shape()?[arr_2.ndim()? - 1];
-        let chunk_len = arr_1.get_shape()?[arr_1.ndim()? - 2 ..].iter().product::<usize>();
-        let len = std::cmp::max(arr_1.len()?, arr_2.len()?) / chunk_len;
-        matmul_split(arr_1, len, chunk_len)?
-            .into_iter()
-            .zip(&matmul_split(arr_2, len, chunk_len)?)
-            .map(|(a, b)| a.matmul(b))
-            .collect::<Vec<Result<Array<N>, _>>>()
-            .has_error()?
-            .into_iter()
-            .flat_map(Result::unwrap)
-            .collect::<Array<N>>()
-            .reshape(&new_shape)
+        // vectors are promoted to matrices, and the added dimension is removed from the result
+        let (ndim_1, ndim_2) = (arr_1.ndim()?, arr_2.ndim()?);
+        let shape_1 = if ndim_1 == 1 { vec![1, arr_1.len()?] } else { arr_1.get_shape()? };
+        let shape_2 = if ndim_2 == 1 { vec![arr_2.len()?, 1] } else { arr_2.get_shape()? };
+        shape_1.shapes_align(shape_1.len() - 1, &shape_2, shape_2.len() - 2)?;
+
+        let (rows, summed, cols) = (shape_1[shape_1.len() - 2], shape_1[shape_1.len() - 1], shape_2[shape_2.len() - 1]);
+        let (leading_1, leading_2) = (&shape_1[..shape_1.len() - 2], &shape_2[..shape_2.len() - 2]);
+        let leading = broadcast_leading_shape(leading_1, leading_2)?;
+        let offsets_1 = batch_offsets(leading_1, &leading, rows * summed);
+        let offsets_2 = batch_offsets(leading_2, &leading, summed * cols);
+
+        let offsets = offsets_1.into_iter().zip(offsets_2).collect::<Vec<_>>();
+        let result = backend_gemm((rows, summed, cols), arr_1, arr_2, &offsets);
+
+        let mut new_shape = leading;
+        if ndim_1 > 1 { new_shape.push(rows) }
+        if ndim_2 > 1 { new_shape.push(cols) }
+        Array::new(result, new_shape)
     }
 }
 
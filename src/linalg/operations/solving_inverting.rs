@@ -2,7 +2,11 @@ use crate::{
     core::prelude::*,
     errors::prelude::*,
     extensions::prelude::*,
-    linalg::prelude::*,
+    linalg::{
+        backend::linalg_backend,
+        operations::common::{batch_offsets, broadcast_leading_shape, lu_decompose, lu_substitute, solve_stacked, svd_qr, LinalgScalar},
+        prelude::*,
+    },
     numeric::prelude::*,
     validators::prelude::*,
 };
@@ -10,11 +14,12 @@ use crate::{
 /// `ArrayTrait` - Array Linalg Solving equations and Inverting matrices functions
 pub trait ArrayLinalgSolvingInvertingProducts<N: NumericOps> where Self: Sized + Clone {
 
-    /// Solve a linear matrix equation, or system of linear scalar equations
+    /// Solve a linear matrix equation, or system of linear scalar equations.
+    /// stacks of matrices are solved over the leading dimensions, broadcast against these of `other`
     ///
     /// # Arguments
     ///
-    /// * `other` - other array to perform operations with
+    /// * `other` - right-hand side, vector of shape `[N]` shared by all matrices or stack of matrices of shape `[..., N, K]`
     ///
     /// # Examples
     ///
@@ -24,85 +29,534 @@ pub trait ArrayLinalgSolvingInvertingProducts<N: NumericOps> where Self: Sized +
     /// let arr_1 = Array::new(vec![2., 1., 1., 3.], vec![2, 2]).unwrap();
     /// let arr_2 = Array::new(vec![5., 8., 3., 6.], vec![2, 2]).unwrap();
     /// assert_eq!(Array::new(vec![2.4, 3.6, 0.2, 0.8], vec![2, 2]), arr_1.solve(&arr_2));
+    ///
+    /// let arr_1 = Array::new(vec![2., 0., 0., 4., 1., 0., 0., 2.], vec![2, 2, 2]).unwrap();
+    /// let arr_2 = Array::flat(vec![4., 8.]).unwrap();
+    /// assert_eq!(Array::new(vec![2., 2., 4., 4.], vec![2, 2]), arr_1.solve(&arr_2));
     /// ```
     ///
     /// # Errors
     ///
     /// may returns `ArrayError`
     fn solve(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the inverse of a matrix.
+    /// stacks of matrices are inverted over the leading dimensions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![2., 1., 1., 1.], vec![2, 2]).unwrap();
+    /// assert_eq!(Array::new(vec![1., -1., -1., 2.], vec![2, 2]), arr.inv());
+    ///
+    /// let arr = Array::new(vec![1., 2., 2., 4.], vec![2, 2]).unwrap();
+    /// assert_eq!(Err(ArrayError::SingularMatrix), arr.inv());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn inv(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the Moore-Penrose pseudo-inverse of a matrix, using its singular value decomposition.
+    /// stacks of matrices are inverted over the leading dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `rcond` - cutoff for small singular values, relative to the largest one. optional, defaults to 1e-15
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1., 0., 0., 0., 2., 0.], vec![2, 3]).unwrap();
+    /// assert_eq!(Array::new(vec![1., 0., 0., 0.5, 0., 0.], vec![3, 2]), arr.pinv(None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn pinv(&self, rcond: Option<f64>) -> Result<Array<N>, ArrayError>;
+
+    /// Raise a square matrix to the integer power.
+    /// negative powers are computed from the inverse of the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - exponent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1, 1, 1, 0], vec![2, 2]).unwrap();
+    /// assert_eq!(Array::new(vec![8, 5, 5, 3], vec![2, 2]), arr.matrix_power(5));
+    /// assert_eq!(Array::new(vec![1, 0, 0, 1], vec![2, 2]), arr.matrix_power(0));
+    ///
+    /// let arr = Array::new(vec![2., 0., 0., 4.], vec![2, 2]).unwrap();
+    /// assert_eq!(Array::new(vec![0.25, 0., 0., 0.0625], vec![2, 2]), arr.matrix_power(-2));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn matrix_power(&self, n: i32) -> Result<Array<N>, ArrayError>;
+
+    /// Return matrix rank of array, using its singular value decomposition.
+    /// rectangular matrices are first reduced to triangular factor of pivoted qr decomposition.
+    /// stacks of matrices are handled over the leading dimensions
+    ///
+    /// # Arguments
+    ///
+    /// * `tol` - threshold below which singular values are considered zero. optional, defaults to `max(s) * max(M, N) * eps`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// assert_eq!(Array::single(2), Array::new(vec![1., 2., 3., 2., 4., 6., 1., 0., 1.], vec![3, 3]).matrix_rank(None));
+    /// assert_eq!(Array::flat(vec![2, 1]), Array::new(vec![1., 0., 0., 1., 1., 1., 1., 1.], vec![2, 2, 2]).matrix_rank(None));
+    /// assert_eq!(Array::single(1), Array::new(vec![1., 0., 0., 1e-3], vec![2, 2]).matrix_rank(Some(1e-2)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn matrix_rank(&self, tol: Option<f64>) -> Result<Array<usize>, ArrayError>;
+
+    /// Return the least-squares solution to a linear matrix equation, using singular value decomposition.
+    /// rectangular matrices are first reduced to triangular factor of pivoted qr decomposition.
+    /// Returns solution, sums of squared residuals, effective rank and singular values of array.
+    /// residuals are empty if rank of any matrix is lower than `N` or `M <= N`.
+    /// stacks of matrices are solved over the leading dimensions, broadcast against these of `b`
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - ordinate values, of shape `[M]` shared by all matrices, or `[..., M, K]`
+    /// * `rcond` - cutoff for small singular values, relative to the largest one. optional, defaults to `max(M, N) * eps`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1., 0., 1., 1., 1., 2., 1., 3.], vec![4, 2]).unwrap();
+    /// let (x, residuals, rank, s) = arr.lstsq(&Array::flat(vec![1., 3., 3., 5.]).unwrap(), None).unwrap();
+    ///
+    /// assert_eq!(Array::flat(vec![1.2, 1.2]), x.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// assert_eq!(Array::flat(vec![0.8]), residuals.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// assert_eq!(Array::single(2), Ok(rank));
+    /// assert_eq!(2, s.len().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn lstsq(&self, b: &Array<N>, rcond: Option<f64>) -> LstsqResult<N>;
+
+    /// Solve a linear matrix equation with triangular matrix, using forward or back substitution.
+    /// only the selected triangle of the array is referenced
+    ///
+    /// # Arguments
+    ///
+    /// * `b` - right-hand side, of shape `[N]` or `[N, K]`
+    /// * `lower` - whether to use the lower or upper triangle of the array. optional, defaults to false
+    /// * `unit_diagonal` - if true, diagonal elements are assumed to be 1 and are not referenced. optional, defaults to false
+    /// * `trans` - form of the system to solve, `a x = b`, `a^T x = b` or `a^H x = b`. optional, defaults to `NoTranspose`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![2., 0., 1., 4.], vec![2, 2]).unwrap();
+    /// let b = Array::flat(vec![2., 9.]).unwrap();
+    /// assert_eq!(Array::flat(vec![1., 2.]), arr.solve_triangular(&b, Some(true), None, None::<TransposeMode>));
+    /// assert_eq!(Array::flat(vec![-0.125, 2.25]), arr.solve_triangular(&b, Some(true), None, Some("t")));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn solve_triangular(&self, b: &Array<N>, lower: Option<bool>, unit_diagonal: Option<bool>, trans: Option<impl TransposeModeType>) -> Result<Array<N>, ArrayError>;
+
+    /// Solve a linear matrix equation with banded matrix, using lu decomposition with partial pivoting.
+    /// array holds the matrix in diagonal ordered form, where `ab[u + i - j, j] = a[i, j]`
+    ///
+    /// # Arguments
+    ///
+    /// * `l_and_u` - number of non-zero lower and upper diagonals
+    /// * `b` - right-hand side, of shape `[N]` or `[N, K]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// // [[2, 1, 0], [1, 2, 1], [0, 1, 2]]
+    /// let ab = Array::new(vec![0., 1., 1., 2., 2., 2., 1., 1., 0.], vec![3, 3]).unwrap();
+    /// let b = Array::flat(vec![4., 8., 8.]).unwrap();
+    /// let result = ab.solve_banded((1, 1), &b).unwrap();
+    /// assert_eq!(Array::flat(vec![1., 2., 3.]), result.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn solve_banded(&self, l_and_u: (usize, usize), b: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Solve a linear matrix equation with tridiagonal matrix, using thomas algorithm.
+    /// no pivoting is performed, so the matrix should be diagonally dominant or symmetric positive definite
+    ///
+    /// # Arguments
+    ///
+    /// * `lower` - sub-diagonal, of length `N - 1`
+    /// * `diagonal` - main diagonal, of length `N`
+    /// * `upper` - super-diagonal, of length `N - 1`
+    /// * `b` - right-hand side, of shape `[N]` or `[N, K]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let lower = Array::flat(vec![1., 1.]).unwrap();
+    /// let diagonal = Array::flat(vec![2., 2., 2.]).unwrap();
+    /// let upper = Array::flat(vec![1., 1.]).unwrap();
+    /// let b = Array::flat(vec![4., 8., 8.]).unwrap();
+    /// let result = Array::solve_tridiagonal(&lower, &diagonal, &upper, &b).unwrap();
+    /// assert_eq!(Array::flat(vec![1., 2., 3.]), result.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn solve_tridiagonal(lower: &Array<N>, diagonal: &Array<N>, upper: &Array<N>, b: &Array<N>) -> Result<Array<N>, ArrayError>;
 }
 
 impl <N: NumericOps> ArrayLinalgSolvingInvertingProducts<N> for Array<N> {
 
     fn solve(&self, other: &Self) -> Result<Self, ArrayError> {
-        self.is_dim_supported(&[2])?;
-        let n = self.get_shape()?[0];
-        self.is_square()?;
-        other.get_shape()?[0].is_equal(&n)?;
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        if self.is_complex()? || other.is_complex()? {
+            let factors = self.get_matrices()?.iter()
+                .map(|matrix| Ok(lu_decompose(matrix.to_matrix_complex()?)))
+                .collect::<Result<Vec<(ComplexMatrix, Vec<usize>)>, ArrayError>>()?;
+            return solve_stacked(&self.get_shape()?, other, |idx, rhs| lu_substitute(&factors[idx].0, &factors[idx].1, rhs))
+        }
+        let factors = self.get_matrices()?.iter()
+            .map(|matrix| Ok(linalg_backend().lu(matrix.to_matrix_f64()?)))
+            .collect::<Result<Vec<(Vec<Vec<f64>>, Vec<usize>)>, ArrayError>>()?;
+        solve_stacked(&self.get_shape()?, other, |idx, rhs| lu_substitute(&factors[idx].0, &factors[idx].1, rhs))
+    }
+
+    fn inv(&self) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        let is_complex = self.is_complex()?;
+        self.map_matrices(|matrix| if is_complex {
+            Self::from_matrix_scalar(&Self::lu_inverse(lu_decompose(matrix.to_matrix_complex()?))?)
+        } else {
+            Self::from_matrix_scalar(&Self::lu_inverse(linalg_backend().lu(matrix.to_matrix_f64()?))?)
+        })
+    }
+
+    fn pinv(&self, rcond: Option<f64>) -> Result<Self, ArrayError> {
+        self.map_matrices(|matrix| {
+            let (u, values, v) = linalg_backend().svd(&matrix.to_matrix_f64()?)?;
+            let cutoff = rcond.unwrap_or(1e-15) * values.first().copied().unwrap_or(0.);
+            let inverse = v.iter()
+                .map(|v_row| u.iter()
+                    .map(|u_row| values.iter().enumerate()
+                        .filter(|(_, &value)| value > cutoff)
+                        .map(|(k, value)| v_row[k] * u_row[k] / value)
+                        .sum())
+                    .collect())
+                .collect::<Vec<Vec<f64>>>();
+            Self::from_matrix_f64(&inverse)
+        })
+    }
+
+    fn matrix_power(&self, n: i32) -> Result<Self, ArrayError> {
+        self.is_dim_unsupported(&[0, 1])?;
+        self.get_shape()?.is_square()?;
+        self.map_matrices(|matrix| {
+            let mut base = if n < 0 { matrix.inv()? } else { matrix.clone() };
+            let (mut result, mut exponent) = (Self::identity(matrix.get_shape()?[0])?, n.unsigned_abs());
+            while exponent > 0 {
+                if exponent & 1 == 1 { result = result.matmul(&base)? }
+                exponent >>= 1;
+                if exponent > 0 { base = base.matmul(&base)? }
+            }
+            Ok(result)
+        })
+    }
 
-        if self.det()?[0].to_f64().abs() < 1e-12 {
-            return Err(ArrayError::SingularMatrix);
+    fn matrix_rank(&self, tol: Option<f64>) -> Result<Array<usize>, ArrayError> {
+        self.map_matrices(|matrix| {
+            let shape = matrix.get_shape()?;
+            let (_, values, _) = svd_qr(&matrix.to_matrix_f64()?)?;
+            let tol = tol.unwrap_or_else(|| values.first().copied().unwrap_or(0.) * shape[0].max(shape[1]).to_f64() * f64::EPSILON);
+            Array::single(values.iter().filter(|&&value| value > tol).count())
+        })
+    }
+
+    fn lstsq(&self, b: &Self, rcond: Option<f64>) -> LstsqResult<N> {
+        self.is_dim_unsupported(&[0, 1])?;
+        b.is_dim_unsupported(&[0])?;
+        let shape = self.get_shape()?;
+        let (rows, cols) = (shape[shape.len() - 2], shape[shape.len() - 1]);
+        let b_shape = b.get_shape()?;
+        let is_vector = b_shape.len() == 1;
+        let (b_leading, rhs_shape) = b_shape.split_at(if is_vector { 0 } else { b_shape.len() - 2 });
+        rhs_shape[0].is_equal(&rows)?;
+        let rhs_cols = if is_vector { 1 } else { rhs_shape[1] };
+
+        let a_leading = self.leading_shape()?;
+        let leading = broadcast_leading_shape(&a_leading, b_leading)?;
+        let matrices = self.get_matrices()?.iter()
+            .map(|matrix| {
+                let matrix = matrix.to_matrix_f64()?;
+                let (u, values, v) = svd_qr(&matrix)?;
+                let cutoff = rcond.unwrap_or_else(|| rows.max(cols).to_f64() * f64::EPSILON) * values.first().copied().unwrap_or(0.);
+                let rank = values.iter().filter(|&&value| value > cutoff).count();
+                Ok((matrix, u, values, v, rank))
+            })
+            .collect::<Result<Vec<_>, ArrayError>>()?;
+        b.is_complex_unsupported()?;
+        let elements = b.to_array_f64()?.get_elements()?;
+        let (solutions, residuals): (Vec<_>, Vec<_>) = batch_offsets(&a_leading, &leading, 1).into_iter()
+            .zip(batch_offsets(b_leading, &leading, rows * rhs_cols))
+            .map(|(idx, offset)| {
+                let (matrix, u, values, v, rank) = &matrices[idx];
+                let rhs = elements[offset..offset + rows * rhs_cols].chunks(rhs_cols.max(1)).map(<[f64]>::to_vec).collect::<Vec<_>>();
+                Self::lstsq_matrix(matrix, (u, &values[..*rank], v), &rhs)
+            })
+            .unzip();
+
+        let x_shape = if is_vector { vec![cols] } else { vec![cols, rhs_cols] };
+        let residuals = if matrices.iter().any(|(.., rank)| *rank < cols) || rows <= cols { Self::flat(vec![])? } else {
+            Self::new(residuals.into_iter().flatten().map(N::from).collect(), [&leading[..], &[rhs_cols]].concat())?
         };
+        let (ranks, values): (Vec<_>, Vec<_>) = matrices.into_iter().map(|(.., values, _, rank)| (rank, values)).unzip();
+        let rank_shape = if a_leading.is_empty() { vec![1] } else { a_leading.clone() };
+        Ok((
+            Self::new(solutions.into_iter().flatten().map(N::from).collect(), [&leading[..], &x_shape].concat())?,
+            residuals,
+            Array::new(ranks, rank_shape)?,
+            Self::new(values.into_iter().flatten().map(N::from).collect(), [&a_leading[..], &[rows.min(cols)]].concat())?,
+        ))
+    }
 
-        let mut arr_l = Self::identity(n)?.to_array_f64()?.to_matrix()?;
-        let mut arr_u = self.to_array_f64()?.to_matrix()?;
+    fn solve_triangular(&self, b: &Self, lower: Option<bool>, unit_diagonal: Option<bool>, trans: Option<impl TransposeModeType>) -> Result<Self, ArrayError> {
+        self.is_dim_supported(&[2])?;
+        self.get_shape()?.is_square()?;
+        let size = self.get_shape()?[0];
+        let mut rhs = Self::structured_rhs(b, size)?;
+        let matrix = self.to_matrix()?.iter()
+            .map(|row| row.iter().map(Numeric::to_complex).collect())
+            .collect::<ComplexMatrix>();
+        // transposed systems are solved with the transposed triangle, which is of the opposite kind
+        let lower = lower.unwrap_or(false);
+        let (matrix, lower) = match trans.map_or(Ok(TransposeMode::NoTranspose), TransposeModeType::to_transpose_mode)? {
+            TransposeMode::NoTranspose => (matrix, lower),
+            TransposeMode::Transpose => ((0..size).map(|i| (0..size).map(|j| matrix[j][i]).collect()).collect(), !lower),
+            TransposeMode::ConjugateTranspose => ((0..size).map(|i| (0..size).map(|j| matrix[j][i].conj()).collect()).collect(), !lower),
+        };
 
-        for j in 0..n {
-            let mut pivot_row = j;
-            for i in j + 1..n {
-                if arr_u[i][j].abs() > arr_u[pivot_row][j].abs() { pivot_row = i; }
+        let order = if lower { (0..size).collect::<Vec<usize>>() } else { (0..size).rev().collect() };
+        for i in order {
+            let known = if lower { 0..i } else { i + 1..size };
+            let mut row = known.fold(rhs[i].clone(), |mut acc, j| {
+                acc.iter_mut().zip(&rhs[j]).for_each(|(value, item)| *value -= matrix[i][j] * *item);
+                acc
+            });
+            if !unit_diagonal.unwrap_or(false) {
+                if matrix[i][i].norm() == 0. { return Err(ArrayError::SingularMatrix) }
+                for value in &mut row { *value /= matrix[i][i] }
             }
+            rhs[i] = row;
+        }
+        Self::from_structured_rhs(&rhs, b)
+    }
 
-            if pivot_row != j {
-                let tmp = arr_u[pivot_row].clone();
-                arr_u[pivot_row] = arr_u[j].clone();
-                arr_u[j] = tmp;
+    fn solve_banded(&self, l_and_u: (usize, usize), b: &Self) -> Result<Self, ArrayError> {
+        self.is_dim_supported(&[2])?;
+        let (lower, upper) = l_and_u;
+        let shape = self.get_shape()?;
+        shape[0].is_equal(&(lower + upper + 1))?;
+        let size = shape[1];
+        let mut rhs = Self::structured_rhs(b, size)?;
+        let ab = self.to_matrix()?;
 
-                let tmp = arr_l[pivot_row].clone();
-                for (idx, item) in tmp.iter().enumerate().take(j) {
-                    arr_l[pivot_row][idx] = arr_l[j][idx];
-                    arr_l[j][idx] = *item;
-                }
+        // row `p` keeps columns `p - l ..= p + u + l`, leaving room for the fill-in caused by row interchanges
+        let width = 2 * lower + upper + 1;
+        let index = |row: usize, col: usize| row * width + col + lower - row;
+        let mut band = vec![Complex::<f64>::zero(); size * width];
+        for col in 0..size { for row in col.saturating_sub(upper)..size.min(col + lower + 1) {
+            band[index(row, col)] = ab[upper + row - col][col].to_complex();
+        } }
+
+        for k in 0..size {
+            let (last_row, last_col) = ((k + lower).min(size - 1), (k + upper + lower).min(size - 1));
+            let pivot = (k..=last_row).fold(k, |best, row| if band[index(row, k)].norm() > band[index(best, k)].norm() { row } else { best });
+            if band[index(pivot, k)].norm() == 0. { return Err(ArrayError::SingularMatrix) }
+            if pivot != k {
+                (k..=last_col).for_each(|col| band.swap(index(k, col), index(pivot, col)));
+                rhs.swap(k, pivot);
             }
-            for i in j + 1..n {
-                let factor = arr_u[i][j] / arr_u[j][j];
-                arr_l[i][j] = factor;
-                let uu = ((arr_u[j][j..].to_vec().to_array()? * factor)?).get_elements()?;
-                for jj in j..n {
-                    arr_u[i][jj] -= uu[jj - j];
+            for row in k + 1..=last_row {
+                let factor = band[index(row, k)] / band[index(k, k)];
+                if factor.norm() == 0. { continue }
+                for col in k..=last_col {
+                    let value = band[index(k, col)];
+                    band[index(row, col)] -= factor * value;
                 }
+                let (head, tail) = rhs.split_at_mut(row);
+                tail[0].iter_mut().zip(&head[k]).for_each(|(value, item)| *value -= factor * *item);
             }
         }
-
-        let other = other.to_array_f64()?;
-        let mut arr_y = Array::<f64>::zeros_like(&other)?.get_rows()?;
-        let arr_b = other.to_array_f64()?.get_rows()?;
-        for i in 0..n {
-            let l_tmp = arr_l[i][..i].to_vec().to_array()?;
-            let y_tmp = arr_y[..i].iter().flatten().copied().collect::<Vec<f64>>().to_array()?;
-            let dot = l_tmp.dot(&y_tmp).unwrap_or(Array::flat(vec![0.; arr_b[0].len()?])?);
-            arr_y[i] = arr_b[i].broadcast_to(dot.get_shape()?)? - dot;
+        for k in (0..size).rev() {
+            let last_col = (k + upper + lower).min(size - 1);
+            let (head, solved) = rhs.split_at_mut(k + 1);
+            for col in k + 1..=last_col {
+                head[k].iter_mut().zip(&solved[col - k - 1]).for_each(|(value, item)| *value -= band[index(k, col)] * *item);
+            }
+            head[k].iter_mut().for_each(|value| *value /= band[index(k, k)]);
         }
+        Self::from_structured_rhs(&rhs, b)
+    }
 
-        let mut arr_x = Array::<f64>::zeros_like(&other)?.get_rows()?;
-        for i in (0..n).rev() {
-            let u_tmp = arr_u[i][i + 1..].to_vec().to_array()?;
-            let x_tmp = arr_x[i + 1..].iter().flatten().copied().collect::<Vec<f64>>().to_array()?;
-            let dot = u_tmp.dot(&x_tmp).unwrap_or(Array::flat(vec![0.; arr_b[0].len()?])?);
-            arr_x[i] = ((arr_y[i].clone() - dot) / arr_u[i][i])?;
-        }
+    fn solve_tridiagonal(lower: &Self, diagonal: &Self, upper: &Self, b: &Self) -> Result<Self, ArrayError> {
+        diagonal.is_dim_supported(&[1])?;
+        lower.is_dim_supported(&[1])?;
+        upper.is_dim_supported(&[1])?;
+        let size = diagonal.len()?;
+        lower.len()?.is_equal(&(size - 1))?;
+        upper.len()?.is_equal(&(size - 1))?;
+        let mut rhs = Self::structured_rhs(b, size)?;
+        let to_complex = |arr: &Self| arr.get_elements().map(|elements| elements.iter().map(Numeric::to_complex).collect::<Vec<Complex<f64>>>());
+        let (lower, diagonal, upper) = (to_complex(lower)?, to_complex(diagonal)?, to_complex(upper)?);
 
-        arr_x.into_iter()
-            .flatten()
-            .collect::<Array<_>>()
-            .to_array_num()
-            .reshape(&other.get_shape()?)
+        let mut modified = vec![Complex::<f64>::zero(); size];
+        for i in 0..size {
+            let factor = if i == 0 { Complex::zero() } else { lower[i - 1] };
+            let pivot = diagonal[i] - factor * if i == 0 { Complex::zero() } else { modified[i - 1] };
+            if pivot.norm() == 0. { return Err(ArrayError::SingularMatrix) }
+            if i + 1 < size { modified[i] = upper[i] / pivot }
+            let (head, tail) = rhs.split_at_mut(i);
+            tail[0].iter_mut().enumerate().for_each(|(j, value)| {
+                if i > 0 { *value -= factor * head[i - 1][j] }
+                *value /= pivot;
+            });
+        }
+        for i in (0..size - 1).rev() {
+            let (head, tail) = rhs.split_at_mut(i + 1);
+            head[i].iter_mut().zip(&tail[0]).for_each(|(value, item)| *value -= modified[i] * *item);
+        }
+        Self::from_structured_rhs(&rhs, b)
     }
 }
 
 impl <N: NumericOps> ArrayLinalgSolvingInvertingProducts<N> for Result<Array<N>, ArrayError> {
 
+    fn lstsq(&self, b: &Array<N>, rcond: Option<f64>) -> LstsqResult<N> {
+        self.clone()?.lstsq(b, rcond)
+    }
+
+
     fn solve(&self, other: &Array<N>) -> Self {
         self.clone()?.solve(other)
     }
+
+    fn inv(&self) -> Self {
+        self.clone()?.inv()
+    }
+
+    fn pinv(&self, rcond: Option<f64>) -> Self {
+        self.clone()?.pinv(rcond)
+    }
+
+    fn matrix_power(&self, n: i32) -> Self {
+        self.clone()?.matrix_power(n)
+    }
+
+    fn matrix_rank(&self, tol: Option<f64>) -> Result<Array<usize>, ArrayError> {
+        self.clone()?.matrix_rank(tol)
+    }
+
+    fn solve_triangular(&self, b: &Array<N>, lower: Option<bool>, unit_diagonal: Option<bool>, trans: Option<impl TransposeModeType>) -> Self {
+        self.clone()?.solve_triangular(b, lower, unit_diagonal, trans)
+    }
+
+    fn solve_banded(&self, l_and_u: (usize, usize), b: &Array<N>) -> Self {
+        self.clone()?.solve_banded(l_and_u, b)
+    }
+
+    fn solve_tridiagonal(lower: &Array<N>, diagonal: &Array<N>, upper: &Array<N>, b: &Array<N>) -> Self {
+        Array::solve_tridiagonal(lower, diagonal, upper, b)
+    }
+}
+
+trait SolvingInvertingHelper {
+
+    fn lstsq_matrix(matrix: &[Vec<f64>], (u, values, v): (&[Vec<f64>], &[f64], &[Vec<f64>]), rhs: &[Vec<f64>]) -> (Vec<f64>, Vec<f64>) {
+        let rhs_cols = rhs[0].len();
+        let projected = values.iter().enumerate()
+            .map(|(k, value)| (0..rhs_cols)
+                .map(|j| u.iter().zip(rhs).fold(0., |acc: f64, (u_row, b_row)| u_row[k].mul_add(b_row[j], acc)) / value)
+                .collect())
+            .collect::<Vec<Vec<f64>>>();
+        let solution = v.iter()
+            .map(|v_row| (0..rhs_cols)
+                .map(|j| projected.iter().enumerate().fold(0., |acc: f64, (k, row)| v_row[k].mul_add(row[j], acc)))
+                .collect())
+            .collect::<Vec<Vec<f64>>>();
+        let residuals = (0..rhs_cols)
+            .map(|j| matrix.iter().zip(rhs)
+                .map(|(row, b_row)| row.iter().zip(&solution).fold(-b_row[j], |acc: f64, (a, x_row)| a.mul_add(x_row[j], acc)))
+                .map(|residual| residual * residual)
+                .sum::<f64>())
+            .collect();
+        (solution.into_iter().flatten().collect(), residuals)
+    }
+
+    fn lu_inverse<T: LinalgScalar>((lu, pivots): (Vec<Vec<T>>, Vec<usize>)) -> Result<Vec<Vec<T>>, ArrayError> {
+        let identity = (0..lu.len())
+            .map(|i| (0..lu.len()).map(|j| if i == j { T::one() } else { T::zero() }).collect())
+            .collect();
+        lu_substitute(&lu, &pivots, identity)
+    }
 }
+
+impl <N: NumericOps> SolvingInvertingHelper for Array<N> {}
+
+type ComplexMatrix = Vec<Vec<Complex<f64>>>;
+
+trait StructuredSolveHelper<N: NumericOps> {
+
+    fn structured_rhs(b: &Array<N>, size: usize) -> Result<ComplexMatrix, ArrayError> {
+        b.is_dim_supported(&[1, 2])?;
+        b.get_shape()?[0].is_equal(&size)?;
+        let rhs = b.get_elements()?
+            .chunks(b.len()? / size)
+            .map(|row| row.iter().map(Numeric::to_complex).collect())
+            .collect();
+        Ok(rhs)
+    }
+
+    fn from_structured_rhs(rhs: &ComplexMatrix, b: &Array<N>) -> Result<Array<N>, ArrayError> {
+        rhs.iter().flatten()
+            .map(|value| N::from_complex(*value))
+            .collect::<Array<N>>()
+            .reshape(&b.get_shape()?)
+    }
+}
+
+impl <N: NumericOps> StructuredSolveHelper<N> for Array<N> {}
@@ -0,0 +1,161 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    linalg::{
+        backend::linalg_backend,
+        operations::common::{complete_orthonormal, transpose},
+        prelude::*,
+    },
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// `ArrayTrait` - Array Linalg Subspaces functions
+pub trait ArrayLinalgSubspaces<N: NumericOps> where Self: Sized + Clone {
+
+    /// Construct an orthonormal basis for the null space of a matrix using svd
+    ///
+    /// # Arguments
+    ///
+    /// * `rcond` - relative condition number, singular values smaller than `rcond * max(s)` are considered zero. optional, defaults to `max(m, n) * eps`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![1., 1., 1., 1.], vec![2, 2]).unwrap();
+    /// let result = array.null_space(None).unwrap();
+    /// assert_eq!(vec![2, 1], result.get_shape().unwrap());
+    /// assert_eq!(Array::new(vec![0., 0.], vec![2, 1]), array.dot(&result).unwrap().map(|v: &f64| (v * 1e9).round() / 1e9 + 0.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn null_space(&self, rcond: Option<f64>) -> Result<Array<N>, ArrayError>;
+
+    /// Construct an orthonormal basis for the range (column space) of a matrix using svd
+    ///
+    /// # Arguments
+    ///
+    /// * `rcond` - relative condition number, singular values smaller than `rcond * max(s)` are considered zero. optional, defaults to `max(m, n) * eps`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![2., 0., 0., 0.], vec![2, 2]).unwrap();
+    /// assert_eq!(Array::new(vec![1., 0.], vec![2, 1]), array.orth(None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn orth(&self, rcond: Option<f64>) -> Result<Array<N>, ArrayError>;
+
+    /// Compute the principal angles between column spaces of two matrices, in radians and descending order
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - other matrix, with the same number of rows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let array = Array::new(vec![1., 0., 0.], vec![3, 1]).unwrap();
+    /// let other = Array::new(vec![1., 1., 0.], vec![3, 1]).unwrap();
+    /// let result = array.subspace_angles(&other).unwrap();
+    /// assert_eq!(Array::flat(vec![0.785398163]), result.map(|v: &f64| (v * 1e9).round() / 1e9));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn subspace_angles(&self, other: &Array<N>) -> Result<Array<f64>, ArrayError>;
+}
+
+impl <N: NumericOps> ArrayLinalgSubspaces<N> for Array<N> {
+
+    fn null_space(&self, rcond: Option<f64>) -> Result<Self, ArrayError> {
+        self.is_dim_supported(&[2])?;
+        let cols = self.get_shape()?[1];
+        let (_, values, v) = linalg_backend().svd(&self.to_matrix_f64()?)?;
+        let rank = Self::numerical_rank(&self.get_shape()?, &values, rcond);
+        let v = complete_orthonormal(&v, cols);
+        Self::from_columns(&v, rank..cols)
+    }
+
+    fn orth(&self, rcond: Option<f64>) -> Result<Self, ArrayError> {
+        self.is_dim_supported(&[2])?;
+        let (u, values, _) = linalg_backend().svd(&self.to_matrix_f64()?)?;
+        let rank = Self::numerical_rank(&self.get_shape()?, &values, rcond);
+        Self::from_columns(&u, 0..rank)
+    }
+
+    fn subspace_angles(&self, other: &Self) -> Result<Array<f64>, ArrayError> {
+        self.is_dim_supported(&[2])?;
+        other.is_dim_supported(&[2])?;
+        other.get_shape()?[0].is_equal(&self.get_shape()?[0])?;
+        let (basis_a, basis_b) = (self.orth(None)?, other.orth(None)?);
+        let (size_a, size_b) = (basis_a.get_shape()?[1], basis_b.get_shape()?[1]);
+        if size_a == 0 || size_b == 0 { return Array::new(vec![], vec![0]) }
+        let (basis_a, basis_b) = (basis_a.to_matrix_f64()?, basis_b.to_matrix_f64()?);
+
+        let product = |a: &[Vec<f64>], b: &[Vec<f64>]| a.iter()
+            .map(|row| (0..b[0].len()).map(|j| row.iter().zip(b).map(|(item, b_row)| item * b_row[j]).sum()).collect())
+            .collect::<Vec<Vec<f64>>>();
+        let cross = product(&transpose(&basis_a), &basis_b);
+        let cosines = linalg_backend().svd(&cross)?.1;
+        // residual of projecting the smaller basis onto the larger one has singular values equal to sines of the angles
+        let (larger, smaller, cross) = if size_a >= size_b { (basis_a, basis_b, cross) } else { (basis_b, basis_a, transpose(&cross)) };
+        let projected = product(&larger, &cross);
+        let residual = smaller.iter().zip(&projected)
+            .map(|(row, projected_row)| row.iter().zip(projected_row).map(|(item, value)| item - value).collect())
+            .collect::<Vec<Vec<f64>>>();
+        let sines = linalg_backend().svd(&residual)?.1;
+
+        // arccos is inaccurate for small angles, so arcsin of the residual is used for them
+        let angles = cosines.iter().rev().zip(&sines)
+            .map(|(&cosine, &sine)| if cosine * cosine >= 0.5 { sine.clamp(-1., 1.).asin() } else { cosine.clamp(-1., 1.).acos() })
+            .collect();
+        Array::flat(angles)
+    }
+}
+
+impl <N: NumericOps> ArrayLinalgSubspaces<N> for Result<Array<N>, ArrayError> {
+
+    fn null_space(&self, rcond: Option<f64>) -> Self {
+        self.clone()?.null_space(rcond)
+    }
+
+    fn orth(&self, rcond: Option<f64>) -> Self {
+        self.clone()?.orth(rcond)
+    }
+
+    fn subspace_angles(&self, other: &Array<N>) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.subspace_angles(other)
+    }
+}
+
+trait SubspacesHelper<N: NumericOps> {
+
+    fn numerical_rank(shape: &[usize], values: &[f64], rcond: Option<f64>) -> usize {
+        let rcond = rcond.unwrap_or_else(|| shape[0].max(shape[1]).to_f64() * f64::EPSILON);
+        let tol = values.first().copied().unwrap_or(0.) * rcond;
+        values.iter().filter(|&&value| value > tol).count()
+    }
+
+    fn from_columns(matrix: &[Vec<f64>], columns: std::ops::Range<usize>) -> Result<Array<N>, ArrayError> {
+        let width = columns.len();
+        let elements = matrix.iter()
+            .flat_map(|row| row[columns.clone()].iter().map(|&item| N::from(item)))
+            .collect();
+        Array::new(elements, vec![matrix.len(), width])
+    }
+}
+
+impl <N: NumericOps> SubspacesHelper<N> for Array<N> {}
@@ -1,15 +1,40 @@
 #[cfg(feature = "linalg")]
 pub use crate::linalg::{
+    backend::{linalg_backend, set_linalg_backend, DefaultBackend, LinalgBackend},
     operations::{
         decompositions::ArrayLinalgDecompositions,
         eigen::ArrayLinalgEigen,
+        einsum::ArrayLinalgEinsum,
+        iterative::ArrayLinalgIterative,
+        matrix_functions::ArrayLinalgMatrixFunctions,
         norms::ArrayLinalgNorms,
         products::ArrayLinalgProducts,
         solving_inverting::ArrayLinalgSolvingInvertingProducts,
+        subspaces::ArrayLinalgSubspaces,
     },
     types::{
+        decompositions::qr_mode::{QrMode, QrModeType},
+        iterative::{
+            iterative_info::IterativeInfo,
+            linear_operator::LinearOperator,
+            preconditioner::{Preconditioner, PreconditionerType},
+        },
         norms::norm_ord::{NormOrd, NormOrdType},
+        products::tensor_axes::{TensorAxes, TensorAxesType},
+        solving::transpose_mode::{TransposeMode, TransposeModeType},
+        EighKernelResult,
+        EighResult,
+        IterativeResult,
+        LdlResult,
         LinalgResult,
+        LstsqResult,
+        LuFactorResult,
+        LuResult,
+        QrResult,
+        RealSchurKernelResult,
+        SchurKernelResult,
+        SvdKernelResult,
+        SvdResult,
     },
 };
 
@@ -0,0 +1,2 @@
+/// qr mode type definition
+pub mod qr_mode;
@@ -0,0 +1,57 @@
+use crate::errors::prelude::*;
+
+/// the form of qr decomposition result
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum QrMode {
+    /// `q` of shape `[M, K]` and `r` of shape `[K, N]`, where `K = min(M, N)`
+    #[default]
+    Reduced,
+    /// `q` of shape `[M, M]` and `r` of shape `[M, N]`
+    Complete,
+    /// only `r` of shape `[K, N]`
+    R,
+    /// householder reflectors stored below the diagonal of `r`, of shape `[M, N]`, and their scaling factors `tau`
+    Raw,
+}
+
+/// `QrMode` trait
+pub trait QrModeType: Clone {
+
+    /// Parse input to `QrMode` type
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_qr_mode(self) -> Result<QrMode, ArrayError>;
+}
+
+impl QrModeType for QrMode {
+
+    fn to_qr_mode(self) -> Result<QrMode, ArrayError> {
+        Ok(self)
+    }
+}
+
+impl QrModeType for &str {
+
+    fn to_qr_mode(self) -> Result<QrMode, ArrayError> {
+        parse_qr_mode(self)
+    }
+}
+
+impl QrModeType for String {
+
+    fn to_qr_mode(self) -> Result<QrMode, ArrayError> {
+        parse_qr_mode(&self)
+    }
+}
+
+fn parse_qr_mode(value: &str) -> Result<QrMode, ArrayError> {
+    match value.to_lowercase().as_str() {
+        "reduced" => Ok(QrMode::Reduced),
+        "complete" => Ok(QrMode::Complete),
+        "r" => Ok(QrMode::R),
+        "raw" => Ok(QrMode::Raw),
+        _ => Err(ArrayError::ParameterError { param: "`mode`", message: "must be one of {`reduced`, `complete`, `r`, `raw`}" }),
+    }
+}
@@ -0,0 +1,10 @@
+/// Convergence report of an iterative solver
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IterativeInfo {
+    /// whether the residual norm reached the requested tolerance
+    pub converged: bool,
+    /// number of performed iterations
+    pub iterations: usize,
+    /// norm of the final residual `b - Ax`
+    pub residual: f64,
+}
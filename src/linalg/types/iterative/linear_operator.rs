@@ -0,0 +1,32 @@
+use crate::errors::prelude::*;
+
+/// Linear operator, representing a square matrix by its action on vectors.
+/// implemented for dense arrays and sparse matrices, may be implemented for matrix-free operators
+pub trait LinearOperator {
+
+    /// Shape of the operator, as number of rows and columns
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn operator_shape(&self) -> Result<(usize, usize), ArrayError>;
+
+    /// Compute matrix-vector product `Ax`
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - vector to multiply
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn matvec(&self, x: &[f64]) -> Result<Vec<f64>, ArrayError>;
+
+    /// Non-zero entries of each row, as pairs of column index and value sorted by column.
+    /// required by `Jacobi` and `Ilu0` preconditioners
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn operator_rows(&self) -> Result<Vec<Vec<(usize, f64)>>, ArrayError>;
+}
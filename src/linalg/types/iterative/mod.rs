@@ -0,0 +1,8 @@
+/// iterative solver convergence info type definition
+pub mod iterative_info;
+
+/// linear operator type definition
+pub mod linear_operator;
+
+/// preconditioner type definition
+pub mod preconditioner;
@@ -0,0 +1,54 @@
+use crate::errors::prelude::*;
+
+/// the preconditioner of iterative solvers
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Preconditioner {
+    /// no preconditioning
+    #[default]
+    None,
+    /// diagonal (Jacobi) preconditioning
+    Jacobi,
+    /// incomplete LU factorization with no fill-in
+    Ilu0,
+}
+
+/// `Preconditioner` trait
+pub trait PreconditionerType: Clone {
+
+    /// Parse input to `Preconditioner` type
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_preconditioner(self) -> Result<Preconditioner, ArrayError>;
+}
+
+impl PreconditionerType for Preconditioner {
+
+    fn to_preconditioner(self) -> Result<Preconditioner, ArrayError> {
+        Ok(self)
+    }
+}
+
+impl PreconditionerType for &str {
+
+    fn to_preconditioner(self) -> Result<Preconditioner, ArrayError> {
+        parse_preconditioner(self)
+    }
+}
+
+impl PreconditionerType for String {
+
+    fn to_preconditioner(self) -> Result<Preconditioner, ArrayError> {
+        parse_preconditioner(&self)
+    }
+}
+
+fn parse_preconditioner(value: &str) -> Result<Preconditioner, ArrayError> {
+    match value.to_lowercase().as_str() {
+        "none" => Ok(Preconditioner::None),
+        "jacobi" => Ok(Preconditioner::Jacobi),
+        "ilu0" => Ok(Preconditioner::Ilu0),
+        _ => Err(ArrayError::ParameterError { param: "`preconditioner`", message: "must be one of {`none`, `jacobi`, `ilu0`}" }),
+    }
+}
@@ -1,7 +1,55 @@
-use crate::prelude::{Array, ArrayError};
+use crate::prelude::{Array, ArrayError, Complex, IterativeInfo};
+
+/// Decompositions parameters types definition
+pub mod decompositions;
+
+/// Iterative solvers types definition
+pub mod iterative;
 
 /// Norms parameters types definition
 pub mod norms;
 
+/// Products parameters types definition
+pub mod products;
+
+/// Solving parameters types definition
+pub mod solving;
+
 /// qr decomposition result type definition
-pub type LinalgResult<N> = Result<Vec<(Array<N>, Array<N>)>, ArrayError>;
+pub type QrResult<N> = Result<(Option<Array<N>>, Array<N>, Option<Array<usize>>), ArrayError>;
+
+/// pairs of stacked matrices result type definition
+pub type LinalgResult<N> = Result<(Array<N>, Array<N>), ArrayError>;
+
+/// lu decomposition result type definition
+pub type LuResult<N> = Result<(Array<N>, Array<N>, Array<N>), ArrayError>;
+
+/// lu factorization result type definition
+pub type LuFactorResult<N> = Result<(Array<N>, Array<usize>), ArrayError>;
+
+/// ldl decomposition result type definition
+pub type LdlResult<N> = Result<(Array<N>, Array<N>, Array<usize>), ArrayError>;
+
+/// svd decomposition result type definition
+pub type SvdResult<N> = Result<(Option<Array<N>>, Array<N>, Option<Array<N>>), ArrayError>;
+
+/// hermitian eigen decomposition result type definition
+pub type EighResult<N> = Result<(Array<f64>, Array<N>), ArrayError>;
+
+/// least-squares solution result type definition
+pub type LstsqResult<N> = Result<(Array<N>, Array<N>, Array<usize>, Array<N>), ArrayError>;
+
+/// iterative solver result type definition
+pub type IterativeResult<N> = Result<(Array<N>, IterativeInfo), ArrayError>;
+
+/// complex schur decomposition backend kernel result type definition
+pub type SchurKernelResult = Result<(Vec<Vec<Complex<f64>>>, Vec<Vec<Complex<f64>>>), ArrayError>;
+
+/// real schur decomposition backend kernel result type definition
+pub type RealSchurKernelResult = Result<(Vec<Vec<f64>>, Vec<Vec<f64>>), ArrayError>;
+
+/// singular value decomposition backend kernel result type definition
+pub type SvdKernelResult = Result<(Vec<Vec<f64>>, Vec<f64>, Vec<Vec<f64>>), ArrayError>;
+
+/// hermitian eigen decomposition backend kernel result type definition
+pub type EighKernelResult = Result<(Vec<f64>, Vec<Vec<Complex<f64>>>), ArrayError>;
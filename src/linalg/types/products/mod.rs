@@ -0,0 +1,2 @@
+/// tensor axes type definition
+pub mod tensor_axes;
@@ -0,0 +1,43 @@
+use crate::errors::prelude::*;
+
+/// the axes to sum over in tensordot operation
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TensorAxes {
+    /// last `n` axes of the first array with first `n` axes of the second one
+    Count(usize),
+    /// explicit list of axes of the first and the second array
+    Pairs(Vec<isize>, Vec<isize>),
+}
+
+/// `TensorAxes` trait
+pub trait TensorAxesType: Clone {
+
+    /// Parse input to `TensorAxes` type
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_axes(self) -> Result<TensorAxes, ArrayError>;
+}
+
+impl TensorAxesType for TensorAxes {
+
+    fn to_axes(self) -> Result<TensorAxes, ArrayError> {
+        Ok(self)
+    }
+}
+
+impl TensorAxesType for usize {
+
+    fn to_axes(self) -> Result<TensorAxes, ArrayError> {
+        Ok(TensorAxes::Count(self))
+    }
+}
+
+impl TensorAxesType for (Vec<isize>, Vec<isize>) {
+
+    fn to_axes(self) -> Result<TensorAxes, ArrayError> {
+        if self.0.len() == self.1.len() { Ok(TensorAxes::Pairs(self.0, self.1)) }
+        else { Err(ArrayError::ParameterError { param: "`axes`", message: "must have the same length for both arrays" }) }
+    }
+}
@@ -0,0 +1,2 @@
+/// transpose mode type definition
+pub mod transpose_mode;
@@ -0,0 +1,54 @@
+use crate::errors::prelude::*;
+
+/// the form of the system to solve with triangular matrix
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum TransposeMode {
+    /// solve `a x = b`
+    #[default]
+    NoTranspose,
+    /// solve `a^T x = b`
+    Transpose,
+    /// solve `a^H x = b`
+    ConjugateTranspose,
+}
+
+/// `TransposeMode` trait
+pub trait TransposeModeType: Clone {
+
+    /// Parse input to `TransposeMode` type
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_transpose_mode(self) -> Result<TransposeMode, ArrayError>;
+}
+
+impl TransposeModeType for TransposeMode {
+
+    fn to_transpose_mode(self) -> Result<TransposeMode, ArrayError> {
+        Ok(self)
+    }
+}
+
+impl TransposeModeType for &str {
+
+    fn to_transpose_mode(self) -> Result<TransposeMode, ArrayError> {
+        parse_transpose_mode(self)
+    }
+}
+
+impl TransposeModeType for String {
+
+    fn to_transpose_mode(self) -> Result<TransposeMode, ArrayError> {
+        parse_transpose_mode(&self)
+    }
+}
+
+fn parse_transpose_mode(value: &str) -> Result<TransposeMode, ArrayError> {
+    match value.to_lowercase().as_str() {
+        "n" => Ok(TransposeMode::NoTranspose),
+        "t" => Ok(TransposeMode::Transpose),
+        "c" => Ok(TransposeMode::ConjugateTranspose),
+        _ => Err(ArrayError::ParameterError { param: "`trans`", message: "must be one of {`n`, `t`, `c`}" }),
+    }
+}
@@ -317,13 +317,13 @@ impl <N: Numeric> ArrayArithmetic<N> for Array<N> {
     fn add(&self, value: &Self) -> Result<Self, ArrayError> {
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64() + tuple.1.to_f64()))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| a + b, |a, b| a + b))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
 
     fn reciprocal(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().recip()))
+        self.map(|i| i.apply_unary(f64::recip, |z| Complex::one() / z))
     }
 
     fn positive(&self) -> Result<Self, ArrayError> {
@@ -331,13 +331,13 @@ impl <N: Numeric> ArrayArithmetic<N> for Array<N> {
     }
 
     fn negative(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(-i.to_f64()))
+        self.map(|i| i.apply_unary(|x| -x, |z| -z))
     }
 
     fn multiply(&self, value: &Self) -> Result<Self, ArrayError> {
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64() * tuple.1.to_f64()))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| a * b, |a, b| a * b))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -348,7 +348,7 @@ impl <N: Numeric> ArrayArithmetic<N> for Array<N> {
         }
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64() / tuple.1.to_f64()))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| a / b, |a, b| a / b))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -364,7 +364,7 @@ impl <N: Numeric> ArrayArithmetic<N> for Array<N> {
     fn power(&self, value: &Self) -> Result<Self, ArrayError> {
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64().powi(tuple.1.to_i32())))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| a.powi(b.to_i32()), |a, b| a.powc(b)))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -372,7 +372,7 @@ impl <N: Numeric> ArrayArithmetic<N> for Array<N> {
     fn float_power(&self, value: &Self) -> Result<Self, ArrayError> {
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64().powf(tuple.1.to_f64())))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, f64::powf, |a, b| a.powc(b)))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -380,7 +380,7 @@ impl <N: Numeric> ArrayArithmetic<N> for Array<N> {
     fn subtract(&self, value: &Self) -> Result<Self, ArrayError> {
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64() - tuple.1.to_f64()))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| a - b, |a, b| a - b))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -395,7 +395,7 @@ impl <N: Numeric> ArrayArithmetic<N> for Array<N> {
         }
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from((tuple.0.to_f64() / tuple.1.to_f64()).floor().mul_add(-tuple.1.to_f64(), tuple.0.to_f64())))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| (a / b).floor().mul_add(-b, a), |a, b| a % b))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -412,7 +412,7 @@ impl <N: Numeric> ArrayArithmetic<N> for Array<N> {
         }
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64() % tuple.1.to_f64()))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| a % b, |a, b| a % b))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -0,0 +1,312 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    extensions::prelude::*,
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// `ArrayTrait` - Array Calculus functions
+pub trait ArrayCalculus<N: NumericOps> where Self: Sized + Clone {
+
+    /// Integrate along the given axis using the composite trapezoidal rule
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - sample points, 1-D with length of `axis` or of the same shape as array. optional
+    /// * `dx` - spacing between sample points, used when `x` is not specified. optional, defaults to 1
+    /// * `axis` - the axis along which to integrate. optional, defaults to last axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(f64, 1., 2., 3.);
+    /// assert_eq!(array_flat!(f64, 4.), arr.trapezoid(None, None, None));
+    /// assert_eq!(array_flat!(f64, 8.), arr.trapezoid(Some(array_flat!(f64, 4., 6., 8.).unwrap()), None, None));
+    ///
+    /// let arr = array!(f64, [[0., 1., 2.], [3., 4., 5.]]);
+    /// assert_eq!(array_flat!(f64, 1.5, 2.5, 3.5), arr.trapezoid(None, None, Some(0)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn trapezoid(&self, x: Option<Array<N>>, dx: Option<N>, axis: Option<isize>) -> Result<Array<N>, ArrayError>;
+
+    /// Integrate along the given axis using the composite simpson's rule.
+    /// for even number of samples, the last interval is integrated with a parabola through the last three samples
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - sample points, 1-D with length of `axis` or of the same shape as array. optional
+    /// * `dx` - spacing between sample points, used when `x` is not specified. optional, defaults to 1
+    /// * `axis` - the axis along which to integrate. optional, defaults to last axis
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(f64, 0., 1., 4., 9., 16.);
+    /// assert_eq!(array_flat!(f64, 64. / 3.), arr.simpson(None, None, None));
+    /// assert_eq!(array_flat!(f64, 9.), array_flat!(f64, 0., 1., 4., 9.).simpson(None, None, None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn simpson(&self, x: Option<Array<N>>, dx: Option<N>, axis: Option<isize>) -> Result<Array<N>, ArrayError>;
+
+    /// Cumulatively integrate along the given axis using the composite trapezoidal rule
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - sample points, 1-D with length of `axis` or of the same shape as array. optional
+    /// * `dx` - spacing between sample points, used when `x` is not specified. optional, defaults to 1
+    /// * `axis` - the axis along which to integrate. optional, defaults to last axis
+    /// * `initial` - value inserted at the beginning of the result. optional, result is shorter by one if not specified
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(f64, 1., 2., 3.);
+    /// assert_eq!(array_flat!(f64, 1.5, 4.), arr.cumulative_trapezoid(None, None, None, None));
+    /// assert_eq!(array_flat!(f64, 0., 3., 8.), arr.cumulative_trapezoid(None, Some(2.), None, Some(0.)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn cumulative_trapezoid(&self, x: Option<Array<N>>, dx: Option<N>, axis: Option<isize>, initial: Option<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Return the gradient of an array, using second order accurate central differences in the interior
+    /// and either first or second order accurate one-sided differences at the boundaries
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - spacing between samples, single value or coordinates for each axis, one element applies to all axes. optional, defaults to 1
+    /// * `axis` - the axes along which to compute the gradient. optional, defaults to all axes
+    /// * `edge_order` - accuracy of differences at the boundaries, 1 or 2. optional, defaults to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(f64, 1., 2., 4., 7., 11., 16.);
+    /// assert_eq!(vec![array_flat!(f64, 1., 1.5, 2.5, 3.5, 4.5, 5.).unwrap()], arr.gradient(None, None, None).unwrap());
+    /// assert_eq!(vec![array_flat!(f64, 0.5, 1.5, 2.5, 3.5, 4.5, 5.5).unwrap()], arr.gradient(None, None, Some(2)).unwrap());
+    ///
+    /// let arr = array!(f64, [[1., 2., 6.], [3., 4., 5.]]);
+    /// let spacing = vec![Array::single(2.).unwrap(), array_flat!(f64, 0., 1., 3.).unwrap()];
+    /// assert_eq!(vec![
+    ///     array!(f64, [[1., 1., -0.5], [1., 1., -0.5]]).unwrap(),
+    ///     array!(f64, [[1., 4. / 3., 2.], [1., 5. / 6., 0.5]]).unwrap(),
+    /// ], arr.gradient(Some(spacing), None, None).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn gradient(&self, spacing: Option<Vec<Array<N>>>, axis: Option<Vec<isize>>, edge_order: Option<usize>) -> Result<Vec<Array<N>>, ArrayError>;
+}
+
+impl <N: NumericOps> ArrayCalculus<N> for Array<N> {
+
+    fn trapezoid(&self, x: Option<Self>, dx: Option<N>, axis: Option<isize>) -> Result<Self, ArrayError> {
+        self.integrate_lanes(x, dx, axis, |x, y| vec![Self::trapezoid_lane(x, y)])
+    }
+
+    fn simpson(&self, x: Option<Self>, dx: Option<N>, axis: Option<isize>) -> Result<Self, ArrayError> {
+        self.integrate_lanes(x, dx, axis, |x, y| vec![Self::simpson_lane(x, y)])
+    }
+
+    fn cumulative_trapezoid(&self, x: Option<Self>, dx: Option<N>, axis: Option<isize>, initial: Option<N>) -> Result<Self, ArrayError> {
+        let axis = self.normalize_axis(axis.unwrap_or(-1));
+        self.axis_in_bounds(axis)?;
+        let (lanes, points) = (self.lanes(axis)?, self.sample_points(x, dx, axis)?);
+        let lanes = lanes.iter().zip(&points)
+            .map(|(y, x)| initial.map(|v| v.to_f64()).into_iter()
+                .chain((1..y.len()).scan(0., |acc, i| {
+                    *acc += 0.5 * (x[i] - x[i - 1]) * (y[i] + y[i - 1]);
+                    Some(*acc)
+                }))
+                .collect())
+            .collect();
+        let len = self.get_shape()?[axis].saturating_sub(1) + <usize as From<bool>>::from(initial.is_some());
+        Self::from_lanes(lanes, self.get_shape()?.update_at(axis, len), axis)
+    }
+
+    fn gradient(&self, spacing: Option<Vec<Self>>, axis: Option<Vec<isize>>, edge_order: Option<usize>) -> Result<Vec<Self>, ArrayError> {
+        let edge_order = edge_order.unwrap_or(1);
+        if !(1..=2).contains(&edge_order) {
+            return Err(ArrayError::ParameterError { param: "`edge_order`", message: "must be 1 or 2" })
+        }
+        let axes = match axis {
+            Some(axis) => axis.into_iter().map(|axis| self.normalize_axis(axis)).collect(),
+            None => (0..self.ndim()?).collect::<Vec<usize>>(),
+        };
+        axes.iter().try_for_each(|&axis| self.axis_in_bounds(axis))?;
+        let spacing = match spacing {
+            Some(spacing) => spacing,
+            None => vec![Self::single(N::one())?],
+        };
+        if spacing.len() != 1 && spacing.len() != axes.len() {
+            return Err(ArrayError::ParameterError { param: "`spacing`", message: "must have one element or as many elements as axes" })
+        }
+
+        axes.iter().enumerate()
+            .map(|(idx, &axis)| {
+                if self.get_shape()?[axis] <= edge_order {
+                    return Err(ArrayError::ParameterError { param: "`array`", message: "at least `edge_order + 1` elements are required along each axis" })
+                }
+                let spacing = &spacing[if spacing.len() == 1 { 0 } else { idx }];
+                let points = if spacing.len()? == 1 {
+                    self.sample_points(None, Some(spacing[0]), axis)?
+                } else {
+                    self.sample_points(Some(spacing.clone()), None, axis)?
+                };
+                let lanes = self.lanes(axis)?.iter().zip(&points)
+                    .map(|(y, x)| Self::gradient_lane(x, y, edge_order))
+                    .collect();
+                Self::from_lanes(lanes, self.get_shape()?, axis)
+            })
+            .collect()
+    }
+}
+
+impl <N: NumericOps> ArrayCalculus<N> for Result<Array<N>, ArrayError> {
+
+    fn trapezoid(&self, x: Option<Array<N>>, dx: Option<N>, axis: Option<isize>) -> Self {
+        self.clone()?.trapezoid(x, dx, axis)
+    }
+
+    fn simpson(&self, x: Option<Array<N>>, dx: Option<N>, axis: Option<isize>) -> Self {
+        self.clone()?.simpson(x, dx, axis)
+    }
+
+    fn cumulative_trapezoid(&self, x: Option<Array<N>>, dx: Option<N>, axis: Option<isize>, initial: Option<N>) -> Self {
+        self.clone()?.cumulative_trapezoid(x, dx, axis, initial)
+    }
+
+    fn gradient(&self, spacing: Option<Vec<Array<N>>>, axis: Option<Vec<isize>>, edge_order: Option<usize>) -> Result<Vec<Array<N>>, ArrayError> {
+        self.clone()?.gradient(spacing, axis, edge_order)
+    }
+}
+
+trait CalculusHelper<N: NumericOps> {
+
+    fn lanes(&self, axis: usize) -> Result<Vec<Vec<f64>>, ArrayError>;
+
+    fn sample_points(&self, x: Option<Array<N>>, dx: Option<N>, axis: usize) -> Result<Vec<Vec<f64>>, ArrayError>;
+
+    fn from_lanes(lanes: Vec<Vec<f64>>, shape: Vec<usize>, axis: usize) -> Result<Array<N>, ArrayError>;
+
+    fn integrate_lanes<F: Fn(&[f64], &[f64]) -> Vec<f64>>(&self, x: Option<Array<N>>, dx: Option<N>, axis: Option<isize>, f: F) -> Result<Array<N>, ArrayError>;
+
+    fn trapezoid_lane(x: &[f64], y: &[f64]) -> f64;
+
+    fn simpson_lane(x: &[f64], y: &[f64]) -> f64;
+
+    fn gradient_lane(x: &[f64], y: &[f64], edge_order: usize) -> Vec<f64>;
+}
+
+impl <N: NumericOps> CalculusHelper<N> for Array<N> {
+
+    fn lanes(&self, axis: usize) -> Result<Vec<Vec<f64>>, ArrayError> {
+        let shape = self.get_shape()?;
+        let count = shape.iter().enumerate().filter(|(i, _)| *i != axis).map(|(_, &s)| s).product::<usize>();
+        if shape[axis] == 0 { return Ok(vec![vec![]; count]) }
+        let last = (self.ndim()? - 1).to_isize();
+        Ok(self.moveaxis(vec![axis.to_isize()], vec![last])?
+            .get_elements()?
+            .chunks(shape[axis])
+            .map(|lane| lane.iter().map(Numeric::to_f64).collect())
+            .collect())
+    }
+
+    fn sample_points(&self, x: Option<Self>, dx: Option<N>, axis: usize) -> Result<Vec<Vec<f64>>, ArrayError> {
+        let shape = self.get_shape()?;
+        let count = shape.iter().enumerate().filter(|(i, _)| *i != axis).map(|(_, &s)| s).product::<usize>();
+        match x {
+            Some(x) if x.ndim()? == 1 => {
+                x.len()?.is_equal(&shape[axis])?;
+                Ok(vec![x.get_elements()?.iter().map(Numeric::to_f64).collect(); count])
+            },
+            Some(x) => {
+                x.get_shape()?.is_equal(&shape)?;
+                x.lanes(axis)
+            },
+            None => {
+                let dx = dx.map_or(1., |dx| dx.to_f64());
+                Ok(vec![(0..shape[axis]).map(|i| i.to_f64() * dx).collect(); count])
+            },
+        }
+    }
+
+    fn from_lanes(lanes: Vec<Vec<f64>>, shape: Vec<usize>, axis: usize) -> Result<Self, ArrayError> {
+        let last = shape.len() - 1;
+        let mut moved = shape;
+        let len = moved.remove(axis);
+        moved.push(len);
+        Self::new(lanes.into_iter().flatten().map(N::from_f64).collect(), moved)
+            .moveaxis(vec![last.to_isize()], vec![axis.to_isize()])
+    }
+
+    fn integrate_lanes<F: Fn(&[f64], &[f64]) -> Vec<f64>>(&self, x: Option<Self>, dx: Option<N>, axis: Option<isize>, f: F) -> Result<Self, ArrayError> {
+        let axis = self.normalize_axis(axis.unwrap_or(-1));
+        self.axis_in_bounds(axis)?;
+        let (lanes, points) = (self.lanes(axis)?, self.sample_points(x, dx, axis)?);
+        let lanes = lanes.iter().zip(&points).map(|(y, x)| f(x, y)).collect();
+        let result = Self::from_lanes(lanes, self.get_shape()?.update_at(axis, 1), axis)?;
+        result.reshape(&result.get_shape()?.remove_at_if(axis, result.ndim()? > 1))
+    }
+
+    fn trapezoid_lane(x: &[f64], y: &[f64]) -> f64 {
+        (1..y.len()).map(|i| 0.5 * (x[i] - x[i - 1]) * (y[i] + y[i - 1])).sum()
+    }
+
+    fn simpson_lane(x: &[f64], y: &[f64]) -> f64 {
+        let len = y.len();
+        if len < 3 { return Self::trapezoid_lane(x, y) }
+
+        let odd_len = if len % 2 == 1 { len } else { len - 1 };
+        let result = (0..odd_len - 2).step_by(2)
+            .map(|i| {
+                let (width, ratio) = (x[i + 2] - x[i], (x[i + 2] - x[i + 1]) / (x[i + 1] - x[i]));
+                width / 6. * (2. - ratio).mul_add(y[i], (2. + ratio + 1. / ratio).mul_add(y[i + 1], (2. - 1. / ratio) * y[i + 2]))
+            })
+            .sum::<f64>();
+        if len % 2 == 1 { return result }
+
+        // the last interval of even number of samples is integrated with a parabola through the last three samples
+        let (h_0, h_1) = (x[len - 2] - x[len - 3], x[len - 1] - x[len - 2]);
+        let alpha = h_1.mul_add(2. * h_1, 3. * h_0 * h_1) / (6. * (h_0 + h_1));
+        let beta = h_1.mul_add(h_1, 3. * h_0 * h_1) / (6. * h_0);
+        let eta = h_1 * h_1 * h_1 / (6. * h_0 * (h_0 + h_1));
+        result + alpha.mul_add(y[len - 1], beta.mul_add(y[len - 2], -eta * y[len - 3]))
+    }
+
+    fn gradient_lane(x: &[f64], y: &[f64], edge_order: usize) -> Vec<f64> {
+        let len = y.len();
+        let interior = (1..len - 1).map(|i| {
+            let (h_s, h_d) = (x[i] - x[i - 1], x[i + 1] - x[i]);
+            (h_s * h_s).mul_add(y[i + 1], h_d.mul_add(h_d, -h_s * h_s).mul_add(y[i], -h_d * h_d * y[i - 1])) / (h_s * h_d * (h_s + h_d))
+        });
+        let (first, last) = if edge_order == 1 {
+            ((y[1] - y[0]) / (x[1] - x[0]), (y[len - 1] - y[len - 2]) / (x[len - 1] - x[len - 2]))
+        } else {
+            let (h_1, h_2) = (x[1] - x[0], x[2] - x[1]);
+            let (w_0, w_1, w_2) = (-h_1.mul_add(2., h_2) / (h_1 * (h_1 + h_2)), (h_1 + h_2) / (h_1 * h_2), -h_1 / (h_2 * (h_1 + h_2)));
+            let first = w_0.mul_add(y[0], w_1.mul_add(y[1], w_2 * y[2]));
+            let (h_1, h_2) = (x[len - 2] - x[len - 3], x[len - 1] - x[len - 2]);
+            let (w_0, w_1, w_2) = (h_2 / (h_1 * (h_1 + h_2)), -(h_1 + h_2) / (h_1 * h_2), h_2.mul_add(2., h_1) / (h_2 * (h_1 + h_2)));
+            (first, w_0.mul_add(y[len - 3], w_1.mul_add(y[len - 2], w_2 * y[len - 1])))
+        };
+        std::iter::once(first).chain(interior).chain(std::iter::once(last)).collect()
+    }
+}
@@ -185,15 +185,15 @@ pub trait ArrayExpLog<N: Numeric> where Self: Sized + Clone {
 impl <N: Numeric> ArrayExpLog<N> for Array<N> {
 
     fn exp(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().exp()))
+        self.map(|i| i.apply_unary(f64::exp, |z| z.exp()))
     }
 
     fn exp2(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().exp2()))
+        self.map(|i| i.apply_unary(f64::exp2, |z| Complex::new(2., 0.).powc(z)))
     }
 
     fn exp_m1(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().exp_m1()))
+        self.map(|i| i.apply_unary(f64::exp_m1, |z| z.exp() - Complex::one()))
     }
 
     fn log(&self) -> Result<Self, ArrayError> {
@@ -201,21 +201,21 @@ impl <N: Numeric> ArrayExpLog<N> for Array<N> {
     }
 
     fn log2(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().log2()))
+        self.map(|i| i.apply_unary(f64::log2, |z| z.log2()))
     }
 
     fn log10(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().log10()))
+        self.map(|i| i.apply_unary(f64::log10, |z| z.log10()))
     }
 
     fn log_1p(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().ln_1p()))
+        self.map(|i| i.apply_unary(f64::ln_1p, |z| (Complex::one() + z).ln()))
     }
 
     fn logn(&self, value: &Self) -> Result<Self, ArrayError> {
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64().log(tuple.1.to_f64())))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, f64::log, |a, b| a.log(b)))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -223,7 +223,7 @@ impl <N: Numeric> ArrayExpLog<N> for Array<N> {
     fn log_add_exp(&self, value: &Self) -> Result<Self, ArrayError> {
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from((tuple.0.to_f64().exp() + tuple.1.to_f64().exp()).ln()))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| (a.exp() + b.exp()).ln(), |a, b| (a.exp() + b.exp()).ln()))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
@@ -231,7 +231,7 @@ impl <N: Numeric> ArrayExpLog<N> for Array<N> {
     fn log_add_exp2(&self, value: &Self) -> Result<Self, ArrayError> {
         let broadcasted = self.broadcast(value)?;
         let elements = broadcasted.clone().into_iter()
-            .map(|tuple| N::from(tuple.0.to_f64().mul_add(tuple.0.to_f64(), tuple.1.to_f64().powi(2)).log2()))
+            .map(|tuple| tuple.0.apply_binary(&tuple.1, |a, b| a.mul_add(a, b.powi(2)).log2(), |a, b| (a * a + b * b).log2()))
             .collect();
         Self::new(elements, broadcasted.get_shape()?)
     }
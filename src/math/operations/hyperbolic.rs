@@ -107,27 +107,27 @@ pub trait ArrayHyperbolic<N: Numeric> where Self: Sized + Clone {
 impl <N: Numeric> ArrayHyperbolic<N> for Array<N> {
     
     fn sinh(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().sinh()))
+        self.map(|i| i.apply_unary(f64::sinh, |z| z.sinh()))
     }
 
     fn cosh(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().cosh()))
+        self.map(|i| i.apply_unary(f64::cosh, |z| z.cosh()))
     }
 
     fn tanh(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().tanh()))
+        self.map(|i| i.apply_unary(f64::tanh, |z| z.tanh()))
     }
 
     fn asinh(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().asinh()))
+        self.map(|i| i.apply_unary(f64::asinh, |z| z.asinh()))
     }
 
     fn acosh(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().acosh()))
+        self.map(|i| i.apply_unary(f64::acosh, |z| z.acosh()))
     }
 
     fn atanh(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().atanh()))
+        self.map(|i| i.apply_unary(f64::atanh, |z| z.atanh()))
     }
 }
 
@@ -32,6 +32,31 @@ pub trait ArrayMathMisc<N: Numeric> where Self: Sized + Clone {
     /// may returns `ArrayError`
     fn convolve(&self, other: &Array<N>, mode: Option<impl ConvolveModeType>) -> Result<Array<N>, ArrayError>;
 
+    /// Returns the cross-correlation of two one-dimensional sequences
+    /// arrays are flattened for computation
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - array to perform the operation with
+    /// * `mode` - {`full`, `valid`, `same`}, optional. defaults to `valid`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![1., 2., 3.]);
+    /// let other = Array::flat(vec![0., 1., 0.5]);
+    /// assert_eq!(Array::flat(vec![3.5]), arr.correlate(&other.clone().unwrap(), None::<ConvolveMode>));
+    /// assert_eq!(Array::flat(vec![2., 3.5, 3.]), arr.correlate(&other.clone().unwrap(), Some("same")));
+    /// assert_eq!(Array::flat(vec![0.5, 2., 3.5, 3., 0.]), arr.correlate(&other.unwrap(), Some("full")));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn correlate(&self, other: &Array<N>, mode: Option<impl ConvolveModeType>) -> Result<Array<N>, ArrayError>;
+
     /// Clip (limit) the values in an array
     ///
     /// # Arguments
@@ -112,6 +137,9 @@ pub trait ArrayMathMisc<N: Numeric> where Self: Sized + Clone {
     ///
     /// let arr = Array::flat(vec![1, -2, 3, -4]);
     /// assert_eq!(Array::flat(vec![1, 2, 3, 4]), arr.absolute());
+    ///
+    /// let arr = Array::flat(vec![Complex::new(3., -4.)]);
+    /// assert_eq!(Array::flat(vec![Complex::new(5., 0.)]), arr.absolute());
     /// ```
     ///
     /// # Errors
@@ -220,19 +248,36 @@ impl <N: Numeric> ArrayMathMisc<N> for Array<N> {
 
         let mut arrays = (self.to_array_f64()?, other.to_array_f64()?);
         if arrays.1.len()? > arrays.0.len()? { arrays = arrays.swap() };
-        let mut out = vec![0.; arrays.0.len()? + arrays.1.len()? - 1];
-        for i in 0..arrays.0.len()? { for j in 0..arrays.1.len()? {
-            out[i + j] += arrays.0[i] * arrays.1[j];
-        } }
         let (n, m) = (arrays.0.len()?, arrays.1.len()?);
+        let out = Self::convolve_full(&arrays.0.get_elements()?, &arrays.1.get_elements()?);
 
         match mode {
-            ConvolveMode::Full => out.clone(),
+            ConvolveMode::Full => out,
             ConvolveMode::Valid => out.iter().skip(m - 1).take(n - m + 1).copied().collect(),
             ConvolveMode::Same => out.iter().skip((m - 1) / 2).take(n).copied().collect(),
         }.to_array()?.to_array_num()
     }
 
+    fn correlate(&self, other: &Self, mode: Option<impl ConvolveModeType>) -> Result<Self, ArrayError> {
+        if self.len()? == 0 || other.len()? == 0 {
+            return Err(ArrayError::ParameterError { param: "`array|other`", message: "cannot be empty", })
+        }
+
+        let mode = match mode {
+            Some(cm) => cm.to_mode()?,
+            None => ConvolveMode::Valid,
+        };
+
+        if other.len()? > self.len()? {
+            other.ravel()?
+                .convolve(&self.ravel().flip(None)?, Some(mode))
+                .flip(None)
+        } else {
+            self.ravel()?
+                .convolve(&other.ravel().flip(None)?, Some(mode))
+        }
+    }
+
     fn clip(&self, a_min: Option<Self>, a_max: Option<Self>) -> Result<Self, ArrayError> {
         let a_min = if let Some(min) = a_min { min } else { self.min(None)? }
             .broadcast_to(self.get_shape()?)?;
@@ -249,7 +294,7 @@ impl <N: Numeric> ArrayMathMisc<N> for Array<N> {
     }
 
     fn sqrt(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().sqrt()))
+        self.map(|i| i.apply_unary(f64::sqrt, |z| z.sqrt()))
     }
 
     fn cbrt(&self) -> Result<Self, ArrayError> {
@@ -257,11 +302,11 @@ impl <N: Numeric> ArrayMathMisc<N> for Array<N> {
     }
 
     fn square(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().powi(2)))
+        self.map(|i| i.apply_unary(|x| x.powi(2), |z| z * z))
     }
 
     fn absolute(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().abs()))
+        self.map(|i| i.apply_unary(f64::abs, |z| Complex::new(z.norm(), 0.)))
     }
 
     fn abs(&self) -> Result<Self, ArrayError> {
@@ -300,6 +345,10 @@ impl <N: Numeric> ArrayMathMisc<N> for Result<Array<N>, ArrayError> {
         self.clone()?.convolve(other, mode)
     }
 
+    fn correlate(&self, other: &Array<N>, mode: Option<impl ConvolveModeType>) -> Self {
+        self.clone()?.correlate(other, mode)
+    }
+
     fn clip(&self, a_min: Option<Array<N>>, a_max: Option<Array<N>>) -> Self {
         self.clone()?.clip(a_min, a_max)
     }
@@ -340,3 +389,27 @@ impl <N: Numeric> ArrayMathMisc<N> for Result<Array<N>, ArrayError> {
         self.clone()?.nan_to_num()
     }
 }
+
+trait MathMiscHelper {
+
+    #[cfg(feature = "fft")]
+    const FFT_CONVOLVE_THRESHOLD: usize = 64;
+
+    fn convolve_full(long: &[f64], short: &[f64]) -> Vec<f64> {
+        #[cfg(feature = "fft")]
+        if short.len() >= Self::FFT_CONVOLVE_THRESHOLD {
+            let out = crate::fft::operations::common::fft_convolve(long, short);
+            // convolution of integral values is integral, so the transform round-off error is dropped
+            let is_integral = long.iter().chain(short).all(|value| value.fract() == 0.);
+            return if is_integral { out.into_iter().map(f64::round).collect() } else { out }
+        }
+
+        let mut out = vec![0.; long.len() + short.len() - 1];
+        for (i, a) in long.iter().enumerate() { for (j, b) in short.iter().enumerate() {
+            out[i + j] += a * b;
+        } }
+        out
+    }
+}
+
+impl <N: Numeric> MathMiscHelper for Array<N> {}
@@ -1,6 +1,9 @@
 /// array arithmetic functions
 pub mod arithmetic;
 
+/// array calculus functions
+pub mod calculus;
+
 /// array exponential and logarithmic functions
 pub mod exp_log;
 
@@ -25,6 +28,9 @@ pub mod rounding;
 /// array special functions
 pub mod special;
 
+/// array statistics functions
+pub mod statistics;
+
 /// array sum, product, diff functions
 pub mod sum_prod_diff;
 
@@ -1,3 +1,5 @@
+use std::f64::consts::{PI, SQRT_2};
+
 use crate::{
     core::prelude::*,
     errors::prelude::*,
@@ -38,6 +40,282 @@ pub trait ArrayMathSpecial<N: NumericOps> where Self: Sized + Clone {
     ///
     /// may returns `ArrayError`
     fn sinc(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Gamma function
+    ///
+    /// relative error is about 1e-15, overflows to `inf` for x > 171.6.
+    /// returns `inf` at zero (signed as zero) and `NaN` at negative integers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![1., 5., -1.]);
+    /// assert_eq!(format!("{:?}", Array::flat(vec![1., 24., f64::NAN])), format!("{:?}", arr.gamma()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn gamma(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Natural logarithm of the absolute value of gamma function
+    ///
+    /// relative error is about 1e-15, also close to the zeros at 1 and 2.
+    /// returns `inf` at non-positive integers and infinities
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![1., 4., 0.]);
+    /// assert_eq!(Array::flat(vec![0., 6_f64.ln(), f64::INFINITY]), arr.lgamma());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn lgamma(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Digamma function - logarithmic derivative of gamma function
+    ///
+    /// absolute error is about 1e-15, so relative error grows close to the zero at x ≈ 1.4616.
+    /// returns `NaN` at non-positive integers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![1., 0.5]);
+    /// assert_eq!(Array::flat(vec![-0.5772156649015324, -1.9635100260214229]), arr.digamma());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn digamma(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Beta function, `gamma(a) * gamma(b) / gamma(a + b)`
+    ///
+    /// relative error is about 1e-15 for small arguments,
+    /// for larger ones it's computed via logarithms with relative error proportional to `|ln(beta(a, b))| * eps`.
+    /// returns `inf` at the poles of gamma function
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - second argument of the function, broadcasted with the array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![1., 2., 3.]);
+    /// let other = Array::flat(vec![2.]).unwrap();
+    /// assert_eq!(Array::flat(vec![0.5, 1. / 6., 1. / 12.]), arr.beta(&other));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn beta(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Error function
+    ///
+    /// relative error is about 1e-15. `erf(±inf) = ±1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![-1., 0., 2.]);
+    /// assert_eq!(Array::flat(vec![-0.842700792949715, 0., 0.9953222650189527]), arr.erf());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn erf(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Complementary error function, `1 - erf(x)`, accurate also for large x
+    ///
+    /// relative error is about 1e-15 (and about 1e-13 before underflow at x ≈ 27). `erfc(-inf) = 2`, `erfc(inf) = 0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![-1., 0., 5.]);
+    /// assert_eq!(Array::flat(vec![1.842700792949715, 1., 1.5374597944280345e-12]), arr.erfc());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn erfc(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Inverse error function
+    ///
+    /// relative error is about 1e-15, also close to ±1 where `erf` is flat.
+    /// returns `±inf` at ±1 and `NaN` outside of [-1, 1]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![0., 0.5, -1.]);
+    /// assert_eq!(Array::flat(vec![0., 0.47693627620446993, f64::NEG_INFINITY]), arr.erfinv());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn erfinv(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Bessel function of the first kind, order 0
+    ///
+    /// absolute error is about 1e-15, so relative error grows close to the zeros of the function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![0., 1., 10.]);
+    /// assert_eq!(Array::flat(vec![1., 0.7651976865579666, -0.24593576445134832]), arr.j0());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn j0(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Bessel function of the first kind, order 1
+    ///
+    /// absolute error is about 1e-15, so relative error grows close to the zeros of the function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![0., 1., -10.]);
+    /// assert_eq!(Array::flat(vec![0., 0.44005058574493355, -0.043472746168861535]), arr.j1());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn j1(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Bessel function of the second kind, order 0
+    ///
+    /// absolute error is about 1e-15, so relative error grows close to the zeros of the function.
+    /// returns `-inf` at zero and `NaN` for negative values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![0., 1., 10.]);
+    /// assert_eq!(Array::flat(vec![f64::NEG_INFINITY, 0.088256964215677, 0.05567116728359942]), arr.y0());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn y0(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Bessel function of the second kind, order 1
+    ///
+    /// absolute error is about 1e-15, so relative error grows close to the zeros of the function.
+    /// returns `-inf` at zero and `NaN` for negative values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![0., 1., 10.]);
+    /// assert_eq!(Array::flat(vec![f64::NEG_INFINITY, -0.7812128213002888, 0.24901542420695386]), arr.y1());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn y1(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Modified Bessel function of the first kind, of real order
+    ///
+    /// relative error is below 1e-13. returns `NaN` for negative values, unless order is an integer
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - order of the function, broadcasted with the array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![0., 1., 2.]);
+    /// let order = Array::flat(vec![1.]).unwrap();
+    /// assert_eq!(Array::flat(vec![0., 0.5651591039924848, 1.590636854637327]), arr.iv(&order));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn iv(&self, order: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Modified Bessel function of the second kind, of real order
+    ///
+    /// relative error is below 1e-14. returns `inf` at zero and `NaN` for negative values
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - order of the function, broadcasted with the array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![0., 1., 2.]);
+    /// let order = Array::flat(vec![0.5]).unwrap();
+    /// assert_eq!(Array::flat(vec![f64::INFINITY, 0.46106850444789454, 0.11993777196806145]), arr.kv(&order));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn kv(&self, order: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Riemann zeta function
+    ///
+    /// relative error is about 1e-15 for x >= 0. negative arguments are computed with the reflection formula,
+    /// with relative error growing to about 1e-13 for large |x|. returns `inf` at the pole at 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![2., 0., -2.]);
+    /// assert_eq!(Array::flat(vec![1.6449340668482264, -0.5, 0.]), arr.zeta());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn zeta(&self) -> Result<Array<N>, ArrayError>;
 }
 
 impl <N: NumericOps> ArrayMathSpecial<N> for Array<N> {
@@ -54,6 +332,62 @@ impl <N: NumericOps> ArrayMathSpecial<N> for Array<N> {
             N::from(y.sin() / y)
         })
     }
+
+    fn gamma(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(gamma(x.to_f64())))
+    }
+
+    fn lgamma(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(lgamma(x.to_f64())))
+    }
+
+    fn digamma(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(digamma(x.to_f64())))
+    }
+
+    fn beta(&self, other: &Self) -> Result<Self, ArrayError> {
+        self.map_broadcast(other, beta)
+    }
+
+    fn erf(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(erf(x.to_f64())))
+    }
+
+    fn erfc(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(erfc(x.to_f64())))
+    }
+
+    fn erfinv(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(erfinv(x.to_f64())))
+    }
+
+    fn j0(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(bessel_j0(x.to_f64())))
+    }
+
+    fn j1(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(bessel_j1(x.to_f64())))
+    }
+
+    fn y0(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(bessel_y0(x.to_f64())))
+    }
+
+    fn y1(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(bessel_y1(x.to_f64())))
+    }
+
+    fn iv(&self, order: &Self) -> Result<Self, ArrayError> {
+        self.map_broadcast(order, bessel_iv)
+    }
+
+    fn kv(&self, order: &Self) -> Result<Self, ArrayError> {
+        self.map_broadcast(order, bessel_kv)
+    }
+
+    fn zeta(&self) -> Result<Self, ArrayError> {
+        self.map(|&x| N::from(zeta(x.to_f64())))
+    }
 }
 
 impl <N: NumericOps> ArrayMathSpecial<N> for Result<Array<N>, ArrayError> {
@@ -65,6 +399,623 @@ impl <N: NumericOps> ArrayMathSpecial<N> for Result<Array<N>, ArrayError> {
     fn sinc(&self) -> Self {
         self.clone()?.sinc()
     }
+
+    fn gamma(&self) -> Self {
+        self.clone()?.gamma()
+    }
+
+    fn lgamma(&self) -> Self {
+        self.clone()?.lgamma()
+    }
+
+    fn digamma(&self) -> Self {
+        self.clone()?.digamma()
+    }
+
+    fn beta(&self, other: &Array<N>) -> Self {
+        self.clone()?.beta(other)
+    }
+
+    fn erf(&self) -> Self {
+        self.clone()?.erf()
+    }
+
+    fn erfc(&self) -> Self {
+        self.clone()?.erfc()
+    }
+
+    fn erfinv(&self) -> Self {
+        self.clone()?.erfinv()
+    }
+
+    fn j0(&self) -> Self {
+        self.clone()?.j0()
+    }
+
+    fn j1(&self) -> Self {
+        self.clone()?.j1()
+    }
+
+    fn y0(&self) -> Self {
+        self.clone()?.y0()
+    }
+
+    fn y1(&self) -> Self {
+        self.clone()?.y1()
+    }
+
+    fn iv(&self, order: &Array<N>) -> Self {
+        self.clone()?.iv(order)
+    }
+
+    fn kv(&self, order: &Array<N>) -> Self {
+        self.clone()?.kv(order)
+    }
+
+    fn zeta(&self) -> Self {
+        self.clone()?.zeta()
+    }
+}
+
+trait SpecialHelper<N: NumericOps> {
+
+    fn map_broadcast(&self, other: &Array<N>, f: fn(f64, f64) -> f64) -> Result<Array<N>, ArrayError>;
+}
+
+impl <N: NumericOps> SpecialHelper<N> for Array<N> {
+
+    fn map_broadcast(&self, other: &Self, f: fn(f64, f64) -> f64) -> Result<Self, ArrayError> {
+        let broadcasted = self.broadcast(other)?;
+        let elements = broadcasted.clone().into_iter()
+            .map(|tuple| N::from(f(tuple.0.to_f64(), tuple.1.to_f64())))
+            .collect();
+        Self::new(elements, broadcasted.get_shape()?)
+    }
+}
+
+
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+const MAX_ITERATIONS: usize = 10_000;
+
+/// taylor coefficients of `1 / gamma(1 + z) - 1`, starting from `z`
+const VALUES_RGAMMA: &[f64] = &[
+    0.577_215_664_901_532_9,
+    -0.655_878_071_520_253_9,
+    -0.042_002_635_034_095_24,
+    0.166_538_611_382_291_5,
+    -0.042_197_734_555_544_34,
+    -0.009_621_971_527_876_974,
+    0.007_218_943_246_663_1,
+    -0.001_165_167_591_859_065,
+    -0.000_215_241_674_114_951,
+    0.000_128_050_282_388_116_2,
+    -2.013_485_478_078_824E-5,
+    -1.250_493_482_142_671E-6,
+    1.133_027_231_981_696E-6,
+    -2.056_338_416_977_607E-7,
+    6.116_095_104_481_416E-9,
+    5.002_007_644_469_223E-9,
+    -1.181_274_570_487_02E-9,
+    1.043_426_711_691_1E-10,
+    7.782_263_439_905_071E-12,
+    -3.696_805_618_642_206E-12,
+    5.100_370_287_454_476E-13,
+    -2.058_326_053_566_507E-14,
+    -5.348_122_539_423_018E-15,
+    1.226_778_628_238_261E-15,
+    -1.181_259_301_697_459E-16,
+    1.186_692_254_751_6E-18,
+];
+
+/// coefficients `b(2k) / (2k (2k - 1))` of stirling series for `ln(gamma(x))`
+const VALUES_STIRLING: &[f64] = &[
+    1. / 12.,
+    -1. / 360.,
+    1. / 1260.,
+    -1. / 1680.,
+    1. / 1188.,
+    -691. / 360_360.,
+    1. / 156.,
+    -3617. / 122_400.,
+];
+
+/// coefficients `b(2k) / 2k` of asymptotic series for digamma function
+const VALUES_DIGAMMA: &[f64] = &[
+    1. / 12.,
+    -1. / 120.,
+    1. / 252.,
+    -1. / 240.,
+    1. / 132.,
+    -691. / 32760.,
+    1. / 12.,
+    -3617. / 8160.,
+];
+
+/// coefficients `(2k)! / b(2k)` of euler-maclaurin summation for zeta function
+const VALUES_ZETA: &[f64] = &[
+    12.,
+    -720.,
+    30_240.,
+    -1_209_600.,
+    47_900_160.,
+    -1.892_437_580_318_379E9,
+    7.472_424_96E10,
+    -2.950_130_727_918_164E12,
+    1.164_678_281_435_006_7E14,
+    -4.597_978_722_407_473E15,
+    1.815_210_540_194_354_7E17,
+    -7.166_165_256_175_667E18,
+];
+
+fn polynomial(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0., |acc, &coefficient| acc.mul_add(x, coefficient))
+}
+
+fn is_non_positive_integer(x: f64) -> bool {
+    x <= 0. && x.fract() == 0.
+}
+
+/// `sin(pi * x)`, exact at integers and half-integers
+fn sin_pi(x: f64) -> f64 {
+    if x.abs() >= 4_503_599_627_370_496. { return 0. }
+    let half_turns = (2. * x).round();
+    let arg = PI * (x - half_turns / 2.);
+    match half_turns.to_isize().rem_euclid(4) {
+        0 => arg.sin(),
+        1 => arg.cos(),
+        2 => -arg.sin(),
+        _ => -arg.cos(),
+    }
+}
+
+/// `1 / gamma(1 + z) - 1`, for |z| <= 0.5
+fn rgamma_m1(z: f64) -> f64 {
+    z * polynomial(z, VALUES_RGAMMA)
+}
+
+/// stirling series correction `ln(gamma(x)) - (x - 0.5) ln(x) + x - ln(2 pi) / 2`, for x >= 10
+fn stirling_series(x: f64) -> f64 {
+    polynomial(1. / (x * x), VALUES_STIRLING) / x
+}
+
+fn gamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY { return f64::NAN }
+    if x == 0. { return 1. / x }
+    if is_non_positive_integer(x) { return f64::NAN }
+    if x > 171.7 { return f64::INFINITY }
+    if x < 0. { return PI / (sin_pi(x) * gamma(1. - x)) }
+    if x < 0.5 { return gamma(x + 1.) / x }
+    if x >= 20. {
+        let power = x.powf(0.5f64.mul_add(x, -0.25));
+        return power * (power / x.exp()) * (2. * PI).sqrt() * stirling_series(x).exp()
+    }
+    // shift the argument to [0.5, 1.5) with `gamma(x + 1) = x gamma(x)`
+    let (mut z, mut product) = (x, 1.);
+    for _ in 0..(x - 0.5).floor().to_usize() {
+        z -= 1.;
+        product *= z;
+    }
+    product / (1. + rgamma_m1(z - 1.))
+}
+
+fn lgamma(x: f64) -> f64 {
+    if x.is_nan() { return f64::NAN }
+    if x.is_infinite() || is_non_positive_integer(x) { return f64::INFINITY }
+    if x < 0. { return (PI / sin_pi(x).abs()).ln() - lgamma(1. - x) }
+    if x < 0.5 { lgamma(x + 1.) - x.ln() }
+    else if x < 1.5 { 0. - rgamma_m1(x - 1.).ln_1p() }
+    else if x < 2.5 { (x - 2.).ln_1p() - rgamma_m1(x - 2.).ln_1p() }
+    else if x < 20. { gamma(x).ln() }
+    else { 0.5f64.mul_add((2. * PI).ln(), (x - 0.5).mul_add(x.ln(), -x)) + stirling_series(x) }
+}
+
+/// sign of gamma function, for x which is not a pole
+fn gamma_sign(x: f64) -> f64 {
+    if x > 0. || x.floor().to_isize() % 2 == 0 { 1. } else { -1. }
+}
+
+fn digamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY || is_non_positive_integer(x) { return f64::NAN }
+    if x == f64::INFINITY { return x }
+    if x < 0. { return digamma(1. - x) - PI * sin_pi(x + 0.5) / sin_pi(x) }
+    let (mut x, mut result) = (x, 0.);
+    for _ in 0..(10. - x).ceil().max(0.).to_usize() {
+        result -= 1. / x;
+        x += 1.;
+    }
+    let inv_square = 1. / (x * x);
+    result + x.ln() - 0.5 / x - inv_square * polynomial(inv_square, VALUES_DIGAMMA)
+}
+
+fn beta(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() { return f64::NAN }
+    if is_non_positive_integer(a) || is_non_positive_integer(b) { return f64::INFINITY }
+    let sum = a + b;
+    if is_non_positive_integer(sum) { return 0. }
+    let (small, large) = if a.abs() < b.abs() { (a, b) } else { (b, a) };
+    if large.abs() < 20. && sum.abs() < 20. { return gamma(a) * gamma(b) / gamma(sum) }
+    if small > 0. {
+        // ln(gamma(large)) - ln(gamma(large + small)) from stirling series, without cancellation
+        let ratio = (0.5 - large).mul_add((small / large).ln_1p(), small.mul_add(-sum.ln(), small))
+            + stirling_series(large) - stirling_series(sum);
+        return (lgamma(small) + ratio).exp()
+    }
+    gamma_sign(a) * gamma_sign(b) * gamma_sign(sum) * (lgamma(a) + lgamma(b) - lgamma(sum)).exp()
+}
+
+fn erf(x: f64) -> f64 {
+    if x.is_nan() { return f64::NAN }
+    if x.abs() < 1.25 { erf_series(x) }
+    else { (1. - erfc_fraction(x.abs())).copysign(x) }
+}
+
+fn erfc(x: f64) -> f64 {
+    if x.is_nan() { return f64::NAN }
+    if x < -1.25 { 2. - erfc_fraction(-x) }
+    else if x < 1.25 { 1. - erf_series(x) }
+    else { erfc_fraction(x) }
+}
+
+/// `erf(x) = 2 x exp(-x^2) / sqrt(pi) * sum((2 x^2)^n / (1 * 3 * ... * (2n + 1)))`, for small |x|
+fn erf_series(x: f64) -> f64 {
+    let square = x * x;
+    let (mut term, mut sum) = (x, x);
+    for n in 1..MAX_ITERATIONS {
+        term *= 2. * square / (2 * n + 1).to_f64();
+        sum += term;
+        if term.abs() <= sum.abs() * f64::EPSILON { break }
+    }
+    2. / PI.sqrt() * (-square).exp() * sum
+}
+
+/// `erfc(x) = gamma(1/2, x^2) / sqrt(pi)`, with continued fraction for upper incomplete gamma function, for x >= 1.25
+fn erfc_fraction(x: f64) -> f64 {
+    if x > 28. { return 0. }
+    let tiny = f64::MIN_POSITIVE / f64::EPSILON;
+    let square = x * x;
+    let square_error = x.mul_add(x, -square);
+    let (mut bn, mut cn) = (square + 0.5, 1. / tiny);
+    let mut dn = 1. / bn;
+    let mut result = dn;
+    for index in 1..MAX_ITERATIONS {
+        let index = index.to_f64();
+        let an = -index * (index - 0.5);
+        bn += 2.;
+        dn = an.mul_add(dn, bn);
+        if dn.abs() < tiny { dn = tiny }
+        cn = bn + an / cn;
+        if cn.abs() < tiny { cn = tiny }
+        dn = 1. / dn;
+        let delta = dn * cn;
+        result *= delta;
+        if (delta - 1.).abs() <= f64::EPSILON { break }
+    }
+    (-square).exp() * (-square_error).exp() * x * result / PI.sqrt()
+}
+
+fn erfinv(y: f64) -> f64 {
+    if y.is_nan() || y.abs() > 1. { return f64::NAN }
+    if y.abs() - 1. == 0. { return f64::INFINITY.copysign(y) }
+    if y == 0. { return y }
+    let z = y.abs();
+    // initial guess with winitzki approximation, refined with halley iterations.
+    // close to 1, complement `1 - z` is exact, so erfc is used to keep the precision
+    let a = 0.147;
+    let log = -((1. - z) * (1. + z)).ln();
+    let term = 2. / (PI * a) - log / 2.;
+    let mut x = (term.mul_add(term, log / a).sqrt() - term).sqrt();
+    for _ in 0..MAX_ITERATIONS {
+        let residual = if z > 0.5 { (1. - z) - erfc(x) } else { erf(x) - z };
+        let step = residual / (2. / PI.sqrt() * (-x * x).exp());
+        let step = step / x.mul_add(step, 1.);
+        x -= step;
+        if step.abs() <= f64::EPSILON * x.abs() { break }
+    }
+    x.copysign(y)
+}
+
+fn bessel_j0(x: f64) -> f64 {
+    if x.is_nan() { return f64::NAN }
+    if x.is_infinite() { return 0. }
+    if x == 0. { return 1. }
+    bessel_jy(x.abs()).0
+}
+
+fn bessel_j1(x: f64) -> f64 {
+    if x.is_nan() { return f64::NAN }
+    if x.is_infinite() { return 0. }
+    if x == 0. { return x }
+    let value = bessel_jy(x.abs()).1;
+    if x < 0. { -value } else { value }
+}
+
+fn bessel_y0(x: f64) -> f64 {
+    if x.is_nan() || x < 0. { return f64::NAN }
+    if x == 0. { return f64::NEG_INFINITY }
+    if x.is_infinite() { return 0. }
+    bessel_jy(x).2
+}
+
+fn bessel_y1(x: f64) -> f64 {
+    if x.is_nan() || x < 0. { return f64::NAN }
+    if x == 0. { return f64::NEG_INFINITY }
+    if x.is_infinite() { return 0. }
+    bessel_jy(x).3
+}
+
+/// bessel functions `(j0, j1, y0, y1)`, for x > 0
+fn bessel_jy(x: f64) -> (f64, f64, f64, f64) {
+    if x <= 2. { bessel_jy_series(x) }
+    else if x <= 25. { bessel_jy_recurrence(x) }
+    else { bessel_jy_asymptotic(x) }
+}
+
+/// power series of bessel functions, for small x
+fn bessel_jy_series(x: f64) -> (f64, f64, f64, f64) {
+    let quarter_square = x * x / 4.;
+    let log_term = (x / 2.).ln() + EULER_GAMMA;
+    // terms `(-x^2 / 4)^k / (k!)^2` and `(-x^2 / 4)^k / (k! (k + 1)!)`
+    let (mut term_0, mut term_1) = (1., 1.);
+    let (mut j0, mut j1) = (1., 1.);
+    let (mut harmonic, mut sum_y0, mut sum_y1) = (0., 0., 2_f64.mul_add(-EULER_GAMMA, 1.));
+    for k in 1..MAX_ITERATIONS {
+        let k_f64 = k.to_f64();
+        term_0 *= -quarter_square / (k_f64 * k_f64);
+        term_1 *= -quarter_square / (k_f64 * (k_f64 + 1.));
+        harmonic += 1. / k_f64;
+        j0 += term_0;
+        j1 += term_1;
+        sum_y0 -= harmonic * term_0;
+        sum_y1 += (2. * (harmonic - EULER_GAMMA)).mul_add(term_1, term_1 / (k_f64 + 1.));
+        if term_0.abs() <= f64::EPSILON * 1e-2 { break }
+    }
+    let j1 = j1 * x / 2.;
+    let y0 = 2. / PI * log_term.mul_add(j0, sum_y0);
+    let y1 = 2. / PI * (x / 2.).ln().mul_add(j1, (x / 4.).mul_add(-sum_y1, -1. / x));
+    (j0, j1, y0, y1)
+}
+
+/// bessel functions with miller backward recurrence, normalized with `j0 + 2 * sum(j2k) = 1`,
+/// and neumann series for the second kind
+fn bessel_jy_recurrence(x: f64) -> (f64, f64, f64, f64) {
+    let start = 2 * ((x + 20.).to_usize() / 2 + 10);
+    let mut values = vec![0.; start + 2];
+    values[start] = 1.;
+    for n in (1..=start).rev() {
+        values[n - 1] = ((2 * n).to_f64() / x).mul_add(values[n], -values[n + 1]);
+    }
+    let norm = 2_f64.mul_add(values.iter().step_by(2).skip(1).sum::<f64>(), values[0]);
+    let values = values.into_iter().map(|value| value / norm).collect::<Vec<_>>();
+
+    let (j0, j1) = (values[0], values[1]);
+    let log_term = (x / 2.).ln() + EULER_GAMMA;
+    let (mut sum_y0, mut sum_y1) = (0., 0.);
+    for k in 1..=start / 2 {
+        let sign = if k % 2 == 0 { 1. } else { -1. } / k.to_f64();
+        sum_y0 += sign * values[2 * k];
+        sum_y1 += sign * (values[2 * k - 1] - values[2 * k + 1]);
+    }
+    let y0 = 2. / PI * log_term.mul_add(j0, -2. * sum_y0);
+    let y1 = 2. / PI * (log_term.mul_add(j1, -j0 / x) + sum_y1);
+    (j0, j1, y0, y1)
+}
+
+/// hankel asymptotic expansion of bessel functions, for large x
+fn bessel_jy_asymptotic(x: f64) -> (f64, f64, f64, f64) {
+    let (p0, q0) = bessel_asymptotic_series(0., x);
+    let (p1, q1) = bessel_asymptotic_series(1., x);
+    let (sin, cos) = x.sin_cos();
+    let factor = (2. / (PI * x)).sqrt() / SQRT_2;
+    // phases `x - pi / 4` and `x - 3 pi / 4`
+    let (cos_0, sin_0) = (cos + sin, sin - cos);
+    let (cos_1, sin_1) = (sin - cos, -sin - cos);
+    (
+        factor * p0.mul_add(cos_0, -q0 * sin_0),
+        factor * p1.mul_add(cos_1, -q1 * sin_1),
+        factor * p0.mul_add(sin_0, q0 * cos_0),
+        factor * p1.mul_add(sin_1, q1 * cos_1),
+    )
+}
+
+/// asymptotic series `p` and `q` of bessel functions of given order
+fn bessel_asymptotic_series(order: f64, x: f64) -> (f64, f64) {
+    let mu = 4. * order * order;
+    let (mut p, mut q, mut term) = (1., 0., 1_f64);
+    for k in 1..MAX_ITERATIONS {
+        let k_f64 = k.to_f64();
+        let odd = 2_f64.mul_add(k_f64, -1.);
+        let next = term * odd.mul_add(-odd, mu) / (8. * k_f64 * x);
+        if next.abs() > term.abs() { break }
+        term = next;
+        let sign = if (k / 2) % 2 == 0 { 1. } else { -1. };
+        if k % 2 == 0 { p += sign * term } else { q += sign * term }
+        if term.abs() <= f64::EPSILON { break }
+    }
+    (p, q)
+}
+
+fn bessel_iv(x: f64, order: f64) -> f64 {
+    if x.is_nan() || !order.is_finite() { return f64::NAN }
+    let is_integer = order.fract() == 0.;
+    if x < 0. {
+        if !is_integer { return f64::NAN }
+        let sign = if order.to_isize() % 2 == 0 { 1. } else { -1. };
+        return sign * bessel_iv(-x, order)
+    }
+    if x == 0. {
+        return if order == 0. { 1. }
+        else if order > 0. || is_integer { 0. }
+        else { f64::INFINITY.copysign(sin_pi(-order)) }
+    }
+    if x.is_infinite() { return f64::INFINITY }
+    let (i, k) = bessel_ik(order.abs(), x);
+    if order >= 0. || is_integer { i }
+    else { (2. / PI * sin_pi(-order)).mul_add(k, i) }
+}
+
+fn bessel_kv(x: f64, order: f64) -> f64 {
+    if x.is_nan() || x < 0. || !order.is_finite() { return f64::NAN }
+    if x == 0. { return f64::INFINITY }
+    if x.is_infinite() { return 0. }
+    bessel_ik(order.abs(), x).1
+}
+
+/// modified bessel functions `(iv, kv)` for order >= 0 and x > 0, with temme method.
+/// kv and kv+1 are computed for fractional order |mu| <= 0.5 (temme series for x < 2, steed continued fraction otherwise)
+/// and recurred forward, iv is found from the ratio `iv+1 / iv` and wronskian
+fn bessel_ik(order: f64, x: f64) -> (f64, f64) {
+    let tiny = f64::MIN_POSITIVE / f64::EPSILON;
+    let steps = (order + 0.5).floor().to_usize();
+    let mu = order - steps.to_f64();
+    let (inv, inv_2) = (1. / x, 2. / x);
+
+    // continued fraction for `iv+1 / iv`
+    let mut fraction = (order * inv).max(tiny);
+    let (mut bn, mut cn, mut dn) = (inv_2 * order, fraction, 0.);
+    for _ in 0..MAX_ITERATIONS * 10 {
+        bn += inv_2;
+        dn = 1. / (bn + dn);
+        cn = bn + 1. / cn;
+        let delta = cn * dn;
+        fraction *= delta;
+        if (delta - 1.).abs() <= f64::EPSILON { break }
+    }
+
+    // downward recurrence of unnormalized iv to the fractional order, rescaled to avoid overflow
+    let (mut i_low, mut i_low_prime) = (tiny, fraction * tiny);
+    let mut i_high = i_low;
+    for step in (1..=steps).rev() {
+        let level = mu + step.to_f64();
+        let next = (level * inv).mul_add(i_low, i_low_prime);
+        i_low_prime = ((level - 1.) * inv).mul_add(next, i_low);
+        i_low = next;
+        if i_low.abs() > 1e250 {
+            i_low *= 1e-250;
+            i_low_prime *= 1e-250;
+            i_high *= 1e-250;
+        }
+    }
+    let ratio = i_low_prime / i_low;
+
+    let (k_mu, k_mu1) = if x < 2. { bessel_k_temme(mu, x) } else { bessel_k_steed(mu, x) };
+    let k_mu_prime = (mu * inv).mul_add(k_mu, -k_mu1);
+    let i_mu = inv / ratio.mul_add(k_mu, -k_mu_prime);
+
+    let (mut k_low, mut k_high) = (k_mu, k_mu1);
+    for step in 1..=steps {
+        let next = ((mu + step.to_f64()) * inv_2).mul_add(k_high, k_low);
+        k_low = k_high;
+        k_high = next;
+    }
+    (i_mu * i_high / i_low, k_low)
+}
+
+/// `gamma_1 = (1 / gamma(1 - mu) - 1 / gamma(1 + mu)) / (2 mu)` and `gamma_2 = (1 / gamma(1 - mu) + 1 / gamma(1 + mu)) / 2`,
+/// from taylor series of `1 / gamma`, without cancellation for small mu
+fn temme_gammas(mu: f64) -> (f64, f64) {
+    let square = mu * mu;
+    let series = |skip: usize| VALUES_RGAMMA.iter().skip(skip).step_by(2).rev()
+        .fold(0_f64, |acc, &coefficient| acc.mul_add(square, coefficient));
+    (-series(0), series(1).mul_add(square, 1.))
+}
+
+/// temme series of `(k_mu, k_mu+1)`, for |mu| <= 0.5 and x < 2
+fn bessel_k_temme(mu: f64, x: f64) -> (f64, f64) {
+    let half = 0.5 * x;
+    let pi_mu = PI * mu;
+    let fact = if pi_mu.abs() < f64::EPSILON { 1. } else { pi_mu / pi_mu.sin() };
+    let log = -half.ln();
+    let exponent = mu * log;
+    let fact_2 = if exponent.abs() < f64::EPSILON { 1. } else { exponent.sinh() / exponent };
+    let (gamma_1, gamma_2) = temme_gammas(mu);
+    let (gamma_plus, gamma_minus) = (mu.mul_add(-gamma_1, gamma_2), mu.mul_add(gamma_1, gamma_2));
+
+    let mut term_f = fact * gamma_1.mul_add(exponent.cosh(), gamma_2 * fact_2 * log);
+    let mut term_p = 0.5 * exponent.exp() / gamma_plus;
+    let mut term_q = 0.5 / (exponent.exp() * gamma_minus);
+    let (mut coefficient, mut sum, mut sum_1) = (1., term_f, term_p);
+    for index in 1..MAX_ITERATIONS {
+        let index = index.to_f64();
+        term_f = index.mul_add(term_f, term_p + term_q) / mu.mul_add(-mu, index * index);
+        coefficient *= half * half / index;
+        term_p /= index - mu;
+        term_q /= index + mu;
+        let delta = coefficient * term_f;
+        sum += delta;
+        sum_1 += coefficient * index.mul_add(-term_f, term_p);
+        if delta.abs() < sum.abs() * f64::EPSILON { break }
+    }
+    (sum, sum_1 / half)
+}
+
+/// steed continued fraction of `(k_mu, k_mu+1)`, for |mu| <= 0.5 and x >= 2
+fn bessel_k_steed(mu: f64, x: f64) -> (f64, f64) {
+    let a_1 = mu.mul_add(-mu, 0.25);
+    let mut bn = 2. * (1. + x);
+    let mut dn = 1. / bn;
+    let (mut fraction, mut delta_fraction) = (dn, dn);
+    let (mut q_1, mut q_2) = (0., 1.);
+    let (mut qn, mut cn, mut an) = (a_1, a_1, -a_1);
+    let mut series = qn.mul_add(delta_fraction, 1.);
+    for index in 2..MAX_ITERATIONS {
+        let index = index.to_f64();
+        an -= 2. * (index - 1.);
+        cn = -an * cn / index;
+        let q_next = bn.mul_add(-q_2, q_1) / an;
+        q_1 = q_2;
+        q_2 = q_next;
+        qn += cn * q_next;
+        bn += 2.;
+        dn = 1. / an.mul_add(dn, bn);
+        delta_fraction *= bn.mul_add(dn, -1.);
+        fraction += delta_fraction;
+        let delta_series = qn * delta_fraction;
+        series += delta_series;
+        if (delta_series / series).abs() < f64::EPSILON { break }
+    }
+    let k_mu = (PI / (2. * x)).sqrt() * (-x).exp() / series;
+    (k_mu, k_mu * a_1.mul_add(-fraction, mu + x + 0.5) / x)
+}
+
+fn zeta(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY { return f64::NAN }
+    if x - 1. == 0. { return f64::INFINITY }
+    if x == f64::INFINITY { return 1. }
+    if x < 0. {
+        if (x / 2.).fract() == 0. { return 0. }
+        // reflection `zeta(x) = 2^x pi^(x - 1) sin(pi x / 2) gamma(1 - x) zeta(1 - x)`
+        let sine = sin_pi(x / 2.);
+        return if x > -170. { x.exp2() * PI.powf(x - 1.) * sine * gamma(1. - x) * zeta(1. - x) }
+        else { sine * x.mul_add((2. * PI).ln(), lgamma(1. - x) - PI.ln()).exp() * zeta(1. - x) }
+    }
+    zeta_summation(x)
+}
+
+/// `sum(k^-x)` with euler-maclaurin summation after first 10 terms, for x >= 0
+fn zeta_summation(x: f64) -> f64 {
+    let mut sum = 1.;
+    for k in 2..=10 {
+        let term = k.to_f64().powf(-x);
+        sum += term;
+        if (term / sum).abs() < f64::EPSILON { return sum }
+    }
+    let base = 10_f64;
+    let mut term = base.powf(-x);
+    sum += term * (base / (x - 1.) - 0.5);
+    let (mut factor, mut level) = (1., 0.);
+    for coefficient in VALUES_ZETA {
+        factor *= x + level;
+        term /= base;
+        let correction = factor * term / coefficient;
+        sum += correction;
+        if (correction / sum).abs() < f64::EPSILON { break }
+        level += 1.;
+        factor *= x + level;
+        term /= base;
+        level += 1.;
+    }
+    sum
 }
 
 const VALUES_I0_A: &[f64] = &[
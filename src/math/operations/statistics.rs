@@ -0,0 +1,145 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    extensions::prelude::*,
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// `ArrayTrait` - Array Statistics functions
+pub trait ArrayStatistics<N: NumericOps> where Self: Sized + Clone {
+
+    /// Estimate a covariance matrix, given data and weights.
+    /// computed in `f64`, so integer input gives fractional covariances
+    ///
+    /// # Arguments
+    ///
+    /// * `rowvar` - if true, each row represents a variable, with observations in the columns. optional, defaults to `true`
+    /// * `bias` - if true, normalization is by `N`, otherwise by `N - 1`. optional, defaults to `false`
+    /// * `ddof` - if provided, overrides the value implied by `bias`. optional
+    /// * `fweights` - integer frequency weights; the number of times each observation vector should be repeated. optional
+    /// * `aweights` - observation vector weights. optional
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![0., 1., 2., 2., 1., 0.], vec![2, 3]);
+    /// assert_eq!(Array::new(vec![1., -1., -1., 1.], vec![2, 2]), arr.cov(None, None, None, None, None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn cov(&self, rowvar: Option<bool>, bias: Option<bool>, ddof: Option<usize>, fweights: Option<Array<usize>>, aweights: Option<Array<f64>>) -> Result<Array<f64>, ArrayError>;
+
+    /// Return Pearson product-moment correlation coefficients, computed in `f64`
+    ///
+    /// # Arguments
+    ///
+    /// * `rowvar` - if true, each row represents a variable, with observations in the columns. optional, defaults to `true`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![0., 1., 2., 2., 1., 0.], vec![2, 3]);
+    /// assert_eq!(Array::new(vec![1., -1., -1., 1.], vec![2, 2]), arr.corrcoef(None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn corrcoef(&self, rowvar: Option<bool>) -> Result<Array<f64>, ArrayError>;
+}
+
+impl <N: NumericOps> ArrayStatistics<N> for Array<N> {
+
+    fn cov(&self, rowvar: Option<bool>, bias: Option<bool>, ddof: Option<usize>, fweights: Option<Array<usize>>, aweights: Option<Array<f64>>) -> Result<Array<f64>, ArrayError> {
+        self.is_dim_supported(&[1, 2])?;
+        let mut array = self.to_array_f64()?.atleast(2)?;
+        if !rowvar.unwrap_or(true) && array.get_shape()?[0] != 1 {
+            array = array.transpose(None)?;
+        }
+        let (vars, obs) = (array.get_shape()?[0], array.get_shape()?[1]);
+        let ddof = ddof.unwrap_or_else(|| <usize as From<bool>>::from(!bias.unwrap_or(false)));
+
+        let fweights = match fweights {
+            Some(fw) => {
+                fw.is_dim_supported(&[1])?;
+                fw.len()?.is_equal(&obs)?;
+                Some(fw.get_elements()?.into_iter().map(|w| w.to_f64()).collect::<Vec<f64>>())
+            },
+            None => None,
+        };
+        let aweights = match aweights {
+            Some(aw) => {
+                aw.is_dim_supported(&[1])?;
+                aw.len()?.is_equal(&obs)?;
+                let aw = aw.get_elements()?;
+                if aw.iter().any(|&w| w < 0.) {
+                    return Err(ArrayError::ParameterError { param: "`aweights`", message: "cannot be negative" })
+                }
+                Some(aw)
+            },
+            None => None,
+        };
+        let weights = match (&fweights, &aweights) {
+            (Some(fw), Some(aw)) => fw.iter().zip(aw).map(|(f, a)| f * a).collect(),
+            (Some(w), None) | (None, Some(w)) => w.clone(),
+            (None, None) => vec![1.; obs],
+        };
+        let w_sum = weights.iter().sum::<f64>();
+        let fact = if ddof == 0 { w_sum } else if let Some(aw) = &aweights {
+            let wa_sum = weights.iter().zip(aw).map(|(w, a)| w * a).sum::<f64>();
+            ddof.to_f64().mul_add(-wa_sum / w_sum, w_sum)
+        } else {
+            w_sum - ddof.to_f64()
+        }.max(0.);
+
+        let rows = array.get_elements()?
+            .chunks(obs)
+            .map(|row| {
+                let avg = row.iter().zip(&weights).map(|(x, w)| x * w).sum::<f64>() / w_sum;
+                row.iter().map(|x| x - avg).collect::<Vec<f64>>()
+            })
+            .collect::<Vec<Vec<f64>>>();
+        let result = (0..vars)
+            .flat_map(|i| (0..vars).map(|j| rows[i].iter()
+                .zip(&rows[j])
+                .zip(&weights)
+                .map(|((x, y), w)| x * y * w)
+                .sum::<f64>() / fact)
+                .collect::<Vec<f64>>())
+            .collect::<Array<f64>>();
+
+        if vars == 1 { result.ravel() }
+        else { result.reshape(&[vars, vars]) }
+    }
+
+    fn corrcoef(&self, rowvar: Option<bool>) -> Result<Array<f64>, ArrayError> {
+        let cov = self.cov(rowvar, None, None, None, None)?;
+        let vars = if cov.ndim()? == 1 { 1 } else { cov.get_shape()?[0] };
+        let stddev = (0..vars)
+            .map(|i| cov[i * vars + i].sqrt())
+            .collect::<Vec<f64>>();
+        cov.get_elements()?.into_iter()
+            .enumerate()
+            .map(|(idx, c)| (c / stddev[idx / vars] / stddev[idx % vars]).clamp(-1., 1.))
+            .collect::<Array<f64>>()
+            .reshape(&cov.get_shape()?)
+    }
+}
+
+impl <N: NumericOps> ArrayStatistics<N> for Result<Array<N>, ArrayError> {
+
+    fn cov(&self, rowvar: Option<bool>, bias: Option<bool>, ddof: Option<usize>, fweights: Option<Array<usize>>, aweights: Option<Array<f64>>) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.cov(rowvar, bias, ddof, fweights, aweights)
+    }
+
+    fn corrcoef(&self, rowvar: Option<bool>) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.corrcoef(rowvar)
+    }
+}
@@ -242,27 +242,27 @@ pub trait ArrayTrigonometric<N: NumericOps> where Self: Sized + Clone {
 impl <N: NumericOps> ArrayTrigonometric<N> for Array<N> {
 
     fn sin(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().sin()))
+        self.map(|i| i.apply_unary(f64::sin, |z| z.sin()))
     }
 
     fn cos(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().cos()))
+        self.map(|i| i.apply_unary(f64::cos, |z| z.cos()))
     }
 
     fn tan(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().tan()))
+        self.map(|i| i.apply_unary(f64::tan, |z| z.tan()))
     }
 
     fn asin(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().asin()))
+        self.map(|i| i.apply_unary(f64::asin, |z| z.asin()))
     }
 
     fn acos(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().acos()))
+        self.map(|i| i.apply_unary(f64::acos, |z| z.acos()))
     }
 
     fn atan(&self) -> Result<Self, ArrayError> {
-        self.map(|i| N::from(i.to_f64().atan()))
+        self.map(|i| i.apply_unary(f64::atan, |z| z.atan()))
     }
 
     fn atan2(&self, other: &Self) -> Result<Self, ArrayError> {
@@ -2,6 +2,7 @@
 pub use crate::math::{
     operations::{
         arithmetic::ArrayArithmetic,
+        calculus::ArrayCalculus,
         exp_log::ArrayExpLog,
         extrema::ArrayExtrema,
         floating::ArrayFloating,
@@ -10,6 +11,7 @@ pub use crate::math::{
         rational::ArrayRational,
         rounding::ArrayRounding,
         special::ArrayMathSpecial,
+        statistics::ArrayStatistics,
         sum_prod_diff::ArraySumProdDiff,
         trigonometric::ArrayTrigonometric,
     },
@@ -184,6 +184,7 @@ pub trait ArrayBinary<N: Numeric> where Self: Sized + Clone {
 impl <N: Numeric> ArrayBinary<N> for Array<N> {
 
     fn bitwise_and(&self, other: &Self) -> Result<Self, ArrayError> {
+        Self::is_bitwise_supported()?;
         self.get_shape()?.is_broadcastable(&other.get_shape()?)?;
         let broadcasted = self.broadcast(other)?;
         let elements = broadcasted.clone().into_iter()
@@ -193,6 +194,7 @@ impl <N: Numeric> ArrayBinary<N> for Array<N> {
     }
 
     fn bitwise_or(&self, other: &Self) -> Result<Self, ArrayError> {
+        Self::is_bitwise_supported()?;
         self.get_shape()?.is_broadcastable(&other.get_shape()?)?;
         let broadcasted = self.broadcast(other)?;
         let elements = broadcasted.clone().into_iter()
@@ -202,6 +204,7 @@ impl <N: Numeric> ArrayBinary<N> for Array<N> {
     }
 
     fn bitwise_xor(&self, other: &Self) -> Result<Self, ArrayError> {
+        Self::is_bitwise_supported()?;
         self.get_shape()?.is_broadcastable(&other.get_shape()?)?;
         let elements = self.broadcast(other)?.into_iter()
             .map(|tuple| tuple.0.bitwise_xor(&tuple.1))
@@ -210,6 +213,7 @@ impl <N: Numeric> ArrayBinary<N> for Array<N> {
     }
 
     fn bitwise_not(&self) -> Result<Self, ArrayError> {
+        Self::is_bitwise_supported()?;
         self.map(|&a| a.bitwise_not())
     }
 
@@ -218,6 +222,7 @@ impl <N: Numeric> ArrayBinary<N> for Array<N> {
     }
 
     fn left_shift(&self, other: &Self) -> Result<Self, ArrayError> {
+        Self::is_bitwise_supported()?;
         self.get_shape()?.is_broadcastable(&other.get_shape()?)?;
         let broadcasted = self.broadcast(other)?;
         let elements = broadcasted.clone().into_iter()
@@ -227,6 +232,7 @@ impl <N: Numeric> ArrayBinary<N> for Array<N> {
     }
 
     fn right_shift(&self, other: &Self) -> Result<Self, ArrayError> {
+        Self::is_bitwise_supported()?;
         self.get_shape()?.is_broadcastable(&other.get_shape()?)?;
         let broadcasted = self.broadcast(other)?;
         let elements = broadcasted.clone().into_iter()
@@ -274,3 +280,13 @@ impl <N: Numeric> ArrayBinary<N> for Result<Array<N>, ArrayError> {
         num.binary_repr()
     }
 }
+
+trait BinaryHelper<N: Numeric> {
+
+    fn is_bitwise_supported() -> Result<(), ArrayError> {
+        if N::supports_bitwise() { Ok(()) }
+        else { Err(ArrayError::ParameterError { param: "`array`", message: "bitwise operations are not supported for complex numbers" }) }
+    }
+}
+
+impl <N: Numeric> BinaryHelper<N> for Array<N> {}
@@ -0,0 +1,125 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+};
+
+/// `ArrayTrait` - Array Complex functions
+pub trait ArrayComplex<N: Numeric> where Self: Sized + Clone {
+
+    /// Return the real part of array elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![Complex::new(1., 2.), Complex::new(-3., 4.)]);
+    /// assert_eq!(Array::flat(vec![1., -3.]), arr.real());
+    /// assert_eq!(Array::flat(vec![1., 2.]), array_flat!(i32, 1, 2).real());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn real(&self) -> Result<Array<f64>, ArrayError>;
+
+    /// Return the imaginary part of array elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![Complex::new(1., 2.), Complex::new(-3., 4.)]);
+    /// assert_eq!(Array::flat(vec![2., 4.]), arr.imag());
+    /// assert_eq!(Array::flat(vec![0., 0.]), array_flat!(i32, 1, 2).imag());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn imag(&self) -> Result<Array<f64>, ArrayError>;
+
+    /// Return the complex conjugate of array elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![Complex::new(1., 2.), Complex::new(-3., -4.)]);
+    /// assert_eq!(Array::flat(vec![Complex::new(1., -2.), Complex::new(-3., 4.)]), arr.conj());
+    /// assert_eq!(Array::flat(vec![1, 2]), array_flat!(i32, 1, 2).conj());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn conj(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Return the angle of array elements
+    ///
+    /// # Arguments
+    ///
+    /// * `deg` - if true, return angle in degrees. optional, defaults to false
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![Complex::new(1., 0.), Complex::new(0., 1.), Complex::new(1., 1.)]);
+    /// assert_eq!(Array::flat(vec![0., std::f64::consts::FRAC_PI_2, std::f64::consts::FRAC_PI_4]), arr.angle(None));
+    /// assert_eq!(Array::flat(vec![0., 90., 45.]), arr.angle(Some(true)));
+    /// assert_eq!(Array::flat(vec![std::f64::consts::PI, 0.]), array_flat!(f64, -1., 1.).angle(None));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn angle(&self, deg: Option<bool>) -> Result<Array<f64>, ArrayError>;
+}
+
+impl <N: Numeric> ArrayComplex<N> for Array<N> {
+
+    fn real(&self) -> Result<Array<f64>, ArrayError> {
+        self.map(|i| i.to_complex().re)
+            .reshape(&self.get_shape()?)
+    }
+
+    fn imag(&self) -> Result<Array<f64>, ArrayError> {
+        self.map(|i| i.to_complex().im)
+            .reshape(&self.get_shape()?)
+    }
+
+    fn conj(&self) -> Result<Self, ArrayError> {
+        self.map(|i| N::from_complex(i.to_complex().conj()))
+            .reshape(&self.get_shape()?)
+    }
+
+    fn angle(&self, deg: Option<bool>) -> Result<Array<f64>, ArrayError> {
+        let deg = deg.unwrap_or(false);
+        self.map(|i| if deg { i.to_complex().arg().to_degrees() } else { i.to_complex().arg() })
+            .reshape(&self.get_shape()?)
+    }
+}
+
+impl <N: Numeric> ArrayComplex<N> for Result<Array<N>, ArrayError> {
+
+    fn real(&self) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.real()
+    }
+
+    fn imag(&self) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.imag()
+    }
+
+    fn conj(&self) -> Self {
+        self.clone()?.conj()
+    }
+
+    fn angle(&self, deg: Option<bool>) -> Result<Array<f64>, ArrayError> {
+        self.clone()?.angle(deg)
+    }
+}
@@ -4,6 +4,9 @@ pub mod binary;
 /// array binary functions
 pub mod binary_bits;
 
+/// array complex functions
+pub mod complex;
+
 /// array create functions
 pub mod create;
 
@@ -3,11 +3,13 @@ pub use crate::numeric::{
     operations::{
         binary::ArrayBinary,
         binary_bits::ArrayBinaryBits,
+        complex::ArrayComplex,
         create::ArrayCreateNumeric,
         create_from::ArrayCreateFrom,
     },
     types::{
         binary::{BitOrder, BitOrderType},
+        complex::Complex,
         floating::Floating,
         numeric::Numeric,
         numeric_ops::NumericOps,
@@ -0,0 +1,472 @@
+use std::fmt::Display;
+use std::ops::{
+    Add, AddAssign,
+    Div, DivAssign,
+    Mul, MulAssign,
+    Neg,
+    Rem, RemAssign,
+    Sub, SubAssign,
+    RangeInclusive,
+};
+use std::str::FromStr;
+
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+};
+
+/// Complex number type for array
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Eq)]
+pub struct Complex<F: Floating> {
+    /// real part
+    pub re: F,
+    /// imaginary part
+    pub im: F,
+}
+
+impl <F: Floating> Complex<F> {
+
+    /// Create new complex number
+    ///
+    /// # Arguments
+    ///
+    /// * `re` - real part
+    /// * `im` - imaginary part
+    pub const fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+
+    fn nan() -> Self {
+        Self::new(F::from_f64(f64::NAN), F::from_f64(f64::NAN))
+    }
+
+    /// Create new complex number from polar coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - modulus
+    /// * `theta` - argument, in radians
+    pub fn from_polar(r: F, theta: F) -> Self {
+        let (sin, cos) = theta.to_f64().sin_cos();
+        Self::new(F::from_f64(r.to_f64() * cos), F::from_f64(r.to_f64() * sin))
+    }
+
+    /// Complex conjugate
+    #[must_use]
+    pub fn conj(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Modulus (absolute value)
+    pub fn norm(&self) -> F {
+        F::from_f64(self.re.to_f64().hypot(self.im.to_f64()))
+    }
+
+    /// Absolute value, alias on `norm`
+    pub fn abs(&self) -> F {
+        self.norm()
+    }
+
+    /// Squared modulus
+    pub fn norm_sqr(&self) -> F {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Argument (angle), in radians
+    pub fn arg(&self) -> F {
+        F::from_f64(self.im.to_f64().atan2(self.re.to_f64()))
+    }
+
+    /// Multiply by a real scalar
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - value to multiply by
+    #[must_use]
+    pub fn scale(&self, factor: F) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+
+    /// Fused multiply-add. computes `self * a + b`
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - value to multiply by
+    /// * `b` - value to add
+    #[must_use]
+    pub fn mul_add(&self, a: Self, b: Self) -> Self {
+        let (z, a, b) = (self.to_complex(), a.to_complex(), b.to_complex());
+        Self::from_complex(Complex::new(
+            z.re.mul_add(a.re, (-z.im).mul_add(a.im, b.re)),
+            z.re.mul_add(a.im, z.im.mul_add(a.re, b.im)),
+        ))
+    }
+
+    /// Exponential function
+    #[must_use]
+    pub fn exp(&self) -> Self {
+        let z = self.to_complex();
+        Self::from_complex(Complex::from_polar(z.re.exp(), z.im))
+    }
+
+    /// Natural logarithm, principal branch
+    #[must_use]
+    pub fn ln(&self) -> Self {
+        let z = self.to_complex();
+        Self::from_complex(Complex::new(z.norm().ln(), z.arg()))
+    }
+
+    /// Logarithm with given base, principal branch
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - base of the logarithm
+    #[must_use]
+    pub fn log(&self, base: Self) -> Self {
+        let (value, base) = (self.ln(), base.ln());
+        value / base
+    }
+
+    /// Base 2 logarithm, principal branch
+    #[must_use]
+    pub fn log2(&self) -> Self {
+        let z = self.ln().to_complex();
+        Self::from_complex(z.scale(std::f64::consts::LOG2_E))
+    }
+
+    /// Base 10 logarithm, principal branch
+    #[must_use]
+    pub fn log10(&self) -> Self {
+        let z = self.ln().to_complex();
+        Self::from_complex(z.scale(std::f64::consts::LOG10_E))
+    }
+
+    /// Square root, principal branch
+    #[must_use]
+    pub fn sqrt(&self) -> Self {
+        let z = self.to_complex();
+        let norm = z.norm();
+        let re = f64::midpoint(norm, z.re).sqrt();
+        let im = ((norm - z.re) / 2.).sqrt().copysign(z.im);
+        Self::from_complex(Complex::new(re, im))
+    }
+
+    /// Raise to complex power, principal branch
+    ///
+    /// # Arguments
+    ///
+    /// * `exponent` - power to raise to
+    #[must_use]
+    pub fn powc(&self, exponent: Self) -> Self {
+        let (z, w) = (self.to_complex(), exponent.to_complex());
+        if z.norm() == 0. {
+            return if w.norm() == 0. { Self::one() } else { Self::zero() }
+        }
+        Self::from_complex((w * z.ln()).exp())
+    }
+
+    /// Sine function
+    #[must_use]
+    pub fn sin(&self) -> Self {
+        let z = self.to_complex();
+        Self::from_complex(Complex::new(z.re.sin() * z.im.cosh(), z.re.cos() * z.im.sinh()))
+    }
+
+    /// Cosine function
+    #[must_use]
+    pub fn cos(&self) -> Self {
+        let z = self.to_complex();
+        Self::from_complex(Complex::new(z.re.cos() * z.im.cosh(), -z.re.sin() * z.im.sinh()))
+    }
+
+    /// Tangent function
+    #[must_use]
+    pub fn tan(&self) -> Self {
+        let z = self.to_complex();
+        let denominator = (2. * z.re).cos() + (2. * z.im).cosh();
+        Self::from_complex(Complex::new((2. * z.re).sin() / denominator, (2. * z.im).sinh() / denominator))
+    }
+
+    /// Inverse sine function, principal branch
+    #[must_use]
+    pub fn asin(&self) -> Self {
+        let (z, i) = (self.to_complex(), Complex::new(0., 1.));
+        Self::from_complex(-i * (i * z + (Complex::one() - z * z).sqrt()).ln())
+    }
+
+    /// Inverse cosine function, principal branch
+    #[must_use]
+    pub fn acos(&self) -> Self {
+        let z = self.asin().to_complex();
+        Self::from_complex(Complex::new(std::f64::consts::FRAC_PI_2, 0.) - z)
+    }
+
+    /// Inverse tangent function, principal branch
+    #[must_use]
+    pub fn atan(&self) -> Self {
+        let (z, i) = (self.to_complex(), Complex::new(0., 1.));
+        let difference = (Complex::one() - i * z).ln() - (Complex::one() + i * z).ln();
+        Self::from_complex(i.scale(0.5) * difference)
+    }
+
+    /// Hyperbolic sine function
+    #[must_use]
+    pub fn sinh(&self) -> Self {
+        let z = self.to_complex();
+        Self::from_complex(Complex::new(z.re.sinh() * z.im.cos(), z.re.cosh() * z.im.sin()))
+    }
+
+    /// Hyperbolic cosine function
+    #[must_use]
+    pub fn cosh(&self) -> Self {
+        let z = self.to_complex();
+        Self::from_complex(Complex::new(z.re.cosh() * z.im.cos(), z.re.sinh() * z.im.sin()))
+    }
+
+    /// Hyperbolic tangent function
+    #[must_use]
+    pub fn tanh(&self) -> Self {
+        let z = self.to_complex();
+        let denominator = (2. * z.re).cosh() + (2. * z.im).cos();
+        Self::from_complex(Complex::new((2. * z.re).sinh() / denominator, (2. * z.im).sin() / denominator))
+    }
+
+    /// Inverse hyperbolic sine function, principal branch
+    #[must_use]
+    pub fn asinh(&self) -> Self {
+        let z = self.to_complex();
+        Self::from_complex((z + (z * z + Complex::one()).sqrt()).ln())
+    }
+
+    /// Inverse hyperbolic cosine function, principal branch
+    #[must_use]
+    pub fn acosh(&self) -> Self {
+        let z = self.to_complex();
+        Self::from_complex((z + (z + Complex::one()).sqrt() * (z - Complex::one()).sqrt()).ln())
+    }
+
+    /// Inverse hyperbolic tangent function, principal branch
+    #[must_use]
+    pub fn atanh(&self) -> Self {
+        let z = self.to_complex();
+        let difference = (Complex::one() + z).ln() - (Complex::one() - z).ln();
+        Self::from_complex(difference.scale(0.5))
+    }
+}
+
+impl <F: Floating> ArrayElement for Complex<F> {
+
+    fn zero() -> Self {
+        Self::new(F::zero(), F::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(F::one(), F::zero())
+    }
+
+    fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+}
+
+impl <F: Floating> Numeric for Complex<F> {
+
+    fn rand(range: RangeInclusive<Self>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(F::rand(start.re..=end.re), F::rand(start.im..=end.im))
+    }
+
+    fn from_usize(value: usize) -> Self {
+        Self::new(F::from_usize(value), F::zero())
+    }
+
+    fn from_f64(value: f64) -> Self {
+        Self::new(F::from_f64(value), F::zero())
+    }
+
+    fn to_usize(&self) -> usize {
+        self.re.to_usize()
+    }
+
+    fn to_isize(&self) -> isize {
+        self.re.to_isize()
+    }
+
+    fn to_i32(&self) -> i32 {
+        self.re.to_i32()
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.re.to_f64()
+    }
+
+    fn is_inf(&self) -> bool {
+        self.re.is_inf() || self.im.is_inf()
+    }
+
+    fn max(&self) -> Self {
+        Self::new(self.re.max(), self.im.max())
+    }
+
+    // bitwise operations are not defined for complex numbers, nan is returned
+
+    fn bitwise_and(&self, _: &Self) -> Self {
+        Self::nan()
+    }
+
+    fn bitwise_or(&self, _: &Self) -> Self {
+        Self::nan()
+    }
+
+    fn bitwise_xor(&self, _: &Self) -> Self {
+        Self::nan()
+    }
+
+    fn bitwise_not(&self) -> Self {
+        Self::nan()
+    }
+
+    fn left_shift(&self, _: &Self) -> Self {
+        Self::nan()
+    }
+
+    fn right_shift(&self, _: &Self) -> Self {
+        Self::nan()
+    }
+
+    fn binary_repr(&self) -> String {
+        format!("{}+{}j", self.re.binary_repr(), self.im.binary_repr())
+    }
+
+    fn supports_bitwise() -> bool {
+        false
+    }
+
+    fn to_complex(&self) -> Complex<f64> {
+        Complex::new(self.re.to_f64(), self.im.to_f64())
+    }
+
+    fn from_complex(value: Complex<f64>) -> Self {
+        Self::new(F::from_f64(value.re), F::from_f64(value.im))
+    }
+
+    fn apply_unary(&self, _: fn(f64) -> f64, complex: fn(Complex<f64>) -> Complex<f64>) -> Self {
+        Self::from_complex(complex(self.to_complex()))
+    }
+
+    fn apply_binary(&self, other: &Self, _: fn(f64, f64) -> f64, complex: fn(Complex<f64>, Complex<f64>) -> Complex<f64>) -> Self {
+        Self::from_complex(complex(self.to_complex(), other.to_complex()))
+    }
+}
+
+impl <F: Floating> NumericOps for Complex<F> {}
+
+impl <F: Floating> SignedNumericOps for Complex<F> {}
+
+impl <F: Floating> FromStr for Complex<F> {
+    type Err = ArrayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = ArrayError::ParameterError { param: "`value`", message: "cannot be parsed as complex number" };
+        let parse_part = |part: &str| match part {
+            "" | "+" => Ok(F::one()),
+            "-" => Ok(-F::one()),
+            _ => part.parse::<F>().map_err(|_| error.clone()),
+        };
+
+        let value = s.trim().trim_start_matches('(').trim_end_matches(')');
+        let Some(value) = value.strip_suffix(['j', 'i']) else {
+            return value.parse::<F>().map(|re| Self::new(re, F::zero())).map_err(|_| error)
+        };
+        let split = value.char_indices()
+            .rfind(|&(idx, c)| idx > 0 && (c == '+' || c == '-') && !value[..idx].ends_with(['e', 'E']))
+            .map(|(idx, _)| idx);
+        match split {
+            Some(idx) => Ok(Self::new(value[..idx].parse::<F>().map_err(|_| error.clone())?, parse_part(&value[idx..])?)),
+            None => Ok(Self::new(F::zero(), parse_part(value)?)),
+        }
+    }
+}
+
+impl <F: Floating> Display for Complex<F> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im < F::zero() { write!(f, "{}-{}j", self.re, -self.im) }
+        else { write!(f, "{}+{}j", self.re, self.im) }
+    }
+}
+
+impl <F: Floating> Add for Complex<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl <F: Floating> Sub for Complex<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl <F: Floating> Mul for Complex<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl <F: Floating> Div for Complex<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let denominator = rhs.norm_sqr();
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / denominator,
+            (self.im * rhs.re - self.re * rhs.im) / denominator,
+        )
+    }
+}
+
+impl <F: Floating> Rem for Complex<F> {
+    type Output = Self;
+
+    /// Remainder of division with quotient truncated component-wise
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = (self / rhs).to_complex();
+        self - rhs * Self::from_complex(Complex::new(quotient.re.trunc(), quotient.im.trunc()))
+    }
+}
+
+impl <F: Floating> Neg for Complex<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+macro_rules! impl_complex_assign_op {
+    ($trait: ident, $fn: ident, $op: tt) => {
+        impl <F: Floating> $trait for Complex<F> {
+
+            fn $fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_complex_assign_op!(AddAssign, add_assign, +);
+impl_complex_assign_op!(SubAssign, sub_assign, -);
+impl_complex_assign_op!(MulAssign, mul_assign, *);
+impl_complex_assign_op!(DivAssign, div_assign, /);
+impl_complex_assign_op!(RemAssign, rem_assign, %);
@@ -1,7 +1,7 @@
-use crate::prelude::Numeric;
+use crate::prelude::SignedNumericOps;
 
 /// Floating type for array
-pub trait Floating: Numeric {}
+pub trait Floating: SignedNumericOps {}
 
 impl Floating for f64 {}
 impl Floating for f32 {}
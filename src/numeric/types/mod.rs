@@ -1,6 +1,9 @@
 /// Binary types definition
 pub mod binary;
 
+/// Complex types definition
+pub mod complex;
+
 /// Floating types definition
 pub mod floating;
 
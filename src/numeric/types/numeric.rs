@@ -4,17 +4,21 @@ use std::ops::RangeInclusive;
 use std::str::FromStr;
 use rand::{Rng, distributions::Uniform};
 
-use crate::core::types::ArrayElement;
+use crate::{
+    core::types::ArrayElement,
+    numeric::types::complex::Complex,
+};
 
-/// Numeric type for array
-pub trait Numeric: ArrayElement + Clone + Copy + Display + Debug + PartialEq + PartialOrd + FromStr {
+/// Numeric type for array.
+/// `'static` is required, so linalg routines can pass `f64` elements to the backend without copying
+pub trait Numeric: ArrayElement + Clone + Copy + Display + Debug + PartialEq + PartialOrd + FromStr + 'static {
 
     /// Generate random value
     fn rand(range: RangeInclusive<Self>) -> Self;
 
     /// Convert from any other numeric
     fn from<U: Numeric>(value: U) -> Self {
-        Self::from_f64(value.to_f64())
+        Self::from_complex(value.to_complex())
     }
 
     /// Convert from usize
@@ -68,6 +72,48 @@ pub trait Numeric: ArrayElement + Clone + Copy + Display + Debug + PartialEq + P
 
     /// binary representation of number
     fn binary_repr(&self) -> String;
+
+    /// Check if bitwise and shift operations are defined for type
+    #[must_use]
+    fn supports_bitwise() -> bool {
+        true
+    }
+
+    /// Convert to complex. imaginary part is zero for real types
+    fn to_complex(&self) -> Complex<f64> {
+        Complex::new(self.to_f64(), 0.)
+    }
+
+    /// Convert from complex. imaginary part is discarded for real types
+    #[must_use]
+    fn from_complex(value: Complex<f64>) -> Self {
+        Self::from_f64(value.re)
+    }
+
+    /// Apply function to value, using its complex counterpart for complex types
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - function applied to real values
+    /// * `complex` - function applied to complex values
+    #[must_use]
+    fn apply_unary(&self, real: fn(f64) -> f64, complex: fn(Complex<f64>) -> Complex<f64>) -> Self {
+        let _ = complex;
+        Self::from_f64(real(self.to_f64()))
+    }
+
+    /// Apply function to pair of values, using its complex counterpart for complex types
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - second argument of the function
+    /// * `real` - function applied to real values
+    /// * `complex` - function applied to complex values
+    #[must_use]
+    fn apply_binary(&self, other: &Self, real: fn(f64, f64) -> f64, complex: fn(Complex<f64>, Complex<f64>) -> Complex<f64>) -> Self {
+        let _ = complex;
+        Self::from_f64(real(self.to_f64(), other.to_f64()))
+    }
 }
 
 macro_rules! impl_numeric {
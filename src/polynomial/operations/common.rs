@@ -0,0 +1,191 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    linalg::prelude::*,
+    numeric::prelude::*,
+    polynomial::prelude::*,
+    validators::prelude::*,
+};
+
+const ABERTH_MAX_ITER: usize = 500;
+const REAL_ROOT_TOLERANCE: f64 = 1e-6;
+
+/// values of basis polynomials `P(0)..=P(deg)` at `t`
+pub(crate) fn basis_values(kind: PolynomialKind, t: f64, deg: usize) -> Vec<f64> {
+    let mut values = Vec::with_capacity(deg + 1);
+    values.push(1.);
+    (0..deg).for_each(|k| {
+        let (a, b) = kind.recurrence(k);
+        let prev = if k == 0 { 0. } else { values[k - 1] };
+        values.push((a * t).mul_add(values[k], -b * prev));
+    });
+    values
+}
+
+/// power series coefficients of basis polynomials `P(0)..=P(deg)`
+fn basis_power(kind: PolynomialKind, deg: usize) -> Vec<Vec<f64>> {
+    let mut basis: Vec<Vec<f64>> = vec![vec![1.]];
+    (0..deg).for_each(|k| {
+        let (a, b) = kind.recurrence(k);
+        let mut next = vec![0.; k + 2];
+        basis[k].iter().enumerate().for_each(|(i, &c)| next[i + 1] += a * c);
+        if k > 0 { basis[k - 1].iter().enumerate().for_each(|(i, &c)| next[i] -= b * c); }
+        basis.push(next);
+    });
+    basis
+}
+
+pub(crate) fn series_to_power(kind: PolynomialKind, coef: &[f64]) -> Vec<f64> {
+    if kind == PolynomialKind::Power { return coef.to_vec() }
+    let basis = basis_power(kind, coef.len() - 1);
+    (0..coef.len())
+        .map(|i| coef.iter().zip(&basis).skip(i).map(|(c, b)| c * b[i]).sum())
+        .collect()
+}
+
+pub(crate) fn power_to_series(kind: PolynomialKind, coef: &[f64]) -> Vec<f64> {
+    if kind == PolynomialKind::Power { return coef.to_vec() }
+    let basis = basis_power(kind, coef.len() - 1);
+    let (mut rest, mut result) = (coef.to_vec(), vec![0.; coef.len()]);
+    (0..coef.len()).rev().for_each(|j| {
+        result[j] = rest[j] / basis[j][j];
+        basis[j].iter().enumerate().for_each(|(i, &b)| rest[i] -= result[j] * b);
+    });
+    result
+}
+
+pub(crate) fn power_eval(coef: &[f64], t: f64) -> f64 {
+    coef.iter().rev().fold(0., |acc, &c| acc.mul_add(t, c))
+}
+
+pub(crate) fn power_der(coef: &[f64]) -> Vec<f64> {
+    if coef.len() < 2 { return vec![0.] }
+    coef.iter().enumerate().skip(1)
+        .map(|(i, &c)| i.to_f64() * c)
+        .collect()
+}
+
+pub(crate) fn power_int(coef: &[f64]) -> Vec<f64> {
+    std::iter::once(0.)
+        .chain(coef.iter().enumerate().map(|(i, &c)| c / (i + 1).to_f64()))
+        .collect()
+}
+
+pub(crate) fn power_mul(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.; a.len() + b.len() - 1];
+    a.iter().enumerate().for_each(|(i, &x)| b.iter().enumerate()
+        .for_each(|(j, &y)| result[i + j] = x.mul_add(y, result[i + j])));
+    result
+}
+
+/// substitutes `t = a + b * s` into power series in `t`
+pub(crate) fn power_compose_linear(coef: &[f64], a: f64, b: f64) -> Vec<f64> {
+    let mut result = coef.iter().rev().fold(vec![0.], |acc, &c| {
+        let mut result = power_mul(&acc, &[a, b]);
+        result[0] += c;
+        result
+    });
+    result.truncate(coef.len());
+    result
+}
+
+/// roots of power series, sorted by real and then imaginary part. eigenvalues of the companion matrix
+/// are used as initial estimates, refined simultaneously with aberth iterations
+pub(crate) fn power_roots(coef: &[f64]) -> Result<Vec<Complex<f64>>, ArrayError> {
+    let last = coef.iter().rposition(|&c| c != 0.).map_or(0, |i| i + 1);
+    let first = coef[..last].iter().position(|&c| c != 0.).unwrap_or(0);
+    let coef = &coef[first..last];
+    let deg = coef.len().saturating_sub(1);
+
+    let mut roots = vec![Complex::new(0., 0.); first];
+    match deg {
+        0 => (),
+        1 => roots.push(Complex::new(-coef[0] / coef[1], 0.)),
+        _ => {
+            let elements = (0..deg * deg)
+                .map(|idx| match (idx / deg, idx % deg) {
+                    (0, j) => -coef[deg - 1 - j] / coef[deg],
+                    (i, j) => if i == j + 1 { 1. } else { 0. },
+                })
+                .collect();
+            let estimates = Array::new(elements, vec![deg, deg]).eigvals()?.get_elements()?;
+            roots.extend(aberth(coef, &estimates).into_iter()
+                .map(|z| if z.im.abs() <= REAL_ROOT_TOLERANCE * z.norm().max(1.) { Complex::new(z.re, 0.) } else { z }));
+        },
+    }
+    sort_roots(&mut roots);
+    Ok(roots)
+}
+
+pub(crate) fn sort_roots(roots: &mut [Complex<f64>]) {
+    roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+}
+
+fn aberth(coef: &[f64], estimates: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let der = power_der(coef);
+    let eval = |coef: &[f64], z: Complex<f64>| coef.iter().rev()
+        .fold(Complex::new(0., 0.), |acc, &c| acc * z + Complex::new(c, 0.));
+
+    // estimates are slightly perturbed, so that repeated roots are separated
+    let count = estimates.len().to_f64();
+    let mut roots = estimates.iter().enumerate()
+        .map(|(k, &e)| {
+            let e = if e.re.is_finite() && e.im.is_finite() { e } else { Complex::new(0., 0.) };
+            let theta = 2. * std::f64::consts::PI * k.to_f64() / count + 0.4;
+            e + Complex::from_polar(1e-3 * e.norm().max(1.), theta)
+        })
+        .collect::<Vec<Complex<f64>>>();
+
+    for _ in 0..ABERTH_MAX_ITER {
+        let mut converged = true;
+        for k in 0..roots.len() {
+            let (value, slope) = (eval(coef, roots[k]), eval(&der, roots[k]));
+            if value.norm() == 0. || slope.norm() == 0. { continue }
+            let ratio = value / slope;
+            let repulsion = roots.iter().enumerate()
+                .filter(|(j, _)| *j != k)
+                .fold(Complex::new(0., 0.), |acc, (_, &z)| acc + Complex::new(1., 0.) / (roots[k] - z));
+            let step = ratio / (Complex::new(1., 0.) - ratio * repulsion);
+            if !step.re.is_finite() || !step.im.is_finite() { continue }
+            roots[k] -= step;
+            converged &= step.norm() <= f64::EPSILON * roots[k].norm().max(1.);
+        }
+        if converged { break }
+    }
+    roots
+}
+
+/// weighted least squares fit of `y` to rows of basis values
+pub(crate) fn fit_least_squares<N: NumericOps>(rows: Vec<Vec<f64>>, y: &Array<N>, w: Option<Array<N>>, deg: usize) -> Result<Vec<f64>, ArrayError> {
+    y.is_dim_supported(&[1])?;
+    let y = y.get_elements()?.iter().map(Numeric::to_f64).collect::<Vec<f64>>();
+    y.len().is_equal(&rows.len())?;
+    let w = match w {
+        Some(w) => {
+            w.is_dim_supported(&[1])?;
+            let w = w.get_elements()?.iter().map(Numeric::to_f64).collect::<Vec<f64>>();
+            w.len().is_equal(&y.len())?;
+            w
+        },
+        None => vec![1.; y.len()],
+    };
+    if rows.len() <= deg {
+        return Err(ArrayError::ParameterError { param: "`deg`", message: "must be lower than number of sample points" })
+    }
+
+    // columns are scaled to unit norm, to improve the condition of the vandermonde-like matrix
+    let (m, n) = (rows.len(), deg + 1);
+    let matrix = rows.into_iter().zip(&w)
+        .flat_map(|(row, &w)| row.into_iter().map(move |v| v * w))
+        .collect::<Vec<f64>>();
+    let scale = (0..n)
+        .map(|j| matrix.iter().skip(j).step_by(n).map(|v| v * v).sum::<f64>().sqrt())
+        .map(|norm| if norm > 0. { norm } else { 1. })
+        .collect::<Vec<f64>>();
+    let matrix = matrix.iter().enumerate().map(|(idx, v)| v / scale[idx % n]).collect();
+    let rhs = y.iter().zip(&w).map(|(y, w)| y * w).collect();
+
+    let (result, _, rank, _) = Array::new(matrix, vec![m, n])?.lstsq(&Array::flat(rhs)?, None)?;
+    if rank[0] < n { return Err(ArrayError::SingularMatrix) }
+    Ok(result.get_elements()?.into_iter().zip(scale).map(|(x, s)| x / s).collect())
+}
@@ -0,0 +1,312 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    polynomial::operations::common::{fit_least_squares, power_der, power_int, power_roots},
+    validators::prelude::*,
+};
+
+/// `ArrayTrait` - Array Polynomial functions.
+/// coefficients are ordered from the highest degree to the constant term
+pub trait ArrayPolynomial<N: NumericOps> where Self: Sized + Clone {
+
+    /// Evaluate a polynomial at specific values
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - values at which to evaluate the polynomial
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(f64, 3., 0., 1.);
+    /// assert_eq!(array!(f64, [[1., 4.], [13., 28.]]), arr.polyval(&array!(f64, [[0., 1.], [2., 3.]]).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn polyval(&self, x: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Find the sum of two polynomials
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - polynomial to add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(i32, 1, 2);
+    /// assert_eq!(array_flat!(i32, 9, 6, 6), arr.polyadd(&array_flat!(i32, 9, 5, 4).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn polyadd(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Difference (subtraction) of two polynomials
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - polynomial to subtract
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(i32, 2, 10, -2);
+    /// assert_eq!(array_flat!(i32, 1, 5, -4), arr.polysub(&array_flat!(i32, 1, 5, 2).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn polysub(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Find the product of two polynomials
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - polynomial to multiply by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(i32, 1, 2, 3);
+    /// assert_eq!(array_flat!(i32, 9, 23, 39, 19, 6), arr.polymul(&array_flat!(i32, 9, 5, 2).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn polymul(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Return the derivative of the specified order of a polynomial
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - order of differentiation. optional, defaults to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(f64, 1., 1., 1., 1.);
+    /// assert_eq!(array_flat!(f64, 3., 2., 1.), arr.polyder(None));
+    /// assert_eq!(array_flat!(f64, 6., 2.), arr.polyder(Some(2)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn polyder(&self, m: Option<usize>) -> Result<Array<N>, ArrayError>;
+
+    /// Return an antiderivative (indefinite integral) of a polynomial
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - order of the antiderivative. optional, defaults to 1
+    /// * `k` - integration constants, one for each integration. optional, defaults to zeros
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(f64, 3., 2., 1.);
+    /// assert_eq!(array_flat!(f64, 1., 1., 1., 0.), arr.polyint(None, None));
+    /// assert_eq!(array_flat!(f64, 1., 1., 1., 5.), arr.polyint(None, Some(vec![5.])));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn polyint(&self, m: Option<usize>, k: Option<Vec<N>>) -> Result<Array<N>, ArrayError>;
+
+    /// Return the roots of a polynomial, sorted by real and then imaginary part.
+    /// eigenvalues of the companion matrix are refined to roots with aberth iterations
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = array_flat!(f64, 1., 0., -5., 0., 4.);
+    /// let expected = Array::flat(vec![-2., -1., 1., 2.].into_iter().map(|re| Complex::new(re, 0.)).collect());
+    /// assert_eq!(expected, arr.roots());
+    ///
+    /// let arr = array_flat!(f64, 1., 0., 1.);
+    /// assert_eq!(Array::flat(vec![Complex::new(0., -1.), Complex::new(0., 1.)]), arr.roots());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn roots(&self) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Least squares polynomial fit. self is treated as x-coordinates of the sample points
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - y-coordinates of the sample points
+    /// * `deg` - degree of the fitting polynomial
+    /// * `w` - weights applied to the residuals. optional
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let x = array_flat!(f64, 0., 1., 2., 3.);
+    /// let coef = x.polyfit(&array_flat!(f64, 1., 2., 5., 10.).unwrap(), 2, None);
+    /// assert_eq!(array_flat!(f64, 1., 0., 1.), coef.unwrap().map(|&c| c.round()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn polyfit(&self, y: &Array<N>, deg: usize, w: Option<Array<N>>) -> Result<Array<N>, ArrayError>;
+}
+
+impl <N: NumericOps> ArrayPolynomial<N> for Array<N> {
+
+    fn polyval(&self, x: &Self) -> Result<Self, ArrayError> {
+        let coef = self.get_coefficients()?;
+        x.map(|&x| N::from_f64(coef.iter().fold(0., |acc, &c| acc.mul_add(x.to_f64(), c))))
+            .reshape(&x.get_shape()?)
+    }
+
+    fn polyadd(&self, other: &Self) -> Result<Self, ArrayError> {
+        self.combine(other, |a, b| a + b)
+    }
+
+    fn polysub(&self, other: &Self) -> Result<Self, ArrayError> {
+        self.combine(other, |a, b| a - b)
+    }
+
+    fn polymul(&self, other: &Self) -> Result<Self, ArrayError> {
+        let (a, b) = (self.ravel()?.get_elements()?, other.ravel()?.get_elements()?);
+        if a.is_empty() || b.is_empty() { return Self::flat(vec![]) }
+        let mut result = vec![N::zero(); a.len() + b.len() - 1];
+        a.iter().enumerate().for_each(|(i, &x)| b.iter().enumerate()
+            .for_each(|(j, &y)| result[i + j] += x * y));
+        Self::flat(result)
+    }
+
+    fn polyder(&self, m: Option<usize>) -> Result<Self, ArrayError> {
+        let coef = (0..m.unwrap_or(1))
+            .fold(self.get_coefficients_increasing()?, |coef, _| power_der(&coef));
+        Self::from_coefficients_increasing(coef)
+    }
+
+    fn polyint(&self, m: Option<usize>, k: Option<Vec<N>>) -> Result<Self, ArrayError> {
+        let m = m.unwrap_or(1);
+        let k = k.unwrap_or_else(|| vec![N::zero(); m]);
+        k.len().is_equal(&m)?;
+        let coef = k.iter()
+            .fold(self.get_coefficients_increasing()?, |coef, k| {
+                let mut coef = power_int(&coef);
+                coef[0] = k.to_f64();
+                coef
+            });
+        Self::from_coefficients_increasing(coef)
+    }
+
+    fn roots(&self) -> Result<Array<Complex<f64>>, ArrayError> {
+        Array::flat(power_roots(&self.get_coefficients_increasing()?)?)
+    }
+
+    fn polyfit(&self, y: &Self, deg: usize, w: Option<Self>) -> Result<Self, ArrayError> {
+        self.is_dim_supported(&[1])?;
+        let rows = Array::flat(self.get_elements()?.iter().map(Numeric::to_f64).collect())?
+            .vander(Some(deg + 1), None)?
+            .get_elements()?
+            .chunks(deg + 1)
+            .map(<[f64]>::to_vec)
+            .collect();
+        let coef = fit_least_squares(rows, y, w, deg)?;
+        Self::flat(coef.into_iter().map(N::from_f64).collect())
+    }
+}
+
+impl <N: NumericOps> ArrayPolynomial<N> for Result<Array<N>, ArrayError> {
+
+    fn polyval(&self, x: &Array<N>) -> Self {
+        self.clone()?.polyval(x)
+    }
+
+    fn polyadd(&self, other: &Array<N>) -> Self {
+        self.clone()?.polyadd(other)
+    }
+
+    fn polysub(&self, other: &Array<N>) -> Self {
+        self.clone()?.polysub(other)
+    }
+
+    fn polymul(&self, other: &Array<N>) -> Self {
+        self.clone()?.polymul(other)
+    }
+
+    fn polyder(&self, m: Option<usize>) -> Self {
+        self.clone()?.polyder(m)
+    }
+
+    fn polyint(&self, m: Option<usize>, k: Option<Vec<N>>) -> Self {
+        self.clone()?.polyint(m, k)
+    }
+
+    fn roots(&self) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.roots()
+    }
+
+    fn polyfit(&self, y: &Array<N>, deg: usize, w: Option<Array<N>>) -> Self {
+        self.clone()?.polyfit(y, deg, w)
+    }
+}
+
+trait PolynomialFunctionsHelper<N: NumericOps> {
+
+    fn get_coefficients(&self) -> Result<Vec<f64>, ArrayError>;
+
+    fn get_coefficients_increasing(&self) -> Result<Vec<f64>, ArrayError>;
+
+    fn from_coefficients_increasing(coef: Vec<f64>) -> Result<Array<N>, ArrayError>;
+
+    fn combine<F: Fn(N, N) -> N>(&self, other: &Array<N>, f: F) -> Result<Array<N>, ArrayError>;
+}
+
+impl <N: NumericOps> PolynomialFunctionsHelper<N> for Array<N> {
+
+    fn get_coefficients(&self) -> Result<Vec<f64>, ArrayError> {
+        self.is_dim_supported(&[1])?;
+        self.len()?.is_at_least(&1)?;
+        Ok(self.get_elements()?.iter().map(Numeric::to_f64).collect())
+    }
+
+    fn get_coefficients_increasing(&self) -> Result<Vec<f64>, ArrayError> {
+        let mut coef = self.get_coefficients()?;
+        coef.reverse();
+        Ok(coef)
+    }
+
+    fn from_coefficients_increasing(coef: Vec<f64>) -> Result<Self, ArrayError> {
+        Self::flat(coef.into_iter().rev().map(N::from_f64).collect())
+    }
+
+    fn combine<F: Fn(N, N) -> N>(&self, other: &Self, f: F) -> Result<Self, ArrayError> {
+        let (a, b) = (self.ravel()?.get_elements()?, other.ravel()?.get_elements()?);
+        let len = a.len().max(b.len());
+        let pad = |v: Vec<N>| std::iter::repeat_n(N::zero(), len - v.len()).chain(v);
+        Self::flat(pad(a).zip(pad(b)).map(|(a, b)| f(a, b)).collect())
+    }
+}
@@ -0,0 +1,11 @@
+/// array polynomial common functions
+pub(crate) mod common;
+
+/// array polynomial functions
+pub mod functions;
+
+/// polynomial series functions
+pub mod series;
+
+/// polynomial series ops functions
+pub mod ops;
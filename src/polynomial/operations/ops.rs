@@ -0,0 +1,66 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{
+    errors::prelude::*,
+    numeric::prelude::*,
+    polynomial::{
+        operations::common::{power_mul, power_to_series, series_to_power},
+        prelude::*,
+    },
+};
+
+macro_rules! impl_polynomial_op {
+    ($op_trait: ident, $op_func: ident, $op: tt) => {
+        impl <N: NumericOps> $op_trait<Self> for Polynomial<N> {
+            type Output = Result<Self, ArrayError>;
+
+            fn $op_func(self, other: Self) -> Self::Output {
+                self.is_compatible(&other)?;
+                let (a, b) = (self.coef_f64(), other.coef_f64());
+                let coef = (0..a.len().max(b.len()))
+                    .map(|i| a.get(i).unwrap_or(&0.) $op b.get(i).unwrap_or(&0.))
+                    .collect();
+                self.with_coef(coef)
+            }
+        }
+    };
+}
+
+impl_polynomial_op!(Add, add, +);
+impl_polynomial_op!(Sub, sub, -);
+
+impl <N: NumericOps> Mul<Self> for Polynomial<N> {
+    type Output = Result<Self, ArrayError>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.is_compatible(&other)?;
+        let kind = self.get_kind();
+        let coef = power_mul(
+            &series_to_power(kind, &self.coef_f64()),
+            &series_to_power(kind, &other.coef_f64()),
+        );
+        self.with_coef(power_to_series(kind, &coef))
+    }
+}
+
+impl <N: NumericOps> Mul<N> for Polynomial<N> {
+    type Output = Result<Self, ArrayError>;
+
+    fn mul(self, other: N) -> Self::Output {
+        let coef = self.coef_f64().into_iter()
+            .map(|c| c * other.to_f64())
+            .collect();
+        self.with_coef(coef)
+    }
+}
+
+impl <N: NumericOps> Neg for Polynomial<N> {
+    type Output = Result<Self, ArrayError>;
+
+    fn neg(self) -> Self::Output {
+        let coef = self.coef_f64().into_iter()
+            .map(|c| -c)
+            .collect();
+        self.with_coef(coef)
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    polynomial::{
+        operations::common::{
+            basis_values,
+            power_compose_linear,
+            power_der,
+            power_eval,
+            power_int,
+            power_roots,
+            power_to_series,
+            series_to_power,
+            sort_roots,
+        },
+        prelude::*,
+    },
+    validators::prelude::*,
+};
+
+/// `PolynomialTrait` - Polynomial Series functions
+pub trait PolynomialSeries<N: NumericOps> where Self: Sized + Clone {
+
+    /// Evaluate the series at specific values
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - values at which to evaluate the series
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let poly = Polynomial::new(&array_flat!(f64, 1., 2., 3.).unwrap(), Some("legendre"), None, None);
+    /// assert_eq!(array_flat!(f64, 6., -0.5), poly.eval(&array_flat!(f64, 1., 0.).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn eval(&self, x: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Differentiate the series
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - order of differentiation. optional, defaults to 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let poly = Polynomial::new(&array_flat!(f64, 1., 2., 3.).unwrap(), None::<PolynomialKind>, None, None);
+    /// assert_eq!(array_flat!(f64, 2., 6.), poly.deriv(None).map(|p| p.get_coef()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn deriv(&self, m: Option<usize>) -> Result<Polynomial<N>, ArrayError>;
+
+    /// Integrate the series. each antiderivative equals its integration constant at `x = 0`
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - order of integration. optional, defaults to 1
+    /// * `k` - integration constants, one for each integration. optional, defaults to zeros
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let poly = Polynomial::new(&array_flat!(f64, 2., 6.).unwrap(), None::<PolynomialKind>, None, None);
+    /// assert_eq!(array_flat!(f64, 1., 2., 3.), poly.integ(None, Some(vec![1.])).map(|p| p.get_coef()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn integ(&self, m: Option<usize>, k: Option<Vec<N>>) -> Result<Polynomial<N>, ArrayError>;
+
+    /// Return the roots of the series, sorted by real and then imaginary part
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let poly = Polynomial::new(&array_flat!(f64, 0., 0., 1.).unwrap(), Some("chebyshev"), Some([0., 2.]), None);
+    /// let roots = poly.roots().unwrap();
+    /// assert!((roots[0] - Complex::new(1. - 0.5_f64.sqrt(), 0.)).norm() < 1e-12);
+    /// assert!((roots[1] - Complex::new(1. + 0.5_f64.sqrt(), 0.)).norm() < 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn roots(&self) -> Result<Array<Complex<f64>>, ArrayError>;
+
+    /// Convert the series to different kind, domain or window
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - basis of the converted series. optional, defaults to current kind
+    /// * `domain` - domain of the converted series. optional, defaults to current domain
+    /// * `window` - window of the converted series. optional, defaults to current window
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let poly = Polynomial::new(&array_flat!(f64, 0., 0., 1.).unwrap(), Some("chebyshev"), None, None);
+    /// assert_eq!(array_flat!(f64, -1., 0., 2.), poly.convert(Some("power"), None, None).map(|p| p.get_coef()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn convert(&self, kind: Option<impl PolynomialKindType>, domain: Option<[f64; 2]>, window: Option<[f64; 2]>) -> Result<Polynomial<N>, ArrayError>;
+}
+
+impl <N: NumericOps> PolynomialSeries<N> for Polynomial<N> {
+
+    fn eval(&self, x: &Array<N>) -> Result<Array<N>, ArrayError> {
+        let (coef, kind) = (self.coef_f64(), self.get_kind());
+        x.map(|&x| {
+            let values = basis_values(kind, self.map_argument(x.to_f64()), coef.len() - 1);
+            N::from_f64(coef.iter().zip(values).map(|(c, v)| c * v).sum())
+        }).reshape(&x.get_shape()?)
+    }
+
+    fn deriv(&self, m: Option<usize>) -> Result<Self, ArrayError> {
+        let (kind, (_, scale)) = (self.get_kind(), self.map_parameters());
+        let coef = (0..m.unwrap_or(1))
+            .fold(series_to_power(kind, &self.coef_f64()), |coef, _| power_der(&coef)
+                .into_iter().map(|c| c * scale).collect());
+        self.with_coef(power_to_series(kind, &coef))
+    }
+
+    fn integ(&self, m: Option<usize>, k: Option<Vec<N>>) -> Result<Self, ArrayError> {
+        let m = m.unwrap_or(1);
+        let k = k.unwrap_or_else(|| vec![N::zero(); m]);
+        k.len().is_equal(&m)?;
+        let (kind, (offset, scale)) = (self.get_kind(), self.map_parameters());
+        let coef = k.iter()
+            .fold(series_to_power(kind, &self.coef_f64()), |coef, k| {
+                let mut coef = power_int(&coef).into_iter().map(|c| c / scale).collect::<Vec<f64>>();
+                coef[0] += k.to_f64() - power_eval(&coef, offset);
+                coef
+            });
+        self.with_coef(power_to_series(kind, &coef))
+    }
+
+    fn roots(&self) -> Result<Array<Complex<f64>>, ArrayError> {
+        let (offset, scale) = self.map_parameters();
+        let mut roots = power_roots(&series_to_power(self.get_kind(), &self.coef_f64()))?
+            .into_iter()
+            .map(|t| Complex::new((t.re - offset) / scale, t.im / scale))
+            .collect::<Vec<Complex<f64>>>();
+        sort_roots(&mut roots);
+        Array::flat(roots)
+    }
+
+    fn convert(&self, kind: Option<impl PolynomialKindType>, domain: Option<[f64; 2]>, window: Option<[f64; 2]>) -> Result<Self, ArrayError> {
+        let kind = match kind {
+            Some(kind) => kind.to_kind()?,
+            None => self.get_kind(),
+        };
+        let target = Self::new(
+            &self.get_coef(),
+            Some(kind),
+            Some(domain.unwrap_or_else(|| self.get_domain())),
+            Some(window.unwrap_or_else(|| self.get_window())),
+        )?;
+
+        let ((offset, scale), (target_offset, target_scale)) = (self.map_parameters(), target.map_parameters());
+        let coef = power_compose_linear(
+            &series_to_power(self.get_kind(), &self.coef_f64()),
+            (scale / target_scale).mul_add(-target_offset, offset),
+            scale / target_scale,
+        );
+        target.with_coef(power_to_series(kind, &coef))
+    }
+}
+
+impl <N: NumericOps> PolynomialSeries<N> for Result<Polynomial<N>, ArrayError> {
+
+    fn eval(&self, x: &Array<N>) -> Result<Array<N>, ArrayError> {
+        self.clone()?.eval(x)
+    }
+
+    fn deriv(&self, m: Option<usize>) -> Self {
+        self.clone()?.deriv(m)
+    }
+
+    fn integ(&self, m: Option<usize>, k: Option<Vec<N>>) -> Self {
+        self.clone()?.integ(m, k)
+    }
+
+    fn roots(&self) -> Result<Array<Complex<f64>>, ArrayError> {
+        self.clone()?.roots()
+    }
+
+    fn convert(&self, kind: Option<impl PolynomialKindType>, domain: Option<[f64; 2]>, window: Option<[f64; 2]>) -> Self {
+        self.clone()?.convert(kind, domain, window)
+    }
+}
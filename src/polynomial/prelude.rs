@@ -0,0 +1,11 @@
+#[cfg(feature = "polynomial")]
+pub use crate::polynomial::{
+    operations::{
+        functions::ArrayPolynomial,
+        series::PolynomialSeries,
+    },
+    types::{
+        polynomial::Polynomial,
+        polynomial_kind::{PolynomialKind, PolynomialKindType},
+    },
+};
@@ -0,0 +1,5 @@
+/// Polynomial type definition
+pub mod polynomial;
+
+/// Polynomial kind type definition
+pub mod polynomial_kind;
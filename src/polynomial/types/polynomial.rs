@@ -0,0 +1,173 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    polynomial::{
+        operations::common::{basis_values, fit_least_squares},
+        prelude::*,
+    },
+    validators::prelude::*,
+};
+
+/// Polynomial series, with coefficients in increasing order of degree.
+/// argument is mapped from `domain` to `window` before evaluating the series
+#[derive(Clone, Debug)]
+pub struct Polynomial<N: NumericOps> {
+    coef: Array<N>,
+    kind: PolynomialKind,
+    domain: [f64; 2],
+    window: [f64; 2],
+}
+
+impl <N: NumericOps> Polynomial<N> {
+
+    /// Create new polynomial series
+    ///
+    /// # Arguments
+    ///
+    /// * `coef` - series coefficients, in increasing order of degree
+    /// * `kind` - basis of the series. optional, defaults to `Power`
+    /// * `domain` - interval mapped to `window`. optional, defaults to [-1, 1]
+    /// * `window` - interval of the series argument. optional, defaults to [-1, 1]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let poly = Polynomial::new(&array_flat!(f64, 1., 2., 3.).unwrap(), None::<PolynomialKind>, None, None).unwrap();
+    /// assert_eq!(2, poly.degree());
+    /// assert_eq!(array_flat!(f64, 6., 17.), poly.eval(&array_flat!(f64, 1., 2.).unwrap()));
+    ///
+    /// let poly = Polynomial::new(&array_flat!(f64, 0., 0., 1.).unwrap(), Some("chebyshev"), Some([0., 2.]), None).unwrap();
+    /// assert_eq!(array_flat!(f64, 1., -1., 1.), poly.eval(&array_flat!(f64, 0., 1., 2.).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn new(coef: &Array<N>, kind: Option<impl PolynomialKindType>, domain: Option<[f64; 2]>, window: Option<[f64; 2]>) -> Result<Self, ArrayError> {
+        coef.is_dim_supported(&[1])?;
+        coef.len()?.is_at_least(&1)?;
+        let kind = match kind {
+            Some(kind) => kind.to_kind()?,
+            None => PolynomialKind::default(),
+        };
+        let (domain, window) = (domain.unwrap_or([-1., 1.]), window.unwrap_or([-1., 1.]));
+        if domain.iter().chain(&window).any(|v| !v.is_finite()) {
+            return Err(ArrayError::ParameterError { param: "`domain`, `window`", message: "bounds must be finite" })
+        }
+        if (domain[1] - domain[0]).abs() <= f64::EPSILON || (window[1] - window[0]).abs() <= f64::EPSILON {
+            return Err(ArrayError::ParameterError { param: "`domain`, `window`", message: "bounds must be distinct" })
+        }
+        Ok(Self { coef: coef.clone(), kind, domain, window })
+    }
+
+    /// Least squares fit of polynomial series to data
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - x-coordinates of the sample points
+    /// * `y` - y-coordinates of the sample points
+    /// * `deg` - degree of the fitting series
+    /// * `w` - weights applied to the residuals. optional
+    /// * `kind` - basis of the series. optional, defaults to `Power`
+    /// * `domain` - domain of the series. optional, defaults to range of `x`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let x = array_flat!(f64, 0., 1., 2., 3.).unwrap();
+    /// let y = array_flat!(f64, 1., 3., 5., 7.).unwrap();
+    /// let poly = Polynomial::fit(&x, &y, 1, None, None::<PolynomialKind>, None).unwrap();
+    /// assert_eq!([0., 3.], poly.get_domain());
+    /// assert_eq!(array_flat!(f64, 4., 3.), poly.get_coef().map(|c| c.round()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn fit(x: &Array<N>, y: &Array<N>, deg: usize, w: Option<Array<N>>, kind: Option<impl PolynomialKindType>, domain: Option<[f64; 2]>) -> Result<Self, ArrayError> {
+        x.is_dim_supported(&[1])?;
+        let x = x.get_elements()?.iter().map(Numeric::to_f64).collect::<Vec<f64>>();
+        let domain = domain.unwrap_or_else(|| [
+            x.iter().copied().fold(f64::INFINITY, f64::min),
+            x.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        ]);
+        let empty = Self::new(&Array::single(N::zero())?, kind, Some(domain), None)?;
+
+        let rows = x.iter()
+            .map(|&x| basis_values(empty.kind, empty.map_argument(x), deg))
+            .collect();
+        let coef = fit_least_squares(rows, y, w, deg)?;
+        Self::new(&Array::flat(coef.into_iter().map(N::from_f64).collect())?, Some(empty.kind), Some(domain), None)
+    }
+
+    /// Series coefficients, in increasing order of degree
+    #[must_use]
+    pub fn get_coef(&self) -> Array<N> {
+        self.coef.clone()
+    }
+
+    /// Basis of the series
+    #[must_use]
+    pub const fn get_kind(&self) -> PolynomialKind {
+        self.kind
+    }
+
+    /// Domain of the series
+    #[must_use]
+    pub const fn get_domain(&self) -> [f64; 2] {
+        self.domain
+    }
+
+    /// Window of the series
+    #[must_use]
+    pub const fn get_window(&self) -> [f64; 2] {
+        self.window
+    }
+
+    /// Degree of the series
+    #[must_use]
+    pub fn degree(&self) -> usize {
+        self.coef.len().unwrap_or(1) - 1
+    }
+
+    /// offset and scale of the linear map from `domain` to `window`
+    pub(crate) fn map_parameters(&self) -> (f64, f64) {
+        let ([d_0, d_1], [w_0, w_1]) = (self.domain, self.window);
+        let scale = (w_1 - w_0) / (d_1 - d_0);
+        (scale.mul_add(-d_0, w_0), scale)
+    }
+
+    pub(crate) fn map_argument(&self, x: f64) -> f64 {
+        let (offset, scale) = self.map_parameters();
+        scale.mul_add(x, offset)
+    }
+
+    pub(crate) fn coef_f64(&self) -> Vec<f64> {
+        self.coef.get_elements().unwrap_or_default().iter().map(Numeric::to_f64).collect()
+    }
+
+    pub(crate) fn with_coef(&self, coef: Vec<f64>) -> Result<Self, ArrayError> {
+        let coef = Array::flat(coef.into_iter().map(N::from_f64).collect())?;
+        Ok(Self { coef, ..self.clone() })
+    }
+
+    pub(crate) fn is_compatible(&self, other: &Self) -> Result<(), ArrayError> {
+        let same = |a: [f64; 2], b: [f64; 2]| a.iter().zip(b).all(|(a, b)| (a - b).abs() <= f64::EPSILON * a.abs().max(b.abs()).max(1.));
+        if self.kind == other.kind && same(self.domain, other.domain) && same(self.window, other.window) { Ok(()) }
+        else { Err(ArrayError::ParameterError { param: "`other`", message: "polynomials must have the same kind, domain and window" }) }
+    }
+}
+
+impl <N: NumericOps> PartialEq for Polynomial<N> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.is_compatible(other).is_ok()
+            && self.coef.get_shape() == other.coef.get_shape()
+            && self.coef == other.coef
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    errors::prelude::*,
+    numeric::prelude::*,
+};
+
+/// the basis of polynomial series
+#[derive(Copy, Clone, Debug, Default, Ord, PartialOrd, Eq, PartialEq)]
+pub enum PolynomialKind {
+    /// power series, `1, x, x^2, ...`
+    #[default]
+    Power,
+    /// Chebyshev polynomials of the first kind
+    Chebyshev,
+    /// Legendre polynomials
+    Legendre,
+    /// Hermite polynomials, physicists' version
+    Hermite,
+}
+
+/// `PolynomialKind` trait
+pub trait PolynomialKindType {
+
+    /// Parse input to `PolynomialKind` type
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_kind(self) -> Result<PolynomialKind, ArrayError>;
+}
+
+impl PolynomialKindType for PolynomialKind {
+
+    fn to_kind(self) -> Result<PolynomialKind, ArrayError> {
+        Ok(self)
+    }
+}
+
+impl PolynomialKindType for &str {
+
+    fn to_kind(self) -> Result<PolynomialKind, ArrayError> {
+        match self {
+            "power" => Ok(PolynomialKind::Power),
+            "chebyshev" => Ok(PolynomialKind::Chebyshev),
+            "legendre" => Ok(PolynomialKind::Legendre),
+            "hermite" => Ok(PolynomialKind::Hermite),
+            _ => Err(ArrayError::ParameterError { param: "`kind`", message: "must be one of {`power`, `chebyshev`, `legendre`, `hermite`}" })
+        }
+    }
+}
+
+impl PolynomialKindType for String {
+
+    fn to_kind(self) -> Result<PolynomialKind, ArrayError> {
+        self.as_str().to_kind()
+    }
+}
+
+impl PolynomialKind {
+
+    /// coefficients `(a, b)` of the three-term recurrence `P(k+1) = a * x * P(k) - b * P(k-1)`
+    pub(crate) fn recurrence(self, k: usize) -> (f64, f64) {
+        let k_f = k.to_f64();
+        match (self, k) {
+            (Self::Power, _) | (Self::Chebyshev | Self::Legendre, 0) => (1., 0.),
+            (Self::Chebyshev, _) => (2., 1.),
+            (Self::Legendre, _) => (2_f64.mul_add(k_f, 1.) / (k_f + 1.), k_f / (k_f + 1.)),
+            (Self::Hermite, _) => (2., 2. * k_f),
+        }
+    }
+}
@@ -6,6 +6,12 @@ pub use crate::alphanumeric::prelude::*;
 #[cfg(feature = "boolean")]
 pub use crate::boolean::prelude::*;
 
+#[cfg(feature = "fft")]
+pub use crate::fft::prelude::*;
+
+#[cfg(feature = "interpolate")]
+pub use crate::interpolate::prelude::*;
+
 #[cfg(feature = "linalg")]
 pub use crate::linalg::prelude::*;
 
@@ -15,6 +21,15 @@ pub use crate::math::prelude::*;
 #[cfg(feature = "numeric")]
 pub use crate::numeric::prelude::*;
 
+#[cfg(feature = "polynomial")]
+pub use crate::polynomial::prelude::*;
+
+#[cfg(feature = "random")]
+pub use crate::random::prelude::*;
+
+#[cfg(feature = "sparse")]
+pub use crate::sparse::prelude::*;
+
 pub use crate::errors::prelude::*;
 
 #[cfg(feature = "macros")]
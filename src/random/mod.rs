@@ -0,0 +1,8 @@
+/// random operations
+pub mod operations;
+
+/// types definitions
+pub mod types;
+
+/// prelude module - imports facade
+pub mod prelude;
@@ -0,0 +1,395 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    random::prelude::*,
+};
+
+/// `GeneratorTrait` - Random Distributions functions
+pub trait RandomDistributions {
+
+    /// Draw samples from a uniform distribution over [0, 1)
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).random(vec![2, 2]).unwrap();
+    /// assert_eq!(vec![2, 2], arr.get_shape().unwrap());
+    /// assert!(arr.get_elements().unwrap().iter().all(|&i| (0. ..1.).contains(&i)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn random(&mut self, shape: Vec<usize>) -> Result<Array<f64>, ArrayError>;
+
+    /// Draw samples from a uniform distribution over [low, high)
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - lower boundary of the output interval
+    /// * `high` - upper boundary of the output interval
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).uniform(-1., 1., vec![8]).unwrap();
+    /// assert!(arr.get_elements().unwrap().iter().all(|&i| (-1. ..1.).contains(&i)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn uniform(&mut self, low: f64, high: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError>;
+
+    /// Draw random integers from [low, high), or [low, high] if `endpoint` is true
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - lowest integer to be drawn
+    /// * `high` - upper boundary of the output interval
+    /// * `shape` - output shape
+    /// * `endpoint` - if true, sample from the interval [low, high]. optional, defaults to false
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).integers(0, 5, vec![8], None).unwrap();
+    /// assert!(arr.get_elements().unwrap().iter().all(|&i| (0..5).contains(&i)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn integers(&mut self, low: i64, high: i64, shape: Vec<usize>, endpoint: Option<bool>) -> Result<Array<i64>, ArrayError>;
+
+    /// Draw samples from a standard normal distribution (mean 0, standard deviation 1)
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).standard_normal(vec![2, 3]).unwrap();
+    /// assert_eq!(vec![2, 3], arr.get_shape().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn standard_normal(&mut self, shape: Vec<usize>) -> Result<Array<f64>, ArrayError>;
+
+    /// Draw samples from a normal (Gaussian) distribution
+    ///
+    /// # Arguments
+    ///
+    /// * `loc` - mean of the distribution
+    /// * `scale` - standard deviation of the distribution. must be non-negative
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).normal(10., 0., vec![4]);
+    /// assert_eq!(Array::flat(vec![10.; 4]), arr);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn normal(&mut self, loc: f64, scale: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError>;
+
+    /// Draw samples from a log-normal distribution
+    ///
+    /// # Arguments
+    ///
+    /// * `mean` - mean of the underlying normal distribution
+    /// * `sigma` - standard deviation of the underlying normal distribution. must be non-negative
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).lognormal(0., 1., vec![8]).unwrap();
+    /// assert!(arr.get_elements().unwrap().iter().all(|&i| i > 0.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn lognormal(&mut self, mean: f64, sigma: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError>;
+
+    /// Draw samples from an exponential distribution
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - scale parameter, inverse of the rate. must be non-negative
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).exponential(2., vec![8]).unwrap();
+    /// assert!(arr.get_elements().unwrap().iter().all(|&i| i >= 0.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn exponential(&mut self, scale: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError>;
+
+    /// Draw samples from a gamma distribution
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - shape parameter of the distribution. must be non-negative
+    /// * `theta` - scale parameter of the distribution. must be non-negative
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).gamma(2., 2., vec![8]).unwrap();
+    /// assert!(arr.get_elements().unwrap().iter().all(|&i| i >= 0.));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn gamma(&mut self, k: f64, theta: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError>;
+
+    /// Draw samples from a beta distribution
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - alpha parameter. must be positive
+    /// * `b` - beta parameter. must be positive
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).beta(0.5, 0.5, vec![8]).unwrap();
+    /// assert!(arr.get_elements().unwrap().iter().all(|&i| (0. ..=1.).contains(&i)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn beta(&mut self, a: f64, b: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError>;
+
+    /// Draw samples from a binomial distribution
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - number of trials
+    /// * `p` - probability of success, in range [0, 1]
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).binomial(10, 0.5, vec![8]).unwrap();
+    /// assert!(arr.get_elements().unwrap().iter().all(|&i| i <= 10));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn binomial(&mut self, n: usize, p: f64, shape: Vec<usize>) -> Result<Array<usize>, ArrayError>;
+
+    /// Draw samples from a Poisson distribution
+    ///
+    /// # Arguments
+    ///
+    /// * `lam` - expected number of events. must be non-negative
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).poisson(0., vec![4]);
+    /// assert_eq!(Array::flat(vec![0; 4]), arr);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn poisson(&mut self, lam: f64, shape: Vec<usize>) -> Result<Array<usize>, ArrayError>;
+
+    /// Draw samples from a multinomial distribution.
+    /// output has shape of `shape` with an extra trailing axis of `pvals` length
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - number of experiments
+    /// * `pvals` - probabilities of each of the different outcomes. must sum to 1
+    /// * `shape` - output shape
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Generator::new(Some(42)).multinomial(20, &Array::flat(vec![0.5, 0.5]).unwrap(), vec![3]).unwrap();
+    /// assert_eq!(vec![3, 2], arr.get_shape().unwrap());
+    /// assert!(arr.get_elements().unwrap().chunks(2).all(|row| row.iter().sum::<usize>() == 20));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn multinomial(&mut self, n: usize, pvals: &Array<f64>, shape: Vec<usize>) -> Result<Array<usize>, ArrayError>;
+}
+
+impl RandomDistributions for Generator {
+
+    fn random(&mut self, shape: Vec<usize>) -> Result<Array<f64>, ArrayError> {
+        self.sample(shape, Self::next_f64)
+    }
+
+    fn uniform(&mut self, low: f64, high: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError> {
+        self.sample(shape, |generator| (high - low).mul_add(generator.next_f64(), low))
+    }
+
+    fn integers(&mut self, low: i64, high: i64, shape: Vec<usize>, endpoint: Option<bool>) -> Result<Array<i64>, ArrayError> {
+        let endpoint = endpoint.unwrap_or(false);
+        if low > high || low == high && !endpoint {
+            return Err(ArrayError::ParameterError { param: "`low`", message: "must be lower than `high`" })
+        }
+        // upper bound is made inclusive, so that `high == i64::MAX` with endpoint does not overflow
+        let high = if endpoint { high } else { high - 1 };
+        self.sample(shape, |generator| generator.next_i64(low..=high))
+    }
+
+    fn standard_normal(&mut self, shape: Vec<usize>) -> Result<Array<f64>, ArrayError> {
+        self.sample(shape, Self::next_standard_normal)
+    }
+
+    fn normal(&mut self, loc: f64, scale: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError> {
+        validate_non_negative(scale, "`scale`")?;
+        self.sample(shape, |generator| generator.next_standard_normal().mul_add(scale, loc))
+    }
+
+    fn lognormal(&mut self, mean: f64, sigma: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError> {
+        validate_non_negative(sigma, "`sigma`")?;
+        self.sample(shape, |generator| generator.next_standard_normal().mul_add(sigma, mean).exp())
+    }
+
+    fn exponential(&mut self, scale: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError> {
+        validate_non_negative(scale, "`scale`")?;
+        self.sample(shape, |generator| generator.next_standard_exponential() * scale)
+    }
+
+    fn gamma(&mut self, k: f64, theta: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError> {
+        validate_non_negative(k, "`k`")?;
+        validate_non_negative(theta, "`theta`")?;
+        self.sample(shape, |generator| generator.next_standard_gamma(k) * theta)
+    }
+
+    fn beta(&mut self, a: f64, b: f64, shape: Vec<usize>) -> Result<Array<f64>, ArrayError> {
+        if a.is_nan() || a <= 0. {
+            return Err(ArrayError::ParameterError { param: "`a`", message: "must be positive" })
+        }
+        if b.is_nan() || b <= 0. {
+            return Err(ArrayError::ParameterError { param: "`b`", message: "must be positive" })
+        }
+        self.sample(shape, |generator| generator.next_beta(a, b))
+    }
+
+    fn binomial(&mut self, n: usize, p: f64, shape: Vec<usize>) -> Result<Array<usize>, ArrayError> {
+        validate_probability(p, "`p`")?;
+        self.sample(shape, |generator| generator.next_binomial(n, p))
+    }
+
+    fn poisson(&mut self, lam: f64, shape: Vec<usize>) -> Result<Array<usize>, ArrayError> {
+        validate_non_negative(lam, "`lam`")?;
+        self.sample(shape, |generator| generator.next_poisson(lam))
+    }
+
+    fn multinomial(&mut self, n: usize, pvals: &Array<f64>, shape: Vec<usize>) -> Result<Array<usize>, ArrayError> {
+        let pvals = pvals.get_elements()?;
+        pvals.iter().try_for_each(|&p| validate_probability(p, "`pvals`"))?;
+        if pvals.iter().sum::<f64>() > 1. + 1e-12 {
+            return Err(ArrayError::ParameterError { param: "`pvals`", message: "sum must not exceed 1" })
+        }
+
+        let size = shape.iter().product::<usize>();
+        let elements = (0..size)
+            .flat_map(|_| {
+                let (mut remaining, mut mass) = (n, 1.);
+                pvals.iter().enumerate()
+                    .map(|(idx, &p)| {
+                        let count = if idx == pvals.len() - 1 { remaining }
+                        else { self.next_binomial(remaining, (p / mass).min(1.)) };
+                        remaining -= count;
+                        mass -= p;
+                        count
+                    })
+                    .collect::<Vec<usize>>()
+            })
+            .collect();
+        let mut shape = shape;
+        shape.push(pvals.len());
+        Array::new(elements, shape)
+    }
+}
+
+trait DistributionsHelper {
+
+    fn sample<T: ArrayElement, F: FnMut(&mut Self) -> T>(&mut self, shape: Vec<usize>, f: F) -> Result<Array<T>, ArrayError>;
+}
+
+impl DistributionsHelper for Generator {
+
+    fn sample<T: ArrayElement, F: FnMut(&mut Self) -> T>(&mut self, shape: Vec<usize>, mut f: F) -> Result<Array<T>, ArrayError> {
+        let elements = (0..shape.iter().product::<usize>())
+            .map(|_| f(self))
+            .collect();
+        Array::new(elements, shape)
+    }
+}
+
+fn validate_non_negative(value: f64, param: &'static str) -> Result<(), ArrayError> {
+    if value.is_nan() || value < 0. {
+        Err(ArrayError::ParameterError { param, message: "must be non-negative" })
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_probability(value: f64, param: &'static str) -> Result<(), ArrayError> {
+    if value.is_nan() || !(0. ..=1.).contains(&value) {
+        Err(ArrayError::ParameterError { param, message: "must be in range [0, 1]" })
+    } else {
+        Ok(())
+    }
+}
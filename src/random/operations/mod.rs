@@ -0,0 +1,5 @@
+/// random distributions functions
+pub mod distributions;
+
+/// random permutations functions
+pub mod permutations;
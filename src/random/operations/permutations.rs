@@ -0,0 +1,192 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    random::prelude::*,
+    validators::prelude::*,
+};
+
+/// `GeneratorTrait` - Random Permutations functions
+pub trait RandomPermutations {
+
+    /// Shuffle array in-place along the given axis
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - array to be shuffled
+    /// * `axis` - axis along which to shuffle. optional, defaults to 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let mut arr = Array::flat(vec![1, 2, 3, 4, 5]).unwrap();
+    /// Generator::new(Some(42)).shuffle(&mut arr, None).unwrap();
+    /// assert_eq!(Array::flat(vec![1, 2, 3, 4, 5]), arr.sort(None, None::<&str>));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn shuffle<T: ArrayElement>(&mut self, array: &mut Array<T>, axis: Option<isize>) -> Result<(), ArrayError>;
+
+    /// Randomly permute a copy of an array along the given axis
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - array to be permuted
+    /// * `axis` - axis along which to permute. optional, defaults to 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::new(vec![1, 2, 3, 4, 5, 6], vec![3, 2]).unwrap();
+    /// let permuted = Generator::new(Some(42)).permutation(&arr, None).unwrap();
+    /// assert_eq!(vec![3, 2], permuted.get_shape().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn permutation<T: ArrayElement>(&mut self, array: &Array<T>, axis: Option<isize>) -> Result<Array<T>, ArrayError>;
+
+    /// Generate a random sample from a given 1D array
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - array to sample from
+    /// * `shape` - output shape
+    /// * `replace` - whether the sample is with or without replacement. optional, defaults to true
+    /// * `p` - probabilities associated with each entry. optional, defaults to uniform distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let arr = Array::flat(vec![1, 2, 3, 4, 5]).unwrap();
+    /// let sample = Generator::new(Some(42)).choice(&arr, vec![5], Some(false), None).unwrap();
+    /// assert_eq!(arr.sort(None, None::<&str>), sample.sort(None, None::<&str>));
+    ///
+    /// let p = Array::flat(vec![0., 0., 1., 0., 0.]).unwrap();
+    /// let sample = Generator::new(Some(42)).choice(&arr, vec![3], None, Some(p));
+    /// assert_eq!(Array::flat(vec![3, 3, 3]), sample);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn choice<T: ArrayElement>(&mut self, array: &Array<T>, shape: Vec<usize>, replace: Option<bool>, p: Option<Array<f64>>) -> Result<Array<T>, ArrayError>;
+}
+
+impl RandomPermutations for Generator {
+
+    fn shuffle<T: ArrayElement>(&mut self, array: &mut Array<T>, axis: Option<isize>) -> Result<(), ArrayError> {
+        *array = self.permutation(array, axis)?;
+        Ok(())
+    }
+
+    fn permutation<T: ArrayElement>(&mut self, array: &Array<T>, axis: Option<isize>) -> Result<Array<T>, ArrayError> {
+        array.is_dim_unsupported(&[0])?;
+        let axis = array.normalize_axis(axis.unwrap_or(0));
+        array.axis_in_bounds(axis)?;
+
+        let moved = array.moveaxis(vec![axis.to_isize()], vec![0])?;
+        let shape = moved.get_shape()?;
+        let chunk = shape[1..].iter().product::<usize>();
+        let mut subarrays = moved.get_elements()?
+            .chunks(chunk.max(1))
+            .map(<[T]>::to_vec)
+            .collect::<Vec<_>>();
+        self.fisher_yates(&mut subarrays, shape[0]);
+
+        Array::new(subarrays.concat(), shape)
+            .moveaxis(vec![0], vec![axis.to_isize()])
+    }
+
+    fn choice<T: ArrayElement>(&mut self, array: &Array<T>, shape: Vec<usize>, replace: Option<bool>, p: Option<Array<f64>>) -> Result<Array<T>, ArrayError> {
+        array.is_dim_supported(&[1])?;
+        let elements = array.get_elements()?;
+        let size = shape.iter().product::<usize>();
+        let p = p.map(|p| validate_weights(&p, elements.len())).transpose()?;
+
+        if elements.is_empty() && size > 0 {
+            return Err(ArrayError::ParameterError { param: "`array`", message: "cannot take a sample from an empty array" })
+        }
+
+        let indices = if replace.unwrap_or(true) {
+            if let Some(p) = p {
+                let cdf = p.iter()
+                    .scan(0., |acc, &w| { *acc += w; Some(*acc) })
+                    .collect::<Vec<f64>>();
+                (0..size).map(|_| self.weighted_index(&cdf)).collect()
+            } else {
+                (0..size).map(|_| self.next_index(elements.len())).collect()
+            }
+        } else {
+            if size > elements.len() {
+                return Err(ArrayError::ParameterError { param: "`shape`", message: "cannot take a larger sample than population when `replace` is false" })
+            }
+            if let Some(mut p) = p {
+                if p.iter().filter(|&&w| w > 0.).count() < size {
+                    return Err(ArrayError::ParameterError { param: "`p`", message: "fewer non-zero entries than sample size" })
+                }
+                (0..size).map(|_| {
+                    let cdf = p.iter()
+                        .scan(0., |acc, &w| { *acc += w; Some(*acc) })
+                        .collect::<Vec<f64>>();
+                    let idx = self.weighted_index(&cdf);
+                    p[idx] = 0.;
+                    idx
+                }).collect()
+            } else {
+                let mut indices = (0..elements.len()).collect::<Vec<usize>>();
+                self.fisher_yates(&mut indices, size);
+                indices.truncate(size);
+                indices
+            }
+        };
+
+        Array::new(indices.into_iter().map(|idx| elements[idx].clone()).collect(), shape)
+    }
+}
+
+trait PermutationsHelper {
+
+    fn fisher_yates<T>(&mut self, items: &mut [T], count: usize);
+
+    fn weighted_index(&mut self, cdf: &[f64]) -> usize;
+}
+
+impl PermutationsHelper for Generator {
+
+    fn fisher_yates<T>(&mut self, items: &mut [T], count: usize) {
+        let len = items.len();
+        (0..count.min(len.saturating_sub(1))).for_each(|i| {
+            let j = i + self.next_index(len - i);
+            items.swap(i, j);
+        });
+    }
+
+    fn weighted_index(&mut self, cdf: &[f64]) -> usize {
+        let value = self.next_f64() * cdf[cdf.len() - 1];
+        cdf.iter()
+            .position(|&c| value < c)
+            .unwrap_or_else(|| cdf.iter().rposition(|&c| c > 0.).unwrap_or(0))
+    }
+}
+
+fn validate_weights(p: &Array<f64>, len: usize) -> Result<Vec<f64>, ArrayError> {
+    let p = p.get_elements()?;
+    p.len().is_equal(&len)?;
+    if p.iter().any(|&w| w.is_nan() || w < 0.) {
+        return Err(ArrayError::ParameterError { param: "`p`", message: "probabilities must be non-negative" })
+    }
+    if (p.iter().sum::<f64>() - 1.).abs() > 1e-8 {
+        return Err(ArrayError::ParameterError { param: "`p`", message: "probabilities must sum to 1" })
+    }
+    Ok(p)
+}
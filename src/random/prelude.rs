@@ -0,0 +1,8 @@
+#[cfg(feature = "random")]
+pub use crate::random::{
+    operations::{
+        distributions::RandomDistributions,
+        permutations::RandomPermutations,
+    },
+    types::generator::Generator,
+};
@@ -0,0 +1,129 @@
+use std::{f64::consts::PI, ops::RangeInclusive};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::numeric::prelude::*;
+
+/// Random numbers generator, seedable for reproducible results
+#[derive(Clone, Debug)]
+pub struct Generator {
+    rng: StdRng,
+}
+
+impl Generator {
+
+    /// Create new generator
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - seed of the generator. optional, if not provided generator is seeded from system entropy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let mut gen_1 = Generator::new(Some(42));
+    /// let mut gen_2 = Generator::new(Some(42));
+    /// assert_eq!(gen_1.random(vec![4]), gen_2.random(vec![4]));
+    /// ```
+    #[must_use]
+    pub fn new(seed: Option<u64>) -> Self {
+        let rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+        Self { rng }
+    }
+
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        self.rng.gen::<f64>()
+    }
+
+    pub(crate) fn next_index(&mut self, bound: usize) -> usize {
+        self.rng.gen_range(0..bound)
+    }
+
+    pub(crate) fn next_i64(&mut self, range: RangeInclusive<i64>) -> i64 {
+        self.rng.gen_range(range)
+    }
+
+    pub(crate) fn next_standard_normal(&mut self) -> f64 {
+        let u_1 = 1. - self.next_f64();
+        let u_2 = self.next_f64();
+        (-2. * u_1.ln()).sqrt() * (2. * PI * u_2).cos()
+    }
+
+    pub(crate) fn next_standard_exponential(&mut self) -> f64 {
+        -(1. - self.next_f64()).ln()
+    }
+
+    /// Marsaglia-Tsang method, boosted for `alpha < 1`
+    pub(crate) fn next_standard_gamma(&mut self, alpha: f64) -> f64 {
+        if alpha <= 0. { return 0. }
+        if alpha < 1. {
+            let boost = (1. - self.next_f64()).powf(1. / alpha);
+            return self.next_standard_gamma(alpha + 1.) * boost
+        }
+
+        let d = alpha - 1. / 3.;
+        let c = 1. / (9. * d).sqrt();
+        loop {
+            let (normal, cube) = loop {
+                let normal = self.next_standard_normal();
+                let base = c.mul_add(normal, 1.);
+                if base > 0. { break (normal, base * base * base) }
+            };
+            let uniform = 1. - self.next_f64();
+            if uniform.ln() < (0.5 * normal).mul_add(normal, d - d * cube + d * cube.ln()) {
+                return d * cube
+            }
+        }
+    }
+
+    pub(crate) fn next_beta(&mut self, a: f64, b: f64) -> f64 {
+        let gamma_a = self.next_standard_gamma(a);
+        let gamma_b = self.next_standard_gamma(b);
+        if gamma_a + gamma_b > 0. { gamma_a / (gamma_a + gamma_b) }
+        else if self.next_f64() < a / (a + b) { 1. }
+        else { 0. }
+    }
+
+    /// Direct method for small `n`, Knuth's beta splitting otherwise
+    pub(crate) fn next_binomial(&mut self, n: usize, p: f64) -> usize {
+        if n == 0 || p <= 0. { return 0 }
+        if p >= 1. { return n }
+        if n <= 64 {
+            return (0..n).filter(|_| self.next_f64() < p).count()
+        }
+
+        let lower = 1 + n / 2;
+        let upper = n + 1 - lower;
+        let split = self.next_beta(lower.to_f64(), upper.to_f64());
+        if split >= p { self.next_binomial(lower - 1, p / split) }
+        else { lower + self.next_binomial(upper - 1, (p - split) / (1. - split)) }
+    }
+
+    /// Multiplication method for small `lam`, Knuth's gamma splitting otherwise
+    pub(crate) fn next_poisson(&mut self, lam: f64) -> usize {
+        if lam <= 0. { return 0 }
+        if lam < 30. {
+            let limit = (-lam).exp();
+            let (mut count, mut product) = (0, self.next_f64());
+            loop {
+                if product <= limit { return count }
+                count += 1;
+                product *= self.next_f64();
+            }
+        }
+
+        let m = (lam * 7. / 8.).floor().to_usize();
+        let x = self.next_standard_gamma(m.to_f64());
+        if x < lam { m + self.next_poisson(lam - x) }
+        else { self.next_binomial(m - 1, lam / x) }
+    }
+}
+
+impl Default for Generator {
+
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
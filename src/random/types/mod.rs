@@ -0,0 +1,2 @@
+/// Random generator type definition
+pub mod generator;
@@ -0,0 +1,60 @@
+use crate::{
+    errors::prelude::*,
+    numeric::prelude::*,
+    validators::prelude::*,
+};
+
+/// compressed storage - `indptr`, `indices` and `data` vectors
+pub(crate) type Compressed<N> = (Vec<usize>, Vec<usize>, Vec<N>);
+
+pub(crate) fn validate_shape(shape: &[usize]) -> Result<(usize, usize), ArrayError> {
+    shape.len().is_equal(&2)?;
+    Ok((shape[0], shape[1]))
+}
+
+pub(crate) fn validate_coordinates<N: NumericOps>(majors: &[usize], minors: &[usize], data: &[N], dims: (usize, usize)) -> Result<(), ArrayError> {
+    majors.len().is_equal(&data.len())?;
+    minors.len().is_equal(&data.len())?;
+    if majors.iter().any(|&idx| idx >= dims.0) || minors.iter().any(|&idx| idx >= dims.1) {
+        return Err(ArrayError::OutOfBounds { value: "index" })
+    }
+    Ok(())
+}
+
+/// expands compressed storage into major coordinates of each entry, validating its structure
+pub(crate) fn expand_indptr<N: NumericOps>(indptr: &[usize], indices: &[usize], data: &[N], dims: (usize, usize)) -> Result<Vec<usize>, ArrayError> {
+    indptr.len().is_equal(&(dims.0 + 1))?;
+    if indptr[0] != 0 || indptr.windows(2).any(|pair| pair[0] > pair[1]) || indptr[dims.0] != indices.len() {
+        return Err(ArrayError::ParameterError { param: "`indptr`", message: "must be non-decreasing, starting at zero and ending at the number of entries" })
+    }
+    let majors = indptr.windows(2)
+        .enumerate()
+        .flat_map(|(major, pair)| std::iter::repeat_n(major, pair[1] - pair[0]))
+        .collect::<Vec<usize>>();
+    validate_coordinates(&majors, indices, data, dims)?;
+    Ok(majors)
+}
+
+/// compresses coordinate entries along the major axis.
+/// entries are sorted by minor index, duplicates are summed and zeros are dropped
+pub(crate) fn compress<N: NumericOps>(majors: &[usize], minors: &[usize], data: &[N], major_dim: usize) -> Compressed<N> {
+    let mut order = (0..data.len()).collect::<Vec<usize>>();
+    order.sort_by_key(|&idx| (majors[idx], minors[idx]));
+
+    let mut entries = Vec::<(usize, usize, N)>::with_capacity(order.len());
+    for idx in order {
+        match entries.last_mut() {
+            Some(last) if (last.0, last.1) == (majors[idx], minors[idx]) => last.2 += data[idx],
+            _ => entries.push((majors[idx], minors[idx], data[idx])),
+        }
+    }
+    entries.retain(|entry| entry.2 != N::zero());
+
+    let mut indptr = vec![0; major_dim + 1];
+    for entry in &entries { indptr[entry.0 + 1] += 1; }
+    for idx in 0..major_dim { indptr[idx + 1] += indptr[idx]; }
+    let (indices, values) = entries.into_iter()
+        .map(|(_, minor, value)| (minor, value))
+        .unzip();
+    (indptr, indices, values)
+}
@@ -0,0 +1,74 @@
+use crate::{
+    errors::prelude::*,
+    linalg::prelude::*,
+    numeric::prelude::*,
+    sparse::prelude::*,
+    validators::prelude::*,
+};
+
+impl <N: NumericOps> LinearOperator for CsrMatrix<N> {
+
+    fn operator_shape(&self) -> Result<(usize, usize), ArrayError> {
+        Ok(self.shape)
+    }
+
+    fn matvec(&self, x: &[f64]) -> Result<Vec<f64>, ArrayError> {
+        x.len().is_equal(&self.shape.1)?;
+        let result = self.indptr.windows(2)
+            .map(|range| (range[0]..range[1])
+                .fold(0., |acc, idx| self.data[idx].to_f64().mul_add(x[self.indices[idx]], acc)))
+            .collect();
+        Ok(result)
+    }
+
+    fn operator_rows(&self) -> Result<Vec<Vec<(usize, f64)>>, ArrayError> {
+        let result = self.indptr.windows(2)
+            .map(|range| (range[0]..range[1])
+                .map(|idx| (self.indices[idx], self.data[idx].to_f64()))
+                .collect())
+            .collect();
+        Ok(result)
+    }
+}
+
+impl <N: NumericOps> LinearOperator for CscMatrix<N> {
+
+    fn operator_shape(&self) -> Result<(usize, usize), ArrayError> {
+        Ok(self.shape)
+    }
+
+    fn matvec(&self, x: &[f64]) -> Result<Vec<f64>, ArrayError> {
+        x.len().is_equal(&self.shape.1)?;
+        let mut result = vec![0.; self.shape.0];
+        for (col, range) in self.indptr.windows(2).enumerate() {
+            for idx in range[0]..range[1] {
+                result[self.indices[idx]] += self.data[idx].to_f64() * x[col];
+            }
+        }
+        Ok(result)
+    }
+
+    fn operator_rows(&self) -> Result<Vec<Vec<(usize, f64)>>, ArrayError> {
+        self.to_csr()?.operator_rows()
+    }
+}
+
+impl <N: NumericOps> LinearOperator for CooMatrix<N> {
+
+    fn operator_shape(&self) -> Result<(usize, usize), ArrayError> {
+        Ok(self.shape)
+    }
+
+    fn matvec(&self, x: &[f64]) -> Result<Vec<f64>, ArrayError> {
+        x.len().is_equal(&self.shape.1)?;
+        let mut result = vec![0.; self.shape.0];
+        for ((&row, &col), value) in self.rows.iter().zip(&self.cols).zip(&self.data) {
+            result[row] += value.to_f64() * x[col];
+        }
+        Ok(result)
+    }
+
+    fn operator_rows(&self) -> Result<Vec<Vec<(usize, f64)>>, ArrayError> {
+        self.to_csr()?.operator_rows()
+    }
+}
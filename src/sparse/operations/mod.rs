@@ -0,0 +1,8 @@
+/// sparse common functions
+pub(crate) mod common;
+
+/// sparse linear operator implementations
+pub mod linear_operator;
+
+/// sparse matrix functions
+pub mod sparse_matrix;
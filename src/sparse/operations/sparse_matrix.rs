@@ -0,0 +1,730 @@
+use std::ops::Range;
+
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    sparse::{
+        operations::common::compress,
+        prelude::*,
+    },
+    validators::prelude::*,
+};
+
+/// `SparseMatrixTrait` - Sparse matrix functions
+pub trait SparseMatrix<N: NumericOps> where Self: Sized + Clone {
+
+    /// sparse matrix format the operations are performed on
+    type Matrix;
+
+    /// Shape of the matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::<f64>::from_array(&Array::zeros(vec![2, 3]).unwrap()).unwrap();
+    /// assert_eq!(Ok(vec![2, 3]), csr.get_shape());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn get_shape(&self) -> Result<Vec<usize>, ArrayError>;
+
+    /// Number of stored entries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[1., 0.], [0., 2.]]).unwrap()).unwrap();
+    /// assert_eq!(Ok(2), csr.nnz());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn nnz(&self) -> Result<usize, ArrayError>;
+
+    /// Convert the matrix to dense array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let coo = CooMatrix::new(&[0, 1], &[1, 0], &[2., 3.], &[2, 2]).unwrap();
+    /// assert_eq!(array!(f64, [[0., 2.], [3., 0.]]), coo.to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_array(&self) -> Result<Array<N>, ArrayError>;
+
+    /// Convert the matrix to coordinate format
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[0., 2.], [3., 0.]]).unwrap()).unwrap();
+    /// assert_eq!(CooMatrix::new(&[0, 1], &[1, 0], &[2., 3.], &[2, 2]), csr.to_coo());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_coo(&self) -> Result<CooMatrix<N>, ArrayError>;
+
+    /// Convert the matrix to compressed sparse row format
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let coo = CooMatrix::new(&[1, 0, 1], &[0, 1, 0], &[3., 2., 1.], &[2, 2]).unwrap();
+    /// assert_eq!(CsrMatrix::new(&[0, 1, 2], &[1, 0], &[2., 4.], &[2, 2]), coo.to_csr());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_csr(&self) -> Result<CsrMatrix<N>, ArrayError>;
+
+    /// Convert the matrix to compressed sparse column format
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[0., 2.], [3., 4.]]).unwrap()).unwrap();
+    /// assert_eq!(CscMatrix::new(&[0, 1, 3], &[1, 0, 1], &[3., 2., 4.], &[2, 2]), csr.to_csc());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn to_csc(&self) -> Result<CscMatrix<N>, ArrayError>;
+
+    /// Transpose the matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[1., 2., 0.], [0., 0., 3.]]).unwrap()).unwrap();
+    /// assert_eq!(array!(f64, [[1., 0.], [2., 0.], [0., 3.]]), csr.transpose().to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn transpose(&self) -> Result<Self::Matrix, ArrayError>;
+
+    /// Matrix product with dense 1-D or 2-D array
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - dense array to multiply with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[1., 0.], [0., 2.]]).unwrap()).unwrap();
+    /// assert_eq!(array_flat!(f64, 3., 8.), csr.matmul_dense(&array_flat!(f64, 3., 4.).unwrap()));
+    /// assert_eq!(array!(f64, [[1., 2.], [6., 8.]]), csr.matmul_dense(&array!(f64, [[1., 2.], [3., 4.]]).unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn matmul_dense(&self, other: &Array<N>) -> Result<Array<N>, ArrayError>;
+
+    /// Matrix product with other sparse matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - sparse matrix to multiply with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let a = CsrMatrix::from_array(&array!(f64, [[1., 2.], [0., 1.]]).unwrap()).unwrap();
+    /// let b = CsrMatrix::from_array(&array!(f64, [[1., -2.], [0., 1.]]).unwrap()).unwrap();
+    /// assert_eq!(array!(f64, [[1., 0.], [0., 1.]]), a.matmul(&b).to_array());
+    /// assert_eq!(Ok(2), a.matmul(&b).nnz());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn matmul(&self, other: &Self::Matrix) -> Result<Self::Matrix, ArrayError>;
+
+    /// Element-wise sum of two sparse matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - sparse matrix to add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let a = CsrMatrix::from_array(&array!(f64, [[1., 0.], [0., 2.]]).unwrap()).unwrap();
+    /// let b = CsrMatrix::from_array(&array!(f64, [[0., 3.], [0., -2.]]).unwrap()).unwrap();
+    /// assert_eq!(array!(f64, [[1., 3.], [0., 0.]]), a.add(&b).to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn add(&self, other: &Self::Matrix) -> Result<Self::Matrix, ArrayError>;
+
+    /// Element-wise difference of two sparse matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - sparse matrix to subtract
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let a = CsrMatrix::from_array(&array!(f64, [[1., 0.], [0., 2.]]).unwrap()).unwrap();
+    /// let b = CsrMatrix::from_array(&array!(f64, [[0., 3.], [0., 2.]]).unwrap()).unwrap();
+    /// assert_eq!(array!(f64, [[1., -3.], [0., 0.]]), a.subtract(&b).to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn subtract(&self, other: &Self::Matrix) -> Result<Self::Matrix, ArrayError>;
+
+    /// Element-wise product of two sparse matrices
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - sparse matrix to multiply with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let a = CsrMatrix::from_array(&array!(f64, [[1., 0.], [4., 2.]]).unwrap()).unwrap();
+    /// let b = CsrMatrix::from_array(&array!(f64, [[0., 3.], [2., 2.]]).unwrap()).unwrap();
+    /// assert_eq!(array!(f64, [[0., 0.], [8., 4.]]), a.multiply(&b).to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn multiply(&self, other: &Self::Matrix) -> Result<Self::Matrix, ArrayError>;
+
+    /// Multiply all elements of the matrix by a scalar
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - scalar to multiply with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[1., 0.], [0., 2.]]).unwrap()).unwrap();
+    /// assert_eq!(array!(f64, [[3., 0.], [0., 6.]]), csr.scale(3.).to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn scale(&self, value: N) -> Result<Self::Matrix, ArrayError>;
+
+    /// Extract a range of rows of the matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - range of rows to extract
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[1., 0.], [0., 2.], [3., 0.]]).unwrap()).unwrap();
+    /// assert_eq!(array!(f64, [[0., 2.], [3., 0.]]), csr.slice_rows(1..3).to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn slice_rows(&self, rows: Range<usize>) -> Result<Self::Matrix, ArrayError>;
+
+    /// Sum of matrix elements over a given axis
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - axis along which to perform the sum. if None, all elements are summed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[1., 0.], [2., 3.]]).unwrap()).unwrap();
+    /// assert_eq!(array_single!(f64, 6.), csr.sum(None));
+    /// assert_eq!(array_flat!(f64, 3., 3.), csr.sum(Some(0)));
+    /// assert_eq!(array_flat!(f64, 1., 5.), csr.sum(Some(1)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    fn sum(&self, axis: Option<isize>) -> Result<Array<N>, ArrayError>;
+}
+
+impl <N: NumericOps> SparseMatrix<N> for CsrMatrix<N> {
+
+    type Matrix = Self;
+
+    fn get_shape(&self) -> Result<Vec<usize>, ArrayError> {
+        Ok(vec![self.shape.0, self.shape.1])
+    }
+
+    fn nnz(&self) -> Result<usize, ArrayError> {
+        Ok(self.data.len())
+    }
+
+    fn to_array(&self) -> Result<Array<N>, ArrayError> {
+        let mut elements = vec![N::zero(); self.shape.0 * self.shape.1];
+        for (row, col, value) in self.entries() {
+            elements[row * self.shape.1 + col] = value;
+        }
+        Array::new(elements, vec![self.shape.0, self.shape.1])
+    }
+
+    fn to_coo(&self) -> Result<CooMatrix<N>, ArrayError> {
+        Ok(self.as_coo())
+    }
+
+    fn to_csr(&self) -> Result<Self, ArrayError> {
+        Ok(self.clone())
+    }
+
+    fn to_csc(&self) -> Result<CscMatrix<N>, ArrayError> {
+        Ok(self.as_csc())
+    }
+
+    fn transpose(&self) -> Result<Self, ArrayError> {
+        Ok(self.transposed())
+    }
+
+    fn matmul_dense(&self, other: &Array<N>) -> Result<Array<N>, ArrayError> {
+        other.is_dim_supported(&[1, 2])?;
+        let shape = other.get_shape()?;
+        vec![self.shape.0, self.shape.1].shapes_align(1, &shape, 0)?;
+        let width = if shape.len() == 1 { 1 } else { shape[1] };
+        let elements = other.get_elements()?;
+
+        let mut result = vec![N::zero(); self.shape.0 * width];
+        for (row, col, value) in self.entries() {
+            let (target, source) = (&mut result[row * width..(row + 1) * width], &elements[col * width..(col + 1) * width]);
+            target.iter_mut().zip(source).for_each(|(acc, &item)| *acc += value * item);
+        }
+        let new_shape = if shape.len() == 1 { vec![self.shape.0] } else { vec![self.shape.0, width] };
+        Array::new(result, new_shape)
+    }
+
+    fn matmul(&self, other: &Self) -> Result<Self, ArrayError> {
+        vec![self.shape.0, self.shape.1].shapes_align(1, &[other.shape.0, other.shape.1], 0)?;
+        let (mut rows, mut cols, mut data) = (vec![], vec![], vec![]);
+        let mut accumulator = vec![N::zero(); other.shape.1];
+        let mut touched = vec![false; other.shape.1];
+        for row in 0..self.shape.0 {
+            let mut columns = vec![];
+            for idx in self.indptr[row]..self.indptr[row + 1] {
+                let (inner, value) = (self.indices[idx], self.data[idx]);
+                for other_idx in other.indptr[inner]..other.indptr[inner + 1] {
+                    let col = other.indices[other_idx];
+                    if !touched[col] { touched[col] = true; columns.push(col); }
+                    accumulator[col] += value * other.data[other_idx];
+                }
+            }
+            for col in columns {
+                rows.push(row);
+                cols.push(col);
+                data.push(accumulator[col]);
+                accumulator[col] = N::zero();
+                touched[col] = false;
+            }
+        }
+        Ok(Self::from_compressed((self.shape.0, other.shape.1), compress(&rows, &cols, &data, self.shape.0)))
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, ArrayError> {
+        self.merge(other, |a, b| a + b)
+    }
+
+    fn subtract(&self, other: &Self) -> Result<Self, ArrayError> {
+        self.merge(other, |a, b| a - b)
+    }
+
+    fn multiply(&self, other: &Self) -> Result<Self, ArrayError> {
+        self.merge(other, |a, b| a * b)
+    }
+
+    fn scale(&self, value: N) -> Result<Self, ArrayError> {
+        let coo = self.as_coo();
+        let data = coo.data.iter().map(|&item| item * value).collect::<Vec<N>>();
+        Ok(Self::from_compressed(self.shape, compress(&coo.rows, &coo.cols, &data, self.shape.0)))
+    }
+
+    fn slice_rows(&self, rows: Range<usize>) -> Result<Self, ArrayError> {
+        if rows.start > rows.end || rows.end > self.shape.0 {
+            return Err(ArrayError::OutOfBounds { value: "rows" })
+        }
+        let (start, end) = (self.indptr[rows.start], self.indptr[rows.end]);
+        let indptr = self.indptr[rows.start..=rows.end].iter().map(|&offset| offset - start).collect();
+        Ok(Self {
+            shape: (rows.end - rows.start, self.shape.1),
+            indptr,
+            indices: self.indices[start..end].to_vec(),
+            data: self.data[start..end].to_vec(),
+        })
+    }
+
+    fn sum(&self, axis: Option<isize>) -> Result<Array<N>, ArrayError> {
+        let Some(axis) = axis else {
+            return Array::single(self.data.iter().fold(N::zero(), |acc, &value| acc + value))
+        };
+        let axis = match axis { 0 | -2 => 0, 1 | -1 => 1, _ => return Err(ArrayError::AxisOutOfBounds) };
+        let mut result = vec![N::zero(); if axis == 0 { self.shape.1 } else { self.shape.0 }];
+        for (row, col, value) in self.entries() {
+            result[if axis == 0 { col } else { row }] += value;
+        }
+        Array::flat(result)
+    }
+}
+
+impl <N: NumericOps> SparseMatrix<N> for CscMatrix<N> {
+
+    type Matrix = Self;
+
+    fn get_shape(&self) -> Result<Vec<usize>, ArrayError> {
+        Ok(vec![self.shape.0, self.shape.1])
+    }
+
+    fn nnz(&self) -> Result<usize, ArrayError> {
+        Ok(self.data.len())
+    }
+
+    fn to_array(&self) -> Result<Array<N>, ArrayError> {
+        self.as_csr().to_array()
+    }
+
+    fn to_coo(&self) -> Result<CooMatrix<N>, ArrayError> {
+        Ok(self.as_coo())
+    }
+
+    fn to_csr(&self) -> Result<CsrMatrix<N>, ArrayError> {
+        Ok(self.as_csr())
+    }
+
+    fn to_csc(&self) -> Result<Self, ArrayError> {
+        Ok(self.as_csc())
+    }
+
+    fn transpose(&self) -> Result<Self, ArrayError> {
+        let csr = self.as_csr();
+        Ok(Self { shape: (self.shape.1, self.shape.0), indptr: csr.indptr, indices: csr.indices, data: csr.data })
+    }
+
+    fn matmul_dense(&self, other: &Array<N>) -> Result<Array<N>, ArrayError> {
+        self.as_csr().matmul_dense(other)
+    }
+
+    fn matmul(&self, other: &Self) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().matmul(&other.as_csr())?.as_csc())
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().add(&other.as_csr())?.as_csc())
+    }
+
+    fn subtract(&self, other: &Self) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().subtract(&other.as_csr())?.as_csc())
+    }
+
+    fn multiply(&self, other: &Self) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().multiply(&other.as_csr())?.as_csc())
+    }
+
+    fn scale(&self, value: N) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().scale(value)?.as_csc())
+    }
+
+    fn slice_rows(&self, rows: Range<usize>) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().slice_rows(rows)?.as_csc())
+    }
+
+    fn sum(&self, axis: Option<isize>) -> Result<Array<N>, ArrayError> {
+        self.as_csr().sum(axis)
+    }
+}
+
+impl <N: NumericOps> SparseMatrix<N> for CooMatrix<N> {
+
+    type Matrix = Self;
+
+    fn get_shape(&self) -> Result<Vec<usize>, ArrayError> {
+        Ok(vec![self.shape.0, self.shape.1])
+    }
+
+    fn nnz(&self) -> Result<usize, ArrayError> {
+        Ok(self.data.len())
+    }
+
+    fn to_array(&self) -> Result<Array<N>, ArrayError> {
+        self.as_csr().to_array()
+    }
+
+    fn to_coo(&self) -> Result<Self, ArrayError> {
+        Ok(self.as_coo())
+    }
+
+    fn to_csr(&self) -> Result<CsrMatrix<N>, ArrayError> {
+        Ok(self.as_csr())
+    }
+
+    fn to_csc(&self) -> Result<CscMatrix<N>, ArrayError> {
+        Ok(self.as_csc())
+    }
+
+    fn transpose(&self) -> Result<Self, ArrayError> {
+        Ok(Self { shape: (self.shape.1, self.shape.0), rows: self.cols.clone(), cols: self.rows.clone(), data: self.data.clone() })
+    }
+
+    fn matmul_dense(&self, other: &Array<N>) -> Result<Array<N>, ArrayError> {
+        self.as_csr().matmul_dense(other)
+    }
+
+    fn matmul(&self, other: &Self) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().matmul(&other.as_csr())?.as_coo())
+    }
+
+    fn add(&self, other: &Self) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().add(&other.as_csr())?.as_coo())
+    }
+
+    fn subtract(&self, other: &Self) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().subtract(&other.as_csr())?.as_coo())
+    }
+
+    fn multiply(&self, other: &Self) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().multiply(&other.as_csr())?.as_coo())
+    }
+
+    fn scale(&self, value: N) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().scale(value)?.as_coo())
+    }
+
+    fn slice_rows(&self, rows: Range<usize>) -> Result<Self, ArrayError> {
+        Ok(self.as_csr().slice_rows(rows)?.as_coo())
+    }
+
+    fn sum(&self, axis: Option<isize>) -> Result<Array<N>, ArrayError> {
+        self.as_csr().sum(axis)
+    }
+}
+
+macro_rules! impl_sparse_matrix_result {
+    ($t:ident) => {
+        impl <N: NumericOps> SparseMatrix<N> for Result<$t<N>, ArrayError> {
+
+            type Matrix = $t<N>;
+
+            fn get_shape(&self) -> Result<Vec<usize>, ArrayError> {
+                self.clone()?.get_shape()
+            }
+
+            fn nnz(&self) -> Result<usize, ArrayError> {
+                self.clone()?.nnz()
+            }
+
+            fn to_array(&self) -> Result<Array<N>, ArrayError> {
+                self.clone()?.to_array()
+            }
+
+            fn to_coo(&self) -> Result<CooMatrix<N>, ArrayError> {
+                self.clone()?.to_coo()
+            }
+
+            fn to_csr(&self) -> Result<CsrMatrix<N>, ArrayError> {
+                self.clone()?.to_csr()
+            }
+
+            fn to_csc(&self) -> Result<CscMatrix<N>, ArrayError> {
+                self.clone()?.to_csc()
+            }
+
+            fn transpose(&self) -> Result<$t<N>, ArrayError> {
+                self.clone()?.transpose()
+            }
+
+            fn matmul_dense(&self, other: &Array<N>) -> Result<Array<N>, ArrayError> {
+                self.clone()?.matmul_dense(other)
+            }
+
+            fn matmul(&self, other: &$t<N>) -> Result<$t<N>, ArrayError> {
+                self.clone()?.matmul(other)
+            }
+
+            fn add(&self, other: &$t<N>) -> Result<$t<N>, ArrayError> {
+                self.clone()?.add(other)
+            }
+
+            fn subtract(&self, other: &$t<N>) -> Result<$t<N>, ArrayError> {
+                self.clone()?.subtract(other)
+            }
+
+            fn multiply(&self, other: &$t<N>) -> Result<$t<N>, ArrayError> {
+                self.clone()?.multiply(other)
+            }
+
+            fn scale(&self, value: N) -> Result<$t<N>, ArrayError> {
+                self.clone()?.scale(value)
+            }
+
+            fn slice_rows(&self, rows: Range<usize>) -> Result<$t<N>, ArrayError> {
+                self.clone()?.slice_rows(rows)
+            }
+
+            fn sum(&self, axis: Option<isize>) -> Result<Array<N>, ArrayError> {
+                self.clone()?.sum(axis)
+            }
+        }
+    };
+}
+
+impl_sparse_matrix_result!(CooMatrix);
+impl_sparse_matrix_result!(CscMatrix);
+impl_sparse_matrix_result!(CsrMatrix);
+
+trait SparseHelper<N: NumericOps> {
+
+    fn as_coo(&self) -> CooMatrix<N>;
+
+    fn as_csr(&self) -> CsrMatrix<N>;
+
+    fn as_csc(&self) -> CscMatrix<N>;
+}
+
+impl <N: NumericOps> SparseHelper<N> for CooMatrix<N> {
+
+    fn as_coo(&self) -> Self {
+        self.clone()
+    }
+
+    fn as_csr(&self) -> CsrMatrix<N> {
+        CsrMatrix::from_compressed(self.shape, compress(&self.rows, &self.cols, &self.data, self.shape.0))
+    }
+
+    fn as_csc(&self) -> CscMatrix<N> {
+        CscMatrix::from_compressed(self.shape, compress(&self.cols, &self.rows, &self.data, self.shape.1))
+    }
+}
+
+impl <N: NumericOps> SparseHelper<N> for CsrMatrix<N> {
+
+    fn as_coo(&self) -> CooMatrix<N> {
+        let (rows, cols, data) = self.entries().fold((vec![], vec![], vec![]), |(mut rows, mut cols, mut data), (row, col, value)| {
+            rows.push(row);
+            cols.push(col);
+            data.push(value);
+            (rows, cols, data)
+        });
+        CooMatrix { shape: self.shape, rows, cols, data }
+    }
+
+    fn as_csr(&self) -> Self {
+        self.clone()
+    }
+
+    fn as_csc(&self) -> CscMatrix<N> {
+        self.as_coo().as_csc()
+    }
+}
+
+impl <N: NumericOps> SparseHelper<N> for CscMatrix<N> {
+
+    fn as_coo(&self) -> CooMatrix<N> {
+        self.as_csr().as_coo()
+    }
+
+    fn as_csr(&self) -> CsrMatrix<N> {
+        // compressed columns of a matrix are compressed rows of its transposition
+        CsrMatrix { shape: (self.shape.1, self.shape.0), indptr: self.indptr.clone(), indices: self.indices.clone(), data: self.data.clone() }
+            .transposed()
+    }
+
+    fn as_csc(&self) -> Self {
+        self.clone()
+    }
+}
+
+trait CsrHelper<N: NumericOps> {
+
+    fn transposed(&self) -> Self;
+
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, N)> + '_;
+
+    fn merge<F: Fn(N, N) -> N>(&self, other: &Self, f: F) -> Result<Self, ArrayError> where Self: Sized;
+}
+
+impl <N: NumericOps> CsrHelper<N> for CsrMatrix<N> {
+
+    fn transposed(&self) -> Self {
+        // compressed columns of a matrix are compressed rows of its transposition
+        let csc = self.as_csc();
+        Self { shape: (self.shape.1, self.shape.0), indptr: csc.indptr, indices: csc.indices, data: csc.data }
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, N)> + '_ {
+        (0..self.shape.0).flat_map(move |row| (self.indptr[row]..self.indptr[row + 1])
+            .map(move |idx| (row, self.indices[idx], self.data[idx])))
+    }
+
+    fn merge<F: Fn(N, N) -> N>(&self, other: &Self, f: F) -> Result<Self, ArrayError> {
+        vec![self.shape.0, self.shape.1].matches_shape(&[other.shape.0, other.shape.1])?;
+        let (mut rows, mut cols, mut data) = (vec![], vec![], vec![]);
+        for row in 0..self.shape.0 {
+            let (mut first, mut second) = (self.indptr[row], other.indptr[row]);
+            while first < self.indptr[row + 1] || second < other.indptr[row + 1] {
+                let col_1 = if first < self.indptr[row + 1] { self.indices[first] } else { usize::MAX };
+                let col_2 = if second < other.indptr[row + 1] { other.indices[second] } else { usize::MAX };
+                let col = col_1.min(col_2);
+                let value_1 = if col_1 == col { first += 1; self.data[first - 1] } else { N::zero() };
+                let value_2 = if col_2 == col { second += 1; other.data[second - 1] } else { N::zero() };
+                rows.push(row);
+                cols.push(col);
+                data.push(f(value_1, value_2));
+            }
+        }
+        Ok(Self::from_compressed(self.shape, compress(&rows, &cols, &data, self.shape.0)))
+    }
+}
@@ -0,0 +1,9 @@
+#[cfg(feature = "sparse")]
+pub use crate::sparse::{
+    operations::sparse_matrix::SparseMatrix,
+    types::{
+        coo::CooMatrix,
+        csc::CscMatrix,
+        csr::CsrMatrix,
+    },
+};
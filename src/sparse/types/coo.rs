@@ -0,0 +1,150 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    sparse::operations::common::{validate_coordinates, validate_shape},
+    validators::prelude::*,
+};
+
+/// Sparse matrix in coordinate format, suitable for incremental assembly.
+/// duplicate entries are summed on conversion to compressed formats
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CooMatrix<N: NumericOps> {
+    pub(crate) shape: (usize, usize),
+    pub(crate) rows: Vec<usize>,
+    pub(crate) cols: Vec<usize>,
+    pub(crate) data: Vec<N>,
+}
+
+impl <N: NumericOps> CooMatrix<N> {
+
+    /// Create new coordinate format sparse matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - row indices of the entries
+    /// * `cols` - column indices of the entries
+    /// * `data` - values of the entries
+    /// * `shape` - shape of the matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let coo = CooMatrix::new(&[0, 1, 1], &[1, 0, 1], &[2., 3., 4.], &[2, 2]).unwrap();
+    /// assert_eq!(array!(f64, [[0., 2.], [3., 4.]]), coo.to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn new(rows: &[usize], cols: &[usize], data: &[N], shape: &[usize]) -> Result<Self, ArrayError> {
+        let shape = validate_shape(shape)?;
+        validate_coordinates(rows, cols, data, shape)?;
+        Ok(Self { shape, rows: rows.to_vec(), cols: cols.to_vec(), data: data.to_vec() })
+    }
+
+    /// Create new coordinate format sparse matrix without entries
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - shape of the matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let coo = CooMatrix::<f64>::empty(&[3, 4]).unwrap();
+    /// assert_eq!(Ok(vec![3, 4]), coo.get_shape());
+    /// assert_eq!(Ok(0), coo.nnz());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn empty(shape: &[usize]) -> Result<Self, ArrayError> {
+        Self::new(&[], &[], &[], shape)
+    }
+
+    /// Create coordinate format sparse matrix from non-zero elements of 2-D array
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - dense array to convert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let coo = CooMatrix::from_array(&array!(f64, [[0., 2.], [3., 0.]]).unwrap()).unwrap();
+    /// assert_eq!(vec![0, 1], coo.get_rows());
+    /// assert_eq!(vec![1, 0], coo.get_cols());
+    /// assert_eq!(vec![2., 3.], coo.get_data());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn from_array(array: &Array<N>) -> Result<Self, ArrayError> {
+        array.is_dim_supported(&[2])?;
+        let shape = array.get_shape()?;
+        let mut coo = Self::empty(&shape)?;
+        for (idx, &value) in array.get_elements()?.iter().enumerate() {
+            if value != N::zero() { coo.push(idx / shape[1], idx % shape[1], value)?; }
+        }
+        Ok(coo)
+    }
+
+    /// Append an entry to the matrix. entries at the same position are summed
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - row index of the entry
+    /// * `col` - column index of the entry
+    /// * `value` - value of the entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let mut coo = CooMatrix::empty(&[2, 2]).unwrap();
+    /// coo.push(0, 0, 1.).unwrap();
+    /// coo.push(1, 1, 2.).unwrap();
+    /// coo.push(0, 0, 3.).unwrap();
+    /// assert_eq!(array!(f64, [[4., 0.], [0., 2.]]), coo.to_array());
+    /// assert!(coo.push(2, 0, 1.).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn push(&mut self, row: usize, col: usize, value: N) -> Result<(), ArrayError> {
+        validate_coordinates(&[row], &[col], &[value], self.shape)?;
+        self.rows.push(row);
+        self.cols.push(col);
+        self.data.push(value);
+        Ok(())
+    }
+
+    /// Row indices of the entries
+    #[must_use]
+    pub fn get_rows(&self) -> Vec<usize> {
+        self.rows.clone()
+    }
+
+    /// Column indices of the entries
+    #[must_use]
+    pub fn get_cols(&self) -> Vec<usize> {
+        self.cols.clone()
+    }
+
+    /// Values of the entries
+    #[must_use]
+    pub fn get_data(&self) -> Vec<N> {
+        self.data.clone()
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    sparse::{
+        operations::common::{compress, expand_indptr, validate_shape, Compressed},
+        types::coo::CooMatrix,
+    },
+};
+
+/// Sparse matrix in compressed sparse column format.
+/// entries are kept sorted by row within each column, without duplicates and explicit zeros
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CscMatrix<N: NumericOps> {
+    pub(crate) shape: (usize, usize),
+    pub(crate) indptr: Vec<usize>,
+    pub(crate) indices: Vec<usize>,
+    pub(crate) data: Vec<N>,
+}
+
+impl <N: NumericOps> CscMatrix<N> {
+
+    /// Create new compressed sparse column matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `indptr` - offsets of each column in `indices` and `data`, of length `cols + 1`
+    /// * `indices` - row indices of the entries
+    /// * `data` - values of the entries
+    /// * `shape` - shape of the matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csc = CscMatrix::new(&[0, 1, 3], &[1, 0, 1], &[3., 2., 4.], &[2, 2]).unwrap();
+    /// assert_eq!(array!(f64, [[0., 2.], [3., 4.]]), csc.to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn new(indptr: &[usize], indices: &[usize], data: &[N], shape: &[usize]) -> Result<Self, ArrayError> {
+        let shape = validate_shape(shape)?;
+        let cols = expand_indptr(indptr, indices, data, (shape.1, shape.0))?;
+        Ok(Self::from_compressed(shape, compress(&cols, indices, data, shape.1)))
+    }
+
+    /// Create compressed sparse column matrix from non-zero elements of 2-D array
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - dense array to convert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csc = CscMatrix::from_array(&array!(f64, [[0., 2.], [3., 4.]]).unwrap()).unwrap();
+    /// assert_eq!(vec![0, 1, 3], csc.get_indptr());
+    /// assert_eq!(vec![1, 0, 1], csc.get_indices());
+    /// assert_eq!(vec![3., 2., 4.], csc.get_data());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn from_array(array: &Array<N>) -> Result<Self, ArrayError> {
+        let coo = CooMatrix::from_array(array)?;
+        Ok(Self::from_compressed(coo.shape, compress(&coo.cols, &coo.rows, &coo.data, coo.shape.1)))
+    }
+
+    /// Offsets of each column in `indices` and `data`
+    #[must_use]
+    pub fn get_indptr(&self) -> Vec<usize> {
+        self.indptr.clone()
+    }
+
+    /// Row indices of the entries
+    #[must_use]
+    pub fn get_indices(&self) -> Vec<usize> {
+        self.indices.clone()
+    }
+
+    /// Values of the entries
+    #[must_use]
+    pub fn get_data(&self) -> Vec<N> {
+        self.data.clone()
+    }
+
+    pub(crate) fn from_compressed(shape: (usize, usize), (indptr, indices, data): Compressed<N>) -> Self {
+        Self { shape, indptr, indices, data }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    core::prelude::*,
+    errors::prelude::*,
+    numeric::prelude::*,
+    sparse::{
+        operations::common::{compress, expand_indptr, validate_shape, Compressed},
+        types::coo::CooMatrix,
+    },
+};
+
+/// Sparse matrix in compressed sparse row format.
+/// entries are kept sorted by column within each row, without duplicates and explicit zeros
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsrMatrix<N: NumericOps> {
+    pub(crate) shape: (usize, usize),
+    pub(crate) indptr: Vec<usize>,
+    pub(crate) indices: Vec<usize>,
+    pub(crate) data: Vec<N>,
+}
+
+impl <N: NumericOps> CsrMatrix<N> {
+
+    /// Create new compressed sparse row matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `indptr` - offsets of each row in `indices` and `data`, of length `rows + 1`
+    /// * `indices` - column indices of the entries
+    /// * `data` - values of the entries
+    /// * `shape` - shape of the matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::new(&[0, 1, 3], &[1, 0, 1], &[2., 3., 4.], &[2, 2]).unwrap();
+    /// assert_eq!(array!(f64, [[0., 2.], [3., 4.]]), csr.to_array());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn new(indptr: &[usize], indices: &[usize], data: &[N], shape: &[usize]) -> Result<Self, ArrayError> {
+        let shape = validate_shape(shape)?;
+        let rows = expand_indptr(indptr, indices, data, shape)?;
+        Ok(Self::from_compressed(shape, compress(&rows, indices, data, shape.0)))
+    }
+
+    /// Create compressed sparse row matrix from non-zero elements of 2-D array
+    ///
+    /// # Arguments
+    ///
+    /// * `array` - dense array to convert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arr_rs::prelude::*;
+    ///
+    /// let csr = CsrMatrix::from_array(&array!(f64, [[0., 2.], [3., 4.]]).unwrap()).unwrap();
+    /// assert_eq!(vec![0, 1, 3], csr.get_indptr());
+    /// assert_eq!(vec![1, 0, 1], csr.get_indices());
+    /// assert_eq!(vec![2., 3., 4.], csr.get_data());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// may returns `ArrayError`
+    pub fn from_array(array: &Array<N>) -> Result<Self, ArrayError> {
+        let coo = CooMatrix::from_array(array)?;
+        Ok(Self::from_compressed(coo.shape, compress(&coo.rows, &coo.cols, &coo.data, coo.shape.0)))
+    }
+
+    /// Offsets of each row in `indices` and `data`
+    #[must_use]
+    pub fn get_indptr(&self) -> Vec<usize> {
+        self.indptr.clone()
+    }
+
+    /// Column indices of the entries
+    #[must_use]
+    pub fn get_indices(&self) -> Vec<usize> {
+        self.indices.clone()
+    }
+
+    /// Values of the entries
+    #[must_use]
+    pub fn get_data(&self) -> Vec<N> {
+        self.data.clone()
+    }
+
+    pub(crate) fn from_compressed(shape: (usize, usize), (indptr, indices, data): Compressed<N>) -> Self {
+        Self { shape, indptr, indices, data }
+    }
+}
@@ -0,0 +1,8 @@
+/// Coordinate format sparse matrix type definition
+pub mod coo;
+
+/// Compressed sparse column matrix type definition
+pub mod csc;
+
+/// Compressed sparse row matrix type definition
+pub mod csr;
@@ -55,9 +55,9 @@ impl ValidateShape for Vec<usize> {
         self.len().is_at_least(&2)?;
         let last = self.len() - 1;
         let last_prev = self.len() - 2;
-        self[last].is_at_least(&2)?;
-        self[last_prev].is_at_least(&2)?;
-        self[last].is_equal(&self[last_prev])?;
+        self[last].is_at_least(&1)?;
+        self[last_prev].is_at_least(&1)?;
+        self[last_prev].is_equal(&self[last])?;
         Ok(())
     }
 }
@@ -82,11 +82,7 @@ impl <T: ArrayElement> ValidateShape for Array<T> {
 
     fn is_square(&self) -> Result<(), ArrayError> {
         self.is_dim_unsupported(&[0, 1])?;
-        let shape = self.get_shape()?;
-        shape[0].is_at_least(&2)?;
-        shape[1].is_at_least(&2)?;
-        shape[0].is_equal(&shape[1])?;
-        Ok(())
+        self.get_shape()?.is_square()
     }
 }
 
@@ -0,0 +1,75 @@
+use arr_rs::prelude::*;
+
+/// stack of symmetric, strictly diagonally dominant (so positive definite) matrices of given shape `[..., N, N]`
+pub fn stacked_matrices(shape: &[usize]) -> Array<f64> {
+    let size = shape[shape.len() - 1];
+    let count = shape[..shape.len() - 2].iter().product::<usize>();
+    let elements = (0..count)
+        .flat_map(|batch| (0..size * size).map(move |idx| {
+            let (row, col) = (idx / size, idx % size);
+            if row == col { (size + batch + row) as f64 } else { 1. / (1 + batch + row + col) as f64 }
+        }))
+        .collect();
+    Array::new(elements, shape.to_vec()).unwrap()
+}
+
+/// applies `f` to every matrix of stacked array one by one, and stacks the results over the leading dimensions
+pub fn per_matrix<N: NumericOps, M: ArrayElement, F: Fn(Array<N>) -> Result<Array<M>, ArrayError>>(array: &Array<N>, f: F) -> Result<Array<M>, ArrayError> {
+    let shape = array.get_shape()?;
+    let (leading, sub_shape) = shape.split_at(shape.len() - 2);
+    let results = array.ravel()?
+        .split(leading.iter().product::<usize>(), None)?
+        .into_iter()
+        .map(|matrix| f(matrix.reshape(sub_shape)?))
+        .collect::<Result<Vec<Array<M>>, ArrayError>>()?;
+    // scalar results are shaped as the leading dimensions
+    let result_shape = match results[0].get_shape()? {
+        shape if shape == [1] => vec![],
+        shape => shape,
+    };
+    results.into_iter()
+        .flatten()
+        .collect::<Array<M>>()
+        .reshape(&[leading, &result_shape].concat())
+}
+
+/// values, which can be rounded to fixed number of decimal places, so float results compare exactly
+pub trait Rounded {
+    fn round_to(self, decimals: i32) -> Self;
+}
+
+impl Rounded for f64 {
+    fn round_to(self, decimals: i32) -> Self {
+        let scale = 10_f64.powi(decimals);
+        // adding zero turns `-0.` into `0.`
+        (self * scale).round() / scale + 0.
+    }
+}
+
+impl Rounded for Complex<f64> {
+    fn round_to(self, decimals: i32) -> Self {
+        Self::new(self.re.round_to(decimals), self.im.round_to(decimals))
+    }
+}
+
+impl <N: ArrayElement + Rounded> Rounded for Array<N> {
+    fn round_to(self, decimals: i32) -> Self {
+        self.map(|value| value.clone().round_to(decimals)).unwrap()
+    }
+}
+
+impl <T: Rounded> Rounded for Result<T, ArrayError> {
+    fn round_to(self, decimals: i32) -> Self {
+        self.map(|value| value.round_to(decimals))
+    }
+}
+
+/// rounds value to 9 decimal places
+pub fn rounded<T: Rounded>(value: T) -> T {
+    value.round_to(9)
+}
+
+/// rounds value to given number of decimal places
+pub fn rounded_to<T: Rounded>(value: T, decimals: i32) -> T {
+    value.round_to(decimals)
+}
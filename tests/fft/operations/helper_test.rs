@@ -0,0 +1,41 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+
+#[rstest(
+array, axes, expected,
+case(array![i32, 0, 1, 2, 3, 4, -5, -4, -3, -2, -1], None, array![i32, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4]),
+case(array![i32, 0, 1, 2, -2, -1], None, array![i32, -2, -1, 0, 1, 2]),
+case(array!(i32, [[0, 1, 2], [3, 4, -4], [-3, -2, -1]]), None, array!(i32, [[-1, -3, -2], [2, 0, 1], [-4, 3, 4]])),
+case(array!(i32, [[0, 1, 2], [3, 4, -4], [-3, -2, -1]]), Some(vec![1]), array!(i32, [[2, 0, 1], [-4, 3, 4], [-1, -3, -2]])),
+case(array![i32, 0, 1, 2], Some(vec![1]), Err(ArrayError::AxisOutOfBounds)),
+)] fn test_fftshift(array: Result<Array<i32>, ArrayError>, axes: Option<Vec<isize>>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.fftshift(axes))
+}
+
+#[rstest(
+array, axes, expected,
+case(array![i32, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4], None, array![i32, 0, 1, 2, 3, 4, -5, -4, -3, -2, -1]),
+case(array![i32, -2, -1, 0, 1, 2], None, array![i32, 0, 1, 2, -2, -1]),
+case(array!(i32, [[-1, -3, -2], [2, 0, 1], [-4, 3, 4]]), None, array!(i32, [[0, 1, 2], [3, 4, -4], [-3, -2, -1]])),
+)] fn test_ifftshift(array: Result<Array<i32>, ArrayError>, axes: Option<Vec<isize>>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.ifftshift(axes))
+}
+
+#[rstest(
+n, d, expected,
+case(4, None, array![f64, 0., 0.25, -0.5, -0.25]),
+case(5, None, array![f64, 0., 0.2, 0.4, -0.4, -0.2]),
+case(8, Some(0.1), array![f64, 0., 1.25, 2.5, 3.75, -5., -3.75, -2.5, -1.25]),
+case(0, None, Err(ArrayError::ParameterError { param: "`n`", message: "must be greater than zero" })),
+)] fn test_fftfreq(n: usize, d: Option<f64>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, Array::fftfreq(n, d))
+}
+
+#[rstest(
+n, d, expected,
+case(4, None, array![f64, 0., 0.25, 0.5]),
+case(5, None, array![f64, 0., 0.2, 0.4]),
+case(8, Some(0.1), array![f64, 0., 1.25, 2.5, 3.75, 5.]),
+)] fn test_rfftfreq(n: usize, d: Option<f64>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, Array::rfftfreq(n, d))
+}
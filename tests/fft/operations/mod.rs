@@ -0,0 +1,2 @@
+mod helper_test;
+mod transforms_test;
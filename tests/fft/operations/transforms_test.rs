@@ -0,0 +1,101 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+#[rstest(
+array, n, axis, norm, expected,
+case(array![f64, 1., 2., 3., 4.], None, None, None, Array::flat(vec![c(10., 0.), c(-2., 2.), c(-2., 0.), c(-2., -2.)])),
+case(array![f64, 1., 2., 3., 4.], Some(2), None, None, Array::flat(vec![c(3., 0.), c(-1., 0.)])),
+case(array![f64, 1., 2.], Some(4), None, None, Array::flat(vec![c(3., 0.), c(1., -2.), c(-1., 0.), c(1., 2.)])),
+case(array![f64, 1., 2., 3., 4.], None, None, Some(FftNorm::Ortho), Array::flat(vec![c(5., 0.), c(-1., 1.), c(-1., 0.), c(-1., -1.)])),
+case(array![f64, 1., 2., 3., 4.], None, None, Some(FftNorm::Forward), Array::flat(vec![c(2.5, 0.), c(-0.5, 0.5), c(-0.5, 0.), c(-0.5, -0.5)])),
+case(array![f64, 1., 2., 3.], None, None, None, Array::flat(vec![c(6., 0.), c(-1.5000000000000009, 0.8660254037844377), c(-1.5000000000000002, -0.8660254037844393)])),
+case(array!(f64, [[1., 2.], [3., 4.]]), None, Some(0), None, Array::new(vec![c(4., 0.), c(6., 0.), c(-2., 0.), c(-2., 0.)], vec![2, 2])),
+case(array!(f64, [[1., 2.], [3., 4.]]), None, None, None, Array::new(vec![c(3., 0.), c(-1., 0.), c(7., 0.), c(-1., 0.)], vec![2, 2])),
+case(array![f64, 1., 2., 3., 4.], Some(0), None, None, Err(ArrayError::ParameterError { param: "`n`", message: "invalid number of data points" })),
+case(array![f64, 1., 2., 3., 4.], None, Some(1), None, Err(ArrayError::AxisOutOfBounds)),
+)] fn test_fft(array: Result<Array<f64>, ArrayError>, n: Option<usize>, axis: Option<isize>, norm: Option<FftNorm>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.fft(n, axis, norm))
+}
+
+#[rstest(
+n,
+case(37),
+case(67),
+case(97),
+)] fn test_fft_prime_length(n: usize) {
+    // prime lengths above 32 are computed with bluestein algorithm, compared against direct dft
+    let input = (0..n).map(|k| (k * k % 11) as f64 - 5.).collect::<Vec<f64>>();
+    let expected = (0..n).map(|k| input.iter().enumerate()
+        .map(|(j, x)| Complex::from_polar(*x, -2. * std::f64::consts::PI * ((j * k) % n) as f64 / n as f64))
+        .fold(c(0., 0.), |acc, value| acc + value))
+        .collect::<Vec<Complex<f64>>>();
+    let result = Array::flat(input.clone()).fft(None, None, None::<FftNorm>).unwrap();
+    assert!(result.get_elements().unwrap().iter().zip(&expected).all(|(value, expected)| (*value - *expected).norm() < 1e-9));
+    let inverse = result.ifft(None, None, None::<FftNorm>).unwrap();
+    assert!(inverse.get_elements().unwrap().iter().zip(&input).all(|(value, expected)| (*value - c(*expected, 0.)).norm() < 1e-12));
+}
+
+#[rstest(
+array, n, axis, norm, expected,
+case(Array::flat(vec![c(10., 0.), c(-2., 2.), c(-2., 0.), c(-2., -2.)]), None, None, None, Array::flat(vec![c(1., 0.), c(2., 0.), c(3., 0.), c(4., 0.)])),
+case(Array::flat(vec![c(2.5, 0.), c(-0.5, 0.5), c(-0.5, 0.), c(-0.5, -0.5)]), None, None, Some(FftNorm::Forward), Array::flat(vec![c(1., 0.), c(2., 0.), c(3., 0.), c(4., 0.)])),
+case(Array::flat(vec![c(0., 4.), c(0., 0.)]), None, None, None, Array::flat(vec![c(0., 2.), c(0., 2.)])),
+)] fn test_ifft(array: Result<Array<Complex<f64>>, ArrayError>, n: Option<usize>, axis: Option<isize>, norm: Option<FftNorm>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.ifft(n, axis, norm))
+}
+
+#[rstest(
+array, n, axis, norm, expected,
+case(array![f64, 1., 2., 3., 4.], None, None, None, Array::flat(vec![c(10., 0.), c(-2., 2.), c(-2., 0.)])),
+case(array![f64, 1., 2., 3., 4., 5., 6., 7., 8.], Some(4), None, None, Array::flat(vec![c(10., 0.), c(-2., 2.), c(-2., 0.)])),
+case(array!(f64, [[1., 2.], [3., 4.]]), None, Some(0), None, Array::new(vec![c(4., 0.), c(6., 0.), c(-2., 0.), c(-2., 0.)], vec![2, 2])),
+)] fn test_rfft(array: Result<Array<f64>, ArrayError>, n: Option<usize>, axis: Option<isize>, norm: Option<FftNorm>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.rfft(n, axis, norm))
+}
+
+#[rstest(
+array, n, axis, norm, expected,
+case(Array::flat(vec![c(10., 0.), c(-2., 2.), c(-2., 0.)]), None, None, None, array![f64, 1., 2., 3., 4.]),
+case(Array::flat(vec![c(10., 0.), c(-2., 2.), c(-2., 0.)]), Some(4), None, None, array![f64, 1., 2., 3., 4.]),
+case(Array::flat(vec![c(10., 0.), c(-2., 2.), c(-2., 0.)]), Some(2), None, None, array![f64, 4., 6.]),
+case(Array::flat(vec![c(1., 0.)]), None, None, None, Err(ArrayError::ParameterError { param: "`n`", message: "invalid number of data points" })),
+)] fn test_irfft(array: Result<Array<Complex<f64>>, ArrayError>, n: Option<usize>, axis: Option<isize>, norm: Option<FftNorm>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.irfft(n, axis, norm))
+}
+
+#[rstest(
+array, s, axes, expected,
+case(array!(f64, [[1., 2.], [3., 4.]]), None, None, Array::new(vec![c(10., 0.), c(-2., 0.), c(-4., 0.), c(0., 0.)], vec![2, 2])),
+case(array!(f64, [[1., 2.], [3., 4.]]), Some(vec![2, 4]), None, Array::new(vec![c(10., 0.), c(4., -6.), c(-2., 0.), c(4., 6.), c(-4., 0.), c(-2., 2.), c(0., 0.), c(-2., -2.)], vec![2, 4])),
+case(array!(f64, [[1., 2.], [3., 4.]]), None, Some(vec![0]), Array::new(vec![c(4., 0.), c(6., 0.), c(-2., 0.), c(-2., 0.)], vec![2, 2])),
+case(array!(f64, [[1., 2.], [3., 4.]]), Some(vec![2, 2]), Some(vec![0]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "1".to_string() })),
+)] fn test_fft2(array: Result<Array<f64>, ArrayError>, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.fft2(s, axes, None::<FftNorm>))
+}
+
+#[rstest(
+array, s, axes, expected,
+case(Array::new(vec![c(10., 0.), c(-2., 0.), c(-4., 0.), c(0., 0.)], vec![2, 2]), None, None, Array::new(vec![c(1., 0.), c(2., 0.), c(3., 0.), c(4., 0.)], vec![2, 2])),
+)] fn test_ifft2(array: Result<Array<Complex<f64>>, ArrayError>, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.ifft2(s, axes, None::<FftNorm>))
+}
+
+#[rstest(
+array, s, axes, expected,
+case(array!(f64, [[1., 2.], [3., 4.]]), None, None, Array::new(vec![c(10., 0.), c(-2., 0.), c(-4., 0.), c(0., 0.)], vec![2, 2])),
+case(array!(f64, [[[1., 1.], [1., 1.]], [[1., 1.], [1., 1.]]]), None, None, Array::new(vec![c(8., 0.), c(0., 0.), c(0., 0.), c(0., 0.), c(0., 0.), c(0., 0.), c(0., 0.), c(0., 0.)], vec![2, 2, 2])),
+case(array!(f64, [[[1., 1.], [1., 1.]], [[1., 1.], [1., 1.]]]), Some(vec![2]), None, Array::new(vec![c(2., 0.), c(0., 0.), c(2., 0.), c(0., 0.), c(2., 0.), c(0., 0.), c(2., 0.), c(0., 0.)], vec![2, 2, 2])),
+)] fn test_fftn(array: Result<Array<f64>, ArrayError>, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.fftn(s, axes, None::<FftNorm>))
+}
+
+#[rstest(
+array, s, axes, expected,
+case(Array::new(vec![c(8., 0.), c(0., 0.), c(0., 0.), c(0., 0.), c(0., 0.), c(0., 0.), c(0., 0.), c(0., 0.)], vec![2, 2, 2]), None, None, Array::new(vec![c(1., 0.); 8], vec![2, 2, 2])),
+)] fn test_ifftn(array: Result<Array<Complex<f64>>, ArrayError>, s: Option<Vec<usize>>, axes: Option<Vec<isize>>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.ifftn(s, axes, None::<FftNorm>))
+}
@@ -0,0 +1,27 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+
+#[rstest(
+x, xp, fp, left, right, expected,
+case(array_flat!(f64, 2.5), array_flat!(f64, 1., 2., 3.), array_flat!(f64, 3., 2., 0.), None, None, array_flat!(f64, 1.)),
+case(array_flat!(f64, 0., 1., 1.5, 2.75, 3.14), array_flat!(f64, 1., 2., 3.), array_flat!(f64, 3., 2., 0.), None, None, array_flat!(f64, 3., 3., 2.5, 0.5, 0.)),
+case(array_flat!(f64, 0., 3.14), array_flat!(f64, 1., 2., 3.), array_flat!(f64, 3., 2., 0.), Some(-99.), Some(99.), array_flat!(f64, -99., 99.)),
+case(array!(f64, [[0.5, 1.], [1.5, 2.]]), array_flat!(f64, 0., 2.), array_flat!(f64, 0., 4.), None, None, array!(f64, [[1., 2.], [3., 4.]])),
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 1.), array_flat!(f64, 5.), None, None, array_flat!(f64, 5., 5., 5.)),
+case(array_flat!(f64, 1.), array_flat!(f64, 0., 1., 1., 2.), array_flat!(f64, 0., 1., 3., 4.), None, None, array_flat!(f64, 3.)),
+case(array_flat!(i32, 0, 1, 2, 3), array_flat!(i32, 0, 3), array_flat!(i32, 0, 6), None, None, array_flat!(i32, 0, 2, 4, 6)),
+case(array_flat!(f64, 1.), array_flat!(f64, 2., 1.), array_flat!(f64, 0., 1.), None, None, Err(ArrayError::ParameterError { param: "`xp`", message: "must be increasing" })),
+case(array_flat!(f64, 1.), array_flat!(f64, 1., 2.), array_flat!(f64, 0., 1., 2.), None, None, Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+case(array_flat!(f64, 1.), Array::flat(vec![]), Array::flat(vec![]), None, None, Err(ArrayError::MustBeAtLeast { value1: "0".to_string(), value2: "1".to_string() })),
+)] fn test_interp<N: NumericOps>(x: Result<Array<N>, ArrayError>, xp: Result<Array<N>, ArrayError>, fp: Result<Array<N>, ArrayError>, left: Option<N>, right: Option<N>, expected: Result<Array<N>, ArrayError>) {
+    assert_eq!(expected, x.interp(&xp.unwrap(), &fp.unwrap(), left, right, None))
+}
+
+#[rstest(
+x, xp, fp, period, expected,
+case(array_flat!(f64, -180., -170., -185., 185., -10., -5., 0., 365.), array_flat!(f64, 190., -190., 350., -350.), array_flat!(f64, 5., 10., 3., 4.), 360., array_flat!(f64, 7.5, 5., 8.75, 6.25, 3., 3.25, 3.5, 3.75)),
+case(array_flat!(f64, 0.5, 1.5, -0.5), array_flat!(f64, 0.), array_flat!(f64, 2.), 1., array_flat!(f64, 2., 2., 2.)),
+case(array_flat!(f64, 1.), array_flat!(f64, 0., 1.), array_flat!(f64, 0., 1.), 0., Err(ArrayError::ParameterError { param: "`period`", message: "must be non-zero" })),
+)] fn test_interp_period(x: Result<Array<f64>, ArrayError>, xp: Result<Array<f64>, ArrayError>, fp: Result<Array<f64>, ArrayError>, period: f64, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, x.interp(&xp.unwrap(), &fp.unwrap(), None, None, Some(period)))
+}
@@ -0,0 +1,157 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded;
+
+fn spline(x: Vec<f64>, y: Vec<f64>, boundary: &str) -> CubicSpline<f64> {
+    CubicSpline::new(&Array::flat(x).unwrap(), &Array::flat(y).unwrap(), Some(boundary)).unwrap()
+}
+
+fn b_spline(knots: Vec<f64>, coef: Vec<f64>, degree: usize) -> BSpline<f64> {
+    BSpline::new(&Array::flat(knots).unwrap(), &Array::flat(coef).unwrap(), degree).unwrap()
+}
+
+#[rstest(
+x, y, boundary, expected,
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 0., 1., 0.), SplineBoundary::Natural, Ok(SplineBoundary::Natural)),
+case(array_flat!(f64, 0., 1.), array_flat!(f64, 0., 1.), SplineBoundary::Clamped(1., 2.), Ok(SplineBoundary::Clamped(1., 2.))),
+case(array_flat!(f64, 0., 2., 1.), array_flat!(f64, 0., 1., 0.), SplineBoundary::NotAKnot, Err(ArrayError::ParameterError { param: "`x`", message: "must be strictly increasing" })),
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 0., 1.), SplineBoundary::NotAKnot, Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array_flat!(f64, 0.), array_flat!(f64, 0.), SplineBoundary::NotAKnot, Err(ArrayError::MustBeAtLeast { value1: "1".to_string(), value2: "2".to_string() })),
+case(array_flat!(f64, 0., 1.), array_flat!(f64, 0., f64::INFINITY), SplineBoundary::NotAKnot, Err(ArrayError::ParameterError { param: "`x`, `y`", message: "sample points must be finite" })),
+case(array!(f64, [[0., 1.]]), array_flat!(f64, 0., 1.), SplineBoundary::NotAKnot, Err(ArrayError::UnsupportedDimension { supported: vec![1] })),
+)] fn test_cubic_spline_new(x: Result<Array<f64>, ArrayError>, y: Result<Array<f64>, ArrayError>, boundary: SplineBoundary, expected: Result<SplineBoundary, ArrayError>) {
+    assert_eq!(expected, CubicSpline::new(&x.unwrap(), &y.unwrap(), Some(boundary)).map(|s| s.get_boundary()))
+}
+
+#[rstest(
+boundary, expected,
+case("not-a-knot", Ok(SplineBoundary::NotAKnot)),
+case("natural", Ok(SplineBoundary::Natural)),
+case("clamped", Ok(SplineBoundary::Clamped(0., 0.))),
+case("periodic", Err(ArrayError::ParameterError { param: "`boundary`", message: "must be one of {`not-a-knot`, `natural`, `clamped`}" })),
+)] fn test_spline_boundary(boundary: &str, expected: Result<SplineBoundary, ArrayError>) {
+    assert_eq!(expected, boundary.to_boundary())
+}
+
+#[rstest(
+interpolator, x, expected,
+case(spline(vec![0., 1., 2., 3., 4.], vec![0., -1., 4., 21., 56.], "not-a-knot"), array_flat!(f64, 0.5, 2.5, 5., -1.), array_flat!(f64, -0.875, 10.625, 115., 1.)),
+case(spline(vec![0., 1., 2.], vec![0., 1., 0.], "natural"), array_flat!(f64, 0.5, 1., 1.5), array_flat!(f64, 0.6875, 1., 0.6875)),
+case(spline(vec![0., 1.], vec![0., 1.], "clamped"), array_flat!(f64, 0.25, 0.5, 1.), array_flat!(f64, 0.15625, 0.5, 1.)),
+case(spline(vec![0., 1., 2.], vec![0., 1., 4.], "not-a-knot"), array!(f64, [[0.5], [3.]]), array!(f64, [[0.25], [9.]])),
+)] fn test_cubic_spline_eval(interpolator: CubicSpline<f64>, x: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(interpolator.eval(&x.unwrap())))
+}
+
+#[rstest(
+interpolator, nu, expected,
+case(spline(vec![0., 1., 2., 3., 4.], vec![0., -1., 4., 21., 56.], "not-a-knot"), None, array_flat!(f64, -1.25, 1.)),
+case(spline(vec![0., 1., 2., 3., 4.], vec![0., -1., 4., 21., 56.], "not-a-knot"), Some(2), array_flat!(f64, 3., 6.)),
+case(spline(vec![0., 1., 2., 3., 4.], vec![0., -1., 4., 21., 56.], "not-a-knot"), Some(3), array_flat!(f64, 6., 6.)),
+case(spline(vec![0., 1., 2., 3., 4.], vec![0., -1., 4., 21., 56.], "not-a-knot"), Some(4), array_flat!(f64, 0., 0.)),
+case(spline(vec![0., 1., 2.], vec![0., 1., 0.], "natural"), None, array_flat!(f64, 1.125, 0.)),
+)] fn test_cubic_spline_derivative(interpolator: CubicSpline<f64>, nu: Option<usize>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(interpolator.derivative(&array_flat!(f64, 0.5, 1.).unwrap(), nu)))
+}
+
+#[rstest(
+interpolator, a, b, expected,
+case(spline(vec![0., 1., 2., 3., 4.], vec![0., -1., 4., 21., 56.], "not-a-knot"), 0., 4., Ok(48.)),
+case(spline(vec![0., 1., 2., 3., 4.], vec![0., -1., 4., 21., 56.], "not-a-knot"), 4., 0., Ok(-48.)),
+case(spline(vec![0., 1., 2., 3., 4.], vec![0., -1., 4., 21., 56.], "not-a-knot"), -1., 5., Ok(132.)),
+case(spline(vec![0., 1., 2.], vec![0., 1., 0.], "natural"), 0., 2., Ok(1.25)),
+case(spline(vec![0., 1.], vec![0., 1.], "clamped"), 0., 1., Ok(0.5)),
+)] fn test_cubic_spline_integrate(interpolator: CubicSpline<f64>, a: f64, b: f64, expected: Result<f64, ArrayError>) {
+    assert_eq!(expected, rounded(interpolator.integrate(a, b)))
+}
+
+#[rstest(
+x, y, eval_x, expected,
+case(array_flat!(f64, 0., 1., 2., 3.), array_flat!(f64, 0., 0., 1., 1.), array_flat!(f64, 0.5, 1.5, 2.5), array_flat!(f64, 0., 0.5, 1.)),
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 0., 1., 4.), array_flat!(f64, 0.5, 1.5, 2.), array_flat!(f64, 0.3125, 2.1875, 4.)),
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 1., 3., 5.), array_flat!(f64, -1., 1.5, 3.), array_flat!(f64, -1., 4., 7.)),
+case(array_flat!(f64, 0., 1.), array_flat!(f64, 2., 0.), array_flat!(f64, 0.25), array_flat!(f64, 1.5)),
+case(array_flat!(f64, 1., 0.), array_flat!(f64, 2., 0.), array_flat!(f64, 0.25), Err(ArrayError::ParameterError { param: "`x`", message: "must be strictly increasing" })),
+)] fn test_pchip_eval(x: Result<Array<f64>, ArrayError>, y: Result<Array<f64>, ArrayError>, eval_x: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(Pchip::new(&x.unwrap(), &y.unwrap()).eval(&eval_x.unwrap())))
+}
+
+#[rstest(
+x, y, nu, expected,
+case(array_flat!(f64, 0., 1., 2., 3.), array_flat!(f64, 0., 0., 1., 1.), None, array_flat!(f64, 0., 1.5)),
+case(array_flat!(f64, 0., 1., 2., 3.), array_flat!(f64, 0., 0., 1., 1.), Some(2), array_flat!(f64, 0., 0.)),
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 0., 1., 4.), None, array_flat!(f64, 1.125, 3.125)),
+)] fn test_pchip_derivative(x: Result<Array<f64>, ArrayError>, y: Result<Array<f64>, ArrayError>, nu: Option<usize>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(Pchip::new(&x.unwrap(), &y.unwrap()).derivative(&array_flat!(f64, 0.5, 1.5).unwrap(), nu)))
+}
+
+#[rstest(
+x, y, a, b, expected,
+case(array_flat!(f64, 0., 1., 2., 3.), array_flat!(f64, 0., 0., 1., 1.), 0., 3., Ok(1.5)),
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 1., 3., 5.), -1., 3., Ok(12.)),
+)] fn test_pchip_integrate(x: Result<Array<f64>, ArrayError>, y: Result<Array<f64>, ArrayError>, a: f64, b: f64, expected: Result<f64, ArrayError>) {
+    assert_eq!(expected, rounded(Pchip::new(&x.unwrap(), &y.unwrap()).integrate(a, b)))
+}
+
+#[rstest(
+x, y, eval_x, expected,
+case(array_flat!(f64, 0., 1., 2., 3., 4.), array_flat!(f64, 0., 0., 0., 1., 1.), array_flat!(f64, 0.5, 1.5, 2.5, 3.5), array_flat!(f64, 0., 0., 0.4375, 1.125)),
+case(array_flat!(f64, 0., 1., 3.), array_flat!(f64, 1., 2., 4.), array_flat!(f64, -1., 2., 4.), array_flat!(f64, 0., 3., 5.)),
+case(array_flat!(f64, 0., 1.), array_flat!(f64, 0., 2.), array_flat!(f64, 0.25), array_flat!(f64, 0.5)),
+case(array_flat!(f64, 0., 1.), array_flat!(f64, 0., 1., 2.), array_flat!(f64, 0.25), Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+)] fn test_akima_eval(x: Result<Array<f64>, ArrayError>, y: Result<Array<f64>, ArrayError>, eval_x: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(Akima::new(&x.unwrap(), &y.unwrap()).eval(&eval_x.unwrap())))
+}
+
+#[rstest(
+x, y, expected_derivative, expected_integral,
+case(array_flat!(f64, 0., 1., 2., 3., 4.), array_flat!(f64, 0., 0., 0., 1., 1.), array_flat!(f64, 0., 0., 1.375, 0.), Ok(0.458333333)),
+case(array_flat!(f64, 0., 1., 3.), array_flat!(f64, 1., 2., 4.), array_flat!(f64, 1., 1., 1., 1.), Ok(7.5)),
+)] fn test_akima_derivative_integrate(x: Result<Array<f64>, ArrayError>, y: Result<Array<f64>, ArrayError>, expected_derivative: Result<Array<f64>, ArrayError>, expected_integral: Result<f64, ArrayError>) {
+    let akima = Akima::new(&x.unwrap(), &y.unwrap());
+    assert_eq!(expected_derivative, rounded(akima.derivative(&array_flat!(f64, 0.5, 1.5, 2.5, 3.5).unwrap(), None)));
+    assert_eq!(expected_integral, rounded(akima.integrate(0., 3.)));
+}
+
+#[rstest(
+knots, coef, degree, expected,
+case(array_flat!(f64, 0., 0., 1., 2., 2.), array_flat!(f64, 0., 1., 0.), 1, Ok(1)),
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 3., 5., 7.), 0, Ok(0)),
+case(array_flat!(f64, 0., 0., 1., 2., 2.), array_flat!(f64, 0., 1.), 1, Err(ArrayError::MustBeAtLeast { value1: "2".to_string(), value2: "3".to_string() })),
+case(array_flat!(f64, 0., 0., 1.), array_flat!(f64, 0., 1.), 1, Err(ArrayError::MustBeAtLeast { value1: "3".to_string(), value2: "4".to_string() })),
+case(array_flat!(f64, 0., 2., 1., 2.), array_flat!(f64, 0., 1.), 1, Err(ArrayError::ParameterError { param: "`knots`", message: "must be non-decreasing" })),
+case(array_flat!(f64, 0., 1., 1., 2.), array_flat!(f64, 0., 1.), 1, Err(ArrayError::ParameterError { param: "`knots`", message: "base interval must not be empty" })),
+)] fn test_b_spline_new(knots: Result<Array<f64>, ArrayError>, coef: Result<Array<f64>, ArrayError>, degree: usize, expected: Result<usize, ArrayError>) {
+    assert_eq!(expected, BSpline::new(&knots.unwrap(), &coef.unwrap(), degree).map(|s| s.get_degree()))
+}
+
+#[rstest(
+interpolator, x, expected,
+case(b_spline(vec![0., 1., 2.], vec![3., 5.], 0), array_flat!(f64, -1., 0.5, 1., 2.), array_flat!(f64, 3., 3., 5., 5.)),
+case(b_spline(vec![0., 0., 1., 2., 2.], vec![0., 1., 0.], 1), array_flat!(f64, 0.5, 1., 1.5, 3.), array_flat!(f64, 0.5, 1., 0.5, -1.)),
+case(b_spline(vec![0., 0., 0., 1., 1., 1.], vec![0., 1., 0.], 2), array_flat!(f64, 0.25, 0.5, 1.), array_flat!(f64, 0.375, 0.5, 0.)),
+case(b_spline(vec![0., 0., 0., 0., 1., 1., 1., 1.], vec![0., 1., 2., 3.], 3), array!(f64, [[0.5], [2.]]), array!(f64, [[1.5], [6.]])),
+case(b_spline(vec![0., 1., 2., 3., 4., 5., 6.], vec![0., 0., 1., 0., 0.], 2), array_flat!(f64, 2.5, 3., 3.5), array_flat!(f64, 0.125, 0.5, 0.75)),
+)] fn test_b_spline_eval(interpolator: BSpline<f64>, x: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(interpolator.eval(&x.unwrap())))
+}
+
+#[rstest(
+interpolator, nu, expected,
+case(b_spline(vec![0., 0., 1., 2., 2.], vec![0., 1., 0.], 1), None, array_flat!(f64, 1., -1.)),
+case(b_spline(vec![0., 0., 1., 2., 2.], vec![0., 1., 0.], 1), Some(2), array_flat!(f64, 0., 0.)),
+case(b_spline(vec![0., 0., 0., 2., 2., 2.], vec![0., 2., 0.], 2), None, array_flat!(f64, 1., -1.)),
+case(b_spline(vec![0., 0., 0., 2., 2., 2.], vec![0., 2., 0.], 2), Some(2), array_flat!(f64, -2., -2.)),
+)] fn test_b_spline_derivative(interpolator: BSpline<f64>, nu: Option<usize>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(interpolator.derivative(&array_flat!(f64, 0.5, 1.5).unwrap(), nu)))
+}
+
+#[rstest(
+interpolator, a, b, expected,
+case(b_spline(vec![0., 0., 1., 2., 2.], vec![0., 1., 0.], 1), 0., 2., Ok(1.)),
+case(b_spline(vec![0., 0., 0., 1., 1., 1.], vec![0., 1., 0.], 2), 0., 1., Ok(0.333333333)),
+case(b_spline(vec![0., 0., 0., 0., 1., 1., 1., 1.], vec![0., 1., 2., 3.], 3), 1., 0., Ok(-1.5)),
+case(b_spline(vec![0., 1., 2.], vec![3., 5.], 0), 0., 2., Ok(8.)),
+)] fn test_b_spline_integrate(interpolator: BSpline<f64>, a: f64, b: f64, expected: Result<f64, ArrayError>) {
+    assert_eq!(expected, rounded(interpolator.integrate(a, b)))
+}
@@ -0,0 +1,2 @@
+mod interp_test;
+mod interpolator_test;
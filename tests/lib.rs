@@ -3,8 +3,14 @@ extern crate arr_rs;
 
 mod alphanumeric;
 mod boolean;
+mod common;
 mod core;
+mod fft;
+mod interpolate;
 mod macros;
 mod linalg;
 mod math;
 mod numeric;
+mod polynomial;
+mod random;
+mod sparse;
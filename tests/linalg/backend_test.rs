@@ -0,0 +1,77 @@
+use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+
+use arr_rs::prelude::*;
+
+#[derive(Debug, Default)]
+struct Calls {
+    gemm: AtomicUsize,
+    lu: AtomicUsize,
+    qr: AtomicUsize,
+    svd: AtomicUsize,
+    schur: AtomicUsize,
+    real_schur: AtomicUsize,
+    eigh: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct CountingBackend(Arc<Calls>);
+
+impl LinalgBackend for CountingBackend {
+
+    fn gemm(&self, rows: usize, depth: usize, cols: usize, a: &[f64], b: &[f64], c: &mut [f64]) {
+        self.0.gemm.fetch_add(1, Ordering::SeqCst);
+        DefaultBackend.gemm(rows, depth, cols, a, b, c);
+    }
+
+    fn lu(&self, matrix: Vec<Vec<f64>>) -> (Vec<Vec<f64>>, Vec<usize>) {
+        self.0.lu.fetch_add(1, Ordering::SeqCst);
+        DefaultBackend.lu(matrix)
+    }
+
+    fn qr(&self, matrix: Vec<Vec<f64>>, pivoting: bool) -> (Vec<Vec<f64>>, Vec<f64>, Vec<usize>) {
+        self.0.qr.fetch_add(1, Ordering::SeqCst);
+        DefaultBackend.qr(matrix, pivoting)
+    }
+
+    fn svd(&self, matrix: &[Vec<f64>]) -> SvdKernelResult {
+        self.0.svd.fetch_add(1, Ordering::SeqCst);
+        DefaultBackend.svd(matrix)
+    }
+
+    fn schur(&self, matrix: Vec<Vec<Complex<f64>>>) -> SchurKernelResult {
+        self.0.schur.fetch_add(1, Ordering::SeqCst);
+        DefaultBackend.schur(matrix)
+    }
+
+    fn real_schur(&self, matrix: Vec<Vec<f64>>) -> RealSchurKernelResult {
+        self.0.real_schur.fetch_add(1, Ordering::SeqCst);
+        DefaultBackend.real_schur(matrix)
+    }
+
+    fn eigh(&self, matrix: &[Vec<Complex<f64>>]) -> EighKernelResult {
+        self.0.eigh.fetch_add(1, Ordering::SeqCst);
+        DefaultBackend.eigh(matrix)
+    }
+}
+
+#[test]
+fn test_linalg_backend() {
+    // registered backend is global, so that it delegates to the default kernels not to affect other tests
+    let calls = Arc::new(Calls::default());
+    set_linalg_backend(CountingBackend(calls.clone()));
+    assert!(format!("{:?}", linalg_backend()).starts_with("CountingBackend"));
+
+    let array = array!(f64, [[4., 1.], [1., 3.]]).unwrap();
+    assert_eq!(array!(f64, [[17., 7.], [7., 10.]]), array.matmul(&array));
+    assert_eq!(Array::single(11.), array.det());
+    assert_eq!(vec![2, 2], array.qr(None::<QrMode>, None).unwrap().1.get_shape().unwrap());
+    assert_eq!(Array::single(2), array.matrix_rank(None));
+    assert_eq!(vec![2], array.eigvals().unwrap().get_shape().unwrap());
+    assert_eq!(vec![2], array.eigvalsh(None).unwrap().get_shape().unwrap());
+    assert_eq!(vec![2, 2], array.schur().unwrap().0.get_shape().unwrap());
+    set_linalg_backend(DefaultBackend);
+
+    [&calls.gemm, &calls.lu, &calls.qr, &calls.svd, &calls.schur, &calls.real_schur, &calls.eigh].iter()
+        .for_each(|count| assert!(count.load(Ordering::SeqCst) > 0));
+    assert_eq!("DefaultBackend", format!("{:?}", linalg_backend()));
+}
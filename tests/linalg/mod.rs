@@ -1 +1,2 @@
+mod backend_test;
 mod operations;
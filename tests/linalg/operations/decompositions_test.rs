@@ -1,20 +1,381 @@
 use rstest::rstest;
 use arr_rs::prelude::*;
+use crate::common::{per_matrix, rounded, stacked_matrices};
+
+#[rstest(
+array, mode, pivoting, expected,
+case(array!(f64, [[1., 2.], [3., 4.]]), None, None, Ok((
+    Some(array!(f64, [[-0.316227766, -0.948683298], [-0.948683298, 0.316227766]]).unwrap()),
+    array!(f64, [[-3.16227766, -4.427188724], [0., -0.632455532]]).unwrap(),
+    None,
+))),
+case(array!(f64, [[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]), Some("reduced"), None, Ok((
+    Some(array!(f64, [[-0.857142857, 0.394285714, 0.331428571], [-0.428571429, -0.902857143, -0.034285714], [0.285714286, -0.171428571, 0.942857143]]).unwrap()),
+    array!(f64, [[-14., -21., 14.], [0., -175., 70.], [0., 0., -35.]]).unwrap(),
+    None,
+))),
+case(array!(f64, [[1., 2.], [3., 4.], [5., 6.]]), Some("reduced"), None, Ok((
+    Some(array!(f64, [[-0.169030851, 0.897085227], [-0.507092553, 0.276026224], [-0.845154255, -0.345032780]]).unwrap()),
+    array!(f64, [[-5.916079783, -7.437357442], [0., 0.828078671]]).unwrap(),
+    None,
+))),
+case(array!(f64, [[1., 2.], [3., 4.], [5., 6.]]), Some("complete"), None, Ok((
+    Some(array!(f64, [[-0.169030851, 0.897085227, 0.408248290], [-0.507092553, 0.276026224, -0.816496581], [-0.845154255, -0.345032780, 0.408248290]]).unwrap()),
+    array!(f64, [[-5.916079783, -7.437357442], [0., 0.828078671], [0., 0.]]).unwrap(),
+    None,
+))),
+case(array!(f64, [[1., 2.], [3., 4.], [5., 6.]]), Some("r"), None, Ok((
+    None,
+    array!(f64, [[-5.916079783, -7.437357442], [0., 0.828078671]]).unwrap(),
+    None,
+))),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), None, None, Ok((
+    Some(array!(f64, [[-0.242535625, -0.970142500], [-0.970142500, 0.242535625]]).unwrap()),
+    array!(f64, [[-4.123105626, -5.335783751, -6.548461876], [0., -0.727606875, -1.455213750]]).unwrap(),
+    None,
+))),
+case(array!(f64, [[1., 2.], [3., 4.]]), Some("raw"), None, Ok((
+    Some(array!(f64, [[-3.16227766, -4.427188724], [0.720759220, -0.632455532]]).unwrap()),
+    array_flat!(f64, 1.316227766, 0.).unwrap(),
+    None,
+))),
+case(array!(f64, [[1., 2.], [3., 4.]]), None, Some(true), Ok((
+    Some(array!(f64, [[-0.447213595, -0.894427191], [-0.894427191, 0.447213595]]).unwrap()),
+    array!(f64, [[-4.472135955, -3.130495168], [0., 0.447213595]]).unwrap(),
+    Some(array_flat!(usize, 1, 0).unwrap()),
+))),
+case(array!(f64, [[[1., 2.], [3., 4.]], [[2., 0.], [0., 3.]]]), Some("R"), None, Ok((
+    None,
+    array!(f64, [[[-3.16227766, -4.427188724], [0., -0.632455532]], [[2., 0.], [0., 3.]]]).unwrap(),
+    None,
+))),
+case(array!(f64, [[1., 2.], [3., 4.]]), Some("full"), None, Err(ArrayError::ParameterError { param: "`mode`", message: "must be one of {`reduced`, `complete`, `r`, `raw`}" })),
+case(array_flat!(f64, 1., 2.), None, None, Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_qr(array: Result<Array<f64>, ArrayError>, mode: Option<&str>, pivoting: Option<bool>, expected: QrResult<f64>) {
+    assert_eq!(expected, array.qr(mode, pivoting).map(|(q, r, p)| (q.map(rounded), rounded(r), p)))
+}
+
+#[rstest(
+array, mode, pivoting,
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]), "reduced", false),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]), "reduced", true),
+case(array!(f64, [[2., -1.], [1., 3.], [0., 5.], [4., 1.]]), "complete", true),
+case(array!(f64, [[2., -1., 0., 3.], [1., 3., 5., 1.]]), "complete", false),
+case(array!(f64, [[0., 0.], [0., 0.], [0., 0.]]), "reduced", true),
+case(Array::new((0..64_u32).map(|idx| 1. / <f64 as From<u32>>::from(idx / 8 + idx % 8 + 1)).collect(), vec![8, 8]), "reduced", true),
+)] fn test_linalg_qr_reconstruct(array: Result<Array<f64>, ArrayError>, mode: &str, pivoting: bool) {
+    let array = array.unwrap();
+    let (q, r, p) = array.qr(Some(mode), Some(pivoting)).unwrap();
+    let q = q.unwrap();
+    let (rows, cols) = (array.get_shape().unwrap()[0], array.get_shape().unwrap()[1]);
+    let permutation = p.map_or_else(|| (0..cols).collect(), |p| p.get_elements().unwrap());
+    let permuted = (0..rows)
+        .flat_map(|i| permutation.iter().map(|&j| array.at(&[i, j]).unwrap()).collect::<Vec<f64>>())
+        .collect::<Array<f64>>()
+        .reshape(&[rows, cols]);
+    let size = q.get_shape().unwrap()[1];
+    assert_eq!(rounded(permuted), rounded(q.dot(&r)));
+    assert_eq!(rounded(Array::identity(size)), rounded(q.transpose(None).dot(&q)));
+    if pivoting {
+        let diagonal = (0..rows.min(cols)).map(|i| r.at(&[i, i]).unwrap().abs()).collect::<Vec<f64>>();
+        assert!(diagonal.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[2., 1.], [4., 3.]]), Ok((
+    array!(f64, [[0., 1.], [1., 0.]]).unwrap(),
+    array!(f64, [[1., 0.], [0.5, 1.]]).unwrap(),
+    array!(f64, [[4., 3.], [0., -0.5]]).unwrap(),
+))),
+case(array!(f64, [[1., 2., 3.], [2., 4., 5.], [4., 2., 1.]]), Ok((
+    array!(f64, [[0., 0., 1.], [0., 1., 0.], [1., 0., 0.]]).unwrap(),
+    array!(f64, [[1., 0., 0.], [0.5, 1., 0.], [0.25, 0.5, 1.]]).unwrap(),
+    array!(f64, [[4., 2., 1.], [0., 3., 4.5], [0., 0., 0.5]]).unwrap(),
+))),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), Ok((
+    array!(f64, [[0., 1.], [1., 0.]]).unwrap(),
+    array!(f64, [[1., 0.], [0.25, 1.]]).unwrap(),
+    array!(f64, [[4., 5., 6.], [0., 0.75, 1.5]]).unwrap(),
+))),
+case(array!(f64, [[[2., 1.], [4., 3.]], [[1., 0.], [0., 1.]]]), Ok((
+    array!(f64, [[[0., 1.], [1., 0.]], [[1., 0.], [0., 1.]]]).unwrap(),
+    array!(f64, [[[1., 0.], [0.5, 1.]], [[1., 0.], [0., 1.]]]).unwrap(),
+    array!(f64, [[[4., 3.], [0., -0.5]], [[1., 0.], [0., 1.]]]).unwrap(),
+))),
+case(array_flat!(f64, 1., 2.), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_lu(array: Result<Array<f64>, ArrayError>, expected: LuResult<f64>) {
+    assert_eq!(expected, array.lu())
+}
+
+#[rstest(
+array, expected,
+case(Array::new(vec![Complex::new(0., 1.), Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 1.)], vec![2, 2]), Ok((
+    Array::new(vec![Complex::new(1., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(1., 0.)], vec![2, 2]).unwrap(),
+    Array::new(vec![Complex::new(1., 0.), Complex::new(0., 0.), Complex::new(0., -1.), Complex::new(1., 0.)], vec![2, 2]).unwrap(),
+    Array::new(vec![Complex::new(0., 1.), Complex::new(1., 0.), Complex::new(0., 0.), Complex::new(0., 2.)], vec![2, 2]).unwrap(),
+))),
+case(Array::new(vec![Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 2.), Complex::new(1., 0.)], vec![2, 2]), Ok((
+    Array::new(vec![Complex::new(0., 0.), Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 0.)], vec![2, 2]).unwrap(),
+    Array::new(vec![Complex::new(1., 0.), Complex::new(0., 0.), Complex::new(0., -0.5), Complex::new(1., 0.)], vec![2, 2]).unwrap(),
+    Array::new(vec![Complex::new(0., 2.), Complex::new(1., 0.), Complex::new(0., 0.), Complex::new(1., 0.5)], vec![2, 2]).unwrap(),
+))),
+)] fn test_linalg_lu_complex(array: Result<Array<Complex<f64>>, ArrayError>, expected: LuResult<Complex<f64>>) {
+    assert_eq!(expected, array.lu())
+}
+
+#[rstest(
+array, b, expected,
+case(Array::new(vec![Complex::new(0., 1.), Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 1.)], vec![2, 2]), Array::flat(vec![Complex::new(1., 0.), Complex::new(0., 0.)]), Array::flat(vec![Complex::new(0., -0.5), Complex::new(0.5, 0.)])),
+case(Array::new(vec![Complex::new(2., 0.), Complex::new(1., 0.), Complex::new(4., 0.), Complex::new(3., 0.)], vec![2, 2]), Array::flat(vec![Complex::new(3., 3.), Complex::new(7., 7.)]), Array::flat(vec![Complex::new(1., 1.), Complex::new(1., 1.)])),
+)] fn test_linalg_lu_solve_complex(array: Result<Array<Complex<f64>>, ArrayError>, b: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    let (lu, piv) = array.lu_factor().unwrap();
+    assert_eq!(expected, lu.lu_solve(&piv, &b.unwrap()))
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[2., 1.], [4., 3.]]), Ok((array!(f64, [[4., 3.], [0.5, -0.5]]).unwrap(), Array::flat(vec![1, 1]).unwrap()))),
+case(array!(f64, [[1., 2., 3.], [2., 4., 5.], [4., 2., 1.]]), Ok((array!(f64, [[4., 2., 1.], [0.5, 3., 4.5], [0.25, 0.5, 0.5]]).unwrap(), Array::flat(vec![2, 1, 2]).unwrap()))),
+case(array!(f64, [[[2., 1.], [4., 3.]], [[1., 0.], [0., 1.]]]), Ok((array!(f64, [[[4., 3.], [0.5, -0.5]], [[1., 0.], [0., 1.]]]).unwrap(), Array::new(vec![1, 1, 0, 1], vec![2, 2]).unwrap()))),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+)] fn test_linalg_lu_factor(array: Result<Array<f64>, ArrayError>, expected: LuFactorResult<f64>) {
+    assert_eq!(expected, array.lu_factor())
+}
+
+#[rstest(
+array, b, expected,
+case(array!(f64, [[2., 1.], [4., 3.]]), array_flat!(f64, 3., 7.), array_flat!(f64, 1., 1.)),
+case(array!(f64, [[2., 1.], [4., 3.]]), array!(f64, [[3., 1.], [7., 2.]]), array!(f64, [[1., 0.5], [1., 0.]])),
+case(array!(f64, [[1., 2., 3.], [2., 4., 5.], [4., 2., 1.]]), array_flat!(f64, 6., 11., 7.), array_flat!(f64, 1., 1., 1.)),
+case(array!(f64, [[[2., 1.], [4., 3.]], [[1., 0.], [0., 2.]]]), array_flat!(f64, 3., 7.), array!(f64, [[1., 1.], [3., 3.5]])),
+case(array!(f64, [[[2., 1.], [4., 3.]], [[1., 0.], [0., 2.]]]), array!(f64, [[[3.], [7.]], [[2.], [4.]]]), array!(f64, [[[1.], [1.]], [[2.], [2.]]])),
+case(array!(f64, [[5.]]), array_flat!(f64, 10.), array_flat!(f64, 2.)),
+case(array!(f64, [[1., 2.], [2., 4.]]), array_flat!(f64, 1., 2.), Err(ArrayError::SingularMatrix)),
+case(array!(f64, [[2., 1.], [4., 3.]]), array_flat!(f64, 1., 2., 3.), Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+)] fn test_linalg_lu_solve(array: Result<Array<f64>, ArrayError>, b: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    let (lu, piv) = array.lu_factor().unwrap();
+    assert_eq!(expected, lu.lu_solve(&piv, &b.unwrap()))
+}
+
+#[rstest(
+shape,
+case(&[3, 4, 4]),
+case(&[2, 3, 4, 4]),
+)] fn test_linalg_decompositions_stacked(shape: &[usize]) {
+    let array = stacked_matrices(shape);
+    let b = array_flat!(f64, 1., 2., 3., 4.).unwrap();
+    let (lu, piv) = array.lu_factor().unwrap();
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.lu_factor().and_then(|(lu, piv)| lu.lu_solve(&piv, &b)))), rounded(lu.lu_solve(&piv, &b)));
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.cholesky(None))), rounded(array.cholesky(None)));
+    let factor = array.cholesky(None).unwrap();
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.cholesky(None).and_then(|factor| factor.cho_solve(&b, None)))), rounded(factor.cho_solve(&b, None)));
+}
+
+#[rstest(
+array, lower, expected,
+case(array!(f64, [[4., 2.], [2., 5.]]), None, array!(f64, [[2., 0.], [1., 2.]])),
+case(array!(f64, [[4., 2.], [2., 5.]]), Some(false), array!(f64, [[2., 1.], [0., 2.]])),
+case(array!(f64, [[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]), Some(true), array!(f64, [[2., 0., 0.], [6., 1., 0.], [-8., 5., 3.]])),
+case(array!(f64, [[[4., 2.], [2., 5.]], [[9., 0.], [0., 1.]]]), None, array!(f64, [[[2., 0.], [1., 2.]], [[3., 0.], [0., 1.]]])),
+case(array!(f64, [[25.]]), None, array!(f64, [[5.]])),
+case(array!(f64, [[1., 2.], [2., 1.]]), None, Err(ArrayError::NotPositiveDefinite)),
+case(array!(f64, [[0., 0.], [0., 0.]]), None, Err(ArrayError::NotPositiveDefinite)),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), None, Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+)] fn test_linalg_cholesky(array: Result<Array<f64>, ArrayError>, lower: Option<bool>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.cholesky(lower))
+}
+
+#[rstest(
+array, b, lower, expected,
+case(array!(f64, [[4., 2.], [2., 5.]]), array_flat!(f64, 6., 7.), None, array_flat!(f64, 1., 1.)),
+case(array!(f64, [[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]), array_flat!(f64, 0., 6., 39.), None, array_flat!(f64, 1., 1., 1.)),
+case(array!(f64, [[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]), array_flat!(f64, 0., 6., 39.), Some(false), array_flat!(f64, 1., 1., 1.)),
+case(array!(f64, [[4., 2.], [2., 5.]]), array!(f64, [[6., 4.], [7., 2.]]), Some(true), array!(f64, [[1., 1.], [1., 0.]])),
+case(array!(f64, [[[4., 2.], [2., 5.]], [[9., 0.], [0., 1.]]]), array!(f64, [[[6.], [7.]], [[9.], [2.]]]), None, array!(f64, [[[1.], [1.]], [[1.], [2.]]])),
+case(array!(f64, [[4., 2.], [2., 5.]]), array_flat!(f64, 1., 2., 3.), None, Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+)] fn test_linalg_cho_solve(array: Result<Array<f64>, ArrayError>, b: Result<Array<f64>, ArrayError>, lower: Option<bool>, expected: Result<Array<f64>, ArrayError>) {
+    let factor = array.cholesky(lower).unwrap();
+    assert_eq!(expected, rounded(factor.cho_solve(&b.unwrap(), lower)))
+}
 
 #[rstest(
 array, expected,
-case(array_arange!(f64, 1., 4.).reshape(&[2, 2]), Ok(vec![(
-    array!(f64, [[0.31622776601683794, 0.9486832980505142], [0.9486832980505138, -0.3162277660168369]]).unwrap(),
-    array!(f64, [[3.162277660168379, 4.427188724235731], [3.552713678800501e-15, 0.6324555320336809]]).unwrap(),
-)])),
-case(array_arange!(f64, 1., 9.).reshape(&[3, 3]), Ok(vec![(
-    array!(f64, [[0.12309149097933272, 0.9045340337332908, 0.1111111111111111], [0.4923659639173309, 0.30151134457776335, 0.4444444444444444], [0.8616404368553291, -0.30151134457776435, 0.8888888888888888]]).unwrap(),
-    array!(f64, [[8.12403840463596, 9.601136296387953, 11.078234188139945], [-6.494804694057166e-15, 0.9045340337332837, 1.809068067466573], [8.11111111111111, 9.555555555555555, 11.0]]).unwrap(),
-)])),
-case(array!(f64, [[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]), Ok(vec![(
-    array!(f64, [[0.8571428571428571, -0.3942857142857143, -0.33142857142857146], [0.42857142857142855, 0.9028571428571428, 0.034285714285714364], [-0.2857142857142857, 0.17142857142857143, -0.9428571428571428]]).unwrap(),
-    array!(f64, [[13.999999999999998, 20.999999999999993, -13.999999999999998], [-2.220446049250313e-16, 175.0, -70.0], [0.0, 1.5987211554602254e-14, 34.99999999999999]]).unwrap(),
-)])),
-)] fn test_linalg_qr(array: Result<Array<f64>, ArrayError>, expected: LinalgResult<f64>) {
-    assert_eq!(expected, array.qr())
+case(array!(f64, [[4., 2.], [2., -3.]]), Ok((
+    array!(f64, [[1., 0.], [0.5, 1.]]).unwrap(),
+    array!(f64, [[4., 0.], [0., -4.]]).unwrap(),
+    Array::flat(vec![0, 1]).unwrap(),
+))),
+case(array!(f64, [[0., 1.], [1., 0.]]), Ok((
+    array!(f64, [[1., 0.], [0., 1.]]).unwrap(),
+    array!(f64, [[0., 1.], [1., 0.]]).unwrap(),
+    Array::flat(vec![0, 1]).unwrap(),
+))),
+case(array!(f64, [[1., 4.], [4., 8.]]), Ok((
+    array!(f64, [[1., 0.], [0.5, 1.]]).unwrap(),
+    array!(f64, [[8., 0.], [0., -1.]]).unwrap(),
+    Array::flat(vec![1, 0]).unwrap(),
+))),
+case(array_flat!(f64, 1., 2.), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_ldl(array: Result<Array<f64>, ArrayError>, expected: LdlResult<f64>) {
+    assert_eq!(expected, array.ldl())
+}
+
+#[rstest(
+array,
+case(array!(f64, [[1., 2., 3.], [2., 1., 0.], [3., 0., 1.]])),
+case(array!(f64, [[0., 1., 2., 3.], [1., 0., 4., 5.], [2., 4., 0., 6.], [3., 5., 6., 0.]])),
+case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]])),
+)] fn test_linalg_ldl_reconstruct(array: Result<Array<f64>, ArrayError>) {
+    let array = array.unwrap();
+    let (l, d, perm) = array.ldl().unwrap();
+    let perm = perm.get_elements().unwrap();
+    let permuted = perm.iter()
+        .flat_map(|&i| perm.iter().map(|&j| array.at(&[i, j]).unwrap()).collect::<Vec<f64>>())
+        .collect::<Array<f64>>()
+        .reshape(&array.get_shape().unwrap());
+    assert_eq!(rounded(permuted), rounded(l.dot(&d).dot(&l.transpose(None).unwrap())));
+}
+
+#[rstest(
+array, full_matrices, compute_uv, expected,
+case(array!(f64, [[3., 0.], [0., -2.]]), None, None, Ok((
+    Some(array!(f64, [[1., 0.], [0., -1.]]).unwrap()),
+    array_flat!(f64, 3., 2.).unwrap(),
+    Some(array!(f64, [[1., 0.], [0., 1.]]).unwrap()),
+))),
+case(array!(f64, [[0., 2.], [1., 0.], [0., 0.]]), Some(true), None, Ok((
+    Some(array!(f64, [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]).unwrap()),
+    array_flat!(f64, 2., 1.).unwrap(),
+    Some(array!(f64, [[0., 1.], [1., 0.]]).unwrap()),
+))),
+case(array!(f64, [[0., 2.], [1., 0.], [0., 0.]]), Some(false), None, Ok((
+    Some(array!(f64, [[1., 0.], [0., 1.], [0., 0.]]).unwrap()),
+    array_flat!(f64, 2., 1.).unwrap(),
+    Some(array!(f64, [[0., 1.], [1., 0.]]).unwrap()),
+))),
+case(array!(f64, [[3., 0.], [0., -2.]]), None, Some(false), Ok((None, array_flat!(f64, 3., 2.).unwrap(), None))),
+case(array!(f64, [[[3., 0.], [0., -2.]], [[1., 0.], [0., 4.]]]), None, Some(false), Ok((None, array!(f64, [[3., 2.], [4., 1.]]).unwrap(), None))),
+case(array_flat!(f64, 1., 2.), None, None, Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+case(Array::new(vec![1., f64::NAN, 2., 3.], vec![2, 2]), None, None, Err(ArrayError::ParameterError { param: "`array`", message: "singular value decomposition did not converge" })),
+)] fn test_linalg_svd(array: Result<Array<f64>, ArrayError>, full_matrices: Option<bool>, compute_uv: Option<bool>, expected: SvdResult<f64>) {
+    assert_eq!(expected, array.svd(full_matrices, compute_uv))
+}
+
+#[rstest(
+array, full_matrices,
+case(array!(f64, [[1., 2.], [3., 4.]]), true),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]), true),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]), false),
+case(array!(f64, [[1., 2., 3., 4.], [2., 4., 6., 8.]]), true),
+case(array!(f64, [[1., 2., 3., 4.], [2., 4., 6., 8.]]), false),
+case(array!(f64, [[2., -1.], [1., 3.], [0., 5.], [4., 1.]]), true),
+case(array!(f64, [[2., -1.], [1., 3.], [0., 5.], [4., 1.]]), false),
+case(array!(f64, [[0., 0.], [0., 0.], [0., 0.]]), true),
+case(Array::new((0..120).map(|i| (0.7 * i as f64).sin()).collect(), vec![12, 10]), false),
+case(Array::new((0..120).map(|i| (0.7 * i as f64).sin()).collect(), vec![10, 12]), true),
+)] fn test_linalg_svd_reconstruct(array: Result<Array<f64>, ArrayError>, full_matrices: bool) {
+    let array = array.unwrap();
+    let (u, s, vh) = array.svd(Some(full_matrices), None).unwrap();
+    let (u, vh) = (u.unwrap(), vh.unwrap());
+    let (rows, cols) = (u.get_shape().unwrap()[1], vh.get_shape().unwrap()[0]);
+    let sigma = (0..rows)
+        .flat_map(|i| (0..cols).map(|j| if i == j { s[i] } else { 0. }).collect::<Vec<f64>>())
+        .collect::<Array<f64>>()
+        .reshape(&[rows, cols]);
+    assert_eq!(rounded(Ok(array)), rounded(u.dot(&sigma.unwrap()).dot(&vh)));
+    assert_eq!(rounded(Array::identity(rows)), rounded(u.transpose(None).dot(&u)));
+    assert_eq!(rounded(Array::identity(cols)), rounded(vh.dot(&vh.transpose(None).unwrap())));
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[1., 2.], [3., 4.]]), array_flat!(f64, 5.464985704, 0.365966191)),
+case(array!(f64, [[1., 2., 3., 4.], [2., 4., 6., 8.]]), array_flat!(f64, 12.247448714, 0.)),
+case(array!(f64, [[[3., 0.], [0., -2.]], [[1., 0.], [0., 4.]]]), array!(f64, [[3., 2.], [4., 1.]])),
+)] fn test_linalg_svdvals(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.svdvals()))
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[3., 1.], [4., 2.]]), Ok((
+    array!(f64, [[0.6, -0.8], [0.8, 0.6]]).unwrap(),
+    array!(f64, [[5., 2.2], [0., 0.4]]).unwrap(),
+))),
+case(array!(f64, [[1., 2.], [2., 4.]]), Ok((
+    array!(f64, [[0.447213595, 0.894427191], [0.894427191, -0.447213595]]).unwrap(),
+    array!(f64, [[2.236067977, 4.472135955], [0., 0.]]).unwrap(),
+))),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), Ok((
+    array!(f64, [[0.242535625, 0.9701425], [0.9701425, -0.242535625]]).unwrap(),
+    array!(f64, [[4.123105626, 5.335783751, 6.548461876], [0., 0.727606875, 1.455213750]]).unwrap(),
+))),
+case(array!(f64, [[[3., 1.], [4., 2.]], [[2., 0.], [0., 1.]]]), Ok((
+    array!(f64, [[[0.6, -0.8], [0.8, 0.6]], [[1., 0.], [0., 1.]]]).unwrap(),
+    array!(f64, [[[5., 2.2], [0., 0.4]], [[2., 0.], [0., 1.]]]).unwrap(),
+))),
+case(array_flat!(f64, 1., 2.), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_gram_schmidt(array: Result<Array<f64>, ArrayError>, expected: LinalgResult<f64>) {
+    assert_eq!(expected, array.gram_schmidt().map(|(q, r)| (rounded(q), rounded(r))))
+}
+
+#[rstest(
+array,
+case(array!(f64, [[2., -1.], [1., 3.], [0., 5.], [4., 1.]])),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]])),
+case(Array::new((0..64_u32).map(|idx| 1. / <f64 as From<u32>>::from(idx / 8 + idx % 8 + 1)).collect(), vec![8, 8])),
+)] fn test_linalg_gram_schmidt_orthogonality(array: Result<Array<f64>, ArrayError>) {
+    let array = array.unwrap();
+    let (q, r) = array.gram_schmidt().unwrap();
+    let size = q.get_shape().unwrap()[1];
+    let loss = (q.transpose(None).dot(&q).unwrap() - Array::identity(size).unwrap()).abs().max(None).unwrap();
+    assert!(loss[0] < 1e-12);
+    assert_eq!(rounded(Ok(array)), rounded(q.dot(&r)));
+}
+
+fn hermitian() -> Array<Complex<f64>> {
+    Array::new(vec![Complex::new(2., 0.), Complex::new(0., 1.), Complex::new(0., -1.), Complex::new(2., 0.)], vec![2, 2]).unwrap()
+}
+
+fn complex_unsupported<T>() -> Result<T, ArrayError> {
+    Err(ArrayError::ParameterError { param: "`array`", message: "complex matrices are not supported" })
+}
+
+#[test]
+fn test_linalg_qr_complex() {
+    assert_eq!(complex_unsupported(), hermitian().qr(None::<&str>, None));
+}
+
+#[test]
+fn test_linalg_cholesky_complex() {
+    assert_eq!(complex_unsupported(), hermitian().cholesky(None));
+}
+
+#[test]
+fn test_linalg_cho_solve_complex() {
+    let b = Array::flat(vec![Complex::new(1., 0.), Complex::new(0., 1.)]).unwrap();
+    assert_eq!(complex_unsupported(), hermitian().cho_solve(&b, None));
+    assert_eq!(complex_unsupported(), Array::<Complex<f64>>::identity(2).cho_solve(&b, None));
+}
+
+#[test]
+fn test_linalg_ldl_complex() {
+    assert_eq!(complex_unsupported(), hermitian().ldl());
+}
+
+#[test]
+fn test_linalg_svd_complex() {
+    assert_eq!(complex_unsupported(), hermitian().svd(None, None));
+}
+
+#[test]
+fn test_linalg_svdvals_complex() {
+    assert_eq!(complex_unsupported(), hermitian().svdvals());
+}
+
+#[test]
+fn test_linalg_gram_schmidt_complex() {
+    assert_eq!(complex_unsupported(), hermitian().gram_schmidt());
 }
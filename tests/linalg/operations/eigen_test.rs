@@ -1,38 +1,178 @@
 use rstest::rstest;
 use arr_rs::prelude::*;
+use crate::common::{per_matrix, rounded, stacked_matrices};
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
 
 #[rstest(
 array, expected,
-case(array!(f64, [[1, 2], [3, 4]]), Ok(vec![array_flat!(f64, 5.379562043795623, -0.37956204379562025).unwrap()])),
-case(array!(f64, [[1, 4], [3, 2]]), Ok(vec![array_flat!(f64, 4.6, -1.6).unwrap()])),
-case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]), Ok(vec![array_flat!(f64, 2.8, 2.3428571428571425, 0.8571428571428569).unwrap()])),
-case(array!(f64, [[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]), Ok(vec![array_flat!(f64, 156.20350762022625, -35.473244608879945, 17.26973698865371).unwrap()])),
+case(array!(f64, [[1, 2], [3, 4]]), Array::flat(vec![c(-0.372281323, 0.), c(5.372281323, 0.)])),
+case(array!(f64, [[1, 4], [3, 2]]), Array::flat(vec![c(-2., 0.), c(5., 0.)])),
+case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]), Array::flat(vec![c(0.585786438, 0.), c(2., 0.), c(3.414213562, 0.)])),
+case(array!(f64, [[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]), Array::flat(vec![c(156.136684062, 0.), c(16.0599909395, 0.), c(-34.196675001, 0.)])),
+case(array!(f64, [[0., -1.], [1., 0.]]), Array::flat(vec![c(0., -1.), c(0., 1.)])),
+case(array!(f64, [[0., 0., 1.], [1., 0., 0.], [0., 1., 0.]]), Array::flat(vec![c(-0.5, -0.866025404), c(-0.5, 0.866025404), c(1., 0.)])),
+case(array!(f64, [[[1., 4.], [3., 2.]], [[2., 0.], [0., 3.]]]), Array::new(vec![c(-2., 0.), c(5., 0.), c(2., 0.), c(3., 0.)], vec![2, 2])),
 case(array!(f64, [1, 4, 3]), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
 case(array!(f64, [[1, 4, 3], [3, 2, 4]]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
-)] fn test_linalg_eigvals(array: Result<Array<f64>, ArrayError>, expected: Result<Vec<Array<f64>>, ArrayError>) {
-    assert_eq!(expected, array.eigvals())
+)] fn test_linalg_eigvals(array: Result<Array<f64>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected.map(rounded), array.eigvals().map(rounded))
 }
 
 #[rstest(
 array, expected,
-case(array!(f64, [[1, 2], [3, 4]]), Ok(vec![(
-array_flat!(f64, 5.379562043795623, -0.37956204379562025).unwrap(),
-array!(f64, [[-0.41637599816479626, 0.8265490533652102], [-0.9091925143512071, -0.5628646927824439]]).unwrap(),
-)])),
-case(array!(f64, [[1, 4], [3, 2]]), Ok(vec![(
-array_flat!(f64, 4.6, -1.6).unwrap(),
-array!(f64, [[0.7071067811865475, std::f64::consts::FRAC_1_SQRT_2], [0.7071067811865475, 0.7071067811865474]]).unwrap(),
-)])),
-case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]), Ok(vec![(
-array_flat!(f64, 2.8, 2.3428571428571425, 0.8571428571428569).unwrap(),
-array!(f64, [[-0.16222142113076257, -0.3458744383324873, -0.4908149932527664], [-0.9733285267845752, -0.8722051053601856, -0.7198619901040574], [-0.16222142113076266, -0.34587443833248765, -0.49081499325276634]]).unwrap(),
-)])),
-case(array!(f64, [[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]), Ok(vec![(
-array_flat!(f64, 156.20350762022625, -35.473244608879945, 17.26973698865371).unwrap(),
-array!(f64, [[0.3274744043621118, 0.2741043942532785, -0.9929229419852038], [-0.9370666300603048, 0.3040145084128465, 0.08536232642252124], [-0.12110592601150529, 0.9123825731158716, 0.08256696983166067]]).unwrap(),
-)])),
+case(Array::new(vec![c(1., 1.), c(2., 0.), c(0., 0.), c(2., -1.)], vec![2, 2]), Array::flat(vec![c(1., 1.), c(2., -1.)])),
+case(Array::new(vec![c(0., 1.), c(0., 0.), c(0., 0.), c(0., -2.)], vec![2, 2]), Array::flat(vec![c(0., 1.), c(0., -2.)])),
+)] fn test_linalg_eigvals_complex(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.eigvals().map(rounded))
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[1, 2], [3, 4]]), Ok((
+Array::flat(vec![c(-0.372281323, 0.), c(5.372281323, 0.)]).unwrap(),
+Array::new(vec![c(0.82456484, 0.), c(0.415973558, 0.), c(-0.565767465, 0.), c(0.909376709, 0.)], vec![2, 2]).unwrap(),
+))),
+case(array!(f64, [[1, 4], [3, 2]]), Ok((
+Array::flat(vec![c(-2., 0.), c(5., 0.)]).unwrap(),
+Array::new(vec![c(0.8, 0.), c(0.707106781, 0.), c(-0.6, 0.), c(0.707106781, 0.)], vec![2, 2]).unwrap(),
+))),
+case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]), Ok((
+Array::flat(vec![c(0.585786438, 0.), c(2., 0.), c(3.414213562, 0.)]).unwrap(),
+Array::new(vec![c(0.5, 0.), c(0.707106781, 0.), c(-0.5, 0.), c(0.707106781, 0.), c(0., 0.), c(0.707106781, 0.), c(0.5, 0.), c(-0.707106781, 0.), c(-0.5, 0.)], vec![3, 3]).unwrap(),
+))),
+case(array!(f64, [[0., -1.], [1., 0.]]), Ok((
+Array::flat(vec![c(0., -1.), c(0., 1.)]).unwrap(),
+Array::new(vec![c(0.707106781, 0.), c(0.707106781, 0.), c(0., 0.707106781), c(0., -0.707106781)], vec![2, 2]).unwrap(),
+))),
+case(array!(f64, [[[1, 4], [3, 2]], [[2, 0], [0, 3]]]), Ok((
+Array::new(vec![c(-2., 0.), c(5., 0.), c(2., 0.), c(3., 0.)], vec![2, 2]).unwrap(),
+Array::new(vec![c(0.8, 0.), c(0.707106781, 0.), c(-0.6, 0.), c(0.707106781, 0.), c(1., 0.), c(0., 0.), c(0., 0.), c(1., 0.)], vec![2, 2, 2]).unwrap(),
+))),
+case(array!(f64, [[5]]), Ok((Array::flat(vec![c(5., 0.)]).unwrap(), Array::new(vec![c(1., 0.)], vec![1, 1]).unwrap()))),
 case(array!(f64, [1, 4, 3]), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
 case(array!(f64, [[1, 4, 3], [3, 2, 4]]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
-)] fn test_linalg_eig(array: Result<Array<f64>, ArrayError>, expected: LinalgResult<f64>) {
-    assert_eq!(expected, array.eig())
+)] fn test_linalg_eig(array: Result<Array<f64>, ArrayError>, expected: LinalgResult<Complex<f64>>) {
+    assert_eq!(expected, array.eig().map(|(values, vectors)| (rounded(values), rounded(vectors))))
+}
+
+fn complex(array: Result<Array<f64>, ArrayError>) -> Array<Complex<f64>> {
+    let array = array.unwrap();
+    array.map(Numeric::to_complex).reshape(&array.get_shape().unwrap()).unwrap()
+}
+
+#[rstest(
+array,
+case(complex(array!(f64, [[4., 1., 2.], [0., 3., 1.], [1., 1., 5.]]))),
+case(complex(array!(f64, [[0., 2., 0., 1.], [-2., 0., 1., 0.], [0., -1., 0., 3.], [-1., 0., -3., 0.]]))),
+case(Array::new(vec![c(1., 2.), c(0., 1.), c(3., 0.), c(-1., -1.)], vec![2, 2]).unwrap()),
+)] fn test_linalg_eig_residual(array: Array<Complex<f64>>) {
+    let (values, vectors) = array.eig().unwrap();
+    let scaled = vectors.clone() * values.reshape(&[1, values.len().unwrap()]).broadcast_to(vectors.get_shape().unwrap()).unwrap();
+    let residual = array.dot(&vectors).unwrap() - scaled;
+    assert!(residual.get_elements().unwrap().iter().all(|v| v.norm() < 1e-9));
+}
+
+#[rstest(
+array, lower, expected,
+case(array!(f64, [[2., 1.], [1., 2.]]), None, array_flat!(f64, 1., 3.)),
+case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]), None, array_flat!(f64, 0.585786438, 2., 3.414213562)),
+case(array!(f64, [[2., 5.], [1., 2.]]), Some(true), array_flat!(f64, 1., 3.)),
+case(array!(f64, [[2., 5.], [1., 2.]]), Some(false), array_flat!(f64, -3., 7.)),
+case(array!(f64, [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]), None, array_flat!(f64, 1., 1., 1.)),
+case(array!(f64, [[[2., 1.], [1., 2.]], [[5., 0.], [0., 4.]]]), None, array!(f64, [[1., 3.], [4., 5.]])),
+case(array!(f64, [1., 4., 3.]), None, Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_eigvalsh(array: Result<Array<f64>, ArrayError>, lower: Option<bool>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.eigvalsh(lower)))
+}
+
+#[rstest(
+array, expected,
+case(Array::new(vec![c(2., 0.), c(0., -1.), c(0., 1.), c(2., 0.)], vec![2, 2]), array_flat!(f64, 1., 3.)),
+case(Array::new(vec![c(1., 0.), c(1., 1.), c(1., -1.), c(2., 0.)], vec![2, 2]), array_flat!(f64, 0., 3.)),
+)] fn test_linalg_eigvalsh_complex(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.eigvalsh(None)))
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[2., 1.], [1., 2.]]), Ok((
+    array_flat!(f64, 1., 3.).unwrap(),
+    array!(f64, [[0.707106781, 0.707106781], [-0.707106781, 0.707106781]]).unwrap(),
+))),
+case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]), Ok((
+    array_flat!(f64, 0.585786438, 2., 3.414213562).unwrap(),
+    array!(f64, [[0.5, 0.707106781, -0.5], [0.707106781, 0., 0.707106781], [0.5, -0.707106781, -0.5]]).unwrap(),
+))),
+case(array!(f64, [[[2., 1.], [1., 2.]], [[5., 0.], [0., 4.]]]), Ok((
+    array!(f64, [[1., 3.], [4., 5.]]).unwrap(),
+    array!(f64, [[[0.707106781, 0.707106781], [-0.707106781, 0.707106781]], [[0., 1.], [1., 0.]]]).unwrap(),
+))),
+case(array!(f64, [[5.]]), Ok((array_flat!(f64, 5.).unwrap(), array!(f64, [[1.]]).unwrap()))),
+case(array!(f64, [[1., 4., 3.], [3., 2., 4.]]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+)] fn test_linalg_eigh(array: Result<Array<f64>, ArrayError>, expected: EighResult<f64>) {
+    assert_eq!(expected, array.eigh(None).map(|(values, vectors)| (rounded(values), rounded(vectors))))
+}
+
+#[rstest(
+shape,
+case(&[3, 4, 4]),
+case(&[2, 3, 4, 4]),
+)] fn test_linalg_eigen_stacked(shape: &[usize]) {
+    let array = stacked_matrices(shape);
+    assert_eq!(per_matrix(&array, |matrix| matrix.eigvals()).map(rounded), array.eigvals().map(rounded));
+    assert_eq!(per_matrix(&array, |matrix| matrix.eig().map(|result| result.1)).map(rounded), array.eig().map(|result| rounded(result.1)));
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.eigvalsh(None))), rounded(array.eigvalsh(None)));
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.eigh(None).map(|result| result.1))), rounded(array.eigh(None).map(|result| result.1)));
+}
+
+#[rstest(
+array,
+case(complex(array!(f64, [[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]]))),
+case(complex(array!(f64, [[1., 2., 3., 4.], [2., 1., 0., -1.], [3., 0., 2., 1.], [4., -1., 1., 3.]]))),
+case(complex(array!(f64, [[1., 1., 0.], [1., 1., 0.], [0., 0., 2.]]))),
+case(Array::new(vec![c(2., 0.), c(1., -1.), c(0., 2.), c(1., 1.), c(3., 0.), c(1., 0.), c(0., -2.), c(1., 0.), c(1., 0.)], vec![3, 3]).unwrap()),
+)] fn test_linalg_eigh_residual(array: Array<Complex<f64>>) {
+    let (values, vectors) = array.eigh(None).unwrap();
+    let values = values.get_elements().unwrap();
+    assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    let values = Array::flat(values.into_iter().map(|v| c(v, 0.)).collect()).unwrap();
+    let scaled = vectors.clone() * values.reshape(&[1, values.len().unwrap()]).broadcast_to(vectors.get_shape().unwrap()).unwrap();
+    let residual = array.dot(&vectors).unwrap() - scaled;
+    assert!(residual.get_elements().unwrap().iter().all(|v| v.norm() < 1e-9));
+    let adjoint = vectors.transpose(None).unwrap().map(Complex::conj).reshape(&vectors.get_shape().unwrap()).unwrap();
+    let identity = complex(Array::identity(vectors.get_shape().unwrap()[0]));
+    assert_eq!(identity, rounded(adjoint.dot(&vectors).unwrap()));
+}
+
+#[rstest(
+array,
+case(array!(f64, [[1., 2.], [3., 4.]])),
+case(array!(f64, [[0., -1.], [1., 0.]])),
+case(array!(f64, [[4., 1., 2.], [0., 3., 1.], [1., 1., 5.]])),
+case(array!(f64, [[0., 0., 1.], [1., 0., 0.], [0., 1., 0.]])),
+case(array!(f64, [[0., 2., 0., 1.], [-2., 0., 1., 0.], [0., -1., 0., 3.], [-1., 0., -3., 0.]])),
+case(array!(f64, [[1., 2., 3., 4., 5.], [2., 0., 1., 3., 1.], [0., 4., 1., 1., 2.], [1., 1., 1., 0., 3.], [5., 1., 0., 2., 1.]])),
+case(array!(f64, [[1., 1., 1.], [1., 1., 1.], [1., 1., 1.]])),
+)] fn test_linalg_schur(array: Result<Array<f64>, ArrayError>) {
+    let array = array.unwrap();
+    let (t, z) = array.schur().unwrap();
+    let n = array.get_shape().unwrap()[0];
+    assert_eq!(rounded(Ok(array)), rounded(z.dot(&t).dot(&z.transpose(None).unwrap())));
+    assert_eq!(rounded(Array::identity(n)), rounded(z.transpose(None).dot(&z)));
+    let t = |i: usize, j: usize| t.at(&[i, j]).unwrap();
+    assert!((0..n).all(|i| (0..i.saturating_sub(1)).all(|j| t(i, j) == 0.)));
+    assert!((1..n - 1).all(|i| t(i, i - 1) == 0. || t(i + 1, i) == 0.));
+}
+
+#[rstest(
+array, expected,
+case(Array::new(vec![c(1., 1.), c(2., 0.), c(0., 0.), c(2., -1.)], vec![2, 2]), Ok((
+    Array::new(vec![c(1., 1.), c(2., 0.), c(0., 0.), c(2., -1.)], vec![2, 2]).unwrap(),
+    Array::new(vec![c(1., 0.), c(0., 0.), c(0., 0.), c(1., 0.)], vec![2, 2]).unwrap(),
+))),
+)] fn test_linalg_schur_complex(array: Result<Array<Complex<f64>>, ArrayError>, expected: LinalgResult<Complex<f64>>) {
+    assert_eq!(expected, array.schur())
 }
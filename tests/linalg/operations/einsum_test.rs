@@ -0,0 +1,52 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+
+#[rstest(
+subscripts, operands, expected,
+case("ij,jk->ik", vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6], [7, 8]])], array!(i32, [[19, 22], [43, 50]])),
+case("ij,jk", vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6, 3], [7, 8, 3]])], array!(i32, [[19, 22, 9], [43, 50, 21]])),
+case("ij,jk->ki", vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6], [7, 8]])], array!(i32, [[19, 43], [22, 50]])),
+case("ji", vec![array!(i32, [[1, 2, 3], [4, 5, 6]])], array!(i32, [[1, 4], [2, 5], [3, 6]])),
+case("ij", vec![array!(i32, [[1, 2, 3], [4, 5, 6]])], array!(i32, [[1, 2, 3], [4, 5, 6]])),
+case("ii", vec![array!(i32, [[1, 2], [3, 4]])], array_single!(i32, 5)),
+case("ii->i", vec![array!(i32, [[1, 2], [3, 4]])], array_flat!(i32, 1, 4)),
+case("iii->i", vec![array_arange!(i32, 0, 7).reshape(&[2, 2, 2])], array_flat!(i32, 0, 7)),
+case("ij->", vec![array!(i32, [[1, 2, 3], [4, 5, 6]])], array_single!(i32, 21)),
+case("ij->j", vec![array!(i32, [[1, 2, 3], [4, 5, 6]])], array_flat!(i32, 5, 7, 9)),
+case("i,i", vec![array_flat!(i32, 1, 2, 3), array_flat!(i32, 4, 5, 6)], array_single!(i32, 32)),
+case("i,j", vec![array_flat!(i32, 1, 2), array_flat!(i32, 3, 4)], array!(i32, [[3, 4], [6, 8]])),
+case("i,i->i", vec![array_flat!(i32, 1, 2, 3), array_flat!(i32, 4, 5, 6)], array_flat!(i32, 4, 10, 18)),
+case(",i", vec![array_single!(i32, 2), array_flat!(i32, 1, 2, 3)], array_flat!(i32, 2, 4, 6)),
+case("...ii->...i", vec![array!(i32, [[[1, 2], [3, 4]], [[5, 6], [7, 8]]])], array!(i32, [[1, 4], [5, 8]])),
+case("...ij,...jk->...ik", vec![array!(i32, [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]), array!(i32, [[1, 0], [0, 1]])], array!(i32, [[[1, 2], [3, 4]], [[5, 6], [7, 8]]])),
+case("i...,i...", vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1], [2]])], array_flat!(i32, 7, 10)),
+case("...,...", vec![array!(i32, [[1, 2], [3, 4]]), array_flat!(i32, 10, 100)], array!(i32, [[10, 200], [30, 400]])),
+case("ij,jk,kl->il", vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6], [7, 8]]), array!(i32, [[1, 1], [0, 1]])], array!(i32, [[19, 41], [43, 93]])),
+case("ij,jk", vec![array!(i32, [[1, 2], [3, 4]])], Err(ArrayError::ParameterError { param: "`subscripts`", message: "number of operands does not match subscripts" })),
+case("ijk", vec![array!(i32, [[1, 2], [3, 4]])], Err(ArrayError::ParameterError { param: "`subscripts`", message: "number of labels does not match operand dimensions" })),
+case("ij->ii", vec![array!(i32, [[1, 2], [3, 4]])], Err(ArrayError::ParameterError { param: "`subscripts`", message: "output subscript contains repeated labels" })),
+case("ij->k", vec![array!(i32, [[1, 2], [3, 4]])], Err(ArrayError::ParameterError { param: "`subscripts`", message: "output subscript does not appear in the input" })),
+case("i1", vec![array!(i32, [[1, 2], [3, 4]])], Err(ArrayError::ParameterError { param: "`subscripts`", message: "invalid subscript character" })),
+case("ij,jk", vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2], [3, 4], [5, 6]])], Err(ArrayError::ParameterError { param: "`operands`", message: "dimensions for the same subscript do not match" })),
+case("...,...", vec![array_flat!(i32, 1, 2, 3), array_flat!(i32, 1, 2)], Err(ArrayError::BroadcastShapeMismatch)),
+)] fn test_linalg_einsum(subscripts: &str, operands: Vec<Result<Array<i32>, ArrayError>>, expected: Result<Array<i32>, ArrayError>) {
+    let operands = operands.into_iter().map(Result::unwrap).collect::<Vec<Array<i32>>>();
+    let operands = operands.iter().collect::<Vec<&Array<i32>>>();
+    assert_eq!(expected, Array::einsum(subscripts, &operands, None));
+    assert_eq!(expected, Array::einsum(subscripts, &operands, Some(true)));
+}
+
+#[rstest(
+subscripts, shapes, expected,
+case("ij,jk,kl,lm->im", vec![vec![2, 3], vec![3, 4], vec![4, 2], vec![2, 3]], vec![2, 3]),
+case("i,ij,j->", vec![vec![3], vec![3, 4], vec![4]], vec![1]),
+case("bij,bjk,bkl->bil", vec![vec![2, 3, 2], vec![2, 2, 4], vec![2, 4, 3]], vec![2, 3, 3]),
+)] fn test_linalg_einsum_optimize(subscripts: &str, shapes: Vec<Vec<usize>>, expected: Vec<usize>) {
+    let operands = shapes.iter()
+        .map(|shape| Array::<i32>::arange(0, shape.iter().product::<usize>() as i32 - 1, None).reshape(shape).unwrap())
+        .collect::<Vec<Array<i32>>>();
+    let operands = operands.iter().collect::<Vec<&Array<i32>>>();
+    let plain = Array::einsum(subscripts, &operands, None);
+    assert_eq!(Ok(expected), plain.get_shape());
+    assert_eq!(plain, Array::einsum(subscripts, &operands, Some(true)));
+}
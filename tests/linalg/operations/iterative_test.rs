@@ -0,0 +1,107 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded_to;
+
+fn poisson() -> Array<f64> {
+    array!(f64, [[2., -1., 0., 0., 0.], [-1., 2., -1., 0., 0.], [0., -1., 2., -1., 0.], [0., 0., -1., 2., -1.], [0., 0., 0., -1., 2.]]).unwrap()
+}
+
+#[rstest(
+preconditioner,
+case(Preconditioner::None),
+case(Preconditioner::Jacobi),
+case(Preconditioner::Ilu0),
+)] fn test_linalg_cg(preconditioner: Preconditioner) {
+    let b = array_flat!(f64, 1., 1., 1., 1., 1.).unwrap();
+    let expected = array_flat!(f64, 2.5, 4., 4.5, 4., 2.5).unwrap();
+    let (x, info) = poisson().cg(&b, None, Some(1e-10), None, Some(preconditioner)).unwrap();
+    assert_eq!(expected, rounded_to(x.clone(), 6));
+    assert!(info.converged);
+    assert!(info.iterations <= 5);
+    let (x, info) = CsrMatrix::from_array(&poisson()).unwrap().cg(&b, None, Some(1e-10), None, Some(preconditioner)).unwrap();
+    assert_eq!(expected, rounded_to(x.clone(), 6));
+    assert!(info.converged);
+}
+
+#[rstest(
+preconditioner,
+case("none"),
+case("jacobi"),
+case("ilu0"),
+)] fn test_linalg_bicgstab(preconditioner: &str) {
+    let arr = array!(f64, [[4., 1., 0.], [1., 3., 1.], [0., 2., 5.]]).unwrap();
+    let b = array_flat!(f64, 6., 10., 19.).unwrap();
+    let expected = array_flat!(f64, 1., 2., 3.).unwrap();
+    let (x, info) = arr.bicgstab(&b, None, Some(1e-10), None, Some(preconditioner)).unwrap();
+    assert_eq!(expected, rounded_to(x.clone(), 6));
+    assert!(info.converged);
+    let (x, info) = CscMatrix::from_array(&arr).unwrap().bicgstab(&b, None, Some(1e-10), None, Some(preconditioner)).unwrap();
+    assert_eq!(expected, rounded_to(x.clone(), 6));
+    assert!(info.converged);
+}
+
+#[rstest(
+restart, preconditioner,
+case(None, "none"),
+case(Some(2), "none"),
+case(None, "jacobi"),
+case(Some(1), "ilu0"),
+)] fn test_linalg_gmres(restart: Option<usize>, preconditioner: &str) {
+    let arr = array!(f64, [[4., 1., 0.], [1., 3., 1.], [0., 2., 5.]]).unwrap();
+    let b = array_flat!(f64, 6., 10., 19.).unwrap();
+    let expected = array_flat!(f64, 1., 2., 3.).unwrap();
+    let (x, info) = arr.gmres(&b, None, Some(1e-10), restart, Some(100), Some(preconditioner)).unwrap();
+    assert_eq!(expected, rounded_to(x.clone(), 6));
+    assert!(info.converged);
+    let (x, info) = CooMatrix::from_array(&arr).unwrap().gmres(&b, None, Some(1e-10), restart, Some(100), Some(preconditioner)).unwrap();
+    assert_eq!(expected, rounded_to(x.clone(), 6));
+    assert!(info.converged);
+}
+
+#[rstest(
+x0, expected_iterations,
+case(array_flat!(f64, 0., 0., 0., 0., 0.), 3),
+case(array_flat!(f64, 2.5, 4., 4.5, 4., 2.5), 0),
+)] fn test_linalg_iterative_initial_guess(x0: Result<Array<f64>, ArrayError>, expected_iterations: usize) {
+    let b = array_flat!(f64, 1., 1., 1., 1., 1.).unwrap();
+    let (_, info) = poisson().cg(&b, Some(&x0.unwrap()), Some(1e-10), None, None::<Preconditioner>).unwrap();
+    assert!(info.converged);
+    assert_eq!(expected_iterations, info.iterations);
+}
+
+#[rstest] fn test_linalg_iterative_not_converged() {
+    let b = array_flat!(f64, 1., 1., 1., 1., 1.).unwrap();
+    let (_, info) = poisson().cg(&b, None, Some(1e-10), Some(1), None::<Preconditioner>).unwrap();
+    assert!(!info.converged);
+    assert_eq!(1, info.iterations);
+    assert!(info.residual > 0.);
+    let (_, info) = poisson().bicgstab(&b, None, Some(1e-10), Some(1), None::<Preconditioner>).unwrap();
+    assert!(!info.converged);
+    let (_, info) = poisson().gmres(&b, None, Some(1e-10), None, Some(2), None::<Preconditioner>).unwrap();
+    assert!(!info.converged);
+    assert_eq!(2, info.iterations);
+}
+
+#[rstest] fn test_linalg_iterative_zero_rhs() {
+    let b = array_flat!(f64, 0., 0., 0., 0., 0.).unwrap();
+    let (x, info) = poisson().gmres(&b, None, None, None, None, None::<Preconditioner>).unwrap();
+    assert_eq!(b, x);
+    assert_eq!(IterativeInfo { converged: true, iterations: 0, residual: 0. }, info);
+}
+
+#[rstest(
+arr, b, preconditioner, expected,
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), array_flat!(f64, 1., 1.), "none", Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array_flat!(f64, 1., 2.), array_flat!(f64, 1., 1.), "none", Err(ArrayError::UnsupportedDimension { supported: vec![2] })),
+case(array!(f64, [[1., 2.], [3., 4.]]), array!(f64, [[1.], [1.]]), "none", Err(ArrayError::UnsupportedDimension { supported: vec![1] })),
+case(array!(f64, [[1., 2.], [3., 4.]]), array_flat!(f64, 1., 1., 1.), "none", Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+case(array!(f64, [[0., 2.], [3., 4.]]), array_flat!(f64, 1., 1.), "jacobi", Err(ArrayError::ParameterError { param: "`preconditioner`", message: "requires non-zero diagonal" })),
+case(array!(f64, [[0., 2.], [3., 4.]]), array_flat!(f64, 1., 1.), "ilu0", Err(ArrayError::ParameterError { param: "`preconditioner`", message: "requires non-zero diagonal" })),
+case(array!(f64, [[1., 2.], [2., 4.]]), array_flat!(f64, 1., 1.), "ilu0", Err(ArrayError::SingularMatrix)),
+case(array!(f64, [[1., 2.], [3., 4.]]), array_flat!(f64, 1., 1.), "lu", Err(ArrayError::ParameterError { param: "`preconditioner`", message: "must be one of {`none`, `jacobi`, `ilu0`}" })),
+)] fn test_linalg_iterative_errors(arr: Result<Array<f64>, ArrayError>, b: Result<Array<f64>, ArrayError>, preconditioner: &str, expected: Result<(Array<f64>, IterativeInfo), ArrayError>) {
+    let (arr, b) = (arr.unwrap(), b.unwrap());
+    assert_eq!(expected, arr.cg(&b, None, None, None, Some(preconditioner)));
+    assert_eq!(expected, arr.bicgstab(&b, None, None, None, Some(preconditioner)));
+    assert_eq!(expected, arr.gmres(&b, None, None, None, None, Some(preconditioner)));
+}
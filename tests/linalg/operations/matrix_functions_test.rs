@@ -0,0 +1,79 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded_to;
+
+#[rstest(
+array, expected,
+case(array!(f64, [[0., 0.], [0., 0.]]), array!(f64, [[1., 0.], [0., 1.]])),
+case(array!(f64, [[0., 1.], [0., 0.]]), array!(f64, [[1., 1.], [0., 1.]])),
+case(array!(f64, [[1., 2.], [3., 4.]]), array!(f64, [[51.968956, 74.736565], [112.104847, 164.073803]])),
+case(array!(f64, [[0., -1.], [1., 0.]]), array!(f64, [[0.540302, -0.841471], [0.841471, 0.540302]])),
+case(array!(f64, [[-49., 24.], [-64., 31.]]), array!(f64, [[-0.735759, 0.551819], [-1.471518, 1.103638]])),
+case(array!(f64, [[1., 2., 3.], [0., 1., 4.], [5., 6., 0.]]), array!(f64, [[333.930905, 506.073899, 416.916597], [311.085683, 473.919462, 389.976431], [487.470539, 740.507489, 609.739617]])),
+case(array!(f64, [[[0., 1.], [0., 0.]], [[0., -1.], [1., 0.]]]), array!(f64, [[[1., 1.], [0., 1.]], [[0.540302, -0.841471], [0.841471, 0.540302]]])),
+case(array!(f64, [1., 2.]), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+)] fn test_linalg_expm(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded_to(array.expm(), 6))
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[1., 0.], [0., 1.]]), array!(f64, [[0., 0.], [0., 0.]])),
+case(array!(f64, [[4., 1.], [2., 3.]]), array!(f64, [[1.304008, 0.30543], [0.61086, 0.998577]])),
+case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]), array!(f64, [[0.51986, -0.623225, -0.173287], [-0.623225, 0.346574, -0.623225], [-0.173287, -0.623225, 0.51986]])),
+case(array!(f64, [[-1., 0.], [0., -2.]]), Err(ArrayError::ParameterError { param: "`array`", message: "has no real principal logarithm" })),
+case(array!(f64, [[1., 2.], [2., 4.]]), Err(ArrayError::SingularMatrix)),
+case(array!(f64, [1., 2.]), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_logm(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded_to(array.logm(), 6))
+}
+
+#[rstest(
+array,
+case(array!(f64, [[1., 2.], [3., 4.]])),
+case(array!(f64, [[0., -1.], [1., 0.]])),
+case(array!(f64, [[0.5, 0.2, 0.], [-0.1, 0.3, 0.4], [0., 0.2, -0.6]])),
+)] fn test_linalg_logm_expm(array: Result<Array<f64>, ArrayError>) {
+    assert_eq!(rounded_to(array.clone(), 6), rounded_to(array.expm().logm(), 6))
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[4., 0.], [0., 9.]]), array!(f64, [[2., 0.], [0., 3.]])),
+case(array!(f64, [[1., 4.], [0., 9.]]), array!(f64, [[1., 1.], [0., 3.]])),
+case(array!(f64, [[4., 1.], [2., 3.]]), array!(f64, [[1.962117, 0.273951], [0.547903, 1.688165]])),
+case(array!(f64, [[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]]), array!(f64, [[1.360388, -0.382683, -0.053825], [-0.382683, 1.306563, -0.382683], [-0.053825, -0.382683, 1.360388]])),
+case(array!(f64, [[0., 0.], [0., 0.]]), array!(f64, [[0., 0.], [0., 0.]])),
+case(array!(f64, [[-4., 0.], [0., -9.]]), Err(ArrayError::ParameterError { param: "`array`", message: "has no real principal square root" })),
+case(array!(f64, [[0., 1.], [0., 0.]]), Err(ArrayError::SingularMatrix)),
+)] fn test_linalg_sqrtm(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded_to(array.sqrtm(), 6))
+}
+
+#[rstest] fn test_linalg_sqrtm_complex() {
+    let array = Array::new(vec![Complex::new(-4., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(-9., 0.)], vec![2, 2]);
+    let expected = Array::new(vec![Complex::new(0., 2.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 3.)], vec![2, 2]);
+    assert_eq!(expected, rounded_to(array.sqrtm(), 0))
+}
+
+#[rstest(
+array,
+case(array!(f64, [[2., 1.], [1., 2.]])),
+case(array!(f64, [[4., 1.], [2., 3.]])),
+case(array!(f64, [[0., -1.], [1., 0.]])),
+case(array!(f64, [[[2., 1.], [1., 2.]], [[4., 1.], [2., 3.]]])),
+)] fn test_linalg_funm(array: Result<Array<f64>, ArrayError>) {
+    assert_eq!(rounded_to(array.expm(), 6), rounded_to(array.funm(|v| v.exp()), 6));
+    assert_eq!(rounded_to(array.sqrtm(), 6), rounded_to(array.funm(|v| v.sqrt()), 6));
+    assert_eq!(rounded_to(array.matmul(&array.clone().unwrap()), 6), rounded_to(array.funm(|v| v * v), 6));
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[1., 1.], [0., 1.]]), Err(ArrayError::SingularMatrix)),
+case(array!(f64, [[2., 1.], [1., 2.]]), Err(ArrayError::ParameterError { param: "`array`", message: "function result is not real" })),
+case(array!(f64, [1., 2.]), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_funm_errors(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.funm(|v| v * Complex::new(0., 1.)))
+}
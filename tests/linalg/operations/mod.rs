@@ -1,5 +1,9 @@
 mod decompositions_test;
 mod eigen_test;
+mod einsum_test;
+mod iterative_test;
+mod matrix_functions_test;
 mod norms_test;
 mod products_test;
 mod solving_inverting_test;
+mod subspaces_test;
@@ -1,5 +1,6 @@
 use rstest::rstest;
 use arr_rs::prelude::*;
+use crate::common::{per_matrix, rounded, stacked_matrices};
 
 #[rstest(
 array, ord, axis, keepdims, expected,
@@ -21,9 +22,13 @@ case(array_arange!(f64, -4., 4.).reshape(&[3, 3]), Some(NormOrd::Inf), None, Non
 case(array_arange!(f64, -4., 4.).reshape(&[3, 3]), Some(NormOrd::NegInf), None, None, array_single!(f64, 2.)),
 case(array_arange!(f64, -4., 4.).reshape(&[3, 3]), Some(NormOrd::Int(1)), None, None, array_single!(f64, 7.)),
 case(array_arange!(f64, -4., 4.).reshape(&[3, 3]), Some(NormOrd::Int(-1)), None, None, array_single!(f64, 6.)),
-// case(array_arange!(f64, -4., 4.).reshape(&[3, 3]), Some(NormOrd::Int(2)), None, None, array_single!(f64, 7.745966692414834)),
-// case(array_arange!(f64, -4., 4.).reshape(&[3, 3]), Some(NormOrd::Int(-2)), None, None, array_single!(f64, 0.)),
-// case(array_arange!(f64, 1., 8.).reshape(&[2, 2, 2]), None, Some(vec![1, 2]), None, array_flat!(f64, 3.74165739, 11.22497216)),
+case(array_arange!(f64, -4., 4.).reshape(&[3, 3]), Some(NormOrd::Int(2)), None, None, array_single!(f64, 7.3484692283495345)),
+case(array!(f64, [[1., 2.], [3., 4.]]), Some(NormOrd::Int(-2)), None, None, array_single!(f64, 0.3659661906262574)),
+case(array!(f64, [[3., 0.], [0., -4.]]), Some(NormOrd::Nuc), None, None, array_single!(f64, 7.)),
+case(array_arange!(f64, 1., 8.).reshape(&[2, 2, 2]), None, Some(vec![1, 2]), None, array_flat!(f64, 5.477225575051661, 13.190905958272919)),
+case(array!(f64, [[[3., 0.], [0., -4.]], [[1., 0.], [0., 2.]]]), Some(NormOrd::Nuc), Some(vec![1, 2]), None, array_flat!(f64, 7., 3.)),
+case(array!(f64, [[[3., 0.], [0., -4.]], [[1., 0.], [0., 2.]]]), Some(NormOrd::Int(2)), Some(vec![2, 1]), None, array_flat!(f64, 4., 2.)),
+case(array!(f64, [[1., 2.], [3., 4.]]), Some(NormOrd::Int(3)), None, None, Err(ArrayError::ParameterError { param: "`ord`", message: "invalid norm order for matrices." })),
 case(array_arange!(f64, -4., 4.), Some(NormOrd::Fro), None, None, Err(ArrayError::ParameterError { param: "`ord`", message: "invalid norm order for vectors." })),
 case(array_arange!(f64, -4., 4.), None, Some(vec![2, 2]), None, Err(ArrayError::ParameterError { param: "`axis`", message: "duplicate axes given." })),
 case(array_arange!(f64, -4., 4.), None, Some(vec![1, 2, 3]), None, Err(ArrayError::ParameterError { param: "`axis`", message: "improper number of dimensions to norm." })),
@@ -48,6 +53,77 @@ case(array!(i32, [[1, 2, 6, 6], [4, 7, 3, 2], [0, 0, 0, 0], [1, 2, 2, 9]]), arra
 case(array!(i32, [[4, 3, 2, 2], [0, 1, -3, 3], [0, -1, 3, 3], [0, 3, 1, 1]]), array_single!(i32, -240)),
 case(array!(i32, [[[1, 2], [3, 4]], [[4, 3], [2, 1]]]), array_flat!(i32, -2, -2)),
 case(array!(i32, [[[1, 2, 3], [3, 2, 1], [2, 1, 3]], [[5, 6, 4], [6, 3, 5], [8, 2, 8]]]), array_flat!(i32, -12, -26)),
+case(Array::identity(16), array_single!(i32, 1)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6]]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
 )] fn test_linalg_det(array: Result<Array<i32>, ArrayError>, expected: Result<Array<i32>, ArrayError>) {
     assert_eq!(expected, array.det())
 }
+
+#[rstest(
+array, expected,
+case(array!(f64, [[1., 2., 3.], [2., 4., 5.], [4., 2., 1.]]), array_single!(f64, -6.)),
+case(array!(f64, [[0.5, 0.25], [1.5, 2.]]), array_single!(f64, 0.625)),
+case(array!(f64, [[5.]]), array_single!(f64, 5.)),
+)] fn test_linalg_det_float(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.det())
+}
+
+#[rstest(
+shape,
+case(&[3, 4, 4]),
+case(&[2, 3, 4, 4]),
+)] fn test_linalg_det_stacked(shape: &[usize]) {
+    let array = stacked_matrices(shape);
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.det())), rounded(array.det()));
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.slogdet().map(|result| result.1))), rounded(array.slogdet().map(|result| result.1)));
+}
+
+#[rstest(
+array, expected,
+case(Array::new(vec![Complex::new(0., 1.), Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 1.)], vec![2, 2]), Array::single(Complex::new(-2., 0.))),
+case(Array::new(vec![Complex::new(1., 1.), Complex::new(2., 0.), Complex::new(0., 0.), Complex::new(0., 3.)], vec![2, 2]), Array::single(Complex::new(-3., 3.))),
+case(Array::new(vec![Complex::new(0., 1.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 1.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0., 1.)], vec![3, 3]), Array::single(Complex::new(0., -1.))),
+)] fn test_linalg_det_complex(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.det())
+}
+
+#[rstest(
+array, expected_sign, expected_logdet,
+case(array!(f64, [[3., 8.], [4., 6.]]), array_single!(f64, -1.), array_single!(f64, 14_f64.ln())),
+case(array!(f64, [[1., 2.], [2., 4.]]), array_single!(f64, 0.), array_single!(f64, f64::NEG_INFINITY)),
+case(array!(f64, [[[2., 0.], [0., 3.]], [[0., 2.], [3., 0.]]]), array_flat!(f64, 1., -1.), array_flat!(f64, 6_f64.ln(), 6_f64.ln())),
+case(Array::identity(400).unwrap() * 10., array_single!(f64, 1.), array_single!(f64, 400. * 10_f64.ln())),
+)] fn test_linalg_slogdet(array: Result<Array<f64>, ArrayError>, expected_sign: Result<Array<f64>, ArrayError>, expected_logdet: Result<Array<f64>, ArrayError>) {
+    let (sign, logdet) = array.slogdet().unwrap();
+    assert_eq!(expected_sign, Ok(sign));
+    assert_eq!(rounded(expected_logdet.unwrap()), rounded(logdet));
+}
+
+#[test]
+fn test_linalg_slogdet_complex() {
+    let array = Array::new(vec![Complex::new(0., 1.), Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 1.)], vec![2, 2]);
+    assert_eq!(Err(ArrayError::ParameterError { param: "`array`", message: "complex matrices are not supported, use `det` instead" }), array.slogdet());
+}
+
+#[rstest(
+array, p, expected,
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), None, array_single!(f64, 1.414213562)),
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), Some("fro"), array_single!(f64, 3.16227766)),
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), Some("nuc"), array_single!(f64, 9.242640687)),
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), Some("inf"), array_single!(f64, 2.)),
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), Some("-inf"), array_single!(f64, 1.)),
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), Some("1"), array_single!(f64, 2.)),
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), Some("-1"), array_single!(f64, 1.)),
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), Some("2"), array_single!(f64, 1.414213562)),
+case(array!(f64, [[1., 0., -1.], [0., 1., 0.], [1., 0., 1.]]), Some("-2"), array_single!(f64, 0.707106781)),
+case(array!(f64, [[1., 0., 0.], [0., 2., 0.]]), None, array_single!(f64, 2.)),
+case(array!(f64, [[[1., 0.], [0., 2.]], [[1., 2.], [2., 4.]]]), None, array_flat!(f64, 2., f64::INFINITY)),
+case(array!(f64, [[1., 2.], [2., 4.]]), Some("fro"), array_single!(f64, f64::INFINITY)),
+case(array!(f64, [[1., 2.], [2., 4.]]), Some("-2"), array_single!(f64, 0.)),
+case(array!(f64, [[1., 0., 0.], [0., 2., 0.]]), Some("fro"), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array!(f64, [[1., 2.], [3., 4.]]), Some("3"), Err(ArrayError::ParameterError { param: "`ord`", message: "invalid norm order for matrices." })),
+case(array!(f64, [[1., 2.], [2., 4.]]), Some("3"), Err(ArrayError::ParameterError { param: "`ord`", message: "invalid norm order for matrices." })),
+case(array_flat!(f64, 1., 2.), None, Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_cond(array: Result<Array<f64>, ArrayError>, p: Option<&str>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.cond(p)))
+}
@@ -17,7 +17,8 @@ case(array!(i32, [[[1, 2], [3, 4]], [[5, 6], [7, 8]]]), array!(i32, [[[1, 2], [3
 case(array_flat!(i32, 1, 2, 3), array!(i32, [[1, 2, 3], [4, 5, 6]]), Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
 case(array!(i32, [[1, 2], [3, 4]]), array_flat!(i32, 1, 2, 3), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
 case(array!(i32, [[1, 2], [3, 4]]), array_flat!(i32, 1, 2, 3, 4), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "4".to_string() })),
-case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6, 3], [7, 8, 3]]), Err(ArrayError::ParameterError { param: "`shapes`", message: "are not aligned" })),
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6, 3], [7, 8, 3]]), array!(i32, [[19, 22, 9], [43, 50, 21]])),
+case(array!(i32, [[5, 6, 3], [7, 8, 3]]), array!(i32, [[1, 2], [3, 4]]), Err(ArrayError::ParameterError { param: "`shapes`", message: "are not aligned" })),
 case(array_arange!(i32, 1, 24).reshape(&[2, 3, 4]), array_arange!(i32, 1, 24).reshape(&[2, 3, 4]), Err(ArrayError::ParameterError { param: "`shapes`", message: "are not aligned" })),
 )] fn test_linalg_dot(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, expected: Result<Array<i32>, ArrayError>) {
     assert_eq!(expected, array.dot(&other.unwrap()))
@@ -34,6 +35,15 @@ case(array!(i32, [[1, 2], [3, 4]]), array_flat!(i32, 1, 2, 3), Err(ArrayError::M
     assert_eq!(expected, array.vdot(&other.unwrap()))
 }
 
+#[rstest(
+array, other, expected,
+case(Array::flat(vec![Complex::new(1., 2.), Complex::new(0., 3.)]), Array::flat(vec![Complex::new(1., 2.), Complex::new(0., 3.)]), Array::single(Complex::new(14., 0.))),
+case(Array::flat(vec![Complex::new(1., 2.), Complex::new(3., -1.)]), Array::flat(vec![Complex::new(2., -1.), Complex::new(1., 1.)]), Array::single(Complex::new(2., -1.))),
+case(Array::flat(vec![Complex::new(2., -1.), Complex::new(1., 1.)]), Array::flat(vec![Complex::new(1., 2.), Complex::new(3., -1.)]), Array::single(Complex::new(2., 1.))),
+)] fn test_linalg_vdot_complex(array: Result<Array<Complex<f64>>, ArrayError>, other: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.vdot(&other.unwrap()))
+}
+
 #[rstest(
 array, other, expected,
 case(array_single!(i32, 2), array_single!(i32, 3), array_single!(i32, 6)),
@@ -77,3 +87,117 @@ case(array_arange!(i32, 0, 3).reshape(&[2, 2]), array_arange!(i32, 0, 7).reshape
 )] fn test_linalg_matmul(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, expected: Result<Array<i32>, ArrayError>) {
     assert_eq!(expected, array.matmul(&other.unwrap()))
 }
+
+#[rstest(
+array, other, axes, expected,
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2], [3, 4]]), None, array_single!(i32, 30)),
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2], [3, 4]]), Some(TensorAxes::Count(1)), array!(i32, [[7, 10], [15, 22]])),
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2], [3, 4]]), Some(TensorAxes::Count(0)), array!(i32, [[[[1, 2], [3, 4]], [[2, 4], [6, 8]]], [[[3, 6], [9, 12]], [[4, 8], [12, 16]]]])),
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2], [3, 4]]), Some(TensorAxes::Pairs(vec![0], vec![0])), array!(i32, [[10, 14], [14, 20]])),
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2], [3, 4]]), Some(TensorAxes::Pairs(vec![1, 0], vec![0, 1])), array_single!(i32, 29)),
+case(array_arange!(i32, 0, 23).reshape(&[2, 3, 4]), array_arange!(i32, 0, 5).reshape(&[3, 2]), Some(TensorAxes::Pairs(vec![1], vec![0])), array!(i32, [[[40, 52], [46, 61], [52, 70], [58, 79]], [[112, 160], [118, 169], [124, 178], [130, 187]]])),
+case(array!(i32, [[1, 2], [3, 4]]), array_flat!(i32, 1, 2, 3), Some(TensorAxes::Count(1)), Err(ArrayError::ParameterError { param: "`shapes`", message: "are not aligned" })),
+case(array!(i32, [[1, 2], [3, 4]]), array_flat!(i32, 1, 2), Some(TensorAxes::Count(2)), Err(ArrayError::MustBeAtLeast { value1: "1".to_string(), value2: "2".to_string() })),
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2], [3, 4]]), Some(TensorAxes::Pairs(vec![0, 0], vec![0, 1])), Err(ArrayError::MustBeUnique { value: "[0, 0]".to_string() })),
+)] fn test_linalg_tensordot(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, axes: Option<TensorAxes>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.tensordot(&other.unwrap(), axes))
+}
+
+#[rstest(
+array, other, expected,
+case(array_flat!(i32, 1, 2), array_flat!(i32, 5, 6, 7), array_flat!(i32, 5, 6, 7, 10, 12, 14)),
+case(array!(i32, [[1, 0], [0, 1]]), array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2, 0, 0], [3, 4, 0, 0], [0, 0, 1, 2], [0, 0, 3, 4]])),
+case(array!(i32, [[1, 2]]), array!(i32, [[1], [2]]), array!(i32, [[1, 2], [2, 4]])),
+case(array_flat!(i32, 1, 2), array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2, 2, 4], [3, 4, 6, 8]])),
+)] fn test_linalg_kron(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.kron(&other.unwrap()))
+}
+
+#[rstest(
+array, other, axis, expected,
+case(array_flat!(i32, 1, 2, 3), array_flat!(i32, 4, 5, 6), None, array_flat!(i32, -3, 6, -3)),
+case(array_flat!(i32, 1, 2), array_flat!(i32, 4, 5), None, array_single!(i32, -3)),
+case(array_flat!(i32, 1, 2), array_flat!(i32, 4, 5, 6), None, array_flat!(i32, 12, -6, -3)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6]]), array_flat!(i32, 0, 0, 1), None, array!(i32, [[2, -1, 0], [5, -4, 0]])),
+case(array!(i32, [[1, 4], [2, 5], [3, 6]]), array!(i32, [[4, 1], [5, 2], [6, 3]]), Some(0), array!(i32, [[-3, 3], [6, -6], [-3, 3]])),
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[4, 5], [6, 7]]), None, array_flat!(i32, -3, -3)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6]]), array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), None, Err(ArrayError::BroadcastShapeMismatch)),
+case(array_flat!(i32, 1, 2, 3, 4), array_flat!(i32, 1, 2, 3, 4), None, Err(ArrayError::MustBeEqual { value1: "4".to_string(), value2: "[2, 3]".to_string() })),
+)] fn test_linalg_cross(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, axis: Option<isize>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.cross(&other.unwrap(), axis))
+}
+
+#[rstest(
+array, offset, axis1, axis2, expected,
+case(array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), None, None, None, array_flat!(i32, 1, 5, 9)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), Some(1), None, None, array_flat!(i32, 2, 6)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), Some(-2), None, None, array_flat!(i32, 7)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), Some(3), None, None, Array::empty()),
+case(array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), None, Some(1), Some(0), array_flat!(i32, 1, 5, 9)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6]]), None, None, None, array_flat!(i32, 1, 5)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6]]), Some(1), None, None, array_flat!(i32, 2, 6)),
+case(array_arange!(i32, 0, 7).reshape(&[2, 2, 2]), None, None, None, array!(i32, [[0, 6], [1, 7]])),
+case(array_arange!(i32, 0, 7).reshape(&[2, 2, 2]), None, Some(-2), Some(-1), array!(i32, [[0, 3], [4, 7]])),
+case(array_arange!(i32, 0, 7).reshape(&[2, 2, 2]), None, Some(0), Some(2), array!(i32, [[0, 5], [2, 7]])),
+case(array_flat!(i32, 1, 2, 3), None, None, None, Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+case(array!(i32, [[1, 2], [3, 4]]), None, Some(1), Some(1), Err(ArrayError::ParameterError { param: "`axis1` and `axis2`", message: "cannot be the same" })),
+case(array!(i32, [[1, 2], [3, 4]]), None, Some(0), Some(2), Err(ArrayError::AxisOutOfBounds)),
+)] fn test_linalg_diagonal(array: Result<Array<i32>, ArrayError>, offset: Option<isize>, axis1: Option<isize>, axis2: Option<isize>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.diagonal(offset, axis1, axis2))
+}
+
+#[rstest(
+array, offset, axis1, axis2, expected,
+case(array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), None, None, None, array_single!(i32, 15)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), Some(1), None, None, array_single!(i32, 8)),
+case(array!(i32, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]), Some(-1), None, None, array_single!(i32, 12)),
+case(array_arange!(i32, 0, 7).reshape(&[2, 2, 2]), None, None, None, array_flat!(i32, 6, 8)),
+case(array_arange!(i32, 0, 7).reshape(&[2, 2, 2]), None, Some(1), Some(2), array_flat!(i32, 3, 11)),
+case(array_flat!(i32, 1, 2, 3), None, None, None, Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_trace(array: Result<Array<i32>, ArrayError>, offset: Option<isize>, axis1: Option<isize>, axis2: Option<isize>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.trace(offset, axis1, axis2))
+}
+
+#[rstest(
+arrays, expected,
+case(vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6], [7, 8]])], array!(i32, [[19, 22], [43, 50]])),
+case(vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6], [7, 8]]), array!(i32, [[1, 1], [0, 1]])], array!(i32, [[19, 41], [43, 93]])),
+case(vec![array!(i32, [[1, 2, 3]]), array!(i32, [[1], [2], [3]]), array!(i32, [[1, 2, 3]]), array!(i32, [[1, 0], [0, 1], [1, 1]])], array!(i32, [[56, 70]])),
+case(vec![array_flat!(i32, 1, 1), array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6], [7, 8]])], array_flat!(i32, 62, 72)),
+case(vec![array!(i32, [[1, 2], [3, 4]]), array!(i32, [[5, 6], [7, 8]]), array_flat!(i32, 1, 0)], array_flat!(i32, 19, 43)),
+case(vec![array_flat!(i32, 1, 1), array!(i32, [[1, 2], [3, 4]]), array_flat!(i32, 1, 0)], array_single!(i32, 4)),
+case(vec![array!(i32, [[1, 2], [3, 4]])], Err(ArrayError::MustBeAtLeast { value1: "1".to_string(), value2: "2".to_string() })),
+case(vec![array!(i32, [[1, 2], [3, 4]]), array_flat!(i32, 1, 2, 3)], Err(ArrayError::ParameterError { param: "`shapes`", message: "are not aligned" })),
+case(vec![array!(i32, [[1, 2], [3, 4]]), array_arange!(i32, 1, 8).reshape(&[2, 2, 2]), array!(i32, [[1, 2], [3, 4]])], Err(ArrayError::UnsupportedDimension { supported: vec![2] })),
+)] fn test_linalg_multi_dot(arrays: Vec<Result<Array<i32>, ArrayError>>, expected: Result<Array<i32>, ArrayError>) {
+    let arrays = arrays.into_iter().map(Result::unwrap).collect::<Vec<Array<i32>>>();
+    assert_eq!(expected, Array::multi_dot(&arrays.iter().collect::<Vec<&Array<i32>>>()))
+}
+
+#[rstest(
+array, other, expected,
+case(array!(i32, [[[[1, 2]]], [[[3, 4]]]]), array!(i32, [[[1], [1]], [[1], [0]], [[0], [1]]]), array!(i32, [[[[3]], [[1]], [[2]]], [[[7]], [[3]], [[4]]]])),
+case(array!(i32, [[[1, 2], [3, 4]]]), array!(i32, [[[1, 0], [0, 1]], [[0, 1], [1, 0]]]), array!(i32, [[[1, 2], [3, 4]], [[2, 1], [4, 3]]])),
+case(array_flat!(i32, 1, 2), array!(i32, [[[1, 0], [0, 1]], [[0, 1], [1, 0]]]), array!(i32, [[1, 2], [2, 1]])),
+case(array!(i32, [[[1, 0], [0, 1]], [[0, 1], [1, 0]]]), array_flat!(i32, 1, 2), array!(i32, [[1, 2], [2, 1]])),
+case(array_arange!(i32, 0, 11).reshape(&[2, 2, 3]), array_arange!(i32, 0, 17).reshape(&[3, 3, 2]), Err(ArrayError::BroadcastShapeMismatch)),
+case(array_arange!(i32, 0, 11).reshape(&[2, 2, 3]), array_arange!(i32, 0, 11).reshape(&[2, 2, 3]), Err(ArrayError::ParameterError { param: "`shapes`", message: "are not aligned" })),
+)] fn test_linalg_matmul_broadcast(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.matmul(&other.unwrap()))
+}
+
+#[rstest(
+rows, depth, cols,
+case(5, 3, 7),
+case(70, 300, 9),
+case(9, 260, 530),
+)] fn test_linalg_matmul_blocked(rows: usize, depth: usize, cols: usize) {
+    let array = Array::new((0..rows * depth).map(|i| (i % 7) as i64 - 3).collect(), vec![rows, depth]).unwrap();
+    let other = Array::new((0..depth * cols).map(|i| (i % 5) as i64 - 2).collect(), vec![depth, cols]).unwrap();
+    let (elements_1, elements_2) = (array.get_elements().unwrap(), other.get_elements().unwrap());
+    let expected = (0..rows * cols)
+        .map(|idx| (0..depth).map(|k| elements_1[idx / cols * depth + k] * elements_2[k * cols + idx % cols]).sum())
+        .collect::<Vec<i64>>();
+    assert_eq!(Array::new(expected.clone(), vec![rows, cols]), array.matmul(&other));
+    assert_eq!(Array::new(expected, vec![rows, cols]), array.dot(&other));
+}
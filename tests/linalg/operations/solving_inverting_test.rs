@@ -1,5 +1,6 @@
 use rstest::rstest;
 use arr_rs::prelude::*;
+use crate::common::{per_matrix, rounded, stacked_matrices};
 
 #[rstest(
 array, other, expected,
@@ -12,5 +13,211 @@ case(array!(f64, [[2, 1, 3], [1, 3, 2]]), array_flat!(f64, 3, 1), Err(ArrayError
 case(array!(f64, [[1, 2], [3, 4], [5, 6]]), array_flat!(f64, 3, 1, 6), Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
 case(array!(f64, [[1, 2], [2, 4]]), array_flat!(f64, 3, 6), Err(ArrayError::SingularMatrix)),
 )] fn test_linalg_solve(array: Result<Array<f64>, ArrayError>, other: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(rounded(expected), rounded(array.solve(&other.unwrap())))
+}
+
+#[rstest(
+array, other, expected,
+case(array!(f64, [[5]]), array_flat!(f64, 10), array_flat!(f64, 2)),
+case(array!(f64, [[[2, 1], [1, 3]], [[1, 0], [0, 2]]]), array_flat!(f64, 5, 8), array!(f64, [[1.4, 2.2], [5., 4.]])),
+case(array!(f64, [[[2, 1], [1, 3]], [[1, 0], [0, 2]]]), array!(f64, [[5], [8]]), array!(f64, [[[1.4], [2.2]], [[5.], [4.]]])),
+case(array!(f64, [[2, 1], [1, 3]]), array!(f64, [[[5], [8]], [[2], [1]], [[0], [5]]]), array!(f64, [[[1.4], [2.2]], [[1.], [0.]], [[-1.], [2.]]])),
+case(array!(f64, [[[2, 1], [1, 3]], [[1, 0], [0, 2]]]), array!(f64, [[[5], [8]], [[2], [1]], [[0], [5]]]), Err(ArrayError::BroadcastShapeMismatch)),
+case(array!(f64, [[[2, 1], [1, 3]], [[1, 2], [2, 4]]]), array_flat!(f64, 5, 8), Err(ArrayError::SingularMatrix)),
+)] fn test_linalg_solve_batched(array: Result<Array<f64>, ArrayError>, other: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.solve(&other.unwrap())))
+}
+
+#[rstest(
+array, expected,
+case(array!(f64, [[2., 1.], [1., 1.]]), array!(f64, [[1., -1.], [-1., 2.]])),
+case(array!(f64, [[1., 2.], [3., 4.]]), array!(f64, [[-2., 1.], [1.5, -0.5]])),
+case(array!(f64, [[0., 1., 0.], [0., 0., 1.], [1., 0., 0.]]), array!(f64, [[0., 0., 1.], [1., 0., 0.], [0., 1., 0.]])),
+case(array!(f64, [[4., 3., 2., 2.], [0., 1., -3., 3.], [0., -1., 3., 3.], [0., 3., 1., 1.]]), array!(f64, [[0.25, 0., -0.075, -0.275], [0., 0., -0.1, 0.3], [0., -0.166666667, 0.133333333, 0.1], [0., 0.166666667, 0.166666667, 0.]])),
+case(array!(f64, [[[1., 2.], [3., 4.]], [[2., 0.], [0., 4.]]]), array!(f64, [[[-2., 1.], [1.5, -0.5]], [[0.5, 0.], [0., 0.25]]])),
+case(array!(f64, [[5.]]), array!(f64, [[0.2]])),
+case(array!(f64, [[1., 2.], [2., 4.]]), Err(ArrayError::SingularMatrix)),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]), Err(ArrayError::SingularMatrix)),
+case(array!(f64, [[0., 0.], [0., 0.]]), Err(ArrayError::SingularMatrix)),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array!(f64, [1, 2]), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_inv(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.inv()))
+}
+
+#[rstest(
+array, other, expected,
+case(Array::new(vec![Complex::new(0., 1.), Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 1.)], vec![2, 2]), Array::flat(vec![Complex::new(1., 0.), Complex::new(0., 0.)]), Array::flat(vec![Complex::new(0., -0.5), Complex::new(0.5, 0.)])),
+case(Array::new(vec![Complex::new(2., 0.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(4., 0.)], vec![2, 2]), Array::flat(vec![Complex::new(0., 2.), Complex::new(4., 0.)]), Array::flat(vec![Complex::new(0., 1.), Complex::new(1., 0.)])),
+case(Array::new(vec![Complex::new(1., 1.), Complex::new(2., 0.), Complex::new(2., 2.), Complex::new(4., 0.)], vec![2, 2]), Array::flat(vec![Complex::new(1., 0.), Complex::new(0., 0.)]), Err(ArrayError::SingularMatrix)),
+)] fn test_linalg_solve_complex(array: Result<Array<Complex<f64>>, ArrayError>, other: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
     assert_eq!(expected, array.solve(&other.unwrap()))
 }
+
+#[rstest(
+array, expected,
+case(Array::new(vec![Complex::new(0., 1.), Complex::new(1., 0.), Complex::new(1., 0.), Complex::new(0., 1.)], vec![2, 2]), Array::new(vec![Complex::new(0., -0.5), Complex::new(0.5, 0.), Complex::new(0.5, 0.), Complex::new(0., -0.5)], vec![2, 2])),
+case(Array::new(vec![Complex::new(0., 2.), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(4., 0.)], vec![2, 2]), Array::new(vec![Complex::new(0., -0.5), Complex::new(0., 0.), Complex::new(0., 0.), Complex::new(0.25, 0.)], vec![2, 2])),
+case(Array::new(vec![Complex::new(1., 1.), Complex::new(2., 0.), Complex::new(2., 2.), Complex::new(4., 0.)], vec![2, 2]), Err(ArrayError::SingularMatrix)),
+)] fn test_linalg_inv_complex(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.inv())
+}
+
+#[rstest(
+array, rcond, expected,
+case(array!(f64, [[1., 2.], [3., 4.]]), None, array!(f64, [[-2., 1.], [1.5, -0.5]])),
+case(array!(f64, [[1., 2.], [2., 4.]]), None, array!(f64, [[0.04, 0.08], [0.08, 0.16]])),
+case(array!(f64, [[1., 2.], [3., 4.], [5., 6.]]), None, array!(f64, [[-1.333333333, -0.333333333, 0.666666667], [1.083333333, 0.333333333, -0.416666667]])),
+case(array!(f64, [[1., 2., 3.]]), None, array!(f64, [[0.071428571], [0.142857143], [0.214285714]])),
+case(array!(f64, [[1., 0.], [0., 0.001]]), Some(1e-2), array!(f64, [[1., 0.], [0., 0.]])),
+case(array!(f64, [[[1., 0.], [0., 2.]], [[0., 0.], [0., 0.]]]), None, array!(f64, [[[1., 0.], [0., 0.5]], [[0., 0.], [0., 0.]]])),
+case(array_flat!(f64, 1, 2), None, Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_pinv(array: Result<Array<f64>, ArrayError>, rcond: Option<f64>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.pinv(rcond)))
+}
+
+#[rstest(
+shape,
+case(&[3, 4, 4]),
+case(&[2, 3, 4, 4]),
+)] fn test_linalg_solving_inverting_stacked(shape: &[usize]) {
+    let array = stacked_matrices(shape);
+    let b = array_flat!(f64, 1., 2., 3., 4.).unwrap();
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.solve(&b))), rounded(array.solve(&b)));
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.inv())), rounded(array.inv()));
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.matrix_power(-2))), rounded(array.matrix_power(-2)));
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.matrix_power(3))), rounded(array.matrix_power(3)));
+    assert_eq!(rounded(per_matrix(&array, |matrix| matrix.lstsq(&b, None).map(|result| result.0))), rounded(array.lstsq(&b, None).map(|result| result.0)));
+}
+
+#[rstest(
+array, n, expected,
+case(array!(i32, [[1, 1], [1, 0]]), 0, array!(i32, [[1, 0], [0, 1]])),
+case(array!(i32, [[1, 1], [1, 0]]), 1, array!(i32, [[1, 1], [1, 0]])),
+case(array!(i32, [[1, 1], [1, 0]]), 10, array!(i32, [[89, 55], [55, 34]])),
+case(array!(i32, [[0, 1], [-1, 0]]), 3, array!(i32, [[0, -1], [1, 0]])),
+case(array!(i32, [[[1, 1], [1, 0]], [[2, 0], [0, 3]]]), 3, array!(i32, [[[3, 2], [2, 1]], [[8, 0], [0, 27]]])),
+case(array!(i32, [[1, 2, 3], [4, 5, 6]]), 2, Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+)] fn test_linalg_matrix_power(array: Result<Array<i32>, ArrayError>, n: i32, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, array.matrix_power(n))
+}
+
+#[rstest(
+array, n, expected,
+case(array!(f64, [[2., 0.], [0., 4.]]), -1, array!(f64, [[0.5, 0.], [0., 0.25]])),
+case(array!(f64, [[1., 2.], [3., 4.]]), -2, array!(f64, [[5.5, -2.5], [-3.75, 1.75]])),
+case(array!(f64, [[1., 2.], [2., 4.]]), -1, Err(ArrayError::SingularMatrix)),
+)] fn test_linalg_matrix_power_negative(array: Result<Array<f64>, ArrayError>, n: i32, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.matrix_power(n)))
+}
+
+#[rstest(
+array, tol, expected,
+case(array!(f64, [[1., 0.], [0., 1.]]), None, Array::single(2)),
+case(array!(f64, [[1., 2.], [2., 4.]]), None, Array::single(1)),
+case(array!(f64, [[0., 0.], [0., 0.]]), None, Array::single(0)),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]), None, Array::single(2)),
+case(array!(f64, [[1., 2., 3., 4.], [2., 4., 6., 8.]]), None, Array::single(1)),
+case(array!(f64, [[1., 0.], [0., 0.001]]), Some(1e-2), Array::single(1)),
+case(array!(f64, [[[1., 0.], [0., 1.]], [[1., 1.], [1., 1.]], [[0., 0.], [0., 0.]]]), None, Array::flat(vec![2, 1, 0])),
+case(array_flat!(f64, 1, 2), None, Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+)] fn test_linalg_matrix_rank(array: Result<Array<f64>, ArrayError>, tol: Option<f64>, expected: Result<Array<usize>, ArrayError>) {
+    assert_eq!(expected, array.matrix_rank(tol))
+}
+
+#[rstest(
+array, b, rcond, expected_x, expected_residuals, expected_rank, expected_s,
+case(array!(f64, [[1., 0.], [1., 1.], [1., 2.], [1., 3.]]), array_flat!(f64, 1., 3., 3., 5.), None, array_flat!(f64, 1.2, 1.2), array_flat!(f64, 0.8), Array::single(2), array_flat!(f64, 4.100030448, 1.090756767)),
+case(array!(f64, [[1., 0.], [1., 1.], [1., 2.], [1., 3.]]), array!(f64, [[1., 1.], [3., 2.], [3., 3.], [5., 4.]]), None, array!(f64, [[1.2, 1.], [1.2, 1.]]), array_flat!(f64, 0.8, 0.), Array::single(2), array_flat!(f64, 4.100030448, 1.090756767)),
+case(array!(f64, [[2., 0.], [0., 4.]]), array_flat!(f64, 2., 2.), None, array_flat!(f64, 1., 0.5), Array::flat(vec![]), Array::single(2), array_flat!(f64, 4., 2.)),
+case(array!(f64, [[1., 1.]]), array_flat!(f64, 2.), None, array_flat!(f64, 1., 1.), Array::flat(vec![]), Array::single(1), array_flat!(f64, 1.414213562)),
+case(array!(f64, [[1., 2.], [2., 4.], [3., 6.]]), array_flat!(f64, 1., 2., 3.), None, array_flat!(f64, 0.2, 0.4), Array::flat(vec![]), Array::single(1), array_flat!(f64, 8.366600265, 0.)),
+case(array!(f64, [[1., 0.], [0., 0.001], [0., 0.]]), array_flat!(f64, 1., 1., 1.), Some(0.01), array_flat!(f64, 1., 0.), Array::flat(vec![]), Array::single(1), array_flat!(f64, 1., 0.001)),
+case(array!(f64, [[[1., 0.], [1., 1.], [1., 2.], [1., 3.]], [[2., 0.], [0., 4.], [0., 0.], [0., 0.]]]), array_flat!(f64, 1., 3., 3., 5.), None, array!(f64, [[1.2, 1.2], [0.5, 0.75]]), array!(f64, [[0.8], [34.]]), Array::flat(vec![2, 2]), array!(f64, [[4.100030448, 1.090756767], [4., 2.]])),
+case(array!(f64, [[1., 0.], [1., 1.], [1., 2.], [1., 3.]]), array!(f64, [[[1.], [3.], [3.], [5.]], [[0.], [1.], [2.], [3.]]]), None, array!(f64, [[[1.2], [1.2]], [[0.], [1.]]]), array!(f64, [[0.8], [0.]]), Array::single(2), array_flat!(f64, 4.100030448, 1.090756767)),
+case(array!(f64, [[[1., 0.], [1., 1.], [1., 2.]], [[1., 2.], [2., 4.], [3., 6.]]]), array_flat!(f64, 1., 2., 3.), None, array!(f64, [[1., 1.], [0.2, 0.4]]), Array::flat(vec![]), Array::flat(vec![2, 1]), array!(f64, [[2.676243199, 0.91527173], [8.366600265, 0.]])),
+)] fn test_linalg_lstsq(array: Result<Array<f64>, ArrayError>, b: Result<Array<f64>, ArrayError>, rcond: Option<f64>, expected_x: Result<Array<f64>, ArrayError>, expected_residuals: Result<Array<f64>, ArrayError>, expected_rank: Result<Array<usize>, ArrayError>, expected_s: Result<Array<f64>, ArrayError>) {
+    let (x, residuals, rank, s) = array.lstsq(&b.unwrap(), rcond).unwrap();
+    assert_eq!(expected_x, rounded(Ok(x)));
+    assert_eq!(expected_residuals, rounded(Ok(residuals)));
+    assert_eq!(expected_rank, Ok(rank));
+    assert_eq!(expected_s, rounded(Ok(s)));
+}
+
+#[rstest(
+array, b, expected,
+case(array!(f64, [[1., 0.], [1., 1.]]), array_flat!(f64, 1., 2., 3.), Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+case(array_flat!(f64, 1., 2.), array_flat!(f64, 1., 2.), Err(ArrayError::UnsupportedDimension { supported: vec![0, 1] })),
+case(array!(f64, [[[1., 0.], [1., 1.]], [[1., 0.], [0., 1.]]]), array!(f64, [[[1.], [2.]], [[1.], [2.]], [[1.], [2.]]]), Err(ArrayError::BroadcastShapeMismatch)),
+)] fn test_linalg_lstsq_error(array: Result<Array<f64>, ArrayError>, b: Result<Array<f64>, ArrayError>, expected: LstsqResult<f64>) {
+    assert_eq!(expected, array.lstsq(&b.unwrap(), None))
+}
+
+#[rstest(
+array, b, lower, unit_diagonal, trans, expected,
+case(array!(f64, [[2., 5.], [1., 4.]]), array_flat!(f64, 2., 9.), Some(true), None, None, array_flat!(f64, 1., 2.)),
+case(array!(f64, [[2., 5.], [1., 4.]]), array_flat!(f64, 2., 9.), None, None, None, array_flat!(f64, -4.625, 2.25)),
+case(array!(f64, [[2., 5.], [1., 4.]]), array_flat!(f64, 2., 9.), Some(true), Some(true), None, array_flat!(f64, 2., 7.)),
+case(array!(f64, [[2., 5.], [1., 4.]]), array_flat!(f64, 2., 9.), Some(true), None, Some("t"), array_flat!(f64, -0.125, 2.25)),
+case(array!(f64, [[2., 5.], [1., 4.]]), array_flat!(f64, 2., 9.), Some(true), None, Some("C"), array_flat!(f64, -0.125, 2.25)),
+case(array!(f64, [[2., 5.], [1., 4.]]), array_flat!(f64, 2., 9.), Some(false), None, Some("t"), array_flat!(f64, 1., 1.)),
+case(array!(f64, [[2., 5.], [1., 4.]]), array!(f64, [[2., 4.], [9., 18.]]), Some(true), None, Some("n"), array!(f64, [[1., 2.], [2., 4.]])),
+case(array!(f64, [[1., 0., 0.], [2., 3., 0.], [4., 5., 6.]]), array_flat!(f64, 1., 5., 15.), Some(true), None, None, array_flat!(f64, 1., 1., 1.)),
+case(array!(f64, [[0., 0.], [1., 4.]]), array_flat!(f64, 2., 9.), Some(true), None, None, Err(ArrayError::SingularMatrix)),
+case(array!(f64, [[2., 5.], [1., 4.]]), array_flat!(f64, 2., 9.), Some(true), None, Some("x"), Err(ArrayError::ParameterError { param: "`trans`", message: "must be one of {`n`, `t`, `c`}" })),
+case(array!(f64, [[2., 5.], [1., 4.]]), array_flat!(f64, 2., 9., 1.), Some(true), None, None, Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+case(array!(f64, [[2., 5., 1.], [1., 4., 1.]]), array_flat!(f64, 2., 9.), Some(true), None, None, Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+)] fn test_linalg_solve_triangular(array: Result<Array<f64>, ArrayError>, b: Result<Array<f64>, ArrayError>, lower: Option<bool>, unit_diagonal: Option<bool>, trans: Option<&str>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.solve_triangular(&b.unwrap(), lower, unit_diagonal, trans)))
+}
+
+#[rstest(
+array, l_and_u, b, expected,
+case(array!(f64, [[0., 1., 1.], [2., 2., 2.], [1., 1., 0.]]), (1, 1), array_flat!(f64, 4., 8., 8.), array_flat!(f64, 1., 2., 3.)),
+case(array!(f64, [[0., 2., 5.], [1., 4., 7.], [3., 6., 0.]]), (1, 1), array_flat!(f64, 5., 26., 33.), array_flat!(f64, 1., 2., 3.)),
+case(array!(f64, [[0., 2., 5.], [1., 4., 7.], [3., 6., 0.]]), (1, 1), array!(f64, [[5., 10.], [26., 52.], [33., 66.]]), array!(f64, [[1., 2.], [2., 4.], [3., 6.]])),
+case(array!(f64, [[1., 3., 6.], [2., 5., 0.], [4., 0., 0.]]), (2, 0), array_flat!(f64, 1., 5., 15.), array_flat!(f64, 1., 1., 1.)),
+case(array!(f64, [[0., 0., 1., 1.], [0., 1., 1., 1.], [4., 4., 4., 4.]]), (0, 2), array_flat!(f64, 6., 6., 5., 4.), array_flat!(f64, 1., 1., 1., 1.)),
+case(array!(f64, [[0., 1., 1.], [0., 0., 0.], [1., 1., 0.]]), (1, 1), array_flat!(f64, 1., 1., 1.), Err(ArrayError::SingularMatrix)),
+case(array!(f64, [[0., 1., 1.], [2., 2., 2.]]), (1, 1), array_flat!(f64, 1., 1., 1.), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array!(f64, [[0., 1., 1.], [2., 2., 2.], [1., 1., 0.]]), (1, 1), array_flat!(f64, 1., 1.), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+)] fn test_linalg_solve_banded(array: Result<Array<f64>, ArrayError>, l_and_u: (usize, usize), b: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.solve_banded(l_and_u, &b.unwrap())))
+}
+
+#[rstest(
+lower, diagonal, upper, b, expected,
+case(array_flat!(f64, 1., 1.), array_flat!(f64, 2., 2., 2.), array_flat!(f64, 1., 1.), array_flat!(f64, 4., 8., 8.), array_flat!(f64, 1., 2., 3.)),
+case(array_flat!(f64, -1., -1., -1.), array_flat!(f64, 2., 2., 2., 2.), array_flat!(f64, -1., -1., -1.), array_flat!(f64, 1., 0., 0., 1.), array_flat!(f64, 1., 1., 1., 1.)),
+case(array_flat!(f64, 1., 1.), array_flat!(f64, 2., 2., 2.), array_flat!(f64, 1., 1.), array!(f64, [[4., 1.], [8., 0.], [8., 1.]]), array!(f64, [[1., 1.], [2., -1.], [3., 1.]])),
+case(array_flat!(f64, 1., 1.), array_flat!(f64, 0., 2., 2.), array_flat!(f64, 1., 1.), array_flat!(f64, 4., 8., 8.), Err(ArrayError::SingularMatrix)),
+case(array_flat!(f64, 1.), array_flat!(f64, 2., 2., 2.), array_flat!(f64, 1., 1.), array_flat!(f64, 4., 8., 8.), Err(ArrayError::MustBeEqual { value1: "1".to_string(), value2: "2".to_string() })),
+case(array_flat!(f64, 1., 1.), array_flat!(f64, 2., 2., 2.), array_flat!(f64, 1., 1.), array_flat!(f64, 4., 8.), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+)] fn test_linalg_solve_tridiagonal(lower: Result<Array<f64>, ArrayError>, diagonal: Result<Array<f64>, ArrayError>, upper: Result<Array<f64>, ArrayError>, b: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(Array::solve_tridiagonal(&lower.unwrap(), &diagonal.unwrap(), &upper.unwrap(), &b.unwrap())))
+}
+
+fn hermitian() -> Array<Complex<f64>> {
+    Array::new(vec![Complex::new(2., 0.), Complex::new(0., 1.), Complex::new(0., -1.), Complex::new(2., 0.)], vec![2, 2]).unwrap()
+}
+
+fn complex_unsupported<T>() -> Result<T, ArrayError> {
+    Err(ArrayError::ParameterError { param: "`array`", message: "complex matrices are not supported" })
+}
+
+#[test]
+fn test_linalg_pinv_complex() {
+    assert_eq!(complex_unsupported(), hermitian().pinv(None));
+}
+
+#[test]
+fn test_linalg_matrix_rank_complex() {
+    assert_eq!(complex_unsupported(), hermitian().matrix_rank(None));
+}
+
+#[test]
+fn test_linalg_lstsq_complex() {
+    let b = Array::flat(vec![Complex::new(1., 0.), Complex::new(0., 1.)]).unwrap();
+    assert_eq!(complex_unsupported(), hermitian().lstsq(&b, None));
+    assert_eq!(complex_unsupported(), Array::<Complex<f64>>::identity(2).lstsq(&b, None));
+}
@@ -0,0 +1,56 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded;
+
+#[rstest(
+array, rcond, expected_shape,
+case(array!(f64, [[1., 1.], [1., 1.]]), None, vec![2, 1]),
+case(array!(f64, [[1., 2.], [3., 4.]]), None, vec![2, 0]),
+case(array!(f64, [[1., 0., 0.]]), None, vec![3, 2]),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]), None, vec![3, 1]),
+case(array!(f64, [[1., 0.], [0., 1e-6]]), Some(1e-3), vec![2, 1]),
+case(array!(f64, [[0., 0.], [0., 0.], [0., 0.]]), None, vec![2, 2]),
+)] fn test_linalg_null_space(array: Result<Array<f64>, ArrayError>, rcond: Option<f64>, expected_shape: Vec<usize>) {
+    let array = array.unwrap();
+    let result = array.null_space(rcond).unwrap();
+    assert_eq!(expected_shape, result.get_shape().unwrap());
+    if expected_shape[1] == 0 { return }
+    let cols = expected_shape[1];
+    assert_eq!(rounded(Array::identity(cols)), rounded(result.transpose(None).dot(&result)));
+    if rcond.is_none() {
+        assert_eq!(rounded(Array::zeros(vec![array.get_shape().unwrap()[0], cols])), rounded(array.dot(&result)));
+    }
+}
+
+#[rstest(
+array, rcond, expected,
+case(array!(f64, [[2., 0.], [0., 0.]]), None, array!(f64, [[1.], [0.]])),
+case(array!(f64, [[0., 3.], [2., 0.], [0., 0.]]), None, array!(f64, [[1., 0.], [0., 1.], [0., 0.]])),
+case(array!(f64, [[1., 0.], [0., 1e-6]]), Some(1e-3), array!(f64, [[1.], [0.]])),
+case(array!(f64, [[0., 0.], [0., 0.]]), None, Array::new(vec![], vec![2, 0])),
+case(array_flat!(f64, 1., 2.), None, Err(ArrayError::UnsupportedDimension { supported: vec![2] })),
+)] fn test_linalg_orth(array: Result<Array<f64>, ArrayError>, rcond: Option<f64>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.orth(rcond)))
+}
+
+#[rstest(
+array, other, expected,
+case(array!(f64, [[1.], [0.], [0.]]), array!(f64, [[1.], [1.], [0.]]), array_flat!(f64, 0.785398163)),
+case(array!(f64, [[1., 0.], [0., 1.], [0., 0.]]), array!(f64, [[1., 0.], [0., 0.], [0., 1.]]), array_flat!(f64, 1.570796327, 0.)),
+case(array!(f64, [[1., 1.], [1., -1.], [1., 1.], [1., -1.]]), array!(f64, [[1., 1.], [1., -1.], [-1., -1.], [-1., 1.]]), array_flat!(f64, 1.570796327, 1.570796327)),
+case(array!(f64, [[1., 0.], [0., 1.], [0., 0.]]), array!(f64, [[1.], [1.], [1.]]), array_flat!(f64, 0.615479709)),
+case(array!(f64, [[1.], [1.], [1.]]), array!(f64, [[1., 0.], [0., 1.], [0., 0.]]), array_flat!(f64, 0.615479709)),
+case(array!(f64, [[1.], [0.]]), array!(f64, [[1.], [0.]]), array_flat!(f64, 0.)),
+case(array!(f64, [[1.], [0.]]), array!(f64, [[0.], [0.]]), Array::new(vec![], vec![0])),
+case(array!(f64, [[1.], [0.]]), array!(f64, [[1.], [0.], [0.]]), Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+)] fn test_linalg_subspace_angles(array: Result<Array<f64>, ArrayError>, other: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.subspace_angles(&other.unwrap())))
+}
+
+#[rstest]
+fn test_linalg_subspace_angles_small() {
+    let array = Array::new(vec![1., 0.], vec![2, 1]).unwrap();
+    let other = Array::new(vec![1., 1e-10], vec![2, 1]).unwrap();
+    let angle = array.subspace_angles(&other).unwrap()[0];
+    assert!((angle - 1e-10).abs() < 1e-20);
+}
@@ -0,0 +1,62 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded;
+
+#[rstest(
+array, x, dx, axis, expected,
+case(array_flat!(f64, 1., 2., 3.), None, None, None, array_flat!(f64, 4.)),
+case(array_flat!(f64, 1., 2., 3.), None, Some(0.5), None, array_flat!(f64, 2.)),
+case(array_flat!(f64, 1., 2., 3.), Some(array_flat!(f64, 0., 1., 3.)), None, None, array_flat!(f64, 6.5)),
+case(array_flat!(f64, 1.), None, None, None, array_flat!(f64, 0.)),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), Some(array!(f64, [[0., 1., 2.], [0., 2., 4.]])), None, None, array_flat!(f64, 4., 20.)),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), Some(array_flat!(f64, 0., 2.)), None, Some(0), array_flat!(f64, 5., 7., 9.)),
+case(array_arange!(f64, 0., 23.).reshape(&[2, 3, 4]), None, None, Some(0), array!(f64, [[6., 7., 8., 9.], [10., 11., 12., 13.], [14., 15., 16., 17.]])),
+case(array_arange!(f64, 0., 23.).reshape(&[2, 3, 4]), None, None, Some(1), array!(f64, [[8., 10., 12., 14.], [32., 34., 36., 38.]])),
+case(array_arange!(f64, 0., 23.).reshape(&[2, 3, 4]), None, None, Some(-1), array!(f64, [[4.5, 16.5, 28.5], [40.5, 52.5, 64.5]])),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), Some(array_flat!(f64, 0., 1.)), None, None, Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), None, None, Some(2), Err(ArrayError::AxisOutOfBounds)),
+)] fn test_trapezoid(array: Result<Array<f64>, ArrayError>, x: Option<Result<Array<f64>, ArrayError>>, dx: Option<f64>, axis: Option<isize>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.trapezoid(x.map(Result::unwrap), dx, axis))
+}
+
+#[rstest(
+array, x, dx, axis, expected,
+case(array_flat!(f64, 0., 1., 4., 9., 16.), None, None, None, array_flat!(f64, 21.333333333)),
+case(array_flat!(f64, 0., 1., 4., 9.), None, None, None, array_flat!(f64, 9.)),
+case(array_flat!(f64, 0., 1., 9.), Some(array_flat!(f64, 0., 1., 3.)), None, None, array_flat!(f64, 9.)),
+case(array_flat!(f64, 0., 1., 9., 16.), Some(array_flat!(f64, 0., 1., 3., 4.)), None, None, array_flat!(f64, 21.333333333)),
+case(array_flat!(f64, 0., 0.25, 1.), None, Some(0.5), None, array_flat!(f64, 0.333333333)),
+case(array_flat!(f64, 1., 3.), None, None, None, array_flat!(f64, 2.)),
+case(array_arange!(f64, 0., 23.).reshape(&[2, 3, 4]), None, None, Some(1), array!(f64, [[8., 10., 12., 14.], [32., 34., 36., 38.]])),
+)] fn test_simpson(array: Result<Array<f64>, ArrayError>, x: Option<Result<Array<f64>, ArrayError>>, dx: Option<f64>, axis: Option<isize>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(array.simpson(x.map(Result::unwrap), dx, axis)))
+}
+
+#[rstest(
+array, x, axis, initial, expected,
+case(array_flat!(f64, 1., 2., 3.), None, None, None, array_flat!(f64, 1.5, 4.)),
+case(array_flat!(f64, 1., 2., 3.), None, None, Some(0.), array_flat!(f64, 0., 1.5, 4.)),
+case(array_flat!(f64, 1., 2., 3.), Some(array_flat!(f64, 0., 1., 3.)), None, None, array_flat!(f64, 1.5, 6.5)),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), None, None, None, array!(f64, [[1.5, 4.], [4.5, 10.]])),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), None, Some(0), None, array!(f64, [[2.5, 3.5, 4.5]])),
+case(array!(f64, [[1., 2., 3.], [4., 5., 6.]]), None, Some(0), Some(0.), array!(f64, [[0., 0., 0.], [2.5, 3.5, 4.5]])),
+)] fn test_cumulative_trapezoid(array: Result<Array<f64>, ArrayError>, x: Option<Result<Array<f64>, ArrayError>>, axis: Option<isize>, initial: Option<f64>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.cumulative_trapezoid(x.map(Result::unwrap), None, axis, initial))
+}
+
+#[rstest(
+array, spacing, axis, edge_order, expected,
+case(array_flat!(f64, 1., 2., 4., 7., 11., 16.), None, None, None, Ok(vec![array_flat!(f64, 1., 1.5, 2.5, 3.5, 4.5, 5.)])),
+case(array_flat!(f64, 1., 2., 4., 7., 11., 16.), Some(vec![array_flat!(f64, 2.)]), None, None, Ok(vec![array_flat!(f64, 0.5, 0.75, 1.25, 1.75, 2.25, 2.5)])),
+case(array_flat!(f64, 0., 1., 9.), Some(vec![array_flat!(f64, 0., 1., 3.)]), None, Some(2), Ok(vec![array_flat!(f64, 0., 2., 6.)])),
+case(array!(f64, [[1., 2., 6.], [3., 4., 5.]]), None, None, None, Ok(vec![array!(f64, [[2., 2., -1.], [2., 2., -1.]]), array!(f64, [[1., 2.5, 4.], [1., 1., 1.]])])),
+case(array!(f64, [[1., 2., 6.], [3., 4., 5.]]), None, Some(vec![-1]), None, Ok(vec![array!(f64, [[1., 2.5, 4.], [1., 1., 1.]])])),
+case(array_flat!(f64, 1., 2.), None, None, Some(2), Err(ArrayError::ParameterError { param: "`array`", message: "at least `edge_order + 1` elements are required along each axis" })),
+case(array_flat!(f64, 1., 2., 3.), None, None, Some(3), Err(ArrayError::ParameterError { param: "`edge_order`", message: "must be 1 or 2" })),
+case(array_flat!(f64, 1., 2., 3.), Some(vec![array_flat!(f64, 1.), array_flat!(f64, 1.)]), None, None, Err(ArrayError::ParameterError { param: "`spacing`", message: "must have one element or as many elements as axes" })),
+)] fn test_gradient(array: Result<Array<f64>, ArrayError>, spacing: Option<Vec<Result<Array<f64>, ArrayError>>>, axis: Option<Vec<isize>>, edge_order: Option<usize>, expected: Result<Vec<Result<Array<f64>, ArrayError>>, ArrayError>) {
+    let spacing = spacing.map(|spacing| spacing.into_iter().map(Result::unwrap).collect());
+    let result = array.gradient(spacing, axis, edge_order)
+        .map(|arrays| arrays.into_iter().map(|array| rounded(Ok(array))).collect::<Vec<_>>());
+    assert_eq!(expected, result)
+}
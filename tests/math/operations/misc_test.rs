@@ -23,6 +23,31 @@ case(array_arange![f64, -8., 6.], array_arange![f64, 1., 5.], Some(ConvolveMode:
     assert_eq!(expected, array.convolve(&other.unwrap(), mode))
 }
 
+fn convolve_direct<N: Numeric>(array: &[N], other: &[N]) -> Vec<N> {
+    let mut out = vec![N::zero(); array.len() + other.len() - 1];
+    for (i, a) in array.iter().enumerate() { for (j, b) in other.iter().enumerate() {
+        out[i + j] = N::from(out[i + j].to_f64() + a.to_f64() * b.to_f64());
+    } }
+    out
+}
+
+#[rstest(
+len, kernel_len,
+case(100, 64),
+case(150, 97),
+case(1000, 257),
+)] fn test_convolve_fft(len: usize, kernel_len: usize) {
+    // kernels of at least 64 elements are convolved via fft, integral results must be exact
+    let array = (0..len).map(|i| (i * 7919 % 1000) as i64 - 500).collect::<Vec<i64>>();
+    let other = (0..kernel_len).map(|i| (i * 104_729 % 997) as i64 - 498).collect::<Vec<i64>>();
+    assert_eq!(Array::flat(convolve_direct(&array, &other)), Array::flat(array.clone()).convolve(&Array::flat(other.clone()).unwrap(), None::<ConvolveMode>));
+
+    let array = array.iter().map(|&value| value as f64 / 7.).collect::<Vec<f64>>();
+    let other = other.iter().map(|&value| value as f64 / 3.).collect::<Vec<f64>>();
+    let result = Array::flat(array.clone()).convolve(&Array::flat(other.clone()).unwrap(), None::<ConvolveMode>).unwrap();
+    assert!(result.get_elements().unwrap().iter().zip(convolve_direct(&array, &other)).all(|(value, expected)| (value - expected).abs() < 1e-6));
+}
+
 #[rstest(
 array, a_min, a_max, expected,
 case(array![i32, 1, 2, 3, 4], None, None, array![i32, 1, 2, 3, 4]),
@@ -115,3 +140,18 @@ case(array![f64, 0., f64::INFINITY, 0., f64::INFINITY], array![f64, 0., f64::MAX
 )] fn test_nan_to_num(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
     assert_eq!(expected, array.nan_to_num())
 }
+
+#[rstest(
+array, other, mode, expected,
+case(array![f64, 1., 2., 3.], array![f64, 0., 1., 0.5], None, array![f64, 3.5]),
+case(array![f64, 1., 2., 3.], array![f64, 0., 1., 0.5], Some(ConvolveMode::Full), array![f64, 0.5, 2., 3.5, 3., 0.]),
+case(array![f64, 1., 2., 3.], array![f64, 0., 1., 0.5], Some(ConvolveMode::Valid), array![f64, 3.5]),
+case(array![f64, 1., 2., 3.], array![f64, 0., 1., 0.5], Some(ConvolveMode::Same), array![f64, 2., 3.5, 3.]),
+case(array![f64, 0., 1., 0.5], array![f64, 1., 2., 3.], Some(ConvolveMode::Full), array![f64, 0., 3., 3.5, 2., 0.5]),
+case(array![f64, 0., 1., 0.5], array![f64, 1., 2., 3.], Some(ConvolveMode::Same), array![f64, 3., 3.5, 2.]),
+case(array![f64, 1., 2., 3., 4.], array![f64, 1., 2.], Some(ConvolveMode::Valid), array![f64, 5., 8., 11.]),
+case(array![f64, 1., 2.], array![f64, 1., 2., 3., 4.], Some(ConvolveMode::Valid), array![f64, 11., 8., 5.]),
+case(array![f64, [1., 2.], [3., 4.]], array![f64, 1., 2.], Some(ConvolveMode::Full), array![f64, 2., 5., 8., 11., 4.]),
+)] fn test_correlate(array: Result<Array<f64>, ArrayError>, other: Result<Array<f64>, ArrayError>, mode: Option<ConvolveMode>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.correlate(&other.unwrap(), mode))
+}
@@ -1,4 +1,5 @@
 mod arithmetic_test;
+mod calculus_test;
 mod exp_log_test;
 mod extrema_test;
 mod floating_test;
@@ -7,5 +8,6 @@ mod misc_test;
 mod rational_test;
 mod rounding_test;
 mod special_test;
+mod statistics_test;
 mod sum_prod_diff_test;
 mod trigonometric_test;
@@ -18,3 +18,140 @@ case(array![f64, -1.5, -0.5, 0.5, 1.5], array![f64, -0.2122065907891938, std::f6
 )] fn test_sinc(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
     assert_eq!(expected, array.sinc());
 }
+
+#[rstest(
+array, expected,
+case(array![f64, 0.5, 1., 10.], array![f64, 1.772453850905516, 1., 362880.]),
+case(array![f64, [[1.5, 2.5], [3.5, 4.5]]], array![f64, [[0.886226925452758, 1.329340388179137], [3.3233509704478426, 11.631728396567448]]]),
+case(array![f64, -0.5, -1.5, 0.01], array![f64, -3.544907701811032, 2.363271801207355, 99.4325851191506]),
+case(array![f64, 0., -0., -2., 172., f64::NAN], array![f64, f64::INFINITY, f64::NEG_INFINITY, f64::NAN, f64::INFINITY, f64::NAN]),
+)] fn test_gamma(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.gamma()))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 0.5, 3., 100.], array![f64, 0.5723649429247001, 0.6931471805599453, 359.1342053695754]),
+case(array![f64, -0.5, -2.5, 1.5, 2.5], array![f64, 1.2655121234846454, -0.056243716497674054, -0.12078223763524522, 0.2846828704729192]),
+case(array![f64, 1., 2., 0., -1., f64::NEG_INFINITY, f64::NAN], array![f64, 0., 0., f64::INFINITY, f64::INFINITY, f64::INFINITY, f64::NAN]),
+)] fn test_lgamma(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.lgamma()))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 1., 2., 0.5, 10.], array![f64, -0.5772156649015329, 0.42278433509846713, -1.9635100260214235, 2.251752589066721]),
+case(array![f64, -0.5, -2.5, 1000., 0.01], array![f64, 0.03648997397857652, 1.103156640645243, 6.907255195648812, -100.56088545786868]),
+case(array![f64, 0., -3., f64::INFINITY, f64::NAN], array![f64, f64::NAN, f64::NAN, f64::INFINITY, f64::NAN]),
+)] fn test_digamma(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.digamma()))
+}
+
+#[rstest(
+array, other, expected,
+case(array![f64, 0.5, 30., -0.5], array![f64, 0.5, 2., 2.5], array![f64, std::f64::consts::PI, 0.001075268817204301, -4.71238898038469]),
+case(array![f64, 2.5, 1000., 2.], array![f64, 3.5, 0.5, 3.], array![f64, 0.03681553890925539, 0.056056918840616005, 0.08333333333333333]),
+case(array![f64, [[1., 2.], [3., 4.]]], array![f64, 1., 2.], array![f64, [[1., 0.16666666666666666], [0.3333333333333333, 0.05]]]),
+case(array![f64, 0., 1., f64::NAN], array![f64, 1.], array![f64, f64::INFINITY, 1., f64::NAN]),
+case(array![f64, 1., 2.], array![f64, 1., 2., 3.], Err(ArrayError::BroadcastShapeMismatch)),
+)] fn test_beta(array: Result<Array<f64>, ArrayError>, other: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.beta(&other.unwrap())))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 0., 0.5, 1., 2.], array![f64, 0., 0.5204998778130465, 0.8427007929497149, 0.9953222650189527]),
+case(array![f64, -3., 0.1, 1.3, -1.25, 4.], array![f64, -0.9999779095030014, 0.1124629160182849, 0.9340079449406524, -0.9229001282564583, 0.9999999845827421]),
+case(array![f64, f64::INFINITY, f64::NEG_INFINITY, f64::NAN], array![f64, 1., -1., f64::NAN]),
+)] fn test_erf(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.erf()))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 0., 0.5, 1., 2.], array![f64, 1., 0.4795001221869535, 0.15729920705028513, 0.004677734981047266]),
+case(array![f64, -3., 0.1, 1.3, -1.25, 4.], array![f64, 1.9999779095030015, 0.887537083981715, 0.06599205505934755, 1.9229001282564582, 1.541725790028002e-8]),
+case(array![f64, f64::INFINITY, f64::NEG_INFINITY, f64::NAN], array![f64, 0., 2., f64::NAN]),
+)] fn test_erfc(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.erfc()))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 5., 10., 26.], vec![1.537459794428035e-12, 2.088487583762545e-45, 5.663192408856143e-296]),
+)] fn test_erfc_tail(array: Result<Array<f64>, ArrayError>, expected: Vec<f64>) {
+    let result = array.erfc().get_elements().unwrap();
+    result.iter().zip(expected).for_each(|(value, expected)| assert!(((value - expected) / expected).abs() < 1e-13));
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 0., 0.25, -0.5], array![f64, 0., 0.2253120550121781, -0.4769362762044699]),
+case(array![f64, 0.9, 0.999999, 0.01, -0.7], array![f64, 1.1630871536766743, 3.458910737275499, 0.008862501280950598, -0.7328690779592167]),
+case(array![f64, 1., -1., 1.5, f64::NAN], array![f64, f64::INFINITY, f64::NEG_INFINITY, f64::NAN, f64::NAN]),
+)] fn test_erfinv(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.erfinv()))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 0., 0.5, 1., 5., -5.], array![f64, 1., 0.9384698072408129, 0.7651976865579666, -0.1775967713143383, -0.1775967713143383]),
+case(array![f64, 12.5, 30., 100.], array![f64, 0.1468840547004211, -0.08636798358104021, 0.019985850304223122]),
+case(array![f64, f64::INFINITY, f64::NAN], array![f64, 0., f64::NAN]),
+)] fn test_j0(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.j0()))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 0., 0.5, 1., 5., -5.], array![f64, 0., 0.2422684576748739, 0.4400505857449335, -0.32757913759146523, 0.32757913759146523]),
+case(array![f64, 12.5, 30., 100.], array![f64, -0.16548380461475973, -0.11875106261662294, -0.07714535201411216]),
+case(array![f64, f64::INFINITY, f64::NAN], array![f64, 0., f64::NAN]),
+)] fn test_j1(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.j1()))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 0.5, 1., 5.], array![f64, -0.44451873350670656, 0.08825696421567696, -0.30851762524903376]),
+case(array![f64, 12.5, 30., 100.], array![f64, -0.1712143068446693, -0.11729573168666403, -0.07724431336508315]),
+case(array![f64, 0., -1., f64::INFINITY, f64::NAN], array![f64, f64::NEG_INFINITY, f64::NAN, 0., f64::NAN]),
+)] fn test_y0(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.y0()))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 0.5, 1., 5.], array![f64, -1.471472392670243, -0.7812128213002887, 0.14786314339122683]),
+case(array![f64, 12.5, 30., 100.], array![f64, -0.1538382565375012, 0.08442557066174723, -0.020372312002759792]),
+case(array![f64, 0., -1., f64::INFINITY, f64::NAN], array![f64, f64::NEG_INFINITY, f64::NAN, 0., f64::NAN]),
+)] fn test_y1(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.y1()))
+}
+
+#[rstest(
+array, order, expected,
+case(array![f64, 0., 1., 2., 10.], array![f64, 0.], array![f64, 1., 1.2660658777520084, 2.2795853023360673, 2815.7166284662544]),
+case(array![f64, 1., 1., 1., 1., 5., 0.1], array![f64, 0.5, 2., -0.5, -2., 2.5, 3.], array![f64, 0.9376748882454876, 0.13574766976703828, 1.2312002145929675, 0.13574766976703828, 13.766882138682583, 2.0846357422327155e-5]),
+case(array![f64, -1., -1., -1., 0., 0., f64::INFINITY, f64::NAN], array![f64, 1., 2., 0.5, 1., -0.5, 1., 1.], array![f64, -0.565159103992485, 0.13574766976703828, f64::NAN, 0., f64::INFINITY, f64::INFINITY, f64::NAN]),
+)] fn test_iv(array: Result<Array<f64>, ArrayError>, order: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.iv(&order.unwrap())))
+}
+
+#[rstest(
+array, order, expected,
+case(array![f64, 0.5, 1., 2., 10.], array![f64, 0.], array![f64, 0.9244190712276659, 0.42102443824070834, 0.11389387274953344, 1.778006231616765e-5]),
+case(array![f64, 1., 1., 1., 1., 5., 0.1], array![f64, 0.5, 1., -1., 3.5, 2.5, 3.], array![f64, 0.46106850444789454, 0.6019072301972346, 0.6019072301972346, 17.059534664572098, 0.006495775004385758, 7990.012430465435]),
+case(array![f64, 0., -1., f64::INFINITY, f64::NAN], array![f64, 1.], array![f64, f64::INFINITY, f64::NAN, 0., f64::NAN]),
+)] fn test_kv(array: Result<Array<f64>, ArrayError>, order: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.kv(&order.unwrap())))
+}
+
+#[rstest(
+array, expected,
+case(array![f64, 2., 3., 4., 10.], array![f64, 1.6449340668482264, 1.2020569031595942, 1.0823232337111381, 1.000994575127818]),
+case(array![f64, 0.5, 1.5, 0., -1., -3., -7.5], array![f64, -1.4603545088095868, 2.612375348685488, -0.5, -0.08333333333333333, 0.008333333333333333, 0.00326903957260022]),
+case(array![f64, 1., -2., f64::INFINITY, f64::NEG_INFINITY, f64::NAN], array![f64, f64::INFINITY, 0., 1., f64::NAN, f64::NAN]),
+)] fn test_zeta(array: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(format!("{expected:.10?}"), format!("{:.10?}", array.zeta()))
+}
@@ -0,0 +1,38 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded;
+
+#[rstest(
+array, rowvar, bias, ddof, fweights, aweights, expected,
+case(array!(f64, [[0., 1., 2.], [2., 1., 0.]]), None, None, None, None, None, array!(f64, [[1., -1.], [-1., 1.]])),
+case(array!(f64, [[0., 2.], [1., 1.], [2., 0.]]), Some(false), None, None, None, None, array!(f64, [[1., -1.], [-1., 1.]])),
+case(array!(f64, [[0., 1., 2.], [2., 1., 0.]]), None, Some(true), None, None, None, array!(f64, [[0.6666666666666666, -0.6666666666666666], [-0.6666666666666666, 0.6666666666666666]])),
+case(array!(f64, [[0., 1., 2.], [2., 1., 0.]]), None, Some(true), Some(1), None, None, array!(f64, [[1., -1.], [-1., 1.]])),
+case(array![f64, 1., 2., 3., 4.], None, None, None, None, None, array![f64, 1.6666666666666667]),
+case(array!(f64, [[0., 1., 2.], [2., 1., 0.]]), None, None, None, Some(array![usize, 1, 2, 1].unwrap()), None, array!(f64, [[0.6666666666666666, -0.6666666666666666], [-0.6666666666666666, 0.6666666666666666]])),
+case(array![f64, 0., 1., 2.], None, None, None, None, Some(array![f64, 1., 1., 2.].unwrap()), array![f64, 1.1]),
+case(array![f64, 0., 1., 2.], None, None, None, Some(array![usize, 1, 2].unwrap()), None, Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array![f64, 0., 1., 2.], None, None, None, None, Some(array![f64, 1., -1., 2.].unwrap()), Err(ArrayError::ParameterError { param: "`aweights`", message: "cannot be negative" })),
+case(array!(f64, [[[0., 1.], [2., 3.]]]), None, None, None, None, None, Err(ArrayError::UnsupportedDimension { supported: vec![1, 2] })),
+)] fn test_cov(array: Result<Array<f64>, ArrayError>, rowvar: Option<bool>, bias: Option<bool>, ddof: Option<usize>, fweights: Option<Array<usize>>, aweights: Option<Array<f64>>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.cov(rowvar, bias, ddof, fweights, aweights))
+}
+
+#[rstest(
+array, expected_cov, expected_corrcoef,
+case(array!(i32, [[1, 2, 4], [2, 4, 5]]), array!(f64, [[2.333333333, 2.166666667], [2.166666667, 2.333333333]]), array!(f64, [[1., 0.928571429], [0.928571429, 1.]])),
+case(array![i32, 1, 2], array![f64, 0.5], array![f64, 1.]),
+)] fn test_cov_corrcoef_integer(array: Result<Array<i32>, ArrayError>, expected_cov: Result<Array<f64>, ArrayError>, expected_corrcoef: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected_cov, rounded(array.cov(None, None, None, None, None)));
+    assert_eq!(expected_corrcoef, rounded(array.corrcoef(None)));
+}
+
+#[rstest(
+array, rowvar, expected,
+case(array!(f64, [[1., 2., 3.], [3., 2., 1.]]), None, array!(f64, [[1., -1.], [-1., 1.]])),
+case(array!(f64, [[1., 2., 3., 4.], [1., 3., 2., 4.]]), None, array!(f64, [[1., 0.7999999999999999], [0.7999999999999999, 1.]])),
+case(array!(f64, [[1., 1.], [2., 3.], [3., 2.], [4., 4.]]), Some(false), array!(f64, [[1., 0.7999999999999999], [0.7999999999999999, 1.]])),
+case(array![f64, 1., 2., 3., 4.], None, array![f64, 1.]),
+)] fn test_corrcoef(array: Result<Array<f64>, ArrayError>, rowvar: Option<bool>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.corrcoef(rowvar))
+}
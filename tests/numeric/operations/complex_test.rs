@@ -0,0 +1,163 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded;
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+fn complex_flat(values: &[(f64, f64)]) -> Result<Array<Complex<f64>>, ArrayError> {
+    Array::flat(values.iter().map(|&(re, im)| c(re, im)).collect())
+}
+
+#[rstest(
+value, expected,
+case("1.5-2j", Ok(c(1.5, -2.))),
+case("(3+4j)", Ok(c(3., 4.))),
+case("-2e-3+1e+2j", Ok(c(-0.002, 100.))),
+case("-j", Ok(c(0., -1.))),
+case("2.5j", Ok(c(0., 2.5))),
+case("7", Ok(c(7., 0.))),
+case("1+2k", Err(ArrayError::ParameterError { param: "`value`", message: "cannot be parsed as complex number" })),
+)] fn test_complex_parse(value: &str, expected: Result<Complex<f64>, ArrayError>) {
+    assert_eq!(expected, value.parse::<Complex<f64>>())
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(1., 2.), (-3., 4.)]), array_flat!(f64, 1., -3.)),
+case(array_flat!(i32, 1, -2), array_flat!(f64, 1., -2.)),
+)] fn test_complex_real<N: Numeric>(array: Result<Array<N>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.real())
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(1., 2.), (-3., -4.)]), array_flat!(f64, 2., -4.)),
+case(array_flat!(i32, 1, -2), array_flat!(f64, 0., 0.)),
+)] fn test_complex_imag<N: Numeric>(array: Result<Array<N>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.imag())
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(1., 2.), (-3., -4.)]), complex_flat(&[(1., -2.), (-3., 4.)])),
+)] fn test_complex_conj(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.conj())
+}
+
+#[rstest(
+array, deg, expected,
+case(complex_flat(&[(1., 0.), (0., 1.), (-1., 0.), (0., -2.)]), None, array_flat!(f64, 0., std::f64::consts::FRAC_PI_2, std::f64::consts::PI, -std::f64::consts::FRAC_PI_2)),
+case(complex_flat(&[(1., 1.), (-1., -1.)]), Some(true), array_flat!(f64, 45., -135.)),
+)] fn test_complex_angle(array: Result<Array<Complex<f64>>, ArrayError>, deg: Option<bool>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.angle(deg))
+}
+
+#[rstest(
+array, other, expected,
+case(complex_flat(&[(1., 2.)]), complex_flat(&[(3., -1.)]), complex_flat(&[(5., 5.)])),
+case(complex_flat(&[(1., 2.), (0., 1.)]), complex_flat(&[(0., 1.)]), complex_flat(&[(-2., 1.), (-1., 0.)])),
+)] fn test_complex_multiply(array: Result<Array<Complex<f64>>, ArrayError>, other: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.multiply(&other.unwrap()))
+}
+
+#[rstest(
+array, other, expected,
+case(complex_flat(&[(5., 5.)]), complex_flat(&[(3., -1.)]), complex_flat(&[(1., 2.)])),
+case(complex_flat(&[(1., 0.)]), complex_flat(&[(0., 0.)]), Err(ArrayError::ParameterError { param: "value", message: "cannot contain `0`" })),
+)] fn test_complex_divide(array: Result<Array<Complex<f64>>, ArrayError>, other: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.divide(&other.unwrap())))
+}
+
+#[rstest(
+array, other, expected,
+case(complex_flat(&[(0., 1.), (1., 1.)]), complex_flat(&[(2., 0.)]), complex_flat(&[(-1., 0.), (0., 2.)])),
+case(complex_flat(&[(0., 1.)]), complex_flat(&[(0., 1.)]), complex_flat(&[(0.207879576, 0.)])),
+)] fn test_complex_float_power(array: Result<Array<Complex<f64>>, ArrayError>, other: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.float_power(&other.unwrap())))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(3., -4.), (0., 2.)]), complex_flat(&[(5., 0.), (2., 0.)])),
+)] fn test_complex_absolute(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, array.absolute())
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(-4., 0.), (3., 4.), (-3., -4.)]), complex_flat(&[(0., 2.), (2., 1.), (1., -2.)])),
+)] fn test_complex_sqrt(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.sqrt()))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(1., 2.), (0., std::f64::consts::PI)]), complex_flat(&[(-1.131204384, 2.471726672), (-1., 0.)])),
+)] fn test_complex_exp(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.exp()))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(1., 2.), (-1., 0.)]), complex_flat(&[(0.804718956, 1.107148718), (0., std::f64::consts::PI)])),
+)] fn test_complex_log(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(rounded(expected), rounded(array.log()))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(100., 0.), (0., 10.)]), complex_flat(&[(2., 0.), (1., 0.682188177)])),
+)] fn test_complex_log10(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.log10()))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(1., 2.), (0., 1.)]), complex_flat(&[(3.165778513, 1.959601041), (0., 1.175201194)])),
+)] fn test_complex_sin(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.sin()))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(1., 2.)]), complex_flat(&[(2.032723007, -3.051897799)])),
+)] fn test_complex_cos(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.cos()))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(0.5, 0.), (2., 0.)]), complex_flat(&[(0.523598776, 0.), (1.570796327, -1.316957897)])),
+)] fn test_complex_asin(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.asin()))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(0., 2.)]), complex_flat(&[(1.570796327, 0.549306144)])),
+)] fn test_complex_atan(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.atan()))
+}
+
+#[rstest(
+array, expected,
+case(complex_flat(&[(0., std::f64::consts::PI), (1., 1.)]), complex_flat(&[(-1., 0.), (0.833730025, 0.988897706)])),
+)] fn test_complex_cosh(array: Result<Array<Complex<f64>>, ArrayError>, expected: Result<Array<Complex<f64>>, ArrayError>) {
+    assert_eq!(expected, rounded(array.cosh()))
+}
+
+#[rstest(
+array, other,
+case(complex_flat(&[(1., 2.)]), complex_flat(&[(3., 4.)])),
+)] fn test_complex_bitwise(array: Result<Array<Complex<f64>>, ArrayError>, other: Result<Array<Complex<f64>>, ArrayError>) {
+    let expected = Err(ArrayError::ParameterError { param: "`array`", message: "bitwise operations are not supported for complex numbers" });
+    let other = other.unwrap();
+    assert_eq!(expected, array.bitwise_and(&other));
+    assert_eq!(expected, array.bitwise_or(&other));
+    assert_eq!(expected, array.bitwise_xor(&other));
+    assert_eq!(expected, array.bitwise_not());
+    assert_eq!(expected, array.left_shift(&other));
+    assert_eq!(expected, array.right_shift(&other));
+}
@@ -1,5 +1,6 @@
 mod binary_test;
 mod binary_bits_test;
+mod complex_test;
 mod create_test;
 mod create_from_test;
 mod ops_test;
@@ -0,0 +1,78 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded;
+
+#[rstest(
+array, x, expected,
+case(array_flat!(f64, 3., 0., 1.), array_flat!(f64, 5.), array_flat!(f64, 76.)),
+case(array_flat!(f64, 3., 0., 1.), array!(f64, [[0., 1.], [2., 3.]]), array!(f64, [[1., 4.], [13., 28.]])),
+case(array_flat!(f64, 1, -2, 1), array_flat!(f64, 0, 1, 2, 3), array_flat!(f64, 1, 0, 1, 4)),
+case(array_flat!(f64, 2.), array_flat!(f64, 1., 2.), array_flat!(f64, 2., 2.)),
+case(array!(f64, [[1., 2.]]), array_flat!(f64, 1.), Err(ArrayError::UnsupportedDimension { supported: vec![1] })),
+case(Array::<f64>::empty(), array_flat!(f64, 1.), Err(ArrayError::MustBeAtLeast { value1: "0".to_string(), value2: "1".to_string() })),
+)] fn test_polyval(array: Result<Array<f64>, ArrayError>, x: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.polyval(&x.unwrap()))
+}
+
+#[rstest(
+array, other, expected_add, expected_sub, expected_mul,
+case(array_flat!(i32, 1, 2), array_flat!(i32, 9, 5, 4), array_flat!(i32, 9, 6, 6), array_flat!(i32, -9, -4, -2), array_flat!(i32, 9, 23, 14, 8)),
+case(array_flat!(i32, 1, 2, 3), array_flat!(i32, 1), array_flat!(i32, 1, 2, 4), array_flat!(i32, 1, 2, 2), array_flat!(i32, 1, 2, 3)),
+case(array_flat!(i32, 2, 10, -2), array_flat!(i32, 1, 5, 2), array_flat!(i32, 3, 15, 0), array_flat!(i32, 1, 5, -4), array_flat!(i32, 2, 20, 52, 10, -4)),
+)] fn test_polyadd_polysub_polymul(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, expected_add: Result<Array<i32>, ArrayError>, expected_sub: Result<Array<i32>, ArrayError>, expected_mul: Result<Array<i32>, ArrayError>) {
+    let other = other.unwrap();
+    assert_eq!(expected_add, array.polyadd(&other));
+    assert_eq!(expected_sub, array.polysub(&other));
+    assert_eq!(expected_mul, array.polymul(&other));
+}
+
+#[rstest(
+array, m, expected,
+case(array_flat!(f64, 1., 1., 1., 1.), None, array_flat!(f64, 3., 2., 1.)),
+case(array_flat!(f64, 1., 1., 1., 1.), Some(2), array_flat!(f64, 6., 2.)),
+case(array_flat!(f64, 1., 1., 1., 1.), Some(4), array_flat!(f64, 0.)),
+case(array_flat!(f64, 1., 1., 1., 1.), Some(0), array_flat!(f64, 1., 1., 1., 1.)),
+case(array_flat!(f64, 5.), None, array_flat!(f64, 0.)),
+)] fn test_polyder(array: Result<Array<f64>, ArrayError>, m: Option<usize>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.polyder(m))
+}
+
+#[rstest(
+array, m, k, expected,
+case(array_flat!(f64, 3., 2., 1.), None, None, array_flat!(f64, 1., 1., 1., 0.)),
+case(array_flat!(f64, 3., 2., 1.), None, Some(vec![5.]), array_flat!(f64, 1., 1., 1., 5.)),
+case(array_flat!(f64, 6., 2.), Some(2), Some(vec![1., 2.]), array_flat!(f64, 1., 1., 1., 2.)),
+case(array_flat!(f64, 6., 2.), Some(2), Some(vec![1.]), Err(ArrayError::MustBeEqual { value1: "1".to_string(), value2: "2".to_string() })),
+)] fn test_polyint(array: Result<Array<f64>, ArrayError>, m: Option<usize>, k: Option<Vec<f64>>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, array.polyint(m, k))
+}
+
+#[rstest(
+array, expected,
+case(array_flat!(f64, 1., -3., 2.), Ok(vec![(1., 0.), (2., 0.)])),
+case(array_flat!(f64, 0., 2., -3.), Ok(vec![(1.5, 0.)])),
+case(array_flat!(f64, 2., -3., 0., 0.), Ok(vec![(0., 0.), (0., 0.), (1.5, 0.)])),
+case(array_flat!(f64, 1., 0., -5., 0., 4.), Ok(vec![(-2., 0.), (-1., 0.), (1., 0.), (2., 0.)])),
+case(array_flat!(f64, 1., -6., 11., -6.), Ok(vec![(1., 0.), (2., 0.), (3., 0.)])),
+case(array_flat!(f64, 1., 0., 1.), Ok(vec![(0., -1.), (0., 1.)])),
+case(array_flat!(f64, 1., -3., 4., -2.), Ok(vec![(1., -1.), (1., 0.), (1., 1.)])),
+case(array_flat!(f64, 1., 0., -1., 0., 1.), Ok(vec![(-0.866025404, -0.5), (-0.866025404, 0.5), (0.866025404, -0.5), (0.866025404, 0.5)])),
+case(array_flat!(f64, 3.), Ok(vec![])),
+)] fn test_roots(array: Result<Array<f64>, ArrayError>, expected: Result<Vec<(f64, f64)>, ArrayError>) {
+    let result = rounded(array.roots()).map(|roots| roots.into_iter().map(|c| (c.re, c.im)).collect::<Vec<_>>())
+        .map(|mut roots| { roots.sort_by(|a, b| a.partial_cmp(b).unwrap()); roots });
+    assert_eq!(expected, result)
+}
+
+#[rstest(
+x, y, deg, w, expected,
+case(array_flat!(f64, 0., 1., 2., 3.), array_flat!(f64, 1., 2., 5., 10.), 2, None, array_flat!(f64, 1., 0., 1.)),
+case(array_flat!(f64, 0., 1., 2., 3.), array_flat!(f64, 1., 3., 5., 7.), 1, None, array_flat!(f64, 2., 1.)),
+case(array_flat!(f64, 0., 1., 2., 3.), array_flat!(f64, 0., 0., 0., 4.), 1, Some(array_flat!(f64, 1., 1., 1., 0.).unwrap()), array_flat!(f64, 0., 0.)),
+case(array_flat!(f64, 0., 1.), array_flat!(f64, 1., 3.), 2, None, Err(ArrayError::ParameterError { param: "`deg`", message: "must be lower than number of sample points" })),
+case(array_flat!(f64, 0., 1., 2.), array_flat!(f64, 1., 3.), 1, None, Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array_flat!(f64, 1., 1., 1.), array_flat!(f64, 1., 2., 3.), 1, None, Err(ArrayError::SingularMatrix)),
+)] fn test_polyfit(x: Result<Array<f64>, ArrayError>, y: Result<Array<f64>, ArrayError>, deg: usize, w: Option<Array<f64>>, expected: Result<Array<f64>, ArrayError>) {
+    let result = rounded(x.polyfit(&y.unwrap(), deg, w));
+    assert_eq!(expected, result)
+}
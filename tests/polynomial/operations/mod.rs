@@ -0,0 +1,3 @@
+mod functions_test;
+mod ops_test;
+mod series_test;
@@ -0,0 +1,40 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::rounded_to;
+
+fn poly(coef: Vec<f64>, kind: &str) -> Polynomial<f64> {
+    Polynomial::new(&Array::flat(coef).unwrap(), Some(kind), None, None).unwrap()
+}
+
+#[rstest(
+a, b, expected_add, expected_sub, expected_mul,
+case(poly(vec![1., 2.], "power"), poly(vec![3., 0., 1.], "power"), vec![4., 2., 1.], vec![-2., 2., -1.], vec![3., 6., 1., 2.]),
+case(poly(vec![0., 1.], "chebyshev"), poly(vec![0., 1.], "chebyshev"), vec![0., 2.], vec![0., 0.], vec![0.5, 0., 0.5]),
+case(poly(vec![0., 1.], "legendre"), poly(vec![1., 1.], "legendre"), vec![1., 2.], vec![-1., 0.], vec![1. / 3., 1., 2. / 3.]),
+case(poly(vec![0., 1.], "hermite"), poly(vec![0., 1.], "hermite"), vec![0., 2.], vec![0., 0.], vec![2., 0., 1.]),
+)] fn test_polynomial_ops(a: Polynomial<f64>, b: Polynomial<f64>, expected_add: Vec<f64>, expected_sub: Vec<f64>, expected_mul: Vec<f64>) {
+    assert_eq!(Array::flat(expected_add), (a.clone() + b.clone()).map(|p| p.get_coef()));
+    assert_eq!(Array::flat(expected_sub), (a.clone() - b.clone()).map(|p| p.get_coef()));
+    let result = rounded_to((a * b).map(|p| p.get_coef()), 12);
+    assert_eq!(rounded_to(Array::flat(expected_mul), 12), result);
+}
+
+#[rstest(
+a, b,
+case(poly(vec![1.], "power"), poly(vec![1.], "chebyshev")),
+case(poly(vec![1.], "power"), Polynomial::new(&array_flat!(f64, 1.).unwrap(), None::<PolynomialKind>, Some([0., 1.]), None).unwrap()),
+)] fn test_polynomial_ops_incompatible(a: Polynomial<f64>, b: Polynomial<f64>) {
+    let expected = Err(ArrayError::ParameterError { param: "`other`", message: "polynomials must have the same kind, domain and window" });
+    assert_eq!(expected, a.clone() + b.clone());
+    assert_eq!(expected, a.clone() - b.clone());
+    assert_eq!(expected, a * b);
+}
+
+#[rstest(
+a, scalar, expected_mul, expected_neg,
+case(poly(vec![1., -2.], "power"), 3., vec![3., -6.], vec![-1., 2.]),
+case(poly(vec![0.5, 0., 1.], "legendre"), -2., vec![-1., 0., -2.], vec![-0.5, 0., -1.]),
+)] fn test_polynomial_scalar_ops(a: Polynomial<f64>, scalar: f64, expected_mul: Vec<f64>, expected_neg: Vec<f64>) {
+    assert_eq!(Array::flat(expected_mul), (a.clone() * scalar).map(|p| p.get_coef()));
+    assert_eq!(Array::flat(expected_neg), (-a).map(|p| p.get_coef()));
+}
@@ -0,0 +1,107 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+use crate::common::{rounded, rounded_to};
+
+fn poly(coef: Vec<f64>, kind: &str, domain: Option<[f64; 2]>) -> Polynomial<f64> {
+    Polynomial::new(&Array::flat(coef).unwrap(), Some(kind), domain, None).unwrap()
+}
+
+#[rstest(
+coef, kind, domain, window, expected,
+case(vec![1., 2.], Some("power"), None, None, Ok((PolynomialKind::Power, [-1., 1.], [-1., 1.]))),
+case(vec![1., 2.], Some("chebyshev"), Some([0., 4.]), Some([0., 1.]), Ok((PolynomialKind::Chebyshev, [0., 4.], [0., 1.]))),
+case(vec![1., 2.], Some("laguerre"), None, None, Err(ArrayError::ParameterError { param: "`kind`", message: "must be one of {`power`, `chebyshev`, `legendre`, `hermite`}" })),
+case(vec![1., 2.], None, Some([1., 1.]), None, Err(ArrayError::ParameterError { param: "`domain`, `window`", message: "bounds must be distinct" })),
+case(vec![1., 2.], None, None, Some([0., f64::NAN]), Err(ArrayError::ParameterError { param: "`domain`, `window`", message: "bounds must be finite" })),
+case(vec![], None, None, None, Err(ArrayError::MustBeAtLeast { value1: "0".to_string(), value2: "1".to_string() })),
+)] fn test_new(coef: Vec<f64>, kind: Option<&str>, domain: Option<[f64; 2]>, window: Option<[f64; 2]>, expected: Result<(PolynomialKind, [f64; 2], [f64; 2]), ArrayError>) {
+    let result = Polynomial::new(&Array::flat(coef).unwrap(), kind, domain, window)
+        .map(|p| (p.get_kind(), p.get_domain(), p.get_window()));
+    assert_eq!(expected, result)
+}
+
+#[rstest(
+polynomial, x, expected,
+case(poly(vec![1., 2., 3.], "power", None), array_flat!(f64, -1., 0., 2.), array_flat!(f64, 2., 1., 17.)),
+case(poly(vec![1., 2., 3.], "power", Some([0., 2.])), array_flat!(f64, 0., 1., 2.), array_flat!(f64, 2., 1., 6.)),
+case(poly(vec![0., 0., 0., 1.], "chebyshev", None), array_flat!(f64, -1., 0., 0.5, 1.), array_flat!(f64, -1., 0., -1., 1.)),
+case(poly(vec![0., 0., 1.], "legendre", None), array_flat!(f64, -1., 0., 0.5, 1.), array_flat!(f64, 1., -0.5, -0.125, 1.)),
+case(poly(vec![0., 0., 0., 1.], "hermite", None), array_flat!(f64, -1., 0., 0.5, 1.), array_flat!(f64, 4., 0., -5., -4.)),
+case(poly(vec![1., 1., 1.], "hermite", Some([0., 2.])), array!(f64, [[1.], [2.]]), array!(f64, [[-1.], [5.]])),
+)] fn test_eval(polynomial: Polynomial<f64>, x: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, polynomial.eval(&x.unwrap()))
+}
+
+#[rstest(
+polynomial, m, expected,
+case(poly(vec![1., 2., 3.], "power", None), None, array_flat!(f64, 2., 6.)),
+case(poly(vec![1., 2., 3.], "power", Some([0., 4.])), None, array_flat!(f64, 1., 3.)),
+case(poly(vec![1., 2., 3.], "power", None), Some(3), array_flat!(f64, 0.)),
+case(poly(vec![0., 0., 0., 1.], "chebyshev", None), None, array_flat!(f64, 3., 0., 6.)),
+case(poly(vec![0., 0., 0., 1.], "legendre", None), None, array_flat!(f64, 1., 0., 5.)),
+case(poly(vec![0., 0., 0., 1.], "hermite", None), Some(2), array_flat!(f64, 0., 24.)),
+)] fn test_deriv(polynomial: Polynomial<f64>, m: Option<usize>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(polynomial.deriv(m).map(|p| p.get_coef())))
+}
+
+#[rstest(
+polynomial, m, k, expected,
+case(poly(vec![2., 6.], "power", None), None, None, array_flat!(f64, 0., 2., 3.)),
+case(poly(vec![2., 6.], "power", None), None, Some(vec![1.]), array_flat!(f64, 1., 2., 3.)),
+case(poly(vec![6.], "power", None), Some(2), Some(vec![0., 1.]), array_flat!(f64, 1., 0., 3.)),
+case(poly(vec![1.], "power", Some([0., 2.])), None, None, array_flat!(f64, 1., 1.)),
+case(poly(vec![0., 1.], "chebyshev", None), None, None, array_flat!(f64, 0.25, 0., 0.25)),
+case(poly(vec![1.], "legendre", None), None, Some(vec![1., 2.]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "1".to_string() })),
+)] fn test_integ(polynomial: Polynomial<f64>, m: Option<usize>, k: Option<Vec<f64>>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(polynomial.integ(m, k).map(|p| p.get_coef())))
+}
+
+#[rstest(
+polynomial,
+case(poly(vec![0.5, -1., 2., 0.25, 3.], "chebyshev", Some([-1., 3.]))),
+case(poly(vec![0.5, -1., 2., 0.25, 3.], "legendre", Some([-1., 3.]))),
+case(poly(vec![0.5, -1., 2., 0.25, 3.], "hermite", Some([-1., 3.]))),
+)] fn test_deriv_integ_roundtrip(polynomial: Polynomial<f64>) {
+    let result = polynomial.integ(Some(2), None).deriv(Some(2)).map(|p| p.get_coef());
+    assert_eq!(rounded(Ok(polynomial.get_coef())), rounded(result))
+}
+
+#[rstest(
+polynomial, expected,
+case(poly(vec![2., -3., 1.], "power", None), vec![(1., 0.), (2., 0.)]),
+case(poly(vec![2., -3., 1.], "power", Some([0., 2.])), vec![(2., 0.), (3., 0.)]),
+case(poly(vec![0., 0., 1.], "legendre", None), vec![(-0.57735026919, 0.), (0.57735026919, 0.)]),
+case(poly(vec![0., 0., 0., 1.], "chebyshev", None), vec![(-0.866025403784, 0.), (0., 0.), (0.866025403784, 0.)]),
+case(poly(vec![1., 0., 1.], "power", None), vec![(0., -1.), (0., 1.)]),
+case(poly(vec![1., 0., 1.], "power", Some([0., 2.])), vec![(1., -1.), (1., 1.)]),
+)] fn test_roots(polynomial: Polynomial<f64>, expected: Vec<(f64, f64)>) {
+    let result = rounded_to(polynomial.roots(), 12).map(|roots| roots.into_iter().map(|c| (c.re, c.im)).collect::<Vec<_>>())
+        .map(|mut roots| { roots.sort_by(|a, b| a.partial_cmp(b).unwrap()); roots });
+    assert_eq!(Ok(expected.into_iter().map(|(re, im)| (rounded_to(re, 12), rounded_to(im, 12))).collect()), result)
+}
+
+#[rstest(
+polynomial, kind, domain, window, expected,
+case(poly(vec![0., 0., 1.], "chebyshev", None), Some("power"), None, None, array_flat!(f64, -1., 0., 2.)),
+case(poly(vec![-1., 0., 2.], "power", None), Some("chebyshev"), None, None, array_flat!(f64, 0., 0., 1.)),
+case(poly(vec![0., 0., 1.], "hermite", None), Some("legendre"), None, None, array_flat!(f64, -0.666666667, 0., 2.666666667)),
+case(poly(vec![1., 2., 3.], "power", None), None, Some([0., 2.]), None, array_flat!(f64, 6., 8., 3.)),
+case(poly(vec![1., 2., 3.], "power", Some([0., 2.])), None, None, Some([0., 2.]), array_flat!(f64, 2., -4., 3.)),
+)] fn test_convert(polynomial: Polynomial<f64>, kind: Option<&str>, domain: Option<[f64; 2]>, window: Option<[f64; 2]>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, rounded(polynomial.convert(kind, domain, window).map(|p| p.get_coef())))
+}
+
+#[rstest(
+kind, domain,
+case("power", None),
+case("chebyshev", None),
+case("legendre", Some([-2., 2.])),
+case("hermite", Some([0., 3.])),
+)] fn test_fit(kind: &str, domain: Option<[f64; 2]>) {
+    let expected = poly(vec![0.5, -1., 2., 0.25], kind, Some(domain.unwrap_or([-1., 3.])));
+    let x = Array::linspace(-1., 3., Some(12), None).unwrap();
+    let y = expected.eval(&x).unwrap();
+    let result = Polynomial::fit(&x, &y, 3, None, Some(kind), domain).unwrap();
+    assert_eq!(expected.get_domain(), result.get_domain());
+    assert_eq!(rounded(Ok(expected.get_coef())), rounded(Ok(result.get_coef())));
+}
@@ -0,0 +1,125 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+
+fn mean(array: &Array<f64>) -> f64 {
+    let elements = array.get_elements().unwrap();
+    elements.iter().sum::<f64>() / elements.len() as f64
+}
+
+fn variance(array: &Array<f64>) -> f64 {
+    let (elements, mean) = (array.get_elements().unwrap(), mean(array));
+    elements.iter().map(|i| (i - mean).powi(2)).sum::<f64>() / elements.len() as f64
+}
+
+fn to_f64(array: Result<Array<usize>, ArrayError>) -> Array<f64> {
+    array.unwrap().map(|&i| i as f64).unwrap()
+}
+
+#[rstest(
+seed,
+case(0),
+case(42),
+case(12345),
+)] fn test_reproducible(seed: u64) {
+    let (mut gen_1, mut gen_2) = (Generator::new(Some(seed)), Generator::new(Some(seed)));
+    assert_eq!(gen_1.random(vec![2, 3]), gen_2.random(vec![2, 3]));
+    assert_eq!(gen_1.standard_normal(vec![8]), gen_2.standard_normal(vec![8]));
+    assert_eq!(gen_1.integers(-5, 5, vec![8], None), gen_2.integers(-5, 5, vec![8], None));
+    assert_eq!(gen_1.gamma(0.5, 1., vec![8]), gen_2.gamma(0.5, 1., vec![8]));
+    assert_eq!(gen_1.poisson(50., vec![8]), gen_2.poisson(50., vec![8]));
+    assert_ne!(Generator::new(Some(seed)).random(vec![8]), Generator::new(Some(seed + 1)).random(vec![8]));
+}
+
+#[rstest(
+shape,
+case(vec![]),
+case(vec![5]),
+case(vec![2, 3]),
+case(vec![2, 0, 3]),
+)] fn test_shapes(shape: Vec<usize>) {
+    let mut generator = Generator::new(Some(42));
+    assert_eq!(shape, generator.random(shape.clone()).get_shape().unwrap());
+    assert_eq!(shape, generator.normal(0., 1., shape.clone()).get_shape().unwrap());
+    assert_eq!(shape, generator.binomial(10, 0.5, shape.clone()).get_shape().unwrap());
+}
+
+#[rstest(
+low, high, endpoint, expected,
+case(0, 3, None, vec![0, 1, 2]),
+case(-2, 1, Some(false), vec![-2, -1, 0]),
+case(-2, 1, Some(true), vec![-2, -1, 0, 1]),
+case(i64::MAX - 1, i64::MAX, Some(true), vec![i64::MAX - 1, i64::MAX]),
+)] fn test_integers(low: i64, high: i64, endpoint: Option<bool>, expected: Vec<i64>) {
+    let mut result = Generator::new(Some(42)).integers(low, high, vec![1000], endpoint).get_elements().unwrap();
+    result.sort_unstable();
+    result.dedup();
+    assert_eq!(expected, result);
+}
+
+#[rstest(
+array, expected_mean, expected_variance, tolerance,
+case(Generator::new(Some(42)).random(vec![10000]), 0.5, 1. / 12., 0.02),
+case(Generator::new(Some(42)).uniform(-2., 4., vec![10000]), 1., 3., 0.1),
+case(Generator::new(Some(42)).standard_normal(vec![10000]), 0., 1., 0.05),
+case(Generator::new(Some(42)).normal(5., 2., vec![10000]), 5., 4., 0.1),
+case(Generator::new(Some(42)).exponential(2., vec![10000]), 2., 4., 0.2),
+case(Generator::new(Some(42)).gamma(3., 2., vec![10000]), 6., 12., 0.5),
+case(Generator::new(Some(42)).gamma(0.5, 1., vec![10000]), 0.5, 0.5, 0.05),
+case(Generator::new(Some(42)).beta(2., 3., vec![10000]), 0.4, 0.04, 0.01),
+case(Generator::new(Some(42)).lognormal(0., 0.5, vec![10000]), 0.125_f64.exp(), (0.25_f64.exp() - 1.) * 0.25_f64.exp(), 0.05),
+case(Ok(to_f64(Generator::new(Some(42)).binomial(20, 0.3, vec![10000]))), 6., 4.2, 0.2),
+case(Ok(to_f64(Generator::new(Some(42)).binomial(1000, 0.3, vec![10000]))), 300., 210., 10.),
+case(Ok(to_f64(Generator::new(Some(42)).poisson(4., vec![10000]))), 4., 4., 0.2),
+case(Ok(to_f64(Generator::new(Some(42)).poisson(100., vec![10000]))), 100., 100., 5.),
+)] fn test_moments(array: Result<Array<f64>, ArrayError>, expected_mean: f64, expected_variance: f64, tolerance: f64) {
+    let array = array.unwrap();
+    assert!((mean(&array) - expected_mean).abs() < tolerance);
+    assert!((variance(&array) - expected_variance).abs() < tolerance * expected_variance.max(1.));
+}
+
+#[rstest(
+n, pvals, shape, expected_shape,
+case(10, vec![0.2, 0.3, 0.5], vec![4], vec![4, 3]),
+case(100, vec![0.5, 0.5], vec![2, 2], vec![2, 2, 2]),
+case(7, vec![1.], vec![], vec![1]),
+)] fn test_multinomial(n: usize, pvals: Vec<f64>, shape: Vec<usize>, expected_shape: Vec<usize>) {
+    let result = Generator::new(Some(42)).multinomial(n, &Array::flat(pvals).unwrap(), shape).unwrap();
+    assert_eq!(expected_shape, result.get_shape().unwrap());
+    let totals = result.get_elements().unwrap()
+        .chunks(*expected_shape.last().unwrap())
+        .map(|chunk| chunk.iter().sum::<usize>())
+        .collect::<Vec<usize>>();
+    assert!(totals.iter().all(|&total| total == n));
+}
+
+#[rstest(
+result, expected,
+case(Generator::new(None).integers(3, 3, vec![2], None), Err(ArrayError::ParameterError { param: "`low`", message: "must be lower than `high`" })),
+case(Generator::new(None).integers(3, 3, vec![2], Some(true)), array![i64, 3, 3]),
+case(Generator::new(None).integers(i64::MAX, i64::MAX, vec![2], Some(true)), array![i64, i64::MAX, i64::MAX]),
+case(Generator::new(None).integers(i64::MIN, i64::MIN, vec![2], None), Err(ArrayError::ParameterError { param: "`low`", message: "must be lower than `high`" })),
+)] fn test_integers_errors(result: Result<Array<i64>, ArrayError>, expected: Result<Array<i64>, ArrayError>) {
+    assert_eq!(expected, result)
+}
+
+#[rstest(
+result, expected,
+case(Generator::new(None).normal(0., -1., vec![2]), Err(ArrayError::ParameterError { param: "`scale`", message: "must be non-negative" })),
+case(Generator::new(None).lognormal(0., -1., vec![2]), Err(ArrayError::ParameterError { param: "`sigma`", message: "must be non-negative" })),
+case(Generator::new(None).exponential(f64::NAN, vec![2]), Err(ArrayError::ParameterError { param: "`scale`", message: "must be non-negative" })),
+case(Generator::new(None).gamma(-1., 1., vec![2]), Err(ArrayError::ParameterError { param: "`k`", message: "must be non-negative" })),
+case(Generator::new(None).beta(0., 1., vec![2]), Err(ArrayError::ParameterError { param: "`a`", message: "must be positive" })),
+case(Generator::new(None).beta(1., 0., vec![2]), Err(ArrayError::ParameterError { param: "`b`", message: "must be positive" })),
+)] fn test_distributions_errors(result: Result<Array<f64>, ArrayError>, expected: Result<Array<f64>, ArrayError>) {
+    assert_eq!(expected, result)
+}
+
+#[rstest(
+result, expected,
+case(Generator::new(None).binomial(10, 1.5, vec![2]), Err(ArrayError::ParameterError { param: "`p`", message: "must be in range [0, 1]" })),
+case(Generator::new(None).binomial(10, 1., vec![2]), array![usize, 10, 10]),
+case(Generator::new(None).poisson(-1., vec![2]), Err(ArrayError::ParameterError { param: "`lam`", message: "must be non-negative" })),
+case(Generator::new(None).multinomial(10, &array![f64, 0.6, 0.6].unwrap(), vec![2]), Err(ArrayError::ParameterError { param: "`pvals`", message: "sum must not exceed 1" })),
+)] fn test_discrete_errors(result: Result<Array<usize>, ArrayError>, expected: Result<Array<usize>, ArrayError>) {
+    assert_eq!(expected, result)
+}
@@ -0,0 +1,2 @@
+mod distributions_test;
+mod permutations_test;
@@ -0,0 +1,82 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+
+fn sorted<T: ArrayElement + Ord>(array: &Array<T>) -> Vec<T> {
+    let mut elements = array.get_elements().unwrap();
+    elements.sort();
+    elements
+}
+
+#[rstest(
+array, axis,
+case(array_arange!(i32, 0, 9), None),
+case(array_arange!(i32, 0, 11).reshape(&[3, 4]), None),
+case(array_arange!(i32, 0, 11).reshape(&[3, 4]), Some(1)),
+case(array_arange!(i32, 0, 23).reshape(&[2, 3, 4]), Some(-1)),
+)] fn test_shuffle(array: Result<Array<i32>, ArrayError>, axis: Option<isize>) {
+    let array = array.unwrap();
+    let mut shuffled = array.clone();
+    Generator::new(Some(42)).shuffle(&mut shuffled, axis).unwrap();
+    assert_eq!(array.get_shape(), shuffled.get_shape());
+    assert_eq!(sorted(&array), sorted(&shuffled));
+
+    let mut expected = array.clone();
+    Generator::new(Some(42)).shuffle(&mut expected, axis).unwrap();
+    assert_eq!(expected, shuffled);
+}
+
+#[rstest(
+array, axis,
+case(array_arange!(i32, 0, 11).reshape(&[3, 4]), None),
+case(array_arange!(i32, 0, 11).reshape(&[3, 4]), Some(1)),
+)] fn test_permutation_keeps_subarrays(array: Result<Array<i32>, ArrayError>, axis: Option<isize>) {
+    let array = array.unwrap();
+    let axis = axis.unwrap_or(0);
+    let permuted = Generator::new(Some(42)).permutation(&array, Some(axis)).unwrap();
+    let mut expected = array.split_axis(axis as usize).unwrap()
+        .into_iter().map(|a| a.get_elements().unwrap()).collect::<Vec<_>>();
+    let mut result = permuted.split_axis(axis as usize).unwrap()
+        .into_iter().map(|a| a.get_elements().unwrap()).collect::<Vec<_>>();
+    expected.sort();
+    result.sort();
+    assert_eq!(expected, result);
+}
+
+#[rstest(
+array, axis, expected,
+case(array![i32, 1, 2, 3], Some(1), Err(ArrayError::AxisOutOfBounds)),
+case(array![i32, 1], None, array![i32, 1]),
+)] fn test_permutation(array: Result<Array<i32>, ArrayError>, axis: Option<isize>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, Generator::new(Some(42)).permutation(&array.unwrap(), axis))
+}
+
+#[rstest(
+array, shape, replace, p, expected,
+case(array![i32, 1, 2, 3], vec![4], Some(false), None, Err(ArrayError::ParameterError { param: "`shape`", message: "cannot take a larger sample than population when `replace` is false" })),
+case(array![i32, 1, 2, 3], vec![2], None, Some(array![f64, 0.5, 0.5]), Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(array![i32, 1, 2, 3], vec![2], None, Some(array![f64, 0.5, 0.6, -0.1]), Err(ArrayError::ParameterError { param: "`p`", message: "probabilities must be non-negative" })),
+case(array![i32, 1, 2, 3], vec![2], None, Some(array![f64, 0.5, 0.4, 0.]), Err(ArrayError::ParameterError { param: "`p`", message: "probabilities must sum to 1" })),
+case(array![i32, 1, 2, 3], vec![3], Some(false), Some(array![f64, 0.5, 0.5, 0.]), Err(ArrayError::ParameterError { param: "`p`", message: "fewer non-zero entries than sample size" })),
+case(array![i32, 1, 2, 3], vec![2, 2], None, Some(array![f64, 0., 1., 0.]), array!(i32, [[2, 2], [2, 2]])),
+case(array![i32, 1, 2, 3], vec![2], Some(false), Some(array![f64, 0.5, 0., 0.5]), Ok(Array::flat(vec![1, 3]).unwrap())),
+case(array!(i32, [[1, 2], [3, 4]]), vec![2], None, None, Err(ArrayError::UnsupportedDimension { supported: vec![1] })),
+)] fn test_choice(array: Result<Array<i32>, ArrayError>, shape: Vec<usize>, replace: Option<bool>, p: Option<Result<Array<f64>, ArrayError>>, expected: Result<Array<i32>, ArrayError>) {
+    let result = Generator::new(Some(42)).choice(&array.unwrap(), shape, replace, p.map(Result::unwrap));
+    if replace == Some(false) && result.is_ok() {
+        assert_eq!(sorted(&expected.unwrap()), sorted(&result.unwrap()));
+    } else {
+        assert_eq!(expected, result);
+    }
+}
+
+#[rstest(
+size, replace,
+case(10, Some(false)),
+case(1000, None),
+)] fn test_choice_population(size: usize, replace: Option<bool>) {
+    let array = array_arange!(i32, 0, 9).unwrap();
+    let result = Generator::new(Some(42)).choice(&array, vec![size], replace, None).unwrap();
+    let mut elements = sorted(&result);
+    elements.dedup();
+    assert_eq!(array.get_elements().unwrap(), elements);
+}
@@ -0,0 +1 @@
+mod sparse_matrix_test;
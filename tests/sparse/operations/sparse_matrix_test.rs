@@ -0,0 +1,165 @@
+use rstest::rstest;
+use arr_rs::prelude::*;
+
+fn csr(array: Result<Array<i32>, ArrayError>) -> CsrMatrix<i32> {
+    CsrMatrix::from_array(&array.unwrap()).unwrap()
+}
+
+#[rstest(
+rows, cols, data, shape, expected,
+case(vec![0, 1, 1], vec![1, 0, 1], vec![2, 3, 4], vec![2, 2], array!(i32, [[0, 2], [3, 4]])),
+case(vec![1, 0, 1, 1], vec![0, 1, 0, 1], vec![3, 2, 1, 0], vec![2, 3], array!(i32, [[0, 2, 0], [4, 0, 0]])),
+case(vec![], vec![], vec![], vec![2, 1], array!(i32, [[0], [0]])),
+case(vec![0, 2], vec![0, 0], vec![1, 2], vec![2, 2], Err(ArrayError::OutOfBounds { value: "index" })),
+case(vec![0, 1], vec![0, 0], vec![1], vec![2, 2], Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "1".to_string() })),
+case(vec![0], vec![0], vec![1], vec![2, 2, 2], Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "2".to_string() })),
+)] fn test_sparse_coo_new(rows: Vec<usize>, cols: Vec<usize>, data: Vec<i32>, shape: Vec<usize>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, CooMatrix::new(&rows, &cols, &data, &shape).to_array())
+}
+
+#[test]
+fn test_sparse_coo_push() {
+    let mut coo = CooMatrix::empty(&[3, 3]).unwrap();
+    for idx in 0..3 {
+        coo.push(idx, idx, 2).unwrap();
+        if idx > 0 { coo.push(idx, idx - 1, -1).unwrap(); }
+        if idx < 2 { coo.push(idx, idx + 1, -1).unwrap(); }
+    }
+    coo.push(1, 1, 1).unwrap();
+    assert_eq!(8, coo.nnz().unwrap());
+    assert_eq!(7, coo.to_csr().nnz().unwrap());
+    assert_eq!(array!(i32, [[2, -1, 0], [-1, 3, -1], [0, -1, 2]]), coo.to_array());
+    assert_eq!(Err(ArrayError::OutOfBounds { value: "index" }), coo.push(0, 3, 1));
+}
+
+#[rstest(
+indptr, indices, data, shape, expected,
+case(vec![0, 1, 3], vec![1, 0, 1], vec![2, 3, 4], vec![2, 2], array!(i32, [[0, 2], [3, 4]])),
+case(vec![0, 2, 3], vec![1, 1, 0], vec![2, 3, 4], vec![2, 2], array!(i32, [[0, 5], [4, 0]])),
+case(vec![0, 2, 2], vec![1, 0], vec![2, 0], vec![2, 2], array!(i32, [[0, 2], [0, 0]])),
+case(vec![0, 1], vec![1, 0], vec![2, 3], vec![2, 2], Err(ArrayError::MustBeEqual { value1: "2".to_string(), value2: "3".to_string() })),
+case(vec![0, 2, 1], vec![1, 0], vec![2, 3], vec![2, 2], Err(ArrayError::ParameterError { param: "`indptr`", message: "must be non-decreasing, starting at zero and ending at the number of entries" })),
+case(vec![0, 1, 2], vec![1, 2], vec![2, 3], vec![2, 2], Err(ArrayError::OutOfBounds { value: "index" })),
+)] fn test_sparse_csr_new(indptr: Vec<usize>, indices: Vec<usize>, data: Vec<i32>, shape: Vec<usize>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, CsrMatrix::new(&indptr, &indices, &data, &shape).to_array())
+}
+
+#[rstest(
+indptr, indices, data, shape, expected,
+case(vec![0, 1, 3], vec![1, 0, 1], vec![3, 2, 4], vec![2, 2], array!(i32, [[0, 2], [3, 4]])),
+case(vec![0, 1, 1, 2], vec![0, 1], vec![1, 5], vec![2, 3], array!(i32, [[1, 0, 0], [0, 0, 5]])),
+case(vec![0, 1, 3], vec![1, 0, 1], vec![3, 2, 4], vec![3, 2], array!(i32, [[0, 2], [3, 4], [0, 0]])),
+case(vec![0, 1, 3], vec![1, 0, 1], vec![3, 2, 4], vec![2, 3], Err(ArrayError::MustBeEqual { value1: "3".to_string(), value2: "4".to_string() })),
+)] fn test_sparse_csc_new(indptr: Vec<usize>, indices: Vec<usize>, data: Vec<i32>, shape: Vec<usize>, expected: Result<Array<i32>, ArrayError>) {
+    assert_eq!(expected, CscMatrix::new(&indptr, &indices, &data, &shape).to_array())
+}
+
+#[rstest(
+array, expected,
+case(array!(i32, [[0, 2, 0], [3, 0, 4]]), Ok((vec![0, 1, 3], vec![1, 0, 2], vec![2, 3, 4]))),
+case(array!(i32, [[0, 0], [0, 0]]), Ok((vec![0, 0, 0], vec![], vec![]))),
+case(array_flat!(i32, 1, 2), Err(ArrayError::UnsupportedDimension { supported: vec![2] })),
+)] fn test_sparse_from_array(array: Result<Array<i32>, ArrayError>, expected: Result<(Vec<usize>, Vec<usize>, Vec<i32>), ArrayError>) {
+    let array = array.unwrap();
+    let csr = CsrMatrix::from_array(&array);
+    assert_eq!(expected, csr.clone().map(|m| (m.get_indptr(), m.get_indices(), m.get_data())));
+    if expected.is_ok() {
+        assert_eq!(Ok(array.clone()), csr.to_array());
+        assert_eq!(Ok(array.clone()), CscMatrix::from_array(&array).to_array());
+        assert_eq!(Ok(array.clone()), CooMatrix::from_array(&array).to_array());
+        assert_eq!(CscMatrix::from_array(&array), csr.to_csc());
+        assert_eq!(csr, CscMatrix::from_array(&array).to_csr());
+        assert_eq!(CooMatrix::from_array(&array), csr.to_coo());
+    }
+}
+
+#[rstest(
+array, expected,
+case(array!(i32, [[1, 2, 0], [0, 0, 3]]), array!(i32, [[1, 0], [2, 0], [0, 3]])),
+case(array!(i32, [[0, 0], [5, 0]]), array!(i32, [[0, 5], [0, 0]])),
+)] fn test_sparse_transpose(array: Result<Array<i32>, ArrayError>, expected: Result<Array<i32>, ArrayError>) {
+    let array = array.unwrap();
+    assert_eq!(expected, CsrMatrix::from_array(&array).transpose().to_array());
+    assert_eq!(expected, CscMatrix::from_array(&array).transpose().to_array());
+    assert_eq!(expected, CooMatrix::from_array(&array).transpose().to_array());
+}
+
+#[rstest(
+array, other, expected,
+case(array!(i32, [[1, 0], [0, 2]]), array_flat!(i32, 3, 4), array_flat!(i32, 3, 8)),
+case(array!(i32, [[1, 0, 2], [0, 3, 0]]), array!(i32, [[1, 2], [3, 4], [5, 6]]), array!(i32, [[11, 14], [9, 12]])),
+case(array!(i32, [[0, 0], [0, 0]]), array!(i32, [[1, 2], [3, 4]]), array!(i32, [[0, 0], [0, 0]])),
+case(array!(i32, [[1, 0], [0, 2]]), array_flat!(i32, 3, 4, 5), Err(ArrayError::ParameterError { param: "`shapes`", message: "are not aligned" })),
+case(array!(i32, [[1, 0], [0, 2]]), array_arange!(i32, 1, 8).reshape(&[2, 2, 2]), Err(ArrayError::UnsupportedDimension { supported: vec![1, 2] })),
+)] fn test_sparse_matmul_dense(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, expected: Result<Array<i32>, ArrayError>) {
+    let (array, other) = (array.unwrap(), other.unwrap());
+    assert_eq!(expected, csr(Ok(array.clone())).matmul_dense(&other));
+    assert_eq!(expected, CscMatrix::from_array(&array).matmul_dense(&other));
+}
+
+#[rstest(
+array, other, expected,
+case(array!(i32, [[1, 2], [0, 1]]), array!(i32, [[1, -2], [0, 1]]), array!(i32, [[1, 0], [0, 1]])),
+case(array!(i32, [[1, 0, 2], [0, 3, 0]]), array!(i32, [[1, 2], [3, 4], [5, 6]]), array!(i32, [[11, 14], [9, 12]])),
+case(array!(i32, [[0, 1], [1, 0]]), array!(i32, [[0, 2, 0], [3, 0, 0]]), array!(i32, [[3, 0, 0], [0, 2, 0]])),
+case(array!(i32, [[1, 2], [3, 4]]), array!(i32, [[1, 2, 3]]), Err(ArrayError::ParameterError { param: "`shapes`", message: "are not aligned" })),
+)] fn test_sparse_matmul(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, expected: Result<Array<i32>, ArrayError>) {
+    let (array, other) = (array.unwrap(), other.unwrap());
+    assert_eq!(expected, csr(Ok(array.clone())).matmul(&csr(Ok(other.clone()))).to_array());
+    assert_eq!(expected, CscMatrix::from_array(&array).matmul(&CscMatrix::from_array(&other).unwrap()).to_array());
+    assert_eq!(expected, CooMatrix::from_array(&array).matmul(&CooMatrix::from_array(&other).unwrap()).to_array());
+    if let Ok(expected) = expected {
+        assert_eq!(Ok(expected.get_elements().unwrap().iter().filter(|&&v| v != 0).count()), csr(Ok(array)).matmul(&csr(Ok(other))).nnz());
+    }
+}
+
+#[rstest(
+array, other, expected_add, expected_subtract, expected_multiply,
+case(array!(i32, [[1, 0], [0, 2]]), array!(i32, [[0, 3], [0, 2]]), array!(i32, [[1, 3], [0, 4]]), array!(i32, [[1, -3], [0, 0]]), array!(i32, [[0, 0], [0, 4]])),
+case(array!(i32, [[1, 2, 3]]), array!(i32, [[-1, 0, 3]]), array!(i32, [[0, 2, 6]]), array!(i32, [[2, 2, 0]]), array!(i32, [[-1, 0, 9]])),
+case(array!(i32, [[1, 2]]), array!(i32, [[1], [2]]), Err(ArrayError::ShapesMustMatch { shape_1: vec![1, 2], shape_2: vec![2, 1] }), Err(ArrayError::ShapesMustMatch { shape_1: vec![1, 2], shape_2: vec![2, 1] }), Err(ArrayError::ShapesMustMatch { shape_1: vec![1, 2], shape_2: vec![2, 1] })),
+)] fn test_sparse_elementwise(array: Result<Array<i32>, ArrayError>, other: Result<Array<i32>, ArrayError>, expected_add: Result<Array<i32>, ArrayError>, expected_subtract: Result<Array<i32>, ArrayError>, expected_multiply: Result<Array<i32>, ArrayError>) {
+    let (a, b) = (csr(array), csr(other));
+    assert_eq!(expected_add, a.add(&b).to_array());
+    assert_eq!(expected_subtract, a.subtract(&b).to_array());
+    assert_eq!(expected_multiply, a.multiply(&b).to_array());
+    assert_eq!(expected_add, a.to_coo().add(&b.to_coo().unwrap()).to_array());
+    assert_eq!(expected_subtract, a.to_csc().subtract(&b.to_csc().unwrap()).to_array());
+    if let Ok(expected) = expected_subtract {
+        assert_eq!(Ok(expected.get_elements().unwrap().iter().filter(|&&v| v != 0).count()), a.subtract(&b).nnz());
+    }
+}
+
+#[rstest(
+array, value, expected,
+case(array!(i32, [[1, 0], [0, 2]]), 3, array!(i32, [[3, 0], [0, 6]])),
+case(array!(i32, [[1, 0], [0, 2]]), 0, array!(i32, [[0, 0], [0, 0]])),
+)] fn test_sparse_scale(array: Result<Array<i32>, ArrayError>, value: i32, expected: Result<Array<i32>, ArrayError>) {
+    let matrix = csr(array);
+    assert_eq!(expected, matrix.scale(value).to_array());
+    assert_eq!(expected, matrix.to_coo().scale(value).to_array());
+}
+
+#[rstest(
+array, rows, expected,
+case(array!(i32, [[1, 0], [0, 2], [3, 0]]), 1..3, array!(i32, [[0, 2], [3, 0]])),
+case(array!(i32, [[1, 0], [0, 2], [3, 0]]), 0..1, array!(i32, [[1, 0]])),
+case(array!(i32, [[1, 0], [0, 2], [3, 0]]), 1..4, Err(ArrayError::OutOfBounds { value: "rows" })),
+)] fn test_sparse_slice_rows(array: Result<Array<i32>, ArrayError>, rows: std::ops::Range<usize>, expected: Result<Array<i32>, ArrayError>) {
+    let array = array.unwrap();
+    assert_eq!(expected, CsrMatrix::from_array(&array).slice_rows(rows.clone()).to_array());
+    assert_eq!(expected, CscMatrix::from_array(&array).slice_rows(rows).to_array());
+}
+
+#[rstest(
+array, axis, expected,
+case(array!(i32, [[1, 0, 2], [3, 4, 0]]), None, array_single!(i32, 10)),
+case(array!(i32, [[1, 0, 2], [3, 4, 0]]), Some(0), array_flat!(i32, 4, 4, 2)),
+case(array!(i32, [[1, 0, 2], [3, 4, 0]]), Some(1), array_flat!(i32, 3, 7)),
+case(array!(i32, [[1, 0, 2], [3, 4, 0]]), Some(-1), array_flat!(i32, 3, 7)),
+case(array!(i32, [[1, 0, 2], [3, 4, 0]]), Some(2), Err(ArrayError::AxisOutOfBounds)),
+)] fn test_sparse_sum(array: Result<Array<i32>, ArrayError>, axis: Option<isize>, expected: Result<Array<i32>, ArrayError>) {
+    let array = array.unwrap();
+    assert_eq!(expected, CsrMatrix::from_array(&array).sum(axis));
+    assert_eq!(expected, CooMatrix::from_array(&array).sum(axis));
+}